@@ -167,6 +167,10 @@ impl<'b, B: BlackBoxFunctionSolver<F>, F: AcirField> BrilligSolver<'b, F, B> {
         self.vm.get_fuzzing_trace()
     }
 
+    pub(crate) fn with_max_call_depth(&mut self, max_call_depth: Option<usize>) {
+        self.vm.with_max_call_depth(max_call_depth);
+    }
+
     pub(crate) fn solve(&mut self) -> Result<BrilligSolverStatus<F>, OpcodeResolutionError<F>> {
         let status = self.vm.process_opcodes();
         self.handle_vm_status(status)
@@ -241,6 +241,16 @@ pub struct ACVM<'a, F: AcirField, B: BlackBoxFunctionSolver<F>> {
     brillig_branch_to_feature_map: Option<&'a BranchToFeatureMap>,
 
     brillig_fuzzing_trace: Option<Vec<u32>>,
+
+    // Whether we need to count the number of solving steps
+    step_counting_active: bool,
+
+    /// Number of ACIR opcodes solved plus the number of Brillig opcodes executed
+    /// by this ACVM process. Only maintained when step counting is active.
+    step_count: usize,
+
+    /// The maximum number of nested calls within a Brillig function
+    max_brillig_call_depth: Option<usize>,
 }
 
 impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>> ACVM<'a, F, B> {
@@ -271,6 +281,9 @@ impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>> ACVM<'a, F, B> {
             brillig_fuzzing_active: false,
             brillig_branch_to_feature_map: None,
             brillig_fuzzing_trace: None,
+            step_counting_active: false,
+            step_count: 0,
+            max_brillig_call_depth: None,
         }
     }
 
@@ -292,6 +305,23 @@ impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>> ACVM<'a, F, B> {
         self.brillig_fuzzing_trace.clone()
     }
 
+    // Enable step counting
+    pub fn with_step_counter(&mut self, step_counting_active: bool) {
+        self.step_counting_active = step_counting_active;
+    }
+
+    /// Limits the number of nested calls within each Brillig function, past which solving
+    /// the `BrilligCall` opcode fails.
+    pub fn with_max_brillig_call_depth(&mut self, max_brillig_call_depth: Option<usize>) {
+        self.max_brillig_call_depth = max_brillig_call_depth;
+    }
+
+    /// Returns the number of ACIR opcodes solved plus the number of Brillig opcodes executed
+    /// so far. This is always zero unless step counting was enabled with [`ACVM::with_step_counter`].
+    pub fn step_count(&self) -> usize {
+        self.step_count
+    }
+
     /// Returns a reference to the current state of the ACVM's [`WitnessMap`].
     ///
     /// Once execution has completed, the witness map can be extracted using [`ACVM::finalize`]
@@ -443,12 +473,29 @@ impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>> ACVM<'a, F, B> {
         self.handle_opcode_resolution(resolution)
     }
 
+    /// Skips the opcode at the instruction pointer without solving it, leaving its outputs
+    /// unassigned, and moves on to the next opcode.
+    ///
+    /// This allows solving as much of a circuit as possible when some of its inputs are unknown.
+    pub fn skip_opcode(&mut self) -> ACVMStatus<F> {
+        self.brillig_solver = None;
+        self.instruction_pointer += 1;
+        if self.instruction_pointer == self.opcodes.len() {
+            self.status(ACVMStatus::Solved)
+        } else {
+            self.status(ACVMStatus::InProgress)
+        }
+    }
+
     fn handle_opcode_resolution(
         &mut self,
         resolution: Result<(), OpcodeResolutionError<F>>,
     ) -> ACVMStatus<F> {
         match resolution {
             Ok(()) => {
+                if self.step_counting_active {
+                    self.step_count += 1;
+                }
                 self.instruction_pointer += 1;
                 if self.instruction_pointer == self.opcodes.len() {
                     self.status(ACVMStatus::Solved)
@@ -549,21 +596,31 @@ impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>> ACVM<'a, F, B> {
         // there will be a cached `BrilligSolver` to avoid recomputation.
         let mut solver: BrilligSolver<'_, F, B> = match self.brillig_solver.take() {
             Some(solver) => solver,
-            None => BrilligSolver::new_call(
-                &self.witness_map,
-                &self.block_solvers,
-                inputs,
-                &self.unconstrained_functions[id.as_usize()].bytecode,
-                self.backend,
-                self.instruction_pointer,
-                *id,
-                self.profiling_active,
-                self.brillig_branch_to_feature_map,
-            )?,
+            None => {
+                let mut solver = BrilligSolver::new_call(
+                    &self.witness_map,
+                    &self.block_solvers,
+                    inputs,
+                    &self.unconstrained_functions[id.as_usize()].bytecode,
+                    self.backend,
+                    self.instruction_pointer,
+                    *id,
+                    self.profiling_active,
+                    self.brillig_branch_to_feature_map,
+                )?;
+                solver.with_max_call_depth(self.max_brillig_call_depth);
+                solver
+            }
+        };
+
+        let result = if self.step_counting_active {
+            Self::solve_brillig_counting_steps(&mut solver, &mut self.step_count)
+        } else {
+            solver.solve()
         };
 
         // If we're fuzzing, we need to get the fuzzing trace on an error
-        let result = solver.solve().inspect_err(|_| {
+        let result = result.inspect_err(|_| {
             if self.brillig_fuzzing_active {
                 self.brillig_fuzzing_trace = Some(solver.get_fuzzing_trace());
             };
@@ -610,6 +667,20 @@ impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>> ACVM<'a, F, B> {
         }
     }
 
+    /// Runs the Brillig solver one opcode at a time, adding each executed opcode to `step_count`.
+    fn solve_brillig_counting_steps(
+        solver: &mut BrilligSolver<'_, F, B>,
+        step_count: &mut usize,
+    ) -> Result<BrilligSolverStatus<F>, OpcodeResolutionError<F>> {
+        loop {
+            let status = solver.step()?;
+            *step_count += 1;
+            if !matches!(status, BrilligSolverStatus::InProgress) {
+                return Ok(status);
+            }
+        }
+    }
+
     pub fn step_into_brillig(&mut self) -> StepResult<'a, F, B> {
         let Opcode::BrilligCall { id, inputs, outputs, predicate } =
             &self.opcodes[self.instruction_pointer]
@@ -646,7 +717,10 @@ impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>> ACVM<'a, F, B> {
             self.brillig_branch_to_feature_map,
         );
         match solver {
-            Ok(solver) => StepResult::IntoBrillig(solver),
+            Ok(mut solver) => {
+                solver.with_max_call_depth(self.max_brillig_call_depth);
+                StepResult::IntoBrillig(solver)
+            }
             Err(..) => StepResult::Status(self.handle_opcode_resolution(solver.map(|_| ()))),
         }
     }
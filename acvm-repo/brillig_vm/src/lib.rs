@@ -136,6 +136,9 @@ pub struct VM<'a, F, B: BlackBoxFunctionSolver<F>> {
 
     // Branch to feature map for fuzzing
     branch_to_feature_map: BranchToFeatureMap,
+
+    /// The maximum number of nested calls, past which execution fails
+    max_call_depth: Option<usize>,
 }
 
 impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>> VM<'a, F, B> {
@@ -172,9 +175,16 @@ impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>> VM<'a, F, B> {
             fuzzing_active,
             fuzzer_trace,
             branch_to_feature_map,
+            max_call_depth: None,
         }
     }
 
+    /// Limits the number of nested calls, so that deeply recursive code fails with an error
+    /// once the limit is reached rather than exhausting the resources of the host.
+    pub fn with_max_call_depth(&mut self, max_call_depth: Option<usize>) {
+        self.max_call_depth = max_call_depth;
+    }
+
     pub fn is_profiling_active(&self) -> bool {
         self.profiling_active
     }
@@ -566,6 +576,12 @@ impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>> VM<'a, F, B> {
                 self.increment_program_counter()
             }
             Opcode::Call { location } => {
+                if let Some(max_call_depth) = self.max_call_depth {
+                    if self.call_stack.len() >= max_call_depth {
+                        return self
+                            .fail(format!("Exceeded the maximum call depth of {max_call_depth}"));
+                    }
+                }
                 // Push a return location
                 self.call_stack.push(self.program_counter);
                 self.set_program_counter(*location)
@@ -1840,6 +1856,27 @@ mod tests {
         assert_eq!(memory, expected);
     }
 
+    #[test]
+    fn fails_when_exceeding_max_call_depth() {
+        // A function which unconditionally calls itself
+        let opcodes: [Opcode<FieldElement>; 2] = [Opcode::Call { location: 0 }, Opcode::Return {}];
+
+        let solver = StubbedBlackBoxSolver::default();
+        let mut vm = VM::new(vec![], &opcodes, &solver, false, None);
+        vm.with_max_call_depth(Some(100));
+
+        let status = vm.process_opcodes();
+        assert_eq!(
+            status,
+            VMStatus::Failure {
+                reason: FailureReason::RuntimeError {
+                    message: "Exceeded the maximum call depth of 100".to_string(),
+                },
+                call_stack: vec![0; 101],
+            }
+        );
+    }
+
     /// Helper to execute brillig code
     fn brillig_execute_and_get_vm<'a, F: AcirField>(
         calldata: Vec<F>,
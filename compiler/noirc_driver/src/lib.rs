@@ -14,17 +14,17 @@ use noirc_errors::{CustomDiagnostic, DiagnosticKind};
 use noirc_evaluator::brillig::BrilligOptions;
 use noirc_evaluator::create_program;
 use noirc_evaluator::errors::RuntimeError;
-use noirc_evaluator::ssa::{SsaLogging, SsaProgramArtifact};
+use noirc_evaluator::ssa::{InliningPolicy, SsaEvaluatorOptions, SsaLogging, SsaProgramArtifact};
 use noirc_frontend::debug::build_debug_crate_file;
-use noirc_frontend::elaborator::{FrontendOptions, UnstableFeature};
+use noirc_frontend::elaborator::{DEFAULT_PATTERN_DEPTH_LIMIT, FrontendOptions, UnstableFeature};
 use noirc_frontend::hir::Context;
 use noirc_frontend::hir::def_map::{CrateDefMap, ModuleDefId, ModuleId};
 use noirc_frontend::monomorphization::{
-    errors::MonomorphizationError, monomorphize, monomorphize_debug,
+    ast::Program, errors::MonomorphizationError, monomorphize, monomorphize_debug,
 };
 use noirc_frontend::node_interner::{FuncId, GlobalId, TypeId};
 use noirc_frontend::token::SecondaryAttribute;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 use tracing::info;
 
@@ -32,14 +32,20 @@ mod abi_gen;
 mod contract;
 mod debug;
 mod program;
+mod source_map;
 mod stdlib;
 
 use debug::filter_relevant_files;
 
 pub use contract::{CompiledContract, CompiledContractOutputs, ContractFunction};
 pub use debug::DebugFile;
+pub use noirc_evaluator::ssa::{
+    FunctionOptimizationSummary, LoopOptimization, LoopReport, NotHoistedInstruction,
+    NotHoistedReason, OptimizationSummary, PassStatistics, SsaPassOutput, UnknownSsaPass,
+};
 pub use noirc_frontend::graph::{CrateId, CrateName};
 pub use program::CompiledProgram;
+pub use source_map::SourceMap;
 
 const STD_CRATE_NAME: &str = "std";
 const DEBUG_CRATE_NAME: &str = "__debug";
@@ -99,6 +105,10 @@ pub struct CompileOptions {
     #[arg(long, hide = true)]
     pub benchmark_codegen: bool,
 
+    /// Print a table of the time spent in each SSA pass, sorted from the slowest pass
+    #[arg(long, hide = true)]
+    pub benchmark_ssa_passes: bool,
+
     /// Treat all warnings as errors
     #[arg(long, conflicts_with = "silence_warnings")]
     pub deny_warnings: bool,
@@ -148,6 +158,90 @@ pub struct CompileOptions {
     #[arg(long)]
     pub count_array_copies: bool,
 
+    /// Record statistics about the effect of SSA optimization passes in the compiled artifact
+    #[arg(long, hide = true)]
+    pub pass_statistics: bool,
+
+    /// Print a JSON summary of the effect of the SSA optimization passes on each function
+    #[arg(long, hide = true)]
+    pub optimization_summary: bool,
+
+    /// Record a report of how each loop was optimized in the compiled artifact: whether it was
+    /// unrolled, how many instructions were hoisted out of it and why loop invariant
+    /// instructions were left in it
+    #[arg(long)]
+    pub loop_report: bool,
+
+    /// Include a source map linking each ACIR opcode to its source locations in the compiled artifact
+    #[arg(long)]
+    pub source_map: bool,
+
+    /// Warn about assertions in loops which the loop bounds prove to always hold or to never be executed
+    #[arg(long)]
+    pub warn_loop_assertions: bool,
+
+    /// Report assertions which always fail as compile-time errors instead of leaving them to
+    /// fail at runtime. Only assertions which run every time their function is called are checked.
+    #[arg(long)]
+    pub deny_failing_assertions: bool,
+
+    /// Keep the overflow checks on all Brillig arithmetic, instead of turning operations which
+    /// the loop bounds prove cannot overflow into unchecked ones.
+    #[arg(long)]
+    pub brillig_keep_overflow_checks: bool,
+
+    /// Perform Brillig arithmetic derived from loop counters in the smallest unsigned type which
+    /// the loop bounds prove it fits in. This adds casts around the narrowed operations.
+    #[arg(long)]
+    pub narrow_integer_types: bool,
+
+    /// Combine a lower and an upper bound check on the same value into a single range check.
+    #[arg(long)]
+    pub combine_bound_checks: bool,
+
+    /// Remove casts of values to their own type which loop invariant code motion leaves behind.
+    #[arg(long)]
+    pub remove_identity_casts: bool,
+
+    /// Remove stores of a value which was just loaded from the same reference.
+    #[arg(long)]
+    pub remove_self_assigning_stores: bool,
+
+    /// Remove block parameters which are passed the same constant by every jump to their block.
+    #[arg(long)]
+    pub simplify_constant_block_params: bool,
+
+    /// Convert self-recursive tail calls in Brillig functions into loops, so that they don't grow
+    /// the call stack and can be optimized like any other loop.
+    #[arg(long)]
+    pub tail_call_to_loop: bool,
+
+    /// Specialize functions which are always called with the same constant arguments, calling a
+    /// clone of the function with those arguments baked in instead.
+    #[arg(long)]
+    pub specialize_constant_arguments: bool,
+
+    /// Reassociate arithmetic in loops so that loop invariant operands are combined first, letting
+    /// them be hoisted out of the loop.
+    #[arg(long)]
+    pub reassociate_loop_invariants: bool,
+
+    /// Keep the values of local variables with the given name from being removed by
+    /// optimizations, so that they can be inspected in the optimized SSA. May be repeated.
+    #[arg(long)]
+    pub observe: Vec<String>,
+
+    /// Fail compilation when a constraint only involves values returned by unconstrained
+    /// functions, none of which are constrained together with any other value of the circuit.
+    /// This is a heuristic check for under-constrained programs.
+    #[arg(long)]
+    pub deny_unconstrained_constraints: bool,
+
+    /// Warn about inputs of `main`, or of a contract's entry points, which are not used in any
+    /// constraint or return value, so that any value can be given for them.
+    #[arg(long)]
+    pub warn_ineffective_inputs: bool,
+
     /// Flag to turn on the lookback feature of the Brillig call constraints
     /// check, allowing tracking argument values before the call happens preventing
     /// certain rare false positives (leads to a slowdown on large rollout functions)
@@ -168,6 +262,12 @@ pub struct CompileOptions {
     #[arg(long, hide = true, allow_hyphen_values = true)]
     pub max_bytecode_increase_percent: Option<i32>,
 
+    /// Inline `#[fold]` functions with at most this many SSA instructions into their callers
+    /// instead of keeping them as separate circuits.
+    /// This is useful for contracts where the overhead of calling small circuits is significant.
+    #[arg(long, hide = true)]
+    pub max_inlined_fold_instructions: Option<usize>,
+
     /// Use pedantic ACVM solving, i.e. double-check some black-box function
     /// assumptions when solving.
     /// This is disabled by default.
@@ -183,6 +283,17 @@ pub struct CompileOptions {
     #[clap(long, short = 'Z', value_delimiter = ',')]
     pub unstable_features: Vec<UnstableFeature>,
 
+    /// Features enabling the items of the compiled package marked `#[cfg(feature = "...")]`
+    /// with their name. Items gated on any other feature are left out of the program, and
+    /// dependencies are compiled without any feature.
+    #[arg(long, value_delimiter = ',')]
+    pub features: Vec<String>,
+
+    /// Maximum nesting depth of patterns such as `let ((a, b), c) = ...`.
+    /// Deeper patterns are reported as errors.
+    #[arg(long, hide = true)]
+    pub pattern_depth_limit: Option<usize>,
+
     /// Used internally to avoid comptime println from producing output
     #[arg(long, hide = true)]
     pub disable_comptime_printing: bool,
@@ -210,6 +321,8 @@ impl CompileOptions {
             debug_comptime_in_file: self.debug_comptime_in_file.as_deref(),
             pedantic_solving: self.pedantic_solving,
             enabled_unstable_features: &self.unstable_features,
+            pattern_depth_limit: self.pattern_depth_limit.unwrap_or(DEFAULT_PATTERN_DEPTH_LIMIT),
+            enabled_features: &self.features,
         }
     }
 }
@@ -690,18 +803,7 @@ pub fn compile_no_check(
     cached_program: Option<CompiledProgram>,
     force_compile: bool,
 ) -> Result<CompiledProgram, CompileError> {
-    let force_unconstrained = options.force_brillig;
-
-    let program = if options.instrument_debug {
-        monomorphize_debug(
-            main_function,
-            &mut context.def_interner,
-            &context.debug_instrumenter,
-            force_unconstrained,
-        )?
-    } else {
-        monomorphize(main_function, &mut context.def_interner, force_unconstrained)?
-    };
+    let program = monomorphize_main(context, options, main_function)?;
 
     if options.show_monomorphized {
         println!("{program}");
@@ -715,7 +817,26 @@ pub fn compile_no_check(
         || options.force_brillig
         || options.show_ssa
         || options.show_ssa_pass.is_some()
-        || options.emit_ssa;
+        || options.emit_ssa
+        || options.pass_statistics
+        || options.optimization_summary
+        || options.loop_report
+        || options.benchmark_ssa_passes
+        || options.source_map
+        || options.warn_loop_assertions
+        || options.deny_failing_assertions
+        || options.brillig_keep_overflow_checks
+        || options.warn_ineffective_inputs
+        || options.deny_unconstrained_constraints
+        || options.narrow_integer_types
+        || options.remove_identity_casts
+        || options.remove_self_assigning_stores
+        || options.simplify_constant_block_params
+        || options.tail_call_to_loop
+        || options.specialize_constant_arguments
+        || options.combine_bound_checks
+        || options.reassociate_loop_invariants
+        || !options.observe.is_empty();
 
     // Hash the AST program, which is going to be used to fingerprint the compilation artifact.
     let hash = fxhash::hash64(&program);
@@ -728,7 +849,117 @@ pub fn compile_no_check(
     }
 
     let return_visibility = program.return_visibility;
-    let ssa_evaluator_options = noirc_evaluator::ssa::SsaEvaluatorOptions {
+    let ssa_evaluator_options = ssa_evaluator_options(context, options);
+
+    let SsaProgramArtifact {
+        program,
+        debug,
+        warnings,
+        names,
+        brillig_names,
+        error_types,
+        pass_statistics,
+        loop_report,
+        ..
+    } = create_program(program, &ssa_evaluator_options)?;
+
+    if options.optimization_summary {
+        if let Some(pass_statistics) = &pass_statistics {
+            println!("{}", OptimizationSummary::from(pass_statistics).to_json());
+        }
+    }
+    let pass_statistics = pass_statistics.filter(|_| options.pass_statistics);
+
+    let abi = abi_gen::gen_abi(context, &main_function, return_visibility, error_types);
+    let file_map = filter_relevant_files(&debug, &context.file_manager);
+    let source_map = options.source_map.then(|| SourceMap::new(&program, &debug));
+
+    Ok(CompiledProgram {
+        hash,
+        program,
+        debug,
+        abi,
+        file_map,
+        noir_version: NOIR_ARTIFACT_VERSION_STRING.to_string(),
+        warnings,
+        names,
+        brillig_names,
+        pass_statistics,
+        loop_report,
+        source_map,
+    })
+}
+
+/// Estimates the number of witnesses needed by each ACIR function of the program using
+/// `main_function` as the entrypoint, keyed by function name, without generating ACIR.
+///
+/// The estimate is computed from the optimized SSA of the program and may differ significantly
+/// from the witness count of the compiled program.
+///
+/// This function assumes [`check_crate`] is called beforehand.
+pub fn estimate_witness_counts(
+    context: &mut Context,
+    options: &CompileOptions,
+    main_function: FuncId,
+) -> Result<BTreeMap<String, usize>, CompileError> {
+    let program = monomorphize_main(context, options, main_function)?;
+    let ssa_evaluator_options = ssa_evaluator_options(context, options);
+    Ok(noirc_evaluator::ssa::estimate_witness_counts(program, &ssa_evaluator_options)?)
+}
+
+/// Optimizes the program using `main_function` as the entrypoint into SSA, reporting for each
+/// loop whether it was unrolled, how many instructions were hoisted out of it and why loop
+/// invariant instructions were left in it.
+///
+/// This function assumes [`check_crate`] is called beforehand.
+pub fn loop_report(
+    context: &mut Context,
+    options: &CompileOptions,
+    main_function: FuncId,
+) -> Result<LoopReport, CompileError> {
+    let program = monomorphize_main(context, options, main_function)?;
+    let ssa_evaluator_options = ssa_evaluator_options(context, options);
+    Ok(noirc_evaluator::ssa::create_loop_report(program, &ssa_evaluator_options)?)
+}
+
+/// Generates the initial SSA of the program using `main_function` as the entrypoint and runs
+/// the SSA pass named `pass` on it, returning the SSA before and after the pass.
+/// The passes are named after the steps of the SSA pipeline, e.g. `Mem2Reg` or `Inlining`,
+/// and an [`UnknownSsaPass`] listing them is returned if none of them has that name.
+///
+/// This function assumes [`check_crate`] is called beforehand.
+pub fn run_ssa_pass(
+    context: &mut Context,
+    options: &CompileOptions,
+    main_function: FuncId,
+    pass: &str,
+) -> Result<Result<SsaPassOutput, UnknownSsaPass>, CompileError> {
+    let program = monomorphize_main(context, options, main_function)?;
+    let ssa_evaluator_options = ssa_evaluator_options(context, options);
+    Ok(noirc_evaluator::ssa::run_single_ssa_pass(program, &ssa_evaluator_options, pass)?)
+}
+
+fn monomorphize_main(
+    context: &mut Context,
+    options: &CompileOptions,
+    main_function: FuncId,
+) -> Result<Program, MonomorphizationError> {
+    let force_unconstrained = options.force_brillig;
+
+    if options.instrument_debug {
+        monomorphize_debug(
+            main_function,
+            &mut context.def_interner,
+            &context.debug_instrumenter,
+            force_unconstrained,
+        )
+    } else {
+        monomorphize(main_function, &mut context.def_interner, force_unconstrained)
+    }
+}
+
+fn ssa_evaluator_options(context: &Context, options: &CompileOptions) -> SsaEvaluatorOptions {
+    SsaEvaluatorOptions {
         ssa_logging: match &options.show_ssa_pass {
             Some(string) => SsaLogging::Contains(string.clone()),
             None => {
@@ -745,6 +976,7 @@ pub fn compile_no_check(
             enable_array_copy_counter: options.count_array_copies,
         },
         print_codegen_timings: options.benchmark_codegen,
+        print_pass_timings: options.benchmark_ssa_passes,
         expression_width: if options.bounded_codegen {
             options.expression_width.unwrap_or(DEFAULT_EXPRESSION_WIDTH)
         } else {
@@ -757,25 +989,26 @@ pub fn compile_no_check(
         skip_brillig_constraints_check: options.skip_brillig_constraints_check,
         inliner_aggressiveness: options.inliner_aggressiveness,
         max_bytecode_increase_percent: options.max_bytecode_increase_percent,
-    };
-
-    let SsaProgramArtifact { program, debug, warnings, names, brillig_names, error_types, .. } =
-        create_program(program, &ssa_evaluator_options)?;
-
-    let abi = abi_gen::gen_abi(context, &main_function, return_visibility, error_types);
-    let file_map = filter_relevant_files(&debug, &context.file_manager);
-
-    Ok(CompiledProgram {
-        hash,
-        program,
-        debug,
-        abi,
-        file_map,
-        noir_version: NOIR_ARTIFACT_VERSION_STRING.to_string(),
-        warnings,
-        names,
-        brillig_names,
-    })
+        inlining_policy: InliningPolicy {
+            max_fold_function_instructions: options.max_inlined_fold_instructions,
+        },
+        collect_pass_statistics: options.pass_statistics || options.optimization_summary,
+        collect_loop_report: options.loop_report,
+        warn_on_loop_assertions: options.warn_loop_assertions,
+        deny_failing_assertions: options.deny_failing_assertions,
+        brillig_keep_overflow_checks: options.brillig_keep_overflow_checks,
+        narrow_integer_types: options.narrow_integer_types,
+        combine_bound_checks: options.combine_bound_checks,
+        remove_identity_casts: options.remove_identity_casts,
+        remove_self_assigning_stores: options.remove_self_assigning_stores,
+        simplify_constant_block_params: options.simplify_constant_block_params,
+        tail_call_to_loop: options.tail_call_to_loop,
+        specialize_constant_arguments: options.specialize_constant_arguments,
+        reassociate_loop_invariants: options.reassociate_loop_invariants,
+        observed_variables: options.observe.clone(),
+        deny_unconstrained_constraints: options.deny_unconstrained_constraints,
+        warn_on_ineffective_inputs: options.warn_ineffective_inputs,
+    }
 }
 
 /// Specifies a contract function and extra metadata that
@@ -5,9 +5,11 @@ use fm::FileId;
 
 use noirc_errors::debug_info::DebugInfo;
 use noirc_evaluator::errors::SsaReport;
+use noirc_evaluator::ssa::{LoopReport, OptimizationSummary, PassStatistics};
 use serde::{Deserialize, Serialize};
 
 use super::debug::DebugFile;
+use super::source_map::SourceMap;
 
 #[derive(Debug, Serialize, Deserialize, Clone, Hash)]
 pub struct CompiledProgram {
@@ -31,4 +33,22 @@ pub struct CompiledProgram {
     pub names: Vec<String>,
     /// Names of the unconstrained functions in the program.
     pub brillig_names: Vec<String>,
+    /// Statistics about the SSA optimization passes, if they were requested.
+    #[serde(default)]
+    pub pass_statistics: Option<PassStatistics>,
+    /// How each loop was optimized, if it was requested.
+    #[serde(default)]
+    pub loop_report: Option<LoopReport>,
+    /// Source locations of each ACIR opcode, if they were requested.
+    #[serde(default)]
+    pub source_map: Option<SourceMap>,
+}
+
+impl CompiledProgram {
+    /// Summarizes the effect of the SSA optimization passes on each function, if pass
+    /// statistics were recorded. ACIR transformations, such as `transform_program`, leave
+    /// the summary unchanged.
+    pub fn optimization_summary(&self) -> Option<OptimizationSummary> {
+        self.pass_statistics.as_ref().map(OptimizationSummary::from)
+    }
 }
@@ -0,0 +1,54 @@
+use std::collections::BTreeMap;
+
+use acvm::{
+    FieldElement,
+    acir::circuit::{OpcodeLocation, Program},
+};
+use noirc_errors::{Location, debug_info::DebugInfo};
+use serde::{Deserialize, Serialize};
+
+/// Maps each ACIR opcode of a compiled program to the source locations it originates from,
+/// for debuggers and profilers which only need the opcode locations of the debug info.
+///
+/// Locations refer to files by [`FileId`][fm::FileId], which the program's file map resolves
+/// to a path and source code.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SourceMap {
+    /// For each ACIR function of the program, the call stack each of its opcodes originates
+    /// from, keyed by opcode index. The innermost location comes last.
+    ///
+    /// Opcodes without a known location are left out.
+    pub functions: Vec<BTreeMap<usize, Vec<Location>>>,
+}
+
+impl SourceMap {
+    /// Builds the source map of `program` from its debug info.
+    ///
+    /// The source map must be rebuilt whenever the program's opcodes are transformed.
+    pub fn new(program: &Program<FieldElement>, debug: &[DebugInfo]) -> Self {
+        let functions = program
+            .functions
+            .iter()
+            .zip(debug)
+            .map(|(circuit, debug_info)| {
+                (0..circuit.opcodes.len())
+                    .filter_map(|index| {
+                        let call_stack =
+                            debug_info.opcode_location(&OpcodeLocation::Acir(index))?;
+                        Some((index, call_stack))
+                    })
+                    .collect()
+            })
+            .collect();
+        SourceMap { functions }
+    }
+
+    /// Returns the call stack of source locations of the opcode at `opcode_index` in the ACIR
+    /// function at `function_index`.
+    pub fn opcode_locations(&self, function_index: usize, opcode_index: usize) -> &[Location] {
+        self.functions
+            .get(function_index)
+            .and_then(|opcodes| opcodes.get(&opcode_index))
+            .map_or(&[], Vec::as_slice)
+    }
+}
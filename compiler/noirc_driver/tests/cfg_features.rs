@@ -0,0 +1,93 @@
+mod common;
+
+use noirc_driver::CompileOptions;
+
+const PROGRAM_WITH_GATED_FUNCTION: &str = r#"
+    fn main(x: Field) -> pub Field {
+        x + 1
+    }
+
+    #[cfg(feature = "helpers")]
+    fn double(x: Field) -> Field {
+        x * 2
+    }
+    "#;
+
+/// Compiles the program with the given features and returns whether `double` was defined.
+fn compiles_with_double(features: Vec<String>) -> bool {
+    let (mut context, crate_id) = common::prepare_context(PROGRAM_WITH_GATED_FUNCTION);
+    let options = CompileOptions { features, ..CompileOptions::default() };
+    noirc_driver::compile_main(&mut context, crate_id, &options, None)
+        .expect("failed to compile program");
+
+    let def_map = context.def_map(&crate_id).expect("expected the crate to be collected");
+    def_map[def_map.root()].find_func_with_name(&"double".into()).is_some()
+}
+
+#[test]
+fn excludes_feature_gated_function_when_feature_is_disabled() {
+    assert!(!compiles_with_double(Vec::new()));
+    assert!(!compiles_with_double(vec!["other".to_string()]));
+}
+
+#[test]
+fn includes_feature_gated_function_when_feature_is_enabled() {
+    assert!(compiles_with_double(vec!["helpers".to_string()]));
+}
+
+/// Type checks the program with the given features, returning whether it had no errors.
+fn checks_with_features(source: &str, features: &[&str]) -> bool {
+    let (mut context, crate_id) = common::prepare_context(source);
+    let features = features.iter().map(|feature| feature.to_string()).collect();
+    let options = CompileOptions { features, ..CompileOptions::default() };
+    noirc_driver::check_crate(&mut context, crate_id, &options).is_ok()
+}
+
+#[test]
+fn excludes_feature_gated_trait_impl_methods_and_type_aliases() {
+    // The gated items refer to items which don't exist, so they fail to compile when enabled
+    let trait_impl_method = r#"
+    trait Answer {
+        fn answer() -> Field {
+            1
+        }
+    }
+
+    impl Answer for Field {
+        #[cfg(feature = "broken")]
+        fn answer() -> Field {
+            missing_function()
+        }
+    }
+
+    fn main() -> pub Field {
+        <Field as Answer>::answer()
+    }
+    "#;
+    assert!(checks_with_features(trait_impl_method, &[]));
+    assert!(!checks_with_features(trait_impl_method, &["broken"]));
+
+    let type_alias = r#"
+    #[cfg(feature = "broken")]
+    type Broken = MissingType;
+
+    fn main() {}
+    "#;
+    assert!(checks_with_features(type_alias, &[]));
+    assert!(!checks_with_features(type_alias, &["broken"]));
+}
+
+#[test]
+fn does_not_enable_features_of_dependencies() {
+    let dependency = r#"
+    #[cfg(feature = "broken")]
+    pub fn broken() -> Field {
+        missing_function()
+    }
+    "#;
+    let (mut context, crate_id) =
+        common::prepare_context_with_dependency("fn main() {}", dependency);
+    let options =
+        CompileOptions { features: vec!["broken".to_string()], ..CompileOptions::default() };
+    assert!(noirc_driver::check_crate(&mut context, crate_id, &options).is_ok());
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use noirc_driver::CompileOptions;
+
+#[test]
+fn check_crate_does_not_generate_code() {
+    // The failing static assertion is only evaluated once SSA is generated
+    let source = r#"
+    fn main() {
+        std::static_assert(false, "only reported by code generation");
+    }"#;
+
+    let (mut context, crate_id) = common::prepare_context(source);
+    let check_result =
+        noirc_driver::check_crate(&mut context, crate_id, &CompileOptions::default());
+    assert!(check_result.is_ok());
+
+    let (mut context, crate_id) = common::prepare_context(source);
+    let compile_errors =
+        noirc_driver::compile_main(&mut context, crate_id, &CompileOptions::default(), None)
+            .unwrap_err();
+    assert!(compile_errors.iter().any(|diagnostic| diagnostic.is_error()));
+}
+
+#[test]
+fn check_crate_warnings_are_a_prefix_of_compile_main_warnings() {
+    let source = "
+    fn main(x: Field) {
+        let unused = 1;
+        assert(x != 0);
+    }";
+
+    let (mut context, crate_id) = common::prepare_context(source);
+    let ((), check_warnings) =
+        noirc_driver::check_crate(&mut context, crate_id, &CompileOptions::default()).unwrap();
+
+    let (mut context, crate_id) = common::prepare_context(source);
+    let (_, compile_warnings) =
+        noirc_driver::compile_main(&mut context, crate_id, &CompileOptions::default(), None)
+            .unwrap();
+
+    assert!(!check_warnings.is_empty());
+    assert!(compile_warnings.starts_with(&check_warnings));
+}
@@ -0,0 +1,56 @@
+// Each test binary only uses some of these helpers
+#![allow(dead_code)]
+
+use std::path::Path;
+
+use fm::FileManager;
+use noirc_driver::{CrateId, add_dep, file_manager_with_stdlib, prepare_crate, prepare_dependency};
+use noirc_frontend::hir::{Context, ParsedFiles, def_map::parse_file};
+
+/// Prepare a `main.nr` crate with the given source, along with the standard library.
+pub fn prepare_context(source: &str) -> (Context<'static, 'static>, CrateId) {
+    let root = Path::new("");
+    let file_name = Path::new("main.nr");
+    let mut file_manager = file_manager_with_stdlib(root);
+    file_manager.add_file_with_source(file_name, source.to_owned()).expect(
+        "Adding source buffer to file manager should never fail when file manager is empty",
+    );
+    let parsed_files = parse_all(&file_manager);
+
+    let mut context = Context::new(file_manager, parsed_files);
+    let root_crate_id = prepare_crate(&mut context, file_name);
+    (context, root_crate_id)
+}
+
+/// Prepare a `main.nr` crate with the given source, depending on a crate named `dep` with
+/// `dependency_source` as its source, along with the standard library.
+pub fn prepare_context_with_dependency(
+    source: &str,
+    dependency_source: &str,
+) -> (Context<'static, 'static>, CrateId) {
+    let root = Path::new("");
+    let file_name = Path::new("main.nr");
+    let dependency_file_name = Path::new("dep/lib.nr");
+    let mut file_manager = file_manager_with_stdlib(root);
+    file_manager.add_file_with_source(file_name, source.to_owned()).expect(
+        "Adding source buffer to file manager should never fail when file manager is empty",
+    );
+    file_manager
+        .add_file_with_source(dependency_file_name, dependency_source.to_owned())
+        .expect("Adding the dependency's source buffer to the file manager should never fail");
+    let parsed_files = parse_all(&file_manager);
+
+    let mut context = Context::new(file_manager, parsed_files);
+    let root_crate_id = prepare_crate(&mut context, file_name);
+    let dependency_crate_id = prepare_dependency(&mut context, dependency_file_name);
+    add_dep(&mut context, root_crate_id, dependency_crate_id, "dep".parse().unwrap());
+    (context, root_crate_id)
+}
+
+fn parse_all(file_manager: &FileManager) -> ParsedFiles {
+    file_manager
+        .as_file_map()
+        .all_file_ids()
+        .map(|&file_id| (file_id, parse_file(file_manager, file_id)))
+        .collect()
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use noirc_driver::CompileOptions;
+
+const CONTRACT_WITH_INEFFECTIVE_INPUT: &str = "
+    contract Foo {
+        pub fn bar(x: Field, y: Field) -> pub Field {
+            // Safety: the hint is only printed, never constrained
+            let hint = unsafe { double(y) };
+            println(hint);
+            x + 1
+        }
+
+        unconstrained fn double(y: Field) -> Field {
+            y * 2
+        }
+    }";
+
+fn is_ineffective_input_warning(message: &str) -> bool {
+    message.ends_with("is not used in any constraint or return value")
+}
+
+#[test]
+fn warns_on_ineffective_contract_input() {
+    let (mut context, crate_id) = common::prepare_context(CONTRACT_WITH_INEFFECTIVE_INPUT);
+    let options = CompileOptions { warn_ineffective_inputs: true, ..CompileOptions::default() };
+    let (_, warnings) = noirc_driver::compile_contract(&mut context, crate_id, &options)
+        .expect("failed to compile contract");
+
+    let ineffective_inputs: Vec<_> = warnings
+        .iter()
+        .filter(|warning| is_ineffective_input_warning(&warning.message))
+        .map(|warning| warning.message.as_str())
+        .collect();
+    assert_eq!(
+        ineffective_inputs,
+        vec!["Input `y` is not used in any constraint or return value"],
+        "expected only `y` to be reported"
+    );
+}
+
+#[test]
+fn does_not_warn_on_ineffective_inputs_by_default() {
+    let (mut context, crate_id) = common::prepare_context(CONTRACT_WITH_INEFFECTIVE_INPUT);
+    let (_, warnings) =
+        noirc_driver::compile_contract(&mut context, crate_id, &CompileOptions::default())
+            .expect("failed to compile contract");
+
+    assert!(!warnings.iter().any(|warning| is_ineffective_input_warning(&warning.message)));
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use noirc_driver::CompileOptions;
+
+const SOURCE_WITH_ALWAYS_TRUE_ASSERTION: &str = "
+    fn main(x: u32) {
+        for i in 0..4 {
+            assert(i < 10);
+            assert(i != x);
+        }
+    }";
+
+#[test]
+fn warns_on_always_true_assertion_in_loop() {
+    let (mut context, crate_id) = common::prepare_context(SOURCE_WITH_ALWAYS_TRUE_ASSERTION);
+    let options = CompileOptions { warn_loop_assertions: true, ..CompileOptions::default() };
+    let (_, warnings) = noirc_driver::compile_main(&mut context, crate_id, &options, None)
+        .expect("failed to compile program");
+
+    let always_true_warnings = warnings
+        .iter()
+        .filter(|warning| warning.message == "This assertion is always true")
+        .count();
+    assert_eq!(always_true_warnings, 1, "expected only `i < 10` to be reported");
+}
+
+#[test]
+fn does_not_warn_on_loop_assertions_by_default() {
+    let (mut context, crate_id) = common::prepare_context(SOURCE_WITH_ALWAYS_TRUE_ASSERTION);
+    let (_, warnings) =
+        noirc_driver::compile_main(&mut context, crate_id, &CompileOptions::default(), None)
+            .expect("failed to compile program");
+
+    assert!(warnings.iter().all(|warning| warning.message != "This assertion is always true"));
+}
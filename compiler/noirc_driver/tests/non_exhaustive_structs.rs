@@ -0,0 +1,35 @@
+mod common;
+
+use noirc_driver::CompileOptions;
+
+const DEPENDENCY_WITH_NON_EXHAUSTIVE_STRUCT: &str = "
+    #[non_exhaustive]
+    pub struct Foo {
+        pub a: Field,
+        pub b: Field,
+    }
+
+    pub fn foo() -> Foo {
+        let Foo { a, b } = Foo { a: 1, b: 2 };
+        Foo { a, b }
+    }
+";
+
+#[test]
+fn non_exhaustive_struct_pattern_requires_rest_outside_of_its_crate() {
+    let source = "
+    fn main() {
+        let dep::Foo { a, .. } = dep::foo();
+        let dep::Foo { a: _, b: _ } = dep::foo();
+        assert(a == 1);
+    }";
+
+    let (mut context, crate_id) =
+        common::prepare_context_with_dependency(source, DEPENDENCY_WITH_NON_EXHAUSTIVE_STRUCT);
+    let errors = noirc_driver::check_crate(&mut context, crate_id, &CompileOptions::default())
+        .expect_err("expected the pattern without `..` to be rejected");
+
+    let errors: Vec<_> = errors.iter().filter(|diagnostic| diagnostic.is_error()).collect();
+    assert_eq!(errors.len(), 1, "expected a single error, got {errors:?}");
+    assert!(errors[0].message.contains("is marked `#[non_exhaustive]`"));
+}
@@ -0,0 +1,34 @@
+mod common;
+
+use noirc_driver::CompileOptions;
+
+const SOURCE_WITH_LOOP_INVARIANT: &str = "
+    fn main(x: Field, y: Field) -> pub Field {
+        let mut sum = 0;
+        for i in 0..4 {
+            sum += (i as Field) + x * y;
+        }
+        sum
+    }";
+
+#[test]
+fn pass_statistics_report_hoisted_loop_invariants() {
+    let (mut context, crate_id) = common::prepare_context(SOURCE_WITH_LOOP_INVARIANT);
+    let options = CompileOptions { pass_statistics: true, ..CompileOptions::default() };
+    let (program, _) = noirc_driver::compile_main(&mut context, crate_id, &options, None)
+        .expect("failed to compile program");
+
+    let statistics = program.pass_statistics.expect("expected pass statistics");
+    let hoisted = statistics.loop_invariants_hoisted.get("main").copied().unwrap_or_default();
+    assert!(hoisted > 0, "expected `x * y` to be hoisted out of the loop");
+}
+
+#[test]
+fn pass_statistics_are_not_collected_by_default() {
+    let (mut context, crate_id) = common::prepare_context(SOURCE_WITH_LOOP_INVARIANT);
+    let (program, _) =
+        noirc_driver::compile_main(&mut context, crate_id, &CompileOptions::default(), None)
+            .expect("failed to compile program");
+
+    assert!(program.pass_statistics.is_none());
+}
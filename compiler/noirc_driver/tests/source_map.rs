@@ -0,0 +1,42 @@
+mod common;
+
+use noirc_driver::CompileOptions;
+
+const SOURCE: &str = "
+    fn main(x: Field, y: pub Field) -> pub Field {
+        assert(x * y == 10);
+        let z = x + y;
+        assert(z != 3);
+        z * x
+    }";
+
+#[test]
+fn source_map_locates_every_opcode() {
+    let (mut context, crate_id) = common::prepare_context(SOURCE);
+    let options = CompileOptions { source_map: true, ..CompileOptions::default() };
+    let (program, _) = noirc_driver::compile_main(&mut context, crate_id, &options, None)
+        .expect("failed to compile program");
+
+    let source_map = program.source_map.expect("expected a source map");
+    let opcodes = &program.program.functions[0].opcodes;
+    assert!(!opcodes.is_empty());
+
+    for index in 0..opcodes.len() {
+        let locations = source_map.opcode_locations(0, index);
+        let location = locations.last().unwrap_or_else(|| panic!("opcode {index} has no location"));
+        let file =
+            program.file_map.get(&location.file).expect("location should be in a known file");
+        assert!(location.span.end() as usize <= file.source.len(), "{location:?}");
+        assert!(file.path.ends_with("main.nr"), "opcode {index} points to {:?}", file.path);
+    }
+}
+
+#[test]
+fn source_map_is_not_emitted_by_default() {
+    let (mut context, crate_id) = common::prepare_context(SOURCE);
+    let (program, _) =
+        noirc_driver::compile_main(&mut context, crate_id, &CompileOptions::default(), None)
+            .expect("failed to compile program");
+
+    assert!(program.source_map.is_none());
+}
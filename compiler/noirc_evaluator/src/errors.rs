@@ -53,6 +53,8 @@ pub enum RuntimeError {
     StaticAssertDynamicPredicate { call_stack: CallStack },
     #[error("{message}")]
     StaticAssertFailed { message: String, call_stack: CallStack },
+    #[error("{message}")]
+    ConstantConstraintFailed { message: String, call_stack: CallStack },
     #[error("Nested slices, i.e. slices within an array or slice, are not supported")]
     NestedSlice { call_stack: CallStack },
     #[error("Big Integer modulus do no match")]
@@ -65,6 +67,8 @@ pub enum RuntimeError {
         "Could not resolve some references to the array. All references must be resolved at compile time"
     )]
     UnknownReference { call_stack: CallStack },
+    #[error("This constraint only involves values returned by unconstrained functions")]
+    UnconstrainedValueInConstraint { call_stack: CallStack },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Hash)]
@@ -85,6 +89,15 @@ impl From<SsaReport> for CustomDiagnostic {
                     InternalWarning::VerifyProof { call_stack } => {
                         ("verify_proof(...) aggregates data for the verifier, the actual verification will be done when the full proof is verified using nargo verify. nargo prove may generate an invalid proof if bad data is used as input to verify_proof".to_string(), call_stack)
                     },
+                    InternalWarning::AlwaysTrueAssertion { call_stack } => {
+                        ("The bounds of the enclosing loop guarantee that this assertion holds on every iteration. This may indicate a logic bug".to_string(), call_stack)
+                    },
+                    InternalWarning::UnreachableAssertion { call_stack } => {
+                        ("The bounds of the enclosing loop leave no iterations to execute, so this assertion is never checked. This may indicate a logic bug".to_string(), call_stack)
+                    },
+                    InternalWarning::IneffectiveInput { call_stack, .. } => {
+                        ("Any value can be given for this input without affecting the proof. Consider constraining it or removing it".to_string(), call_stack)
+                    },
                 };
                 let call_stack = vecmap(call_stack, |location| location);
                 let location = call_stack.last().expect("Expected RuntimeError to have a location");
@@ -119,6 +132,12 @@ pub enum InternalWarning {
     ReturnConstant { call_stack: CallStack },
     #[error("Calling std::verify_proof(...) does not verify a proof")]
     VerifyProof { call_stack: CallStack },
+    #[error("This assertion is always true")]
+    AlwaysTrueAssertion { call_stack: CallStack },
+    #[error("This loop never executes, assertion unreachable")]
+    UnreachableAssertion { call_stack: CallStack },
+    #[error("Input `{name}` is not used in any constraint or return value")]
+    IneffectiveInput { name: String, call_stack: CallStack },
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Error, Serialize, Deserialize, Hash)]
@@ -169,6 +188,7 @@ impl RuntimeError {
             | RuntimeError::StaticAssertDynamicMessage { call_stack }
             | RuntimeError::StaticAssertDynamicPredicate { call_stack }
             | RuntimeError::StaticAssertFailed { call_stack, .. }
+            | RuntimeError::ConstantConstraintFailed { call_stack, .. }
             | RuntimeError::IntegerOutOfBounds { call_stack, .. }
             | RuntimeError::UnsupportedIntegerSize { call_stack, .. }
             | RuntimeError::InvalidBlackBoxInputBitSize { call_stack, .. }
@@ -176,7 +196,8 @@ impl RuntimeError {
             | RuntimeError::BigIntModulus { call_stack, .. }
             | RuntimeError::UnconstrainedSliceReturnToConstrained { call_stack }
             | RuntimeError::UnconstrainedOracleReturnToConstrained { call_stack }
-            | RuntimeError::UnknownReference { call_stack } => call_stack,
+            | RuntimeError::UnknownReference { call_stack }
+            | RuntimeError::UnconstrainedValueInConstraint { call_stack } => call_stack,
         }
     }
 }
@@ -211,6 +232,17 @@ impl RuntimeError {
                     *location,
                 )
             }
+            RuntimeError::UnconstrainedValueInConstraint { .. } => {
+                let primary_message = self.to_string();
+                let location =
+                    self.call_stack().last().expect("Expected RuntimeError to have a location");
+
+                CustomDiagnostic::simple_error(
+                    primary_message,
+                    "None of the unconstrained values it checks are constrained together with the inputs they were computed from. This is likely a soundness vulnerability".to_string(),
+                    *location,
+                )
+            }
             _ => {
                 let message = self.to_string();
                 let location =
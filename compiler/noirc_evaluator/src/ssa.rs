@@ -11,6 +11,7 @@ use std::{
     fs::File,
     io::Write,
     path::{Path, PathBuf},
+    time::Instant,
 };
 
 use crate::{
@@ -34,7 +35,8 @@ use noirc_errors::debug_info::{DebugFunctions, DebugInfo, DebugTypes, DebugVaria
 
 use noirc_frontend::shared::Visibility;
 use noirc_frontend::{hir_def::function::FunctionSignature, monomorphization::ast::Program};
-use ssa_gen::Ssa;
+use serde::{Deserialize, Serialize};
+use ssa_gen::{Ssa, main_parameters};
 use tracing::{Level, span};
 
 use crate::acir::GeneratedAcir;
@@ -42,10 +44,20 @@ use crate::acir::GeneratedAcir;
 mod checks;
 pub(super) mod function_builder;
 pub mod ir;
+mod loop_report;
 pub(crate) mod opt;
-#[cfg(test)]
-pub(crate) mod parser;
+mod optimization_summary;
+pub mod parser;
+mod pass_timings;
+mod single_pass;
 pub mod ssa_gen;
+mod witness_estimate;
+
+pub use loop_report::{LoopOptimization, LoopReport, NotHoistedInstruction, NotHoistedReason};
+pub use optimization_summary::{FunctionOptimizationSummary, OptimizationSummary};
+pub use pass_timings::PassTimings;
+use single_pass::SelectedPass;
+pub use single_pass::{SsaPassOutput, UnknownSsaPass, run_single_ssa_pass};
 
 #[derive(Debug, Clone)]
 pub enum SsaLogging {
@@ -54,6 +66,28 @@ pub enum SsaLogging {
     Contains(String),
 }
 
+/// Controls which ACIR functions the inliner may inline into their callers,
+/// on top of the functions it always inlines (including `#[inline_always]` ones).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InliningPolicy {
+    /// Inline `#[fold]` functions with at most this many instructions instead of keeping
+    /// them as separate circuits, trading a larger caller for the overhead of an ACIR call.
+    /// When `None` every `#[fold]` function is kept as its own circuit.
+    pub max_fold_function_instructions: Option<usize>,
+}
+
+impl InliningPolicy {
+    /// A policy which never inlines `#[fold]` functions.
+    pub fn conservative() -> Self {
+        Self { max_fold_function_instructions: None }
+    }
+
+    /// A policy which inlines `#[fold]` functions with at most `max_instructions` instructions.
+    pub fn aggressive(max_instructions: usize) -> Self {
+        Self { max_fold_function_instructions: Some(max_instructions) }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SsaEvaluatorOptions {
     /// Emit debug information for the intermediate SSA IR
@@ -65,6 +99,9 @@ pub struct SsaEvaluatorOptions {
     /// Pretty print benchmark times of each code generation pass
     pub print_codegen_timings: bool,
 
+    /// Print a table of the time spent in each SSA pass, sorted from the slowest pass
+    pub print_pass_timings: bool,
+
     /// Width of expressions to be used for ACIR
     pub expression_width: ExpressionWidth,
 
@@ -89,9 +126,144 @@ pub struct SsaEvaluatorOptions {
     /// When `None` the size increase check is skipped altogether and any decrease in the SSA
     /// instruction count is accepted.
     pub max_bytecode_increase_percent: Option<i32>,
+
+    /// Decides which `#[fold]` functions get inlined into their callers.
+    pub inlining_policy: InliningPolicy,
+
+    /// Collect [PassStatistics] about the effect of the SSA optimization passes
+    pub collect_pass_statistics: bool,
+
+    /// Record a [LoopReport] of how each loop was optimized
+    pub collect_loop_report: bool,
+
+    /// Warn about assertions in loops which the loop bounds prove to always hold or to never be executed
+    pub warn_on_loop_assertions: bool,
+
+    /// Report assertions on constants which always fail, and which are executed whenever their
+    /// function is called, as compile-time errors instead of leaving them to fail at runtime
+    pub deny_failing_assertions: bool,
+
+    /// Keep the overflow checks on all Brillig arithmetic, instead of turning operations which
+    /// are proven not to overflow into unchecked ones
+    pub brillig_keep_overflow_checks: bool,
+
+    /// Perform Brillig arithmetic derived from loop induction variables in the smallest
+    /// unsigned type which the loop bounds prove it fits in
+    pub narrow_integer_types: bool,
+
+    /// Combine a lower and an upper bound check on the same value into a single range check
+    pub combine_bound_checks: bool,
+
+    /// Remove casts of values to their own type left behind by loop invariant code motion
+    pub remove_identity_casts: bool,
+
+    /// Remove the stores of a value just loaded from the same reference
+    pub remove_self_assigning_stores: bool,
+
+    /// Remove the block parameters which are passed the same constant by every jump to their block
+    pub simplify_constant_block_params: bool,
+
+    /// Convert self-recursive tail calls in Brillig functions into loops
+    pub tail_call_to_loop: bool,
+
+    /// Specialize the functions always called with the same constant arguments for those
+    /// arguments, calling a clone with the constants baked in instead
+    pub specialize_constant_arguments: bool,
+
+    /// Reassociate the associative operations in loops so that their loop invariant operands are
+    /// combined first, letting loop invariant code motion hoist them
+    pub reassociate_loop_invariants: bool,
+
+    /// Names of local variables whose values are kept from being eliminated by the
+    /// optimizations, so that they can be inspected when debugging
+    pub observed_variables: Vec<String>,
+
+    /// Fail when a constraint only involves values returned by unconstrained functions,
+    /// none of which are constrained together with any other value of the circuit
+    pub deny_unconstrained_constraints: bool,
+
+    /// Warn about inputs of the `main` function which don't affect any constraint or
+    /// return value of the circuit
+    pub warn_on_ineffective_inputs: bool,
 }
 
-pub(crate) struct ArtifactsAndWarnings(Artifacts, Vec<SsaReport>);
+#[cfg(test)]
+impl SsaEvaluatorOptions {
+    /// Options running the default pipeline without any optional pass, check or output, for
+    /// tests to override the fields they need.
+    pub(crate) fn for_tests() -> Self {
+        Self {
+            ssa_logging: SsaLogging::None,
+            brillig_options: BrilligOptions::default(),
+            print_codegen_timings: false,
+            print_pass_timings: false,
+            expression_width: ExpressionWidth::default(),
+            emit_ssa: None,
+            skip_underconstrained_check: true,
+            enable_brillig_constraints_check_lookback: false,
+            skip_brillig_constraints_check: true,
+            inliner_aggressiveness: 0,
+            max_bytecode_increase_percent: None,
+            inlining_policy: InliningPolicy::default(),
+            collect_pass_statistics: false,
+            collect_loop_report: false,
+            warn_on_loop_assertions: false,
+            deny_failing_assertions: false,
+            brillig_keep_overflow_checks: false,
+            narrow_integer_types: false,
+            combine_bound_checks: false,
+            remove_identity_casts: false,
+            remove_self_assigning_stores: false,
+            simplify_constant_block_params: false,
+            tail_call_to_loop: false,
+            specialize_constant_arguments: false,
+            reassociate_loop_invariants: false,
+            observed_variables: Vec::new(),
+            deny_unconstrained_constraints: false,
+            warn_on_ineffective_inputs: false,
+        }
+    }
+}
+
+/// Statistics about the effect of SSA optimization passes, recorded in the compiled program
+/// so that optimization effectiveness can be tracked over time.
+///
+/// Every statistic is keyed by function name. Functions sharing a name have their counts
+/// added together.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PassStatistics {
+    /// The number of instructions hoisted out of loops by loop invariant code motion.
+    pub loop_invariants_hoisted: BTreeMap<String, usize>,
+    /// The number of instructions of each function before any optimization pass ran.
+    pub instructions_before: BTreeMap<String, usize>,
+    /// The number of instructions of each function left after all optimization passes ran.
+    /// Functions which were removed, for example because they were inlined everywhere, are
+    /// left out.
+    pub instructions_after: BTreeMap<String, usize>,
+    /// The number of calls inlined into each function, including the calls made from the
+    /// bodies of the inlined functions.
+    pub calls_inlined: BTreeMap<String, usize>,
+    /// The number of loop iterations unrolled in each function.
+    pub loop_iterations_unrolled: BTreeMap<String, usize>,
+}
+
+impl PassStatistics {
+    /// Adds the number of instructions of each function of `ssa` to `counts`.
+    fn count_instructions(ssa: &Ssa, counts: &mut BTreeMap<String, usize>) {
+        for function in ssa.functions.values() {
+            let name = function.name().to_string();
+            *counts.entry(name).or_default() += function.num_instructions();
+        }
+    }
+}
+
+pub(crate) struct ArtifactsAndWarnings(
+    Artifacts,
+    Vec<SsaReport>,
+    Option<PassStatistics>,
+    Option<LoopReport>,
+);
 
 /// Optimize the given program by converting it into SSA
 /// form and performing optimizations there. When finished,
@@ -104,16 +276,29 @@ pub(crate) fn optimize_into_acir(
 ) -> Result<ArtifactsAndWarnings, RuntimeError> {
     let ssa_gen_span = span!(Level::TRACE, "ssa_generation");
     let ssa_gen_span_guard = ssa_gen_span.enter();
+    let mut pass_timings = options.print_pass_timings.then(PassTimings::default);
+    let main_parameters = options.warn_on_ineffective_inputs.then(|| main_parameters(&program));
     let builder = SsaBuilder::new(
         program,
         options.ssa_logging.clone(),
         options.print_codegen_timings,
         &options.emit_ssa,
+        &options.observed_variables,
+    )?
+    .with_pass_timings(pass_timings.as_mut());
+
+    let mut pass_statistics = options.collect_pass_statistics.then(PassStatistics::default);
+    let mut loop_assertion_warnings = options.warn_on_loop_assertions.then(Vec::new);
+    let mut loop_report = options.collect_loop_report.then(LoopReport::default);
+    let mut ssa = optimize_all(
+        builder,
+        options,
+        pass_statistics.as_mut(),
+        loop_assertion_warnings.as_mut(),
+        loop_report.as_mut(),
     )?;
 
-    let mut ssa = optimize_all(builder, options)?;
-
-    let mut ssa_level_warnings = vec![];
+    let mut ssa_level_warnings = loop_assertion_warnings.unwrap_or_default();
 
     drop(ssa_gen_span_guard);
 
@@ -129,6 +314,8 @@ pub(crate) fn optimize_into_acir(
         ssa,
         ssa_logging: options.ssa_logging.clone(),
         print_codegen_timings: options.print_codegen_timings,
+        pass_timings: pass_timings.as_mut(),
+        selected_pass: None,
     }
     .run_pass(|ssa| ssa.fold_constants_with_brillig(&brillig), "Inlining Brillig Calls Inlining")
     // It could happen that we inlined all calls to a given brillig function.
@@ -137,6 +324,10 @@ pub(crate) fn optimize_into_acir(
     .run_pass(Ssa::dead_instruction_elimination_acir, "Dead Instruction Elimination (3rd)")
     .finish();
 
+    if let Some(pass_timings) = pass_timings {
+        println!("{pass_timings}");
+    }
+
     if !options.skip_underconstrained_check {
         ssa_level_warnings.extend(time(
             "After Check for Underconstrained Values",
@@ -157,30 +348,86 @@ pub(crate) fn optimize_into_acir(
         ));
     };
 
+    if let Some(main_parameters) = main_parameters {
+        ssa_level_warnings.extend(time(
+            "After Check for Ineffective Inputs",
+            options.print_codegen_timings,
+            || ssa.check_for_ineffective_inputs(&main_parameters),
+        ));
+    }
+
+    if options.deny_unconstrained_constraints {
+        time(
+            "After Check for Constraints on Unconstrained Values",
+            options.print_codegen_timings,
+            || ssa.check_for_unconstrained_constraints(),
+        )?;
+    }
+
     drop(ssa_gen_span_guard);
 
     let artifacts = time("SSA to ACIR", options.print_codegen_timings, || {
         ssa.into_acir(&brillig, &options.brillig_options, options.expression_width)
     })?;
 
-    Ok(ArtifactsAndWarnings(artifacts, ssa_level_warnings))
+    Ok(ArtifactsAndWarnings(artifacts, ssa_level_warnings, pass_statistics, loop_report))
 }
 
-/// Run all SSA passes.
-fn optimize_all(builder: SsaBuilder, options: &SsaEvaluatorOptions) -> Result<Ssa, RuntimeError> {
-    Ok(builder
+/// Run all SSA passes, recording their effect into `statistics` if it is set.
+/// Warnings about assertions found while optimizing loops are pushed onto
+/// `loop_assertion_warnings` if it is set, and how each loop was optimized
+/// is recorded into `loop_report` if it is set.
+fn optimize_all(
+    builder: SsaBuilder,
+    options: &SsaEvaluatorOptions,
+    mut statistics: Option<&mut PassStatistics>,
+    loop_assertion_warnings: Option<&mut Vec<SsaReport>>,
+    mut loop_report: Option<&mut LoopReport>,
+) -> Result<Ssa, RuntimeError> {
+    if let Some(statistics) = statistics.as_deref_mut() {
+        PassStatistics::count_instructions(&builder.ssa, &mut statistics.instructions_before);
+    }
+
+    let ssa = builder
         .run_pass(Ssa::remove_unreachable_functions, "Removing Unreachable Functions (1st)")
         .run_pass(Ssa::defunctionalize, "Defunctionalization")
+        .run_pass(
+            |ssa| ssa.inline_small_fold_functions(options.inlining_policy),
+            "Inlining small fold functions",
+        )
         .run_pass(Ssa::inline_simple_functions, "Inlining simple functions")
         // BUG: Enabling this mem2reg causes an integration test failure in aztec-package; see:
         // https://github.com/AztecProtocol/aztec-packages/pull/11294#issuecomment-2622809518
         //.run_pass(Ssa::mem2reg, "Mem2Reg (1st)")
         .run_pass(Ssa::remove_paired_rc, "Removing Paired rc_inc & rc_decs")
         .run_pass(
-            |ssa| ssa.preprocess_functions(options.inliner_aggressiveness),
+            |ssa| {
+                ssa.preprocess_functions(
+                    options.inliner_aggressiveness,
+                    options.brillig_keep_overflow_checks,
+                )
+            },
             "Preprocessing Functions",
         )
-        .run_pass(|ssa| ssa.inline_functions(options.inliner_aggressiveness), "Inlining (1st)")
+        .run_pass(
+            |ssa| {
+                ssa.inline_functions_with_statistics(
+                    options.inliner_aggressiveness,
+                    statistics.as_deref_mut(),
+                )
+            },
+            "Inlining (1st)",
+        )
+        .run_pass(
+            |ssa| {
+                if options.specialize_constant_arguments {
+                    ssa.specialize_constant_arguments()
+                } else {
+                    ssa
+                }
+            },
+            "Specializing Constant Arguments",
+        )
         // Run mem2reg with the CFG separated into blocks
         .run_pass(Ssa::mem2reg, "Mem2Reg (2nd)")
         .run_pass(Ssa::simplify_cfg, "Simplifying (1st)")
@@ -191,11 +438,64 @@ fn optimize_all(builder: SsaBuilder, options: &SsaEvaluatorOptions) -> Result<Ss
             "`static_assert` and `assert_constant`",
         )?
         .run_pass(Ssa::purity_analysis, "Purity Analysis")
-        .run_pass(Ssa::loop_invariant_code_motion, "Loop Invariant Code Motion")
+        // Run before LICM so that loops created from tail recursion can be optimized
+        .run_pass(
+            |ssa| if options.tail_call_to_loop { ssa.tail_call_to_loop() } else { ssa },
+            "Tail Call to Loop",
+        )
+        // Group loop invariant operands together so that LICM can hoist them
+        .run_pass(
+            |ssa| {
+                if options.reassociate_loop_invariants {
+                    ssa.reassociate_loop_invariants()
+                } else {
+                    ssa
+                }
+            },
+            "Reassociating Loop Invariants",
+        )
+        .run_pass(
+            |ssa| {
+                ssa.loop_invariant_code_motion_with_reports(
+                    statistics.as_deref_mut(),
+                    loop_assertion_warnings,
+                    loop_report.as_deref_mut(),
+                    options.brillig_keep_overflow_checks,
+                )
+            },
+            "Loop Invariant Code Motion",
+        )
+        .run_pass(
+            |ssa| if options.remove_identity_casts { ssa.remove_identity_casts() } else { ssa },
+            "Removing Identity Casts",
+        )
         .try_run_pass(
-            |ssa| ssa.unroll_loops_iteratively(options.max_bytecode_increase_percent),
+            |ssa| {
+                let ssa = ssa.unroll_loops_iteratively_with_statistics(
+                    options.max_bytecode_increase_percent,
+                    statistics.as_deref_mut(),
+                )?;
+                if let Some(loop_report) = loop_report.as_deref_mut() {
+                    ssa.record_unrolled_loops(loop_report);
+                }
+                Ok(ssa)
+            },
             "Unrolling",
         )?
+        .run_pass(
+            |ssa| {
+                if options.simplify_constant_block_params {
+                    ssa.simplify_constant_block_params()
+                } else {
+                    ssa
+                }
+            },
+            "Simplifying Constant Block Parameters",
+        )
+        .run_pass(
+            |ssa| if options.narrow_integer_types { ssa.narrow_integer_types() } else { ssa },
+            "Narrowing Integer Types",
+        )
         .run_pass(Ssa::simplify_cfg, "Simplifying (2nd)")
         .run_pass(Ssa::mem2reg, "Mem2Reg (3rd)")
         .run_pass(Ssa::flatten_cfg, "Flattening")
@@ -207,7 +507,12 @@ fn optimize_all(builder: SsaBuilder, options: &SsaEvaluatorOptions) -> Result<Ss
         // This pass must come immediately following `mem2reg` as the succeeding passes
         // may create an SSA which inlining fails to handle.
         .run_pass(
-            |ssa| ssa.inline_functions_with_no_predicates(options.inliner_aggressiveness),
+            |ssa| {
+                ssa.inline_functions_with_no_predicates_with_statistics(
+                    options.inliner_aggressiveness,
+                    statistics.as_deref_mut(),
+                )
+            },
             "Inlining (2nd)",
         )
         .run_pass(Ssa::remove_if_else, "Remove IfElse")
@@ -216,6 +521,20 @@ fn optimize_all(builder: SsaBuilder, options: &SsaEvaluatorOptions) -> Result<Ss
         .run_pass(Ssa::flatten_basic_conditionals, "Simplify conditionals for unconstrained")
         .run_pass(Ssa::remove_enable_side_effects, "EnableSideEffectsIf removal")
         .run_pass(Ssa::fold_constants_using_constraints, "Constraint Folding")
+        .try_run_pass(
+            |ssa| {
+                if options.deny_failing_assertions {
+                    ssa.simplify_constant_constraints()
+                } else {
+                    Ok(ssa)
+                }
+            },
+            "Simplifying Constant Constraints",
+        )?
+        .run_pass(
+            |ssa| if options.combine_bound_checks { ssa.combine_bound_checks() } else { ssa },
+            "Combining Bound Checks",
+        )
         .run_pass(Ssa::make_constrain_not_equal_instructions, "Adding constrain not equal")
         .run_pass(Ssa::check_u128_mul_overflow, "Check u128 mul overflow")
         .run_pass(Ssa::dead_instruction_elimination, "Dead Instruction Elimination (1st)")
@@ -227,6 +546,16 @@ fn optimize_all(builder: SsaBuilder, options: &SsaEvaluatorOptions) -> Result<Ss
         // Remove any potentially unnecessary duplication from the Brillig entry point analysis.
         .run_pass(Ssa::remove_unreachable_functions, "Removing Unreachable Functions (3rd)")
         .run_pass(Ssa::remove_truncate_after_range_check, "Removing Truncate after RangeCheck")
+        .run_pass(
+            |ssa| {
+                if options.remove_self_assigning_stores {
+                    ssa.remove_self_assigning_stores()
+                } else {
+                    ssa
+                }
+            },
+            "Removing Self-Assigning Stores",
+        )
         // This pass makes transformations specific to Brillig generation.
         // It must be the last pass to either alter or add new instructions before Brillig generation,
         // as other semantics in the compiler can potentially break (e.g. inserting instructions).
@@ -235,7 +564,13 @@ fn optimize_all(builder: SsaBuilder, options: &SsaEvaluatorOptions) -> Result<Ss
         // end up using an existing constant from the globals space.
         .run_pass(Ssa::brillig_array_gets, "Brillig Array Get Optimizations")
         .run_pass(Ssa::dead_instruction_elimination, "Dead Instruction Elimination (2nd)")
-        .finish())
+        .finish();
+
+    if let Some(statistics) = statistics {
+        PassStatistics::count_instructions(&ssa, &mut statistics.instructions_after);
+    }
+
+    Ok(ssa)
 }
 
 // Helper to time SSA passes
@@ -261,6 +596,8 @@ pub struct SsaProgramArtifact {
     pub names: Vec<String>,
     pub brillig_names: Vec<String>,
     pub error_types: BTreeMap<ErrorSelector, ErrorType>,
+    pub pass_statistics: Option<PassStatistics>,
+    pub loop_report: Option<LoopReport>,
 }
 
 impl SsaProgramArtifact {
@@ -278,6 +615,8 @@ impl SsaProgramArtifact {
             names: Vec::default(),
             brillig_names: Vec::default(),
             error_types,
+            pass_statistics: None,
+            loop_report: None,
         }
     }
 
@@ -317,6 +656,8 @@ pub fn create_program(
     let ArtifactsAndWarnings(
         (generated_acirs, generated_brillig, brillig_function_names, error_types),
         ssa_level_warnings,
+        pass_statistics,
+        loop_report,
     ) = optimize_into_acir(program, options)?;
 
     assert_eq!(
@@ -334,6 +675,8 @@ pub fn create_program(
 
     // Add warnings collected at the Ssa stage
     program_artifact.add_warnings(ssa_level_warnings);
+    program_artifact.pass_statistics = pass_statistics;
+    program_artifact.loop_report = loop_report;
     // For setting up the ABI we need separately specify main's input and return witnesses
     let mut is_main = true;
     for (acir, func_sig) in generated_acirs.into_iter().zip(func_sigs) {
@@ -353,6 +696,45 @@ pub fn create_program(
     Ok(program_artifact)
 }
 
+/// Optimizes the given program into SSA and estimates the number of witnesses each of its
+/// ACIR functions needs, keyed by function name, without generating ACIR.
+///
+/// The estimate is a heuristic meant to quickly compare the cost of functions or of changes
+/// to them. The witness count of the compiled program may differ significantly from it.
+pub fn estimate_witness_counts(
+    program: Program,
+    options: &SsaEvaluatorOptions,
+) -> Result<BTreeMap<String, usize>, RuntimeError> {
+    let builder = SsaBuilder::new(
+        program,
+        options.ssa_logging.clone(),
+        options.print_codegen_timings,
+        &options.emit_ssa,
+        &options.observed_variables,
+    )?;
+    let ssa = optimize_all(builder, options, None, None, None)?;
+    Ok(ssa.estimate_witness_counts())
+}
+
+/// Optimizes the given program into SSA, reporting for each loop whether it was unrolled,
+/// how many instructions were hoisted out of it and why loop invariant instructions were
+/// left in it.
+pub fn create_loop_report(
+    program: Program,
+    options: &SsaEvaluatorOptions,
+) -> Result<LoopReport, RuntimeError> {
+    let builder = SsaBuilder::new(
+        program,
+        options.ssa_logging.clone(),
+        options.print_codegen_timings,
+        &options.emit_ssa,
+        &options.observed_variables,
+    )?;
+    let mut loop_report = LoopReport::default();
+    optimize_all(builder, options, None, None, Some(&mut loop_report))?;
+    Ok(loop_report)
+}
+
 pub struct SsaCircuitArtifact {
     name: String,
     circuit: Circuit<FieldElement>,
@@ -476,20 +858,25 @@ fn split_public_and_private_inputs(
 }
 
 // This is just a convenience object to bundle the ssa with `print_ssa_passes` for debug printing.
-struct SsaBuilder {
+struct SsaBuilder<'a> {
     ssa: Ssa,
     ssa_logging: SsaLogging,
     print_codegen_timings: bool,
+    /// Records the time spent in each pass if set
+    pass_timings: Option<&'a mut PassTimings>,
+    /// Only runs the selected pass if set
+    selected_pass: Option<&'a mut SelectedPass>,
 }
 
-impl SsaBuilder {
+impl<'a> SsaBuilder<'a> {
     fn new(
         program: Program,
         ssa_logging: SsaLogging,
         print_codegen_timings: bool,
         emit_ssa: &Option<PathBuf>,
-    ) -> Result<SsaBuilder, RuntimeError> {
-        let ssa = ssa_gen::generate_ssa(program)?;
+        observed_variables: &[String],
+    ) -> Result<SsaBuilder<'a>, RuntimeError> {
+        let ssa = ssa_gen::generate_ssa(program, observed_variables)?;
         if let Some(emit_ssa) = emit_ssa {
             let mut emit_ssa_dir = emit_ssa.clone();
             // We expect the full package artifact path to be passed in here,
@@ -499,7 +886,19 @@ impl SsaBuilder {
             let ssa_path = emit_ssa.with_extension("ssa.json");
             write_to_file(&serde_json::to_vec(&ssa).unwrap(), &ssa_path);
         }
-        Ok(SsaBuilder { ssa_logging, print_codegen_timings, ssa }.print("Initial SSA"))
+        Ok(SsaBuilder {
+            ssa_logging,
+            print_codegen_timings,
+            pass_timings: None,
+            selected_pass: None,
+            ssa,
+        }
+        .print("Initial SSA"))
+    }
+
+    fn with_pass_timings(mut self, pass_timings: Option<&'a mut PassTimings>) -> Self {
+        self.pass_timings = pass_timings;
+        self
     }
 
     fn finish(self) -> Ssa {
@@ -511,7 +910,12 @@ impl SsaBuilder {
     where
         F: FnOnce(Ssa) -> Ssa,
     {
+        if !self.is_selected(msg) {
+            return self;
+        }
+        let start = Instant::now();
         self.ssa = time(msg, self.print_codegen_timings, || pass(self.ssa));
+        self.record_pass_timing(msg, start);
         self.print(msg)
     }
 
@@ -520,10 +924,26 @@ impl SsaBuilder {
     where
         F: FnOnce(Ssa) -> Result<Ssa, RuntimeError>,
     {
+        if !self.is_selected(msg) {
+            return Ok(self);
+        }
+        let start = Instant::now();
         self.ssa = time(msg, self.print_codegen_timings, || pass(self.ssa))?;
+        self.record_pass_timing(msg, start);
         Ok(self.print(msg))
     }
 
+    /// Returns whether the pass with the given message should run, see [SelectedPass].
+    fn is_selected(&mut self, msg: &str) -> bool {
+        self.selected_pass.as_deref_mut().is_none_or(|selected_pass| selected_pass.select(msg))
+    }
+
+    fn record_pass_timing(&mut self, msg: &str, start: Instant) {
+        if let Some(pass_timings) = &mut self.pass_timings {
+            pass_timings.record(msg, start.elapsed());
+        }
+    }
+
     fn print(mut self, msg: &str) -> Self {
         // Always normalize if we are going to print at least one of the passes
         if !matches!(self.ssa_logging, SsaLogging::None) {
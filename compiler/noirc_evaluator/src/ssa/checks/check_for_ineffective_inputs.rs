@@ -0,0 +1,195 @@
+//! This module defines an opt-in SSA check warning about the parameters of an ACIR `main`
+//! function which don't affect any constraint or return value of the circuit.
+//!
+//! Such an input can take any value without changing whether the proof verifies, so it
+//! only adds to the proving cost, and is likely either left over or missing a constraint.
+//! An input only passed to unconstrained functions is reported as well, as nothing checks
+//! the results computed from it.
+//!
+//! The check runs on the optimized SSA, where unused values have been removed, by walking
+//! the uses of each value back from the constraints and return values of `main`.
+use std::collections::HashSet;
+
+use crate::errors::{InternalWarning, SsaReport};
+use crate::ssa::ir::function::Function;
+use crate::ssa::ir::instruction::{Instruction, InstructionId};
+use crate::ssa::ir::value::{Value, ValueId};
+use crate::ssa::ssa_gen::{MainParameter, Ssa};
+
+impl Ssa {
+    /// Returns a warning for each parameter of `main` whose values don't affect any constraint
+    /// or return value, if `main` is an ACIR function.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) fn check_for_ineffective_inputs(
+        &self,
+        main_parameters: &[MainParameter],
+    ) -> Vec<SsaReport> {
+        let main = self.main();
+        if !main.runtime().is_acir() {
+            return Vec::new();
+        }
+
+        let num_values: usize = main_parameters.iter().map(|parameter| parameter.num_values).sum();
+        if main.parameters().len() != num_values {
+            return Vec::new();
+        }
+
+        let effective_values = effective_values(self, main);
+        let mut values = main.parameters().iter();
+        main_parameters
+            .iter()
+            .filter_map(|parameter| {
+                let values: Vec<_> = values.by_ref().take(parameter.num_values).copied().collect();
+                if values.is_empty() || values.iter().any(|value| effective_values.contains(value))
+                {
+                    return None;
+                }
+                Some(SsaReport::Warning(InternalWarning::IneffectiveInput {
+                    name: parameter.name.clone(),
+                    call_stack: vec![parameter.location],
+                }))
+            })
+            .collect()
+    }
+}
+
+/// How the operands of an instruction relate to the constraints of the circuit.
+enum InstructionUse {
+    /// The operands are constrained by the instruction itself.
+    Constrained,
+    /// The operands are constrained if any of the results is.
+    Propagated,
+    /// The operands aren't constrained by the instruction.
+    Unconstrained,
+}
+
+/// Returns the values of the function which affect one of its constraints or return values.
+fn effective_values(ssa: &Ssa, function: &Function) -> HashSet<ValueId> {
+    let dfg = &function.dfg;
+    let mut effective_values = HashSet::new();
+    let mut propagated_instructions = Vec::new();
+
+    for block in function.reachable_blocks() {
+        for instruction_id in dfg[block].instructions() {
+            let instruction = &dfg[*instruction_id];
+            match instruction_use(ssa, function, instruction, *instruction_id) {
+                InstructionUse::Constrained => instruction.for_each_value(|value| {
+                    effective_values.insert(dfg.resolve(value));
+                }),
+                InstructionUse::Propagated => propagated_instructions.push(*instruction_id),
+                InstructionUse::Unconstrained => {}
+            }
+        }
+        dfg[block].unwrap_terminator().for_each_value(|value| {
+            effective_values.insert(dfg.resolve(value));
+        });
+    }
+
+    // The instructions are not necessarily visited after the ones using their results, so
+    // keep going until no more values are found.
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for instruction_id in &propagated_instructions {
+            let results = dfg.instruction_results(*instruction_id);
+            if !results.iter().any(|result| effective_values.contains(&dfg.resolve(*result))) {
+                continue;
+            }
+            dfg[*instruction_id].for_each_value(|value| {
+                changed |= effective_values.insert(dfg.resolve(value));
+            });
+        }
+    }
+
+    effective_values
+}
+
+fn instruction_use(
+    ssa: &Ssa,
+    function: &Function,
+    instruction: &Instruction,
+    instruction_id: InstructionId,
+) -> InstructionUse {
+    match instruction {
+        Instruction::Constrain(..)
+        | Instruction::ConstrainNotEqual(..)
+        | Instruction::RangeCheck { .. }
+        | Instruction::Store { .. }
+        | Instruction::EnableSideEffectsIf { .. } => InstructionUse::Constrained,
+        Instruction::IncrementRc { .. } | Instruction::DecrementRc { .. } | Instruction::Noop => {
+            InstructionUse::Unconstrained
+        }
+        Instruction::Call { func, .. } => match function.dfg[*func] {
+            Value::Function(callee) if ssa.functions[&callee].runtime().is_brillig() => {
+                InstructionUse::Unconstrained
+            }
+            // The arguments of a constrained function are constrained by its body
+            Value::Function(_) => InstructionUse::Constrained,
+            Value::ForeignFunction(_) => InstructionUse::Unconstrained,
+            // An intrinsic without results is only called for its side effects
+            _ if function.dfg.instruction_results(instruction_id).is_empty() => {
+                InstructionUse::Constrained
+            }
+            _ => InstructionUse::Propagated,
+        },
+        _ => InstructionUse::Propagated,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use noirc_errors::Location;
+
+    use crate::{
+        errors::{InternalWarning, SsaReport},
+        ssa::{Ssa, ssa_gen::MainParameter},
+    };
+
+    fn parameter(name: &str) -> MainParameter {
+        MainParameter { name: name.to_string(), location: Location::dummy(), num_values: 1 }
+    }
+
+    #[test]
+    fn warns_on_input_only_passed_to_brillig() {
+        // `v1` is only passed to an unconstrained function whose result is unused,
+        // while `v0` affects the return value.
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: Field, v1: Field):
+            v3 = call f1(v1) -> Field
+            v5 = add v0, Field 1
+            return v5
+        }
+        brillig(inline) fn hint f1 {
+          b0(v0: Field):
+            return v0
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let warnings = ssa.check_for_ineffective_inputs(&[parameter("x"), parameter("y")]);
+        assert_eq!(warnings.len(), 1);
+        let SsaReport::Warning(InternalWarning::IneffectiveInput { name, .. }) = &warnings[0]
+        else {
+            panic!("Expected an ineffective input warning, got {:?}", warnings[0]);
+        };
+        assert_eq!(name, "y");
+    }
+
+    #[test]
+    fn does_not_warn_on_input_used_through_array() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: Field, v1: u32):
+            v2 = make_array [v0, v0] : [Field; 2]
+            v3 = array_get v2, index v1 -> Field
+            constrain v3 == Field 1
+            return
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let warnings = ssa.check_for_ineffective_inputs(&[parameter("x"), parameter("i")]);
+        assert!(warnings.is_empty());
+    }
+}
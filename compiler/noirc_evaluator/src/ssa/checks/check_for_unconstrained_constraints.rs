@@ -0,0 +1,180 @@
+//! This module defines an opt-in SSA check flagging constraints in ACIR functions which only
+//! involve values returned by unconstrained (Brillig) functions.
+//!
+//! Such a constraint only relates values chosen by the prover to each other or to constants,
+//! so unless another constraint ties the same Brillig results to the rest of the circuit, it
+//! doesn't check them against the inputs they were computed from. This is a common way for a
+//! circuit to end up under-constrained. The check is a heuristic: it can't tell whether the
+//! constraint it found is all that is needed for the program to be sound.
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::errors::RuntimeError;
+use crate::ssa::ir::function::Function;
+use crate::ssa::ir::instruction::{Instruction, InstructionId};
+use crate::ssa::ir::post_order::PostOrder;
+use crate::ssa::ir::value::{Value, ValueId};
+use crate::ssa::ssa_gen::Ssa;
+
+impl Ssa {
+    /// Returns an error for the first constraint of an ACIR function whose operands are all
+    /// derived from Brillig call results alone, if none of these Brillig calls has its
+    /// results constrained together with any other value of the circuit.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) fn check_for_unconstrained_constraints(&self) -> Result<(), RuntimeError> {
+        for function in self.functions.values() {
+            if function.runtime().is_acir() {
+                check_function(self, function)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct Context {
+    /// Values computed only from Brillig call results and constants
+    unconstrained: HashSet<ValueId>,
+    /// The Brillig calls each value is computed from, whether or not it is unconstrained
+    brillig_calls: HashMap<ValueId, BTreeSet<InstructionId>>,
+    /// Brillig calls with a result constrained together with a value which isn't unconstrained
+    checked_calls: HashSet<InstructionId>,
+    /// Constraints which only involve unconstrained values, along with the Brillig calls
+    /// these values are computed from
+    unconstrained_constraints: Vec<(InstructionId, BTreeSet<InstructionId>)>,
+}
+
+fn check_function(ssa: &Ssa, function: &Function) -> Result<(), RuntimeError> {
+    let mut context = Context::default();
+    let dfg = &function.dfg;
+
+    let mut blocks = PostOrder::with_function(function).into_vec();
+    blocks.reverse();
+
+    for block in blocks {
+        for instruction_id in dfg[block].instructions() {
+            let instruction = &dfg[*instruction_id];
+
+            let is_brillig_call = match instruction {
+                Instruction::Call { func, .. } => match dfg[*func] {
+                    Value::Function(callee) => ssa.functions[&callee].runtime().is_brillig(),
+                    _ => false,
+                },
+                _ => false,
+            };
+            if is_brillig_call {
+                for result in dfg.instruction_results(*instruction_id) {
+                    context.unconstrained.insert(*result);
+                    context.brillig_calls.insert(*result, BTreeSet::from([*instruction_id]));
+                }
+                continue;
+            }
+
+            let mut operands = Vec::new();
+            instruction.for_each_value(|value| {
+                if !dfg.is_constant(value) && !is_function(function, value) {
+                    operands.push(value);
+                }
+            });
+            let is_unconstrained = !operands.is_empty()
+                && operands.iter().all(|operand| context.unconstrained.contains(operand));
+            let brillig_calls: BTreeSet<InstructionId> = operands
+                .iter()
+                .filter_map(|operand| context.brillig_calls.get(operand))
+                .flatten()
+                .copied()
+                .collect();
+
+            match instruction {
+                Instruction::Constrain(..)
+                | Instruction::ConstrainNotEqual(..)
+                | Instruction::RangeCheck { .. } => {
+                    if is_unconstrained {
+                        context.unconstrained_constraints.push((*instruction_id, brillig_calls));
+                    } else {
+                        context.checked_calls.extend(brillig_calls);
+                    }
+                }
+                // The results of a constrained function are constrained by its body
+                Instruction::Call { func, .. } if matches!(dfg[*func], Value::Function(_)) => {}
+                _ => {
+                    for result in dfg.instruction_results(*instruction_id) {
+                        if is_unconstrained {
+                            context.unconstrained.insert(*result);
+                        }
+                        if !brillig_calls.is_empty() {
+                            context.brillig_calls.insert(*result, brillig_calls.clone());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let unchecked_constraint =
+        context.unconstrained_constraints.into_iter().find(|(_, brillig_calls)| {
+            brillig_calls.iter().any(|call| !context.checked_calls.contains(call))
+        });
+    if let Some((constraint, _)) = unchecked_constraint {
+        let call_stack = dfg.get_instruction_call_stack(constraint);
+        return Err(RuntimeError::UnconstrainedValueInConstraint { call_stack });
+    }
+    Ok(())
+}
+
+fn is_function(function: &Function, value: ValueId) -> bool {
+    matches!(
+        function.dfg[value],
+        Value::Function(_) | Value::Intrinsic(_) | Value::ForeignFunction(_)
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{errors::RuntimeError, ssa::Ssa};
+
+    #[test]
+    fn flags_constraint_between_brillig_results() {
+        // `v2` and `v3` are both chosen by the prover, so asserting that they are equal
+        // doesn't check them against `v0`.
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: Field):
+            v2 = call f1(v0) -> Field
+            v3 = call f1(v0) -> Field
+            constrain v2 == v3
+            return v2
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: Field):
+            return v0
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let result = ssa.check_for_unconstrained_constraints();
+        assert!(matches!(result, Err(RuntimeError::UnconstrainedValueInConstraint { .. })));
+    }
+
+    #[test]
+    fn does_not_flag_brillig_result_checked_against_input() {
+        // `v2` is checked to be the square root of `v0`, so the range check on its own
+        // isn't reported.
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: Field):
+            v2 = call f1(v0) -> Field
+            range_check v2 to 64 bits
+            v3 = mul v2, v2
+            constrain v3 == v0
+            return v2
+        }
+        brillig(inline) fn sqrt f1 {
+          b0(v0: Field):
+            return v0
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        assert!(ssa.check_for_unconstrained_constraints().is_ok());
+    }
+}
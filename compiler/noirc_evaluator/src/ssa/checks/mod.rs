@@ -1 +1,3 @@
+mod check_for_ineffective_inputs;
+mod check_for_unconstrained_constraints;
 mod check_for_underconstrained_values;
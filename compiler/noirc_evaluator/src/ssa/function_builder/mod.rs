@@ -375,7 +375,6 @@ impl FunctionBuilder {
             .first()
     }
 
-    #[cfg(test)]
     pub(crate) fn insert_mutable_array_set(
         &mut self,
         array: ValueId,
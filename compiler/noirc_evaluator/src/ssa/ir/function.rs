@@ -157,6 +157,12 @@ impl Function {
         self.entry_block
     }
 
+    /// Sets the entry block of this function.
+    /// The parameters of the new entry block become the function's parameters.
+    pub(crate) fn set_entry_block(&mut self, block: BasicBlockId) {
+        self.entry_block = block;
+    }
+
     /// Returns the parameters of this function.
     /// The parameters will always match that of this function's entry block.
     pub(crate) fn parameters(&self) -> &[ValueId] {
@@ -39,10 +39,9 @@ impl<T> Id<T> {
 
     /// Creates a test Id with the given index.
     /// The name of this function makes it apparent it should only
-    /// be used for testing. Obtaining Ids in this way should be avoided
+    /// be used for testing and by the SSA parser. Obtaining Ids in this way should be avoided
     /// as unlike DenseMap::push and SparseMap::push, the Ids created
     /// here are likely invalid for any particularly map.
-    #[cfg(test)]
     pub(crate) fn test_new(index: u32) -> Self {
         Self::new(index)
     }
@@ -156,6 +156,19 @@ fn display_terminator(
     }
 }
 
+/// Formats an instruction as it is printed in a function, without its indentation.
+pub(crate) fn instruction_to_string(dfg: &DataFlowGraph, instruction: InstructionId) -> String {
+    struct DisplayInstruction<'a>(&'a DataFlowGraph, InstructionId);
+
+    impl Display for DisplayInstruction<'_> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            display_instruction(self.0, self.1, false, f)
+        }
+    }
+
+    DisplayInstruction(dfg, instruction).to_string().trim().to_string()
+}
+
 /// Display an arbitrary instruction
 fn display_instruction(
     dfg: &DataFlowGraph,
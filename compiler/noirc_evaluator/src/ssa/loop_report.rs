@@ -0,0 +1,107 @@
+//! A human-readable report of how loop invariant code motion and loop unrolling optimized each
+//! loop of a program, meant to help users understand which loops drive the cost of proving.
+use std::fmt::Write;
+
+use noirc_errors::Location;
+use serde::{Deserialize, Serialize};
+
+use super::ir::function::FunctionId;
+
+/// How each loop of a program was optimized, in the order the loops were analyzed.
+///
+/// Loops are identified by their source location. A loop which ends up in several functions,
+/// for example because its function was inlined into more than one caller, is reported once
+/// for each of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LoopReport {
+    pub loops: Vec<LoopOptimization>,
+}
+
+/// How a single loop was optimized.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LoopOptimization {
+    /// The name of the function containing the loop.
+    pub function: String,
+    /// The id of the function containing the loop, which tells apart functions sharing a name.
+    /// It is only known while the report is recorded.
+    #[serde(skip)]
+    pub(crate) function_id: Option<FunctionId>,
+    /// The source location of the loop, if known.
+    pub location: Option<Location>,
+    /// Whether the loop was unrolled.
+    pub unrolled: bool,
+    /// The number of instructions hoisted out of the loop.
+    pub instructions_hoisted: usize,
+    /// The instructions whose operands are all loop invariant, but which were left in the loop.
+    pub not_hoisted: Vec<NotHoistedInstruction>,
+}
+
+/// A loop invariant instruction which was left in its loop, along with the reason why.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NotHoistedInstruction {
+    /// The instruction, as it is printed in the SSA.
+    pub instruction: String,
+    /// The source location the instruction originates from, if known.
+    pub location: Option<Location>,
+    pub reason: NotHoistedReason,
+}
+
+/// Why a loop invariant instruction was left in its loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NotHoistedReason {
+    /// The instruction is only executed on some paths through the loop body, and executing it
+    /// on every path could fail or change the program's result.
+    ControlDependent,
+    /// The instruction has side effects, or reads memory which may change between iterations.
+    SideEffects,
+    /// The instruction may fail, and the loop's bounds don't prove that its body is executed.
+    DynamicBounds,
+    /// The instruction is executed under an `enable_side_effects` predicate which may change
+    /// between iterations.
+    ChangingPredicate,
+}
+
+impl std::fmt::Display for NotHoistedReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let reason = match self {
+            NotHoistedReason::ControlDependent => "it is only executed on some paths of the loop",
+            NotHoistedReason::SideEffects => "it has side effects or reads from memory",
+            NotHoistedReason::DynamicBounds => {
+                "it may fail and the loop bounds don't prove that the loop is executed"
+            }
+            NotHoistedReason::ChangingPredicate => {
+                "it is executed under a predicate which may change between iterations"
+            }
+        };
+        write!(f, "{reason}")
+    }
+}
+
+impl LoopReport {
+    /// Renders the report as text, one section per loop, formatting source locations with
+    /// `format_location`.
+    pub fn render(&self, format_location: impl Fn(&Location) -> String) -> String {
+        let format_location = |location: &Option<Location>| {
+            location.as_ref().map_or_else(|| "unknown location".to_string(), &format_location)
+        };
+
+        let mut report = String::new();
+        for loop_ in &self.loops {
+            let location = format_location(&loop_.location);
+            writeln!(report, "Loop at {location} in `{}`:", loop_.function).unwrap();
+            let unrolled = if loop_.unrolled { "yes" } else { "no" };
+            writeln!(report, "  unrolled: {unrolled}").unwrap();
+            writeln!(report, "  instructions hoisted: {}", loop_.instructions_hoisted).unwrap();
+            for instruction in &loop_.not_hoisted {
+                let location = format_location(&instruction.location);
+                writeln!(
+                    report,
+                    "  not hoisted: `{}` at {location}, because {}",
+                    instruction.instruction, instruction.reason
+                )
+                .unwrap();
+            }
+        }
+        report
+    }
+}
@@ -0,0 +1,243 @@
+//! This module defines an SSA pass which combines a lower-bound and an upper-bound check on
+//! the same unsigned value into a single range check.
+//!
+//! Asserting that `lower <= x` and `x < upper` results in two comparisons, each of which
+//! takes a range check in ACIR, along with their constraints. When `upper - lower` is a power
+//! of two `2^k`, the same bounds are checked by asserting that `x - lower` fits in `k` bits:
+//!
+//! ```text
+//! v1 = lt v0, u32 8
+//! constrain v1 == u1 0
+//! v2 = lt v0, u32 24
+//! constrain v2 == u1 1
+//! ```
+//!
+//! becomes
+//!
+//! ```text
+//! v1 = lt v0, u32 8
+//! v2 = lt v0, u32 24
+//! v3 = cast v0 as Field
+//! v4 = sub v3, Field 8
+//! range_check v4 to 4 bits
+//! ```
+//!
+//! The subtraction is done on fields so that when `x < lower` it results in a value close to
+//! the field modulus, which fails the range check. The comparisons are left for dead
+//! instruction elimination to remove if they aren't used elsewhere.
+//!
+//! Other bounds can't be expressed as a single range check so they are left unchanged.
+use acvm::{AcirField, FieldElement};
+use fxhash::FxHashMap as HashMap;
+
+use crate::ssa::{
+    ir::{
+        basic_block::BasicBlockId,
+        function::Function,
+        instruction::{Binary, BinaryOp, Instruction, InstructionId},
+        types::NumericType,
+        value::ValueId,
+    },
+    ssa_gen::Ssa,
+};
+
+impl Ssa {
+    /// Replaces each pair of constraints asserting that an unsigned value lies between two
+    /// constants which are a power of two apart with a single range check.
+    ///
+    /// This pass only affects ACIR functions and must be placed after CFG flattening, as the
+    /// bounds are only combined when side effects are enabled.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) fn combine_bound_checks(mut self) -> Ssa {
+        for function in self.functions.values_mut() {
+            function.combine_bound_checks();
+        }
+        self
+    }
+}
+
+impl Function {
+    pub(crate) fn combine_bound_checks(&mut self) {
+        if !self.runtime().is_acir() {
+            return;
+        }
+
+        for block in self.reachable_blocks() {
+            self.combine_bound_checks_in_block(block);
+        }
+    }
+
+    fn combine_bound_checks_in_block(&mut self, block: BasicBlockId) {
+        // The constraints found so far bounding each value, along with the bound
+        let mut lower_bounds: HashMap<ValueId, (InstructionId, FieldElement)> = HashMap::default();
+        let mut upper_bounds: HashMap<ValueId, (InstructionId, FieldElement)> = HashMap::default();
+        let mut side_effects_enabled = true;
+
+        let instructions = self.dfg[block].take_instructions();
+        for instruction_id in instructions {
+            self.dfg[block].insert_instruction(instruction_id);
+
+            if let Instruction::EnableSideEffectsIf { condition } = self.dfg[instruction_id] {
+                // A range check isn't affected by the side effects condition, so bounds found
+                // under different conditions, or under a condition which may be false,
+                // can't be combined.
+                lower_bounds.clear();
+                upper_bounds.clear();
+                side_effects_enabled =
+                    self.dfg.get_numeric_constant(condition).is_some_and(|value| value.is_one());
+                continue;
+            }
+            if !side_effects_enabled {
+                continue;
+            }
+
+            let Some((value, bound)) = self.bound_check(instruction_id) else {
+                continue;
+            };
+            match bound {
+                Bound::Lower(lower) => {
+                    lower_bounds.insert(value, (instruction_id, lower));
+                }
+                Bound::Upper(upper) => {
+                    upper_bounds.insert(value, (instruction_id, upper));
+                }
+            }
+
+            let (Some((lower_constraint, lower)), Some((upper_constraint, upper))) =
+                (lower_bounds.get(&value).copied(), upper_bounds.get(&value).copied())
+            else {
+                continue;
+            };
+            let Some(bit_size) = range_bit_size(lower, upper) else {
+                continue;
+            };
+
+            lower_bounds.remove(&value);
+            upper_bounds.remove(&value);
+            self.dfg[block].instructions_mut().retain(|instruction| {
+                *instruction != lower_constraint && *instruction != upper_constraint
+            });
+
+            let call_stack = self.dfg.get_instruction_call_stack_id(instruction_id);
+            let cast = Instruction::Cast(value, NumericType::NativeField);
+            let cast = self.dfg.insert_instruction_and_results(cast, block, None, call_stack);
+            let lower = self.dfg.make_constant(lower, NumericType::NativeField);
+            let offset = Instruction::Binary(Binary {
+                lhs: cast.first(),
+                rhs: lower,
+                operator: BinaryOp::Sub { unchecked: false },
+            });
+            let offset = self.dfg.insert_instruction_and_results(offset, block, None, call_stack);
+            let range_check = Instruction::RangeCheck {
+                value: offset.first(),
+                max_bit_size: bit_size,
+                assert_message: None,
+            };
+            self.dfg.insert_instruction_and_results(range_check, block, None, call_stack);
+        }
+    }
+
+    /// Returns the value bounded by a constraint without an error message, if it asserts
+    /// the result of comparing an unsigned value with a constant.
+    fn bound_check(&self, instruction_id: InstructionId) -> Option<(ValueId, Bound)> {
+        let Instruction::Constrain(lhs, rhs, None) = self.dfg[instruction_id] else {
+            return None;
+        };
+        let holds = self.dfg.get_numeric_constant(rhs)?.is_one();
+
+        let instruction = self.dfg.get_local_or_global_instruction(lhs)?;
+        let Instruction::Binary(Binary { lhs, rhs, operator: BinaryOp::Lt }) = instruction else {
+            return None;
+        };
+
+        let bound = match (self.dfg.get_numeric_constant(*lhs), self.dfg.get_numeric_constant(*rhs))
+        {
+            // `x < c` holds or `x >= c` holds
+            (None, Some(constant)) => {
+                let value = *lhs;
+                (value, if holds { Bound::Upper(constant) } else { Bound::Lower(constant) })
+            }
+            // `c < x` holds, so `x >= c + 1`, or `x <= c` holds, so `x < c + 1`
+            (Some(constant), None) => {
+                let value = *rhs;
+                let constant = constant + FieldElement::one();
+                (value, if holds { Bound::Lower(constant) } else { Bound::Upper(constant) })
+            }
+            _ => return None,
+        };
+
+        let NumericType::Unsigned { .. } = self.dfg.type_of_value(bound.0).unwrap_numeric() else {
+            return None;
+        };
+        Some(bound)
+    }
+}
+
+enum Bound {
+    /// The value is greater than or equal to the constant
+    Lower(FieldElement),
+    /// The value is less than the constant
+    Upper(FieldElement),
+}
+
+/// Returns `k` if `upper - lower` is `2^k`, so that a value is within the bounds exactly when
+/// its difference with `lower` fits in `k` bits.
+fn range_bit_size(lower: FieldElement, upper: FieldElement) -> Option<u32> {
+    if upper <= lower {
+        return None;
+    }
+    let difference = (upper - lower).try_into_u128()?;
+    difference.is_power_of_two().then(|| difference.trailing_zeros())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{opt::assert_normalized_ssa_equals, ssa_gen::Ssa};
+
+    #[test]
+    fn combines_bound_checks_into_range_check() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: u32):
+            v2 = lt v0, u32 8
+            constrain v2 == u1 0
+            v4 = lt v0, u32 24
+            constrain v4 == u1 1
+            return
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        acir(inline) fn main f0 {
+          b0(v0: u32):
+            v2 = lt v0, u32 8
+            v4 = lt v0, u32 24
+            v5 = cast v0 as Field
+            v7 = sub v5, Field 8
+            range_check v7 to 4 bits
+            return
+        }
+        ";
+        let ssa = ssa.combine_bound_checks();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn does_not_combine_bound_checks_on_different_values() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: u32, v1: u32):
+            v3 = lt v0, u32 8
+            constrain v3 == u1 0
+            v5 = lt v1, u32 24
+            constrain v5 == u1 1
+            return
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let ssa = ssa.combine_bound_checks();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+}
@@ -0,0 +1,232 @@
+//! This file contains a pass removing block parameters which receive the same constant
+//! from every predecessor.
+//!
+//! If each `jmp` into a block passes the same numeric constant for one of its parameters,
+//! the parameter always holds that constant. Uses of the parameter are replaced by the
+//! constant and the parameter is removed, along with the corresponding argument of each `jmp`.
+//!
+//! A `jmp` which passes the parameter back to its own block (e.g. from a loop's latch to its
+//! header) doesn't change its value, so it is ignored when checking the other arguments.
+//! A loop's induction variable is never constant, as the latch passes it a different value.
+//!
+//! Such parameters commonly appear after loop invariant code motion and loop unrolling.
+use crate::ssa::{
+    ir::{
+        basic_block::BasicBlockId, cfg::ControlFlowGraph, function::Function,
+        instruction::TerminatorInstruction, value::ValueId,
+    },
+    ssa_gen::Ssa,
+};
+
+impl Ssa {
+    /// Replaces block parameters which receive the same constant from every predecessor
+    /// with that constant, removing them from the block and from each predecessor's `jmp`.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) fn simplify_constant_block_params(mut self) -> Self {
+        for function in self.functions.values_mut() {
+            function.simplify_constant_block_params();
+        }
+        self
+    }
+}
+
+impl Function {
+    pub(crate) fn simplify_constant_block_params(&mut self) {
+        let cfg = ControlFlowGraph::with_function(self);
+
+        // Removing a parameter may turn an argument passed on to another block into a constant,
+        // so keep going until no more parameters can be removed.
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for block in self.reachable_blocks() {
+                let predecessors: Vec<_> = cfg.predecessors(block).collect();
+                changed |= self.simplify_block_params(block, &predecessors);
+            }
+        }
+    }
+
+    /// Removes the parameters of `block` which receive the same constant from all `predecessors`.
+    /// Returns true if any parameter was removed.
+    fn simplify_block_params(
+        &mut self,
+        block: BasicBlockId,
+        predecessors: &[BasicBlockId],
+    ) -> bool {
+        // The entry block's parameters are the function's parameters, which can't be removed
+        if block == self.entry_block()
+            || predecessors.is_empty()
+            || self.dfg[block].parameters().is_empty()
+        {
+            return false;
+        }
+
+        let mut incoming_arguments = Vec::with_capacity(predecessors.len());
+        for predecessor in predecessors {
+            match self.dfg[*predecessor].terminator() {
+                Some(TerminatorInstruction::Jmp { destination, arguments, .. })
+                    if *destination == block =>
+                {
+                    incoming_arguments.push(arguments);
+                }
+                _ => return false,
+            }
+        }
+
+        let parameters = self.dfg[block].parameters();
+        let constant_parameters: Vec<_> = parameters
+            .iter()
+            .enumerate()
+            .filter_map(|(index, parameter)| {
+                let arguments = incoming_arguments
+                    .iter()
+                    .map(|arguments| self.dfg.resolve(arguments[index]))
+                    .filter(|argument| argument != parameter);
+                let constant = same_numeric_constant(self, arguments)?;
+                Some((index, *parameter, constant))
+            })
+            .collect();
+
+        if constant_parameters.is_empty() {
+            return false;
+        }
+
+        for (_, parameter, constant) in &constant_parameters {
+            self.dfg.set_value_from_id(*parameter, *constant);
+        }
+
+        let is_removed = |index: usize| constant_parameters.iter().any(|(i, ..)| *i == index);
+        let parameters = remove_indices(self.dfg[block].take_parameters(), is_removed);
+        self.dfg[block].set_parameters(parameters);
+
+        for predecessor in predecessors {
+            let TerminatorInstruction::Jmp { arguments, .. } =
+                self.dfg[*predecessor].unwrap_terminator_mut()
+            else {
+                unreachable!("Predecessors were already checked to end in a jmp");
+            };
+            *arguments = remove_indices(std::mem::take(arguments), is_removed);
+        }
+
+        true
+    }
+}
+
+/// Returns one of `arguments` if all of them are the same numeric constant.
+fn same_numeric_constant(
+    function: &Function,
+    mut arguments: impl Iterator<Item = ValueId>,
+) -> Option<ValueId> {
+    let first = arguments.next()?;
+    let constant = function.dfg.get_numeric_constant_with_type(first)?;
+    arguments
+        .all(|argument| function.dfg.get_numeric_constant_with_type(argument) == Some(constant))
+        .then_some(first)
+}
+
+fn remove_indices(values: Vec<ValueId>, is_removed: impl Fn(usize) -> bool) -> Vec<ValueId> {
+    values
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| !is_removed(*index))
+        .map(|(_, value)| value)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{opt::assert_normalized_ssa_equals, ssa_gen::Ssa};
+
+    #[test]
+    fn removes_block_param_which_is_always_the_same_constant() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: u1, v1: u32):
+            jmpif v0 then: b1, else: b2
+          b1():
+            jmp b3(u32 5, v1)
+          b2():
+            jmp b3(u32 5, u32 1)
+          b3(v2: u32, v3: u32):
+            v4 = add v2, v3
+            return v4
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0(v0: u1, v1: u32):
+            jmpif v0 then: b1, else: b2
+          b1():
+            jmp b3(v1)
+          b2():
+            jmp b3(u32 1)
+          b3(v2: u32):
+            v4 = add u32 5, v2
+            return v4
+        }
+        ";
+
+        let ssa = ssa.simplify_constant_block_params();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn does_not_remove_loop_induction_variable() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0():
+            jmp b1(u32 0)
+          b1(v0: u32):
+            v3 = lt v0, u32 4
+            jmpif v3 then: b2, else: b3
+          b2():
+            v5 = unchecked_add v0, u32 1
+            jmp b1(v5)
+          b3():
+            return
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.simplify_constant_block_params();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+
+    #[test]
+    fn removes_loop_param_passed_back_unchanged() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0():
+            jmp b1(u32 0, u32 7)
+          b1(v0: u32, v1: u32):
+            v4 = lt v0, u32 4
+            jmpif v4 then: b2, else: b3
+          b2():
+            v6 = unchecked_add v0, u32 1
+            jmp b1(v6, v1)
+          b3():
+            return v1
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0():
+            jmp b1(u32 0)
+          b1(v0: u32):
+            v3 = lt v0, u32 4
+            jmpif v3 then: b2, else: b3
+          b2():
+            v5 = unchecked_add v0, u32 1
+            jmp b1(v5)
+          b3():
+            return u32 7
+        }
+        ";
+
+        let ssa = ssa.simplify_constant_block_params();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+}
@@ -1102,6 +1102,60 @@ mod test {
         assert_normalized_ssa_equals(ssa, expected);
     }
 
+    #[test]
+    fn double_negation_folding() {
+        // The second `not` cancels out the first one, so the constrain can use the comparison
+        // directly. The `not` instruction is left for dead instruction elimination to remove.
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: u32, v1: u32):
+                v2 = lt v0, v1
+                v3 = not v2
+                v4 = not v3
+                constrain v4 == u1 1
+                return
+            }
+            ";
+        let expected = "
+            acir(inline) fn main f0 {
+              b0(v0: u32, v1: u32):
+                v2 = lt v0, v1
+                v3 = not v2
+                constrain v2 == u1 1
+                return
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.fold_constants();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn complementary_comparison_deduplication() {
+        // `v0 >= v1` is lowered as `not (lt v0, v1)`, so when both `v0 < v1` and `v0 >= v1`
+        // are needed the comparison is only computed once.
+        let src = "
+            acir(inline) fn main f0 {
+              b0(v0: u32, v1: u32):
+                v2 = lt v0, v1
+                v3 = lt v0, v1
+                v4 = not v3
+                return v2, v4
+            }
+            ";
+        let expected = "
+            acir(inline) fn main f0 {
+              b0(v0: u32, v1: u32):
+                v2 = lt v0, v1
+                v3 = not v2
+                return v2, v3
+            }
+            ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.fold_constants();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
     #[test]
     fn constant_index_array_access_deduplication() {
         // After constructing this IR, we run constant folding which should replace the second constant-index array get
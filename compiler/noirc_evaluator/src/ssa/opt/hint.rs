@@ -1,37 +1,24 @@
 #[cfg(test)]
 mod tests {
-    use acvm::acir::circuit::ExpressionWidth;
-
     use crate::{
-        brillig::BrilligOptions,
         errors::RuntimeError,
         ssa::{
-            Ssa, SsaBuilder, SsaEvaluatorOptions, SsaLogging, opt::assert_normalized_ssa_equals,
-            optimize_all,
+            Ssa, SsaBuilder, SsaEvaluatorOptions, opt::assert_normalized_ssa_equals, optimize_all,
         },
     };
 
     fn run_all_passes(ssa: Ssa) -> Result<Ssa, RuntimeError> {
-        let options = &SsaEvaluatorOptions {
-            ssa_logging: SsaLogging::None,
-            brillig_options: BrilligOptions::default(),
-            print_codegen_timings: false,
-            expression_width: ExpressionWidth::default(),
-            emit_ssa: None,
-            skip_underconstrained_check: true,
-            enable_brillig_constraints_check_lookback: false,
-            skip_brillig_constraints_check: true,
-            inliner_aggressiveness: 0,
-            max_bytecode_increase_percent: None,
-        };
+        let options = &SsaEvaluatorOptions::for_tests();
 
         let builder = SsaBuilder {
             ssa,
             ssa_logging: options.ssa_logging.clone(),
             print_codegen_timings: false,
+            pass_timings: None,
+            selected_pass: None,
         };
 
-        optimize_all(builder, options)
+        optimize_all(builder, options, None, None, None)
     }
 
     /// Test that the `std::hint::black_box` function prevents some of the optimizations.
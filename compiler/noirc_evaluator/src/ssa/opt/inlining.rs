@@ -8,7 +8,11 @@ use acvm::acir::AcirField;
 use im::HashMap;
 use iter_extended::{btree_map, vecmap};
 
+use noirc_frontend::monomorphization::ast::InlineType;
+use petgraph::algo::tarjan_scc;
+
 use crate::ssa::{
+    InliningPolicy, PassStatistics,
     function_builder::FunctionBuilder,
     ir::{
         basic_block::BasicBlockId,
@@ -18,11 +22,13 @@ use crate::ssa::{
         instruction::{Instruction, InstructionId, TerminatorInstruction},
         value::{Value, ValueId},
     },
+    opt::pure::build_call_graph,
     ssa_gen::Ssa,
 };
 
 pub(super) mod inline_info;
 
+use inline_info::compute_function_own_weight;
 pub(super) use inline_info::{InlineInfo, InlineInfos, compute_inline_infos};
 
 /// An arbitrary limit to the maximum number of recursive call
@@ -50,20 +56,41 @@ impl Ssa {
     /// This step should run after runtime separation, since it relies on the runtime of the called functions being final.
     #[tracing::instrument(level = "trace", skip(self))]
     pub(crate) fn inline_functions(self, aggressiveness: i64) -> Ssa {
+        self.inline_functions_with_statistics(aggressiveness, None)
+    }
+
+    /// Inlines functions as [`Ssa::inline_functions`] does, recording the number of calls
+    /// inlined into each function into `statistics` if it is set.
+    pub(crate) fn inline_functions_with_statistics(
+        self,
+        aggressiveness: i64,
+        statistics: Option<&mut PassStatistics>,
+    ) -> Ssa {
         let inline_infos = compute_inline_infos(&self, false, aggressiveness);
-        Self::inline_functions_inner(self, &inline_infos, false)
+        Self::inline_functions_inner(self, &inline_infos, false, statistics)
     }
 
     /// Run the inlining pass where functions marked with `InlineType::NoPredicates` as not entry points
     pub(crate) fn inline_functions_with_no_predicates(self, aggressiveness: i64) -> Ssa {
+        self.inline_functions_with_no_predicates_with_statistics(aggressiveness, None)
+    }
+
+    /// Runs [`Ssa::inline_functions_with_no_predicates`], recording the number of calls
+    /// inlined into each function into `statistics` if it is set.
+    pub(crate) fn inline_functions_with_no_predicates_with_statistics(
+        self,
+        aggressiveness: i64,
+        statistics: Option<&mut PassStatistics>,
+    ) -> Ssa {
         let inline_infos = compute_inline_infos(&self, true, aggressiveness);
-        Self::inline_functions_inner(self, &inline_infos, true)
+        Self::inline_functions_inner(self, &inline_infos, true, statistics)
     }
 
     fn inline_functions_inner(
         mut self,
         inline_infos: &InlineInfos,
         inline_no_predicates_functions: bool,
+        mut statistics: Option<&mut PassStatistics>,
     ) -> Ssa {
         let inline_targets =
             inline_infos.iter().filter_map(|(id, info)| info.is_inline_target().then_some(*id));
@@ -88,12 +115,42 @@ impl Ssa {
         // instead of inlining the "leaf" functions, moving up towards the entry point.
         self.functions = btree_map(inline_targets, |entry_point| {
             let function = &self.functions[&entry_point];
-            let new_function = function.inlined(&self, &should_inline_call);
+            let (new_function, inlined_calls) =
+                function.inlined_counting_calls(&self, &should_inline_call);
+            if let Some(statistics) = statistics.as_deref_mut() {
+                let name = function.name().to_string();
+                *statistics.calls_inlined.entry(name).or_default() += inlined_calls;
+            }
             (entry_point, new_function)
         });
         self
     }
 
+    /// Turn the `#[fold]` functions which are small enough according to the given policy
+    /// into regular ACIR functions, so that the following inlining passes inline them into
+    /// their callers instead of keeping them as separate circuits.
+    ///
+    /// Recursive functions, including mutually recursive ones, are never turned into regular
+    /// functions, as they could not be fully inlined.
+    pub(crate) fn inline_small_fold_functions(mut self, policy: InliningPolicy) -> Ssa {
+        let Some(max_instructions) = policy.max_fold_function_instructions else {
+            return self;
+        };
+
+        let recursive_functions = recursive_functions(&self);
+        for (id, function) in self.functions.iter_mut() {
+            if *id == self.main_id || function.runtime() != RuntimeType::Acir(InlineType::Fold) {
+                continue;
+            }
+
+            let is_recursive = recursive_functions.contains(id);
+            if !is_recursive && compute_function_own_weight(function) <= max_instructions {
+                function.set_runtime(RuntimeType::Acir(InlineType::Inline));
+            }
+        }
+        self
+    }
+
     pub(crate) fn inline_simple_functions(mut self: Ssa) -> Ssa {
         let should_inline_call = |callee: &Function| {
             if let RuntimeType::Acir(_) = callee.runtime() {
@@ -130,6 +187,16 @@ impl Function {
         ssa: &Ssa,
         should_inline_call: &impl Fn(&Function) -> bool,
     ) -> Function {
+        self.inlined_counting_calls(ssa, should_inline_call).0
+    }
+
+    /// Like [`Function::inlined`], also returning the number of calls which have been inlined,
+    /// including the calls made from the bodies of the inlined functions.
+    pub(super) fn inlined_counting_calls(
+        &self,
+        ssa: &Ssa,
+        should_inline_call: &impl Fn(&Function) -> bool,
+    ) -> (Function, usize) {
         InlineContext::new(ssa, self.id()).inline_all(ssa, &should_inline_call)
     }
 }
@@ -147,6 +214,9 @@ struct InlineContext {
 
     // The FunctionId of the entry point function we're inlining into in the old, unmodified Ssa.
     entry_point: FunctionId,
+
+    // The number of calls inlined so far
+    inlined_calls: usize,
 }
 
 /// The per-function inlining context contains information that is only valid for one function.
@@ -210,6 +280,23 @@ fn called_functions(func: &Function) -> BTreeSet<FunctionId> {
     called_functions_vec(func).into_iter().collect()
 }
 
+/// Find the functions which are part of a cycle in the call graph, either because they call
+/// themselves directly or because they are mutually recursive with other functions.
+fn recursive_functions(ssa: &Ssa) -> HashSet<FunctionId> {
+    let dependencies =
+        ssa.functions.iter().map(|(id, function)| (*id, called_functions(function))).collect();
+    let (graph, _, indices_to_ids) = build_call_graph(dependencies);
+
+    let mut recursive_functions = HashSet::new();
+    for component in tarjan_scc(&graph) {
+        let is_cycle = component.len() > 1 || graph.contains_edge(component[0], component[0]);
+        if is_cycle {
+            recursive_functions.extend(component.iter().map(|index| indices_to_ids[index]));
+        }
+    }
+    recursive_functions
+}
+
 impl InlineContext {
     /// Create a new context object for the function inlining pass.
     /// This starts off with an empty mapping of instructions for main's parameters.
@@ -219,15 +306,22 @@ impl InlineContext {
     fn new(ssa: &Ssa, entry_point: FunctionId) -> Self {
         let source = &ssa.functions[&entry_point];
         let builder = FunctionBuilder::from_existing(source, entry_point);
-        Self { builder, recursion_level: 0, entry_point, call_stack: CallStackId::root() }
+        Self {
+            builder,
+            recursion_level: 0,
+            entry_point,
+            call_stack: CallStackId::root(),
+            inlined_calls: 0,
+        }
     }
 
     /// Start inlining the entry point function and all functions reachable from it.
+    /// Returns the inlined function along with the number of calls which have been inlined.
     fn inline_all(
         mut self,
         ssa: &Ssa,
         should_inline_call: &impl Fn(&Function) -> bool,
-    ) -> Function {
+    ) -> (Function, usize) {
         let entry_point = &ssa.functions[&self.entry_point];
 
         let globals = &entry_point.dfg.globals;
@@ -260,7 +354,7 @@ impl InlineContext {
         assert_eq!(new_ssa.functions.len(), 1);
         let mut new_func = new_ssa.functions.pop_first().unwrap().1;
         new_func.dfg.data_bus = databus;
-        new_func
+        (new_func, self.inlined_calls)
     }
 
     /// Inlines a function into the current function and returns the translated return values
@@ -273,6 +367,7 @@ impl InlineContext {
         should_inline_call: &impl Fn(&Function) -> bool,
     ) -> Vec<ValueId> {
         self.recursion_level += 1;
+        self.inlined_calls += 1;
 
         let source_function = &ssa.functions[&id];
 
@@ -788,7 +883,7 @@ mod test {
     use noirc_frontend::monomorphization::ast::InlineType;
 
     use crate::ssa::{
-        Ssa,
+        InliningPolicy, Ssa,
         function_builder::FunctionBuilder,
         ir::{
             basic_block::BasicBlockId,
@@ -1179,6 +1274,87 @@ mod test {
         assert_eq!(inlined.functions.len(), 2);
     }
 
+    const SMALL_FOLD_FUNCTION_SRC: &str = "
+        acir(inline) fn main f0 {
+          b0(v0: Field):
+            v2 = call f1(v0) -> Field
+            return v2
+        }
+        acir(fold) fn double f1 {
+          b0(v0: Field):
+            v1 = add v0, v0
+            return v1
+        }
+        ";
+
+    #[test]
+    fn inlines_small_fold_function_under_aggressive_policy() {
+        let ssa = Ssa::from_str(SMALL_FOLD_FUNCTION_SRC).unwrap();
+
+        let ssa = ssa.inline_small_fold_functions(InliningPolicy::aggressive(10));
+        let ssa = ssa.inline_functions(i64::MAX);
+
+        let expected = "
+        acir(inline) fn main f0 {
+          b0(v0: Field):
+            v1 = add v0, v0
+            return v1
+        }
+        ";
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn preserves_small_fold_function_under_conservative_policy() {
+        let ssa = Ssa::from_str(SMALL_FOLD_FUNCTION_SRC).unwrap();
+
+        let ssa = ssa.inline_small_fold_functions(InliningPolicy::conservative());
+        let ssa = ssa.inline_functions(i64::MAX);
+
+        assert_normalized_ssa_equals(ssa, SMALL_FOLD_FUNCTION_SRC);
+    }
+
+    #[test]
+    fn preserves_fold_function_above_instruction_threshold() {
+        let ssa = Ssa::from_str(SMALL_FOLD_FUNCTION_SRC).unwrap();
+
+        // `double` has one instruction and a terminator
+        let ssa = ssa.inline_small_fold_functions(InliningPolicy::aggressive(1));
+        let ssa = ssa.inline_functions(i64::MAX);
+
+        assert_eq!(ssa.functions.len(), 2);
+    }
+
+    #[test]
+    fn preserves_mutually_recursive_small_fold_functions() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: u32):
+            v2 = call f1(v0) -> u32
+            return v2
+        }
+        acir(fold) fn ping f1 {
+          b0(v0: u32):
+            v2 = call f2(v0) -> u32
+            return v2
+        }
+        acir(fold) fn pong f2 {
+          b0(v0: u32):
+            v2 = call f1(v0) -> u32
+            return v2
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let ssa = ssa.inline_small_fold_functions(InliningPolicy::aggressive(10));
+
+        for function in ssa.functions.values() {
+            if function.id() != ssa.main_id {
+                assert_eq!(function.runtime(), RuntimeType::Acir(InlineType::Fold));
+            }
+        }
+    }
+
     #[test]
     fn bottom_up_order_and_weights() {
         let src = "
@@ -232,7 +232,7 @@ pub(crate) fn compute_bottom_up_order(ssa: &Ssa) -> Vec<(FunctionId, (usize, usi
 }
 
 /// Compute a weight of a function based on the number of instructions in its reachable blocks.
-fn compute_function_own_weight(func: &Function) -> usize {
+pub(crate) fn compute_function_own_weight(func: &Function) -> usize {
     let mut weight = 0;
     for block_id in func.reachable_blocks() {
         weight += func.dfg[block_id].instructions().len() + 1; // We add one for the terminator
@@ -44,11 +44,46 @@
 //!
 //! We then can store the PDFs for every block as part of the context of this pass, and use it for checking control dependence.
 //! Using PDFs gets us from a worst case n^2 complexity to a worst case n.
+//!
+//! Instructions in the loop body which recompute the loop header's condition, or the comparison
+//! it negates, reuse the value computed by the header in the same iteration.
+//!
+//! Hoisting a "pure with predicate instruction" also moves it out from under the
+//! `enable_side_effects` predicate active in its loop block, so the pass tracks that predicate.
+//! Such instructions are only hoisted while the predicate is the one the loop was entered with,
+//! or when their block resets it to the always-true `u1 1`. They stay in the loop when an
+//! `enable_side_effects` in the loop sets any other predicate, which may change between iterations.
+//! The comparison computing a predicate (e.g. `eq flag, u1 1`) is pure, so it is hoisted when its
+//! operands are loop invariant, while the `enable_side_effects` using it stays in the loop. If the
+//! comparison folds to `u1 1` as it is hoisted, the instructions under it are hoisted as well.
+//!
+//! An unsigned division or modulo by a non-zero constant can't fail, so it is hoisted even from
+//! control dependent blocks. The result of a modulo by `N` is known to be less than `N`, so an
+//! array access with a rotated index such as `arr[(i + k) % N]`, where `i` is the induction
+//! variable of an outer loop, is known to be in bounds of an array of length `N`.
+//!
+//! Bit decompositions (`to_bits` and `to_radix`) of loop invariant values fail if the value
+//! doesn't fit in the requested limbs, so like assertions they are only hoisted out of loops
+//! which are known to execute.
+//!
+//! In Brillig functions, an `inc_rc` of a loop invariant array followed by a `dec_rc` of the same
+//! array in the same loop block leaves the reference count unchanged after every iteration, so the
+//! pair is moved around the loop: the `inc_rc` to the pre-header and the `dec_rc` to the loop exit.
+//!
+//! In Brillig functions, an `allocate` in the loop which is initialized with a loop invariant
+//! value and never stored to again in the loop is hoisted along with its initializing `store`,
+//! as every iteration would otherwise recreate the same cell.
+//!
+//! When a [LoopReport] is requested, the pass records for each loop how many instructions it
+//! hoisted, along with the loop invariant instructions it left in the loop and why.
+use std::collections::BTreeMap;
+
 use acvm::{FieldElement, acir::AcirField};
 use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
+use crate::errors::{InternalWarning, SsaReport};
 use crate::ssa::{
-    Ssa,
+    LoopOptimization, LoopReport, NotHoistedInstruction, NotHoistedReason, PassStatistics, Ssa,
     ir::{
         basic_block::BasicBlockId,
         call_stack::CallStackId,
@@ -58,23 +93,57 @@ use crate::ssa::{
         function::Function,
         function_inserter::FunctionInserter,
         instruction::{
-            Binary, BinaryOp, ConstrainError, Instruction, InstructionId,
-            binary::eval_constant_binary_op,
+            Binary, BinaryOp, ConstrainError, Instruction, InstructionId, Intrinsic,
+            TerminatorInstruction, binary::eval_constant_binary_op,
         },
         post_order::PostOrder,
+        printer::instruction_to_string,
         types::{NumericType, Type},
         value::{Value, ValueId},
     },
     opt::pure::Purity,
 };
 
+use super::read_only_parameters::ReadOnlyParameters;
 use super::unrolling::{Loop, Loops};
 
 impl Ssa {
     #[tracing::instrument(level = "trace", skip(self))]
-    pub(crate) fn loop_invariant_code_motion(mut self) -> Ssa {
+    pub(crate) fn loop_invariant_code_motion(self) -> Ssa {
+        self.loop_invariant_code_motion_with_reports(None, None, None, false)
+    }
+
+    /// Runs loop invariant code motion, recording the number of instructions hoisted
+    /// out of loops in each function into `statistics` if it is set.
+    ///
+    /// If `assertion_warnings` is set, warnings are pushed onto it for assertions which the
+    /// loop bounds prove to always hold, or which are unreachable because their loop never executes.
+    ///
+    /// If `loop_report` is set, how each loop was optimized is recorded into it.
+    ///
+    /// If `keep_brillig_overflow_checks` is set, checked arithmetic in Brillig functions is
+    /// left checked even when the loop bounds prove that it cannot overflow.
+    pub(crate) fn loop_invariant_code_motion_with_reports(
+        mut self,
+        mut statistics: Option<&mut PassStatistics>,
+        mut assertion_warnings: Option<&mut Vec<SsaReport>>,
+        mut loop_report: Option<&mut LoopReport>,
+        keep_brillig_overflow_checks: bool,
+    ) -> Ssa {
+        let read_only_parameters = ReadOnlyParameters::compute(&self);
         for function in self.functions.values_mut() {
-            function.loop_invariant_code_motion();
+            let hoisted = Loops::find_all(function).hoist_loop_invariants(
+                function,
+                assertion_warnings.as_deref_mut(),
+                loop_report.as_deref_mut(),
+                keep_brillig_overflow_checks,
+                &read_only_parameters,
+            );
+
+            if let Some(statistics) = statistics.as_deref_mut() {
+                let name = function.name().to_string();
+                *statistics.loop_invariants_hoisted.entry(name).or_default() += hoisted;
+            }
         }
 
         self
@@ -82,14 +151,44 @@ impl Ssa {
 }
 
 impl Function {
-    pub(super) fn loop_invariant_code_motion(&mut self) {
-        Loops::find_all(self).hoist_loop_invariants(self);
+    /// Returns the number of instructions hoisted out of loops.
+    ///
+    /// Without the rest of the program, every array passed to another function is assumed
+    /// to possibly be mutated by it.
+    pub(super) fn loop_invariant_code_motion(
+        &mut self,
+        keep_brillig_overflow_checks: bool,
+    ) -> usize {
+        let read_only_parameters = ReadOnlyParameters::default();
+        Loops::find_all(self).hoist_loop_invariants(
+            self,
+            None,
+            None,
+            keep_brillig_overflow_checks,
+            &read_only_parameters,
+        )
     }
 }
 
 impl Loops {
-    fn hoist_loop_invariants(mut self, function: &mut Function) {
-        let mut context = LoopInvariantContext::new(function);
+    fn hoist_loop_invariants(
+        mut self,
+        function: &mut Function,
+        assertion_warnings: Option<&mut Vec<SsaReport>>,
+        mut loop_report: Option<&mut LoopReport>,
+        keep_brillig_overflow_checks: bool,
+        read_only_parameters: &ReadOnlyParameters,
+    ) -> usize {
+        let keep_overflow_checks = keep_brillig_overflow_checks && function.runtime().is_brillig();
+        let function_name = function.name().to_string();
+        let function_id = function.id();
+        let mut context = LoopInvariantContext::new(
+            function,
+            assertion_warnings.is_some(),
+            loop_report.is_some(),
+            keep_overflow_checks,
+            read_only_parameters,
+        );
 
         // The loops should be sorted by the number of blocks.
         // We want to access outer nested loops first, which we do by popping
@@ -101,11 +200,27 @@ impl Loops {
             };
 
             context.current_pre_header = Some(pre_header);
+            let hoisted_before = context.hoisted_instructions;
             context.hoist_loop_invariants(&loop_);
+
+            if let Some(loop_report) = loop_report.as_deref_mut() {
+                loop_report.loops.push(LoopOptimization {
+                    function: function_name.clone(),
+                    function_id: Some(function_id),
+                    location: loop_.location(context.inserter.function),
+                    unrolled: false,
+                    instructions_hoisted: context.hoisted_instructions - hoisted_before,
+                    not_hoisted: std::mem::take(&mut context.not_hoisted),
+                });
+            }
         }
 
         context.map_dependent_instructions();
         context.inserter.map_data_bus_in_place();
+        if let Some(assertion_warnings) = assertion_warnings {
+            assertion_warnings.extend(context.assertion_warnings.into_values());
+        }
+        context.hoisted_instructions
     }
 }
 
@@ -124,7 +239,7 @@ impl Loop {
     ///     jmpif v5 then: b3, else: b2
     /// ```
     /// In the example above, `v1` is the induction variable
-    fn get_induction_variable(&self, function: &Function) -> ValueId {
+    pub(super) fn get_induction_variable(&self, function: &Function) -> ValueId {
         function.dfg.block_parameters(self.header)[0]
     }
 }
@@ -152,19 +267,73 @@ struct LoopInvariantContext<'f> {
     // Stores whether the current block being processed is control dependent
     current_block_control_dependent: bool,
 
+    // Whether an `enable_side_effects` in the current loop sets a predicate other than `u1 1`,
+    // in which case loop blocks may be entered under a predicate which isn't the pre-header's
+    loop_sets_predicate: bool,
+
+    // The condition of the last `enable_side_effects` in the loop block being processed
+    block_predicate: Option<ValueId>,
+
     // Maps a block to its post-dominance frontiers
     // This map should be precomputed a single time and used for checking control dependence.
     post_dom_frontiers: HashMap<BasicBlockId, HashSet<BasicBlockId>>,
 
     // Indicates whether the current loop has break or early returns
     no_break: bool,
+
+    // `MakeArray` and array call results which are only ever read from within the function.
+    // Hoisting one of these does not require an extra `inc_rc` as the array is never mutated.
+    immutable_arrays: HashSet<ValueId>,
+
+    // `Allocate` results which are only ever used as the address of a `load` or `store`.
+    // The value behind one of these can only change through a `store` to that address.
+    non_escaping_allocations: HashSet<ValueId>,
+
+    // Maps an address to the values stored at it within the current loop
+    stores_in_loop: HashMap<ValueId, Vec<ValueId>>,
+
+    // Allocations hoisted out of the current loop whose initializing `store` must follow them
+    hoisted_allocations: HashSet<ValueId>,
+
+    // The number of instructions hoisted out of loops so far
+    hoisted_instructions: usize,
+
+    // Whether to report assertions which the loop bounds prove to always hold or to be unreachable
+    warn_on_assertions: bool,
+
+    // Whether to record why loop invariant instructions are left in their loop
+    report_not_hoisted: bool,
+
+    // The loop invariant instructions left in the current loop, along with the reason why
+    not_hoisted: Vec<NotHoistedInstruction>,
+
+    // Whether checked arithmetic must stay checked even if the loop bounds prove it cannot overflow
+    keep_overflow_checks: bool,
+
+    // Instruction results which were replaced by a constant using the bounds of an induction variable
+    values_known_from_loop_bounds: HashSet<ValueId>,
+
+    // Warnings about assertions found while analyzing loop bounds, keyed by the assertion
+    // so that assertions in nested loops are only reported once
+    assertion_warnings: BTreeMap<InstructionId, SsaReport>,
+
+    // The instructions computing the current loop header's condition, along with their results.
+    // This holds the condition itself and, if it is a `not`, the comparison it negates.
+    header_conditions: Vec<(Instruction, ValueId)>,
+
     // Helper constants
     true_value: ValueId,
     false_value: ValueId,
 }
 
 impl<'f> LoopInvariantContext<'f> {
-    fn new(function: &'f mut Function) -> Self {
+    fn new(
+        function: &'f mut Function,
+        warn_on_assertions: bool,
+        report_not_hoisted: bool,
+        keep_overflow_checks: bool,
+        read_only_parameters: &ReadOnlyParameters,
+    ) -> Self {
         let cfg = ControlFlowGraph::with_function(function);
         let reversed_cfg = cfg.reverse();
         let post_order = PostOrder::with_cfg(&reversed_cfg);
@@ -174,6 +343,12 @@ impl<'f> LoopInvariantContext<'f> {
             function.dfg.make_constant(FieldElement::one(), NumericType::Unsigned { bit_size: 1 });
         let false_value =
             function.dfg.make_constant(FieldElement::zero(), NumericType::Unsigned { bit_size: 1 });
+        let immutable_arrays = if function.runtime().is_brillig() {
+            find_immutable_arrays(function, read_only_parameters)
+        } else {
+            HashSet::default()
+        };
+        let non_escaping_allocations = find_non_escaping_allocations(function);
         Self {
             inserter: FunctionInserter::new(function),
             defined_in_loop: HashSet::default(),
@@ -183,10 +358,24 @@ impl<'f> LoopInvariantContext<'f> {
             current_pre_header: None,
             cfg,
             current_block_control_dependent: false,
+            loop_sets_predicate: false,
+            block_predicate: None,
             post_dom_frontiers,
             true_value,
             false_value,
             no_break: false,
+            immutable_arrays,
+            non_escaping_allocations,
+            stores_in_loop: HashMap::default(),
+            hoisted_allocations: HashSet::default(),
+            hoisted_instructions: 0,
+            warn_on_assertions,
+            report_not_hoisted,
+            not_hoisted: Vec::new(),
+            keep_overflow_checks,
+            values_known_from_loop_bounds: HashSet::default(),
+            assertion_warnings: BTreeMap::new(),
+            header_conditions: Vec::new(),
         }
     }
 
@@ -210,50 +399,247 @@ impl<'f> LoopInvariantContext<'f> {
 
     fn hoist_loop_invariants(&mut self, loop_: &Loop) {
         self.set_values_defined_in_loop(loop_);
+        self.header_conditions.clear();
 
+        // The header is processed like any other loop block. It is always executed at least once
+        // whenever the pre-header is reached, so invariant operands of the loop-bound comparison
+        // (e.g. `lt v2, (mul a b)`) are hoisted even though the comparison itself stays in the header.
         for block in loop_.blocks.iter() {
             self.is_control_dependent_post_pre_header(loop_, *block);
-
-            for instruction_id in self.inserter.function.dfg[*block].take_instructions() {
+            self.block_predicate = None;
+
+            let instructions = self.inserter.function.dfg[*block].take_instructions();
+            for (position, instruction_id) in instructions.iter().copied().enumerate() {
+                if let Instruction::EnableSideEffectsIf { condition } =
+                    self.inserter.function.dfg[instruction_id]
+                {
+                    self.block_predicate = Some(self.inserter.resolve(condition));
+                }
+                if self.warn_on_assertions {
+                    self.check_assertion_from_loop_bounds(instruction_id, loop_, *block);
+                }
                 if self.simplify_from_loop_bounds(instruction_id, loop_, block) {
                     continue;
                 }
-                let hoist_invariant = self.can_hoist_invariant(instruction_id);
+                if *block != loop_.header && self.reuse_header_condition(instruction_id) {
+                    continue;
+                }
+                let hoisting = self.can_hoist_invariant(instruction_id);
+                let hoist_invariant = hoisting.is_ok()
+                    || self.can_hoist_initialized_allocation(
+                        instruction_id,
+                        &instructions[position + 1..],
+                    )
+                    || self.is_store_initializing_hoisted_allocation(instruction_id);
+
+                if !hoist_invariant {
+                    if let Some(address) = self.array_get_snapshot_address(instruction_id, loop_) {
+                        self.hoist_array_get_from_snapshot(instruction_id, address);
+                        self.hoisted_instructions += 1;
+                        self.extend_values_defined_in_loop_and_invariants(instruction_id, true);
+                        continue;
+                    }
+                }
 
                 if hoist_invariant {
                     self.inserter.push_instruction(instruction_id, self.pre_header());
+                    self.hoisted_instructions += 1;
 
-                    // If we are hoisting a MakeArray instruction,
+                    // If we are hoisting a MakeArray instruction or a call returning arrays,
                     // we need to issue an extra inc_rc in case they are mutated afterward.
                     if self.inserter.function.runtime().is_brillig()
                         && matches!(
                             self.inserter.function.dfg[instruction_id],
-                            Instruction::MakeArray { .. }
+                            Instruction::MakeArray { .. } | Instruction::Call { .. }
                         )
                     {
-                        let result =
-                            self.inserter.function.dfg.instruction_results(instruction_id)[0];
-                        let inc_rc = Instruction::IncrementRc { value: result };
-                        let call_stack = self
-                            .inserter
-                            .function
-                            .dfg
-                            .get_instruction_call_stack_id(instruction_id);
-                        self.inserter
-                            .function
-                            .dfg
-                            .insert_instruction_and_results(inc_rc, *block, None, call_stack);
+                        self.increment_rc_of_hoisted_arrays(instruction_id, *block);
+                    }
+                } else if self.report_not_hoisted {
+                    // Predicates and reference counts are bookkeeping, which isn't worth reporting
+                    let is_bookkeeping = matches!(
+                        self.inserter.function.dfg[instruction_id],
+                        Instruction::EnableSideEffectsIf { .. }
+                            | Instruction::IncrementRc { .. }
+                            | Instruction::DecrementRc { .. }
+                    );
+                    let new_id = self.inserter.push_instruction(instruction_id, *block);
+                    if let (Err(Some(reason)), Some(new_id), false) =
+                        (hoisting, new_id, is_bookkeeping)
+                    {
+                        self.record_not_hoisted(new_id, reason);
                     }
                 } else {
                     self.inserter.push_instruction(instruction_id, *block);
                 }
                 self.extend_values_defined_in_loop_and_invariants(instruction_id, hoist_invariant);
             }
+
+            if *block == loop_.header {
+                self.set_header_conditions(loop_.header);
+            }
+        }
+
+        if self.inserter.function.runtime().is_brillig() {
+            self.hoist_balanced_reference_counts(loop_);
         }
 
         self.set_induction_var_bounds(loop_, false);
     }
 
+    /// Moves each `inc_rc` of a loop invariant array which is followed by a `dec_rc` of the same
+    /// array in the same loop block out of the loop: the `inc_rc` to the pre-header and the
+    /// `dec_rc` to the start of the loop exit.
+    ///
+    /// Such a pair leaves the reference count unchanged after every iteration, so issuing it
+    /// once around the whole loop keeps the count the same at the loop boundary. Inside the loop
+    /// the count stays at least as high as it was, so the array is still copied before being
+    /// mutated wherever it was before.
+    ///
+    /// This is only done when the loop can only be left through its header, as otherwise
+    /// the `dec_rc` wouldn't be executed on every path out of the loop.
+    fn hoist_balanced_reference_counts(&mut self, loop_: &Loop) {
+        let Some(exit) = self.single_loop_exit(loop_) else {
+            return;
+        };
+
+        let mut hoisted_inc_rcs = Vec::new();
+        let mut sunk_dec_rcs = Vec::new();
+        for block in loop_.blocks.iter() {
+            let mut pending_inc_rcs: HashMap<ValueId, Vec<InstructionId>> = HashMap::default();
+            let mut balanced = HashSet::default();
+            for instruction_id in self.inserter.function.dfg[*block].instructions() {
+                match self.inserter.function.dfg[*instruction_id] {
+                    Instruction::IncrementRc { value } if self.is_loop_invariant(&value) => {
+                        pending_inc_rcs.entry(value).or_default().push(*instruction_id);
+                    }
+                    Instruction::DecrementRc { value } => {
+                        let inc_rc = pending_inc_rcs.get_mut(&value).and_then(|ids| ids.pop());
+                        if let Some(inc_rc) = inc_rc {
+                            hoisted_inc_rcs.push(inc_rc);
+                            sunk_dec_rcs.push(*instruction_id);
+                            balanced.insert(inc_rc);
+                            balanced.insert(*instruction_id);
+                        }
+                    }
+                    _ => (),
+                }
+            }
+
+            if !balanced.is_empty() {
+                let instructions = self.inserter.function.dfg[*block].instructions_mut();
+                instructions.retain(|instruction| !balanced.contains(instruction));
+            }
+        }
+
+        let pre_header = self.pre_header();
+        for inc_rc in hoisted_inc_rcs {
+            self.inserter.function.dfg[pre_header].insert_instruction(inc_rc);
+            self.hoisted_instructions += 1;
+        }
+        let exit_instructions = self.inserter.function.dfg[exit].instructions_mut();
+        exit_instructions.splice(0..0, sunk_dec_rcs);
+    }
+
+    /// Returns the block the loop exits to if the loop can only be left through its header,
+    /// that is if it has neither `break`s nor early returns.
+    fn single_loop_exit(&self, loop_: &Loop) -> Option<BasicBlockId> {
+        if !self.no_break {
+            return None;
+        }
+
+        let dfg = &self.inserter.function.dfg;
+        let returns = loop_.blocks.iter().any(|block| {
+            matches!(dfg[*block].terminator(), Some(TerminatorInstruction::Return { .. }))
+        });
+        if returns {
+            return None;
+        }
+
+        dfg[loop_.header].successors().find(|block| !loop_.blocks.contains(block))
+    }
+
+    /// Records the instructions computing the loop header's `jmpif` condition, once the
+    /// header's instructions have been re-inserted.
+    ///
+    /// The header dominates every other block of the loop, so a body instruction recomputing
+    /// one of these can reuse the value computed by the header in the same iteration.
+    fn set_header_conditions(&mut self, header: BasicBlockId) {
+        let Some(TerminatorInstruction::JmpIf { condition, .. }) =
+            self.inserter.function.dfg[header].terminator()
+        else {
+            return;
+        };
+        let mut condition = self.inserter.resolve(*condition);
+
+        loop {
+            let dfg = &self.inserter.function.dfg;
+            let Value::Instruction { instruction, .. } = dfg[condition] else {
+                return;
+            };
+            if !dfg[header].instructions().contains(&instruction) {
+                return;
+            }
+            let instruction = dfg[instruction].clone();
+            let negated = match &instruction {
+                Instruction::Not(negated) => Some(*negated),
+                _ => None,
+            };
+            self.header_conditions.push((instruction, condition));
+
+            match negated {
+                Some(negated) => condition = negated,
+                None => return,
+            }
+        }
+    }
+
+    /// If the instruction recomputes the loop header's condition, or the comparison it negates,
+    /// replaces its result with the value computed in the header.
+    ///
+    /// Returns true if the instruction was replaced and must not be added to the block.
+    fn reuse_header_condition(&mut self, instruction_id: InstructionId) -> bool {
+        if self.header_conditions.is_empty() {
+            return false;
+        }
+
+        let (instruction, _) = self.inserter.map_instruction(instruction_id);
+        let Some((_, header_value)) =
+            self.header_conditions.iter().find(|(condition, _)| *condition == instruction)
+        else {
+            return false;
+        };
+        let header_value = *header_value;
+
+        let results = self.inserter.function.dfg.instruction_results(instruction_id);
+        assert_eq!(results.len(), 1, "ICE: loop conditions should have a single result");
+        let result = results[0];
+        self.inserter.function.dfg.set_value_from_id(result, header_value);
+        true
+    }
+
+    /// Issues an `inc_rc` in `block` for each array result of a hoisted instruction,
+    /// so that mutating the array inside the loop copies it rather than changing
+    /// the value shared by every iteration.
+    fn increment_rc_of_hoisted_arrays(
+        &mut self,
+        instruction_id: InstructionId,
+        block: BasicBlockId,
+    ) {
+        let dfg = &mut self.inserter.function.dfg;
+        let call_stack = dfg.get_instruction_call_stack_id(instruction_id);
+        let results = dfg.instruction_results(instruction_id).to_vec();
+        for result in results {
+            // Arrays which are never mutated in this function do not need it.
+            let is_array = matches!(dfg.type_of_value(result), Type::Array(..) | Type::Slice(..));
+            if !is_array || self.immutable_arrays.contains(&result) {
+                continue;
+            }
+            let inc_rc = Instruction::IncrementRc { value: result };
+            dfg.insert_instruction_and_results(inc_rc, block, None, call_stack);
+        }
+    }
+
     /// Checks whether a `block` is control dependent on any blocks after
     /// the given loop's header.
     fn is_control_dependent_post_pre_header(&mut self, loop_: &Loop, block: BasicBlockId) {
@@ -297,6 +683,9 @@ impl<'f> LoopInvariantContext<'f> {
         // For a new loop, we clear the previous induction variable and then
         // set the new current induction variable.
         self.current_induction_variables.clear();
+        self.stores_in_loop.clear();
+        self.hoisted_allocations.clear();
+        self.loop_sets_predicate = false;
         self.set_induction_var_bounds(loop_, true);
         self.no_break = self.is_fully_executed(loop_);
 
@@ -306,10 +695,36 @@ impl<'f> LoopInvariantContext<'f> {
             for instruction_id in self.inserter.function.dfg[*block].instructions() {
                 let results = self.inserter.function.dfg.instruction_results(*instruction_id);
                 self.defined_in_loop.extend(results);
+                match self.inserter.function.dfg[*instruction_id] {
+                    Instruction::Store { address, value } => {
+                        self.stores_in_loop.entry(address).or_default().push(value);
+                    }
+                    Instruction::EnableSideEffectsIf { condition } => {
+                        let always_true = self.is_always_true(condition);
+                        self.loop_sets_predicate |= !always_true;
+                    }
+                    _ => (),
+                }
             }
         }
     }
 
+    fn is_always_true(&self, value: ValueId) -> bool {
+        self.inserter.function.dfg.get_numeric_constant(value).is_some_and(|value| value.is_one())
+    }
+
+    /// True if instructions relying on the `enable_side_effects` predicate can be moved out of
+    /// the loop block being processed without changing the predicate they are executed under.
+    ///
+    /// This is the case if the block resets the predicate to `u1 1`, or if it keeps the predicate
+    /// the loop was entered with because no `enable_side_effects` in the loop sets another one.
+    fn is_predicate_hoistable(&self) -> bool {
+        match self.block_predicate {
+            Some(predicate) => self.is_always_true(predicate),
+            None => !self.loop_sets_predicate,
+        }
+    }
+
     /// Update any values defined in the loop and loop invariants after
     /// analyzing and re-inserting a loop's instruction.
     fn extend_values_defined_in_loop_and_invariants(
@@ -332,7 +747,12 @@ impl<'f> LoopInvariantContext<'f> {
         }
     }
 
-    fn can_hoist_invariant(&mut self, instruction_id: InstructionId) -> bool {
+    /// Returns whether an instruction can be hoisted out of the current loop. If it can't, the
+    /// error holds the reason why when all of its operands are loop invariant.
+    fn can_hoist_invariant(
+        &mut self,
+        instruction_id: InstructionId,
+    ) -> Result<(), Option<NotHoistedReason>> {
         use Instruction::*;
 
         let mut is_loop_invariant = true;
@@ -349,14 +769,51 @@ impl<'f> LoopInvariantContext<'f> {
             // which cannot be constants.
             is_loop_invariant &= self.is_loop_invariant(&value);
         });
+        if !is_loop_invariant {
+            return Err(None);
+        }
 
-        let can_be_hoisted = can_be_hoisted(&instruction, self.inserter.function, false)
+        // Each instruction is checked on its own, so a pure instruction (e.g. a comparison)
+        // is hoisted even if the `constrain` consuming its result must stay in a control
+        // dependent block.
+        if can_be_hoisted(&instruction, self.inserter.function, false).is_ok()
             || matches!(instruction, MakeArray { .. })
-            || (can_be_hoisted(&instruction, self.inserter.function, true)
-                && !self.current_block_control_dependent)
-            || self.can_be_hoisted_from_loop_bounds(&instruction);
+        {
+            return Ok(());
+        }
+        let reason = match can_be_hoisted(&instruction, self.inserter.function, true) {
+            Ok(()) => match self.check_predicate_is_hoistable() {
+                Ok(()) => return Ok(()),
+                Err(reason) => reason,
+            },
+            Err(reason) => reason,
+        };
+        // The loop bounds may still allow hoisting, and decide the reason when they are checked
+        match self.can_be_hoisted_from_loop_bounds(&instruction) {
+            Ok(()) => Ok(()),
+            Err(loop_bounds_reason) => Err(Some(loop_bounds_reason.unwrap_or(reason))),
+        }
+    }
 
-        is_loop_invariant && can_be_hoisted
+    /// Checks that the current block is executed on every iteration of the loop, under a
+    /// predicate which doesn't change between iterations, so that an instruction depending on
+    /// the predicate can be hoisted along with it.
+    fn check_predicate_is_hoistable(&self) -> Result<(), NotHoistedReason> {
+        if self.current_block_control_dependent {
+            Err(NotHoistedReason::ControlDependent)
+        } else if !self.is_predicate_hoistable() {
+            Err(NotHoistedReason::ChangingPredicate)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Records an instruction left in the current loop for the [LoopReport].
+    fn record_not_hoisted(&mut self, instruction_id: InstructionId, reason: NotHoistedReason) {
+        let dfg = &self.inserter.function.dfg;
+        let instruction = instruction_to_string(dfg, instruction_id);
+        let location = dfg.get_instruction_call_stack(instruction_id).last().copied();
+        self.not_hoisted.push(NotHoistedInstruction { instruction, location, reason });
     }
 
     /// Keep track of a loop induction variable and respective upper bound.
@@ -379,6 +836,48 @@ impl<'f> LoopInvariantContext<'f> {
         }
     }
 
+    /// True if the current loop has constant bounds which leave no iterations to execute.
+    fn loop_never_executes(&self) -> bool {
+        self.current_induction_variables.values().next().is_some_and(
+            |(lower_bound, upper_bound)| upper_bound.to_u128() <= lower_bound.to_u128(),
+        )
+    }
+
+    /// Reports a `constrain` inside the loop which can never fail because of the loop bounds,
+    /// or which is never executed because the loop body never runs.
+    /// Such assertions often indicate a logic bug in the program.
+    fn check_assertion_from_loop_bounds(
+        &mut self,
+        instruction_id: InstructionId,
+        loop_: &Loop,
+        block: BasicBlockId,
+    ) {
+        let Instruction::Constrain(lhs, rhs, _) = self.inserter.function.dfg[instruction_id] else {
+            return;
+        };
+        let call_stack = self.inserter.function.dfg.get_instruction_call_stack(instruction_id);
+
+        let warning = if block != loop_.header && self.loop_never_executes() {
+            InternalWarning::UnreachableAssertion { call_stack }
+        } else if self.values_known_from_loop_bounds.contains(&lhs)
+            || self.values_known_from_loop_bounds.contains(&rhs)
+        {
+            let lhs = self.inserter.resolve(lhs);
+            let rhs = self.inserter.resolve(rhs);
+            let dfg = &self.inserter.function.dfg;
+            match (dfg.get_numeric_constant(lhs), dfg.get_numeric_constant(rhs)) {
+                (Some(lhs), Some(rhs)) if lhs == rhs => {
+                    InternalWarning::AlwaysTrueAssertion { call_stack }
+                }
+                _ => return,
+            }
+        } else {
+            return;
+        };
+
+        self.assertion_warnings.entry(instruction_id).or_insert(SsaReport::Warning(warning));
+    }
+
     /// Certain instructions can take advantage of that our induction variable has a fixed minimum/maximum.
     ///
     /// For example, an array access can usually only be safely deduplicated when we have a constant
@@ -387,36 +886,278 @@ impl<'f> LoopInvariantContext<'f> {
     /// would determine that the instruction is not safe for hoisting.
     /// However, if we know that the induction variable's upper bound will always be in bounds of the array
     /// we can safely hoist the array access.
-    fn can_be_hoisted_from_loop_bounds(&self, instruction: &Instruction) -> bool {
+    ///
+    /// If the instruction can't be hoisted, the error holds the reason why when the loop bounds
+    /// were what decided it.
+    fn can_be_hoisted_from_loop_bounds(
+        &self,
+        instruction: &Instruction,
+    ) -> Result<(), Option<NotHoistedReason>> {
         use Instruction::*;
 
         match instruction {
             ArrayGet { array, index } => {
                 let array_typ = self.inserter.function.dfg.type_of_value(*array);
-                let upper_bound = self.outer_induction_variables.get(index).map(|bounds| bounds.1);
-                if let (Type::Array(_, len), Some(upper_bound)) = (array_typ, upper_bound) {
-                    upper_bound.to_u128() <= len.into()
-                } else {
-                    false
+                let upper_bound = self
+                    .outer_induction_variables
+                    .get(index)
+                    .map(|bounds| bounds.1)
+                    .or_else(|| self.modulo_upper_bound(*index));
+                match (array_typ, upper_bound) {
+                    (Type::Array(_, len), Some(upper_bound))
+                        if upper_bound.to_u128() <= len.into() =>
+                    {
+                        Ok(())
+                    }
+                    _ => Err(None),
                 }
             }
-            Binary(binary) => self.can_evaluate_binary_op(binary),
+            Binary(binary) => self.can_evaluate_binary_op(binary).then_some(()).ok_or(None),
             Constrain(..) | ConstrainNotEqual(..) | RangeCheck { .. } => {
-                // These instructions should not be hoisted if we know the loop will never be executed (an upper bound or zero or equal loop bounds)
-                // or we are unsure if the loop will ever be executed (dynamic loop bounds).
-                // If the instruction were to be hoisted out of a loop that never executes it could potentially cause the program to fail when it is not meant to fail.
-                let bounds = self.current_induction_variables.values().next().copied();
-                let does_loop_body_execute = bounds
-                    .map(|(lower_bound, upper_bound)| !(upper_bound - lower_bound).is_zero())
-                    .unwrap_or(false);
-                // If we know the loop will be executed these instructions can still only be hoisted if the instructions
-                // are in a non control dependent block.
-                does_loop_body_execute && !self.current_block_control_dependent
+                self.can_hoist_failing_instruction().map_err(Some)
+            }
+            // Decomposing a value fails if it doesn't fit in the requested number of limbs,
+            // so these are only hoisted under the same conditions as an assertion.
+            // A radix decomposition is only hoisted if its radix is a constant, as it also
+            // fails on an invalid radix.
+            Call { func, arguments } => {
+                let dfg = &self.inserter.function.dfg;
+                let is_decomposition = match dfg[*func] {
+                    Value::Intrinsic(Intrinsic::ToBits(_)) => true,
+                    Value::Intrinsic(Intrinsic::ToRadix(_)) => dfg.is_constant(arguments[1]),
+                    _ => false,
+                };
+                if !is_decomposition {
+                    return Err(None);
+                }
+                self.can_hoist_failing_instruction().map_err(Some)
             }
+            _ => Err(None),
+        }
+    }
+
+    /// If `value` is the result of an unsigned `mod` by a non-zero constant `n`, returns `n`,
+    /// which is an exclusive upper bound of `value`.
+    ///
+    /// This bounds rotated indices such as `arr[(i + k) % N]`.
+    fn modulo_upper_bound(&self, value: ValueId) -> Option<FieldElement> {
+        let dfg = &self.inserter.function.dfg;
+        let Value::Instruction { instruction, .. } = &dfg[value] else {
+            return None;
+        };
+        let Instruction::Binary(Binary { rhs, operator: BinaryOp::Mod, .. }) = &dfg[*instruction]
+        else {
+            return None;
+        };
+        let divisor = dfg.get_numeric_constant(*rhs)?;
+        (dfg.type_of_value(*rhs).is_unsigned() && !divisor.is_zero()).then_some(divisor)
+    }
+
+    /// Checks whether an instruction which may fail can be hoisted out of the current loop.
+    fn can_hoist_failing_instruction(&self) -> Result<(), NotHoistedReason> {
+        // These instructions should not be hoisted if we know the loop will never be executed (an upper bound or zero or equal loop bounds)
+        // or we are unsure if the loop will ever be executed (dynamic loop bounds).
+        // If the instruction were to be hoisted out of a loop that never executes it could potentially cause the program to fail when it is not meant to fail.
+        let bounds = self.current_induction_variables.values().next().copied();
+        let does_loop_body_execute = bounds
+            .map(|(lower_bound, upper_bound)| !(upper_bound - lower_bound).is_zero())
+            .unwrap_or(false);
+        // If we know the loop will be executed these instructions can still only be hoisted if the instructions
+        // are in a non control dependent block, and are not disabled by a predicate set in the loop.
+        self.check_predicate_is_hoistable()?;
+        if does_loop_body_execute { Ok(()) } else { Err(NotHoistedReason::DynamicBounds) }
+    }
+
+    /// True if the instruction is an `allocate` in a Brillig function which can be hoisted to the
+    /// pre-header along with the `store` initializing it, as every iteration would recreate the
+    /// same cell:
+    /// ```text
+    /// b3():
+    ///   v6 = allocate -> &mut Field   // Hoisted along with...
+    ///   store Field 5 at v6           // ...this store, as `v6` is never stored to again
+    ///   v7 = load v6 -> Field
+    /// ```
+    /// This is the case when the allocation doesn't escape, the first use of the allocation in
+    /// the rest of its block (`remaining_instructions`) is a `store` of a loop invariant value,
+    /// and that is the only `store` to the allocation within the loop.
+    fn can_hoist_initialized_allocation(
+        &mut self,
+        instruction_id: InstructionId,
+        remaining_instructions: &[InstructionId],
+    ) -> bool {
+        if !self.inserter.function.runtime().is_brillig()
+            || !matches!(self.inserter.function.dfg[instruction_id], Instruction::Allocate)
+        {
+            return false;
+        }
+
+        let address = self.inserter.function.dfg.instruction_results(instruction_id)[0];
+        if !self.non_escaping_allocations.contains(&address) {
+            return false;
+        }
+        let Some(&[value]) = self.stores_in_loop.get(&address).map(Vec::as_slice) else {
+            return false;
+        };
+        let value = self.inserter.resolve(value);
+        if !self.is_loop_invariant(&value) {
+            return false;
+        }
+
+        let dfg = &self.inserter.function.dfg;
+        let first_use = remaining_instructions.iter().find(|instruction| {
+            let mut uses_address = false;
+            dfg[**instruction].for_each_value(|value| uses_address |= value == address);
+            uses_address
+        });
+        let is_initialized = first_use.is_some_and(|instruction| {
+            matches!(dfg[*instruction], Instruction::Store { address: store_address, .. } if store_address == address)
+        });
+        if is_initialized {
+            self.hoisted_allocations.insert(address);
+        }
+        is_initialized
+    }
+
+    /// True if the instruction is the `store` initializing an allocation hoisted out of the loop.
+    fn is_store_initializing_hoisted_allocation(&self, instruction_id: InstructionId) -> bool {
+        match self.inserter.function.dfg[instruction_id] {
+            Instruction::Store { address, .. } => self.hoisted_allocations.contains(&address),
             _ => false,
         }
     }
 
+    /// Returns the address of the array read by an `array_get` when the read can be taken from
+    /// a snapshot of the array in the loop pre-header, even though the array is mutated in the loop.
+    ///
+    /// This is the case when the array is loaded from a non-escaping allocation, the read is at a
+    /// constant in-bounds index, and every value stored at the allocation within the loop is the
+    /// result of `array_set`s on the array loaded from it which never write to that index:
+    /// ```text
+    /// b3():
+    ///   v10 = load v1 -> [Field; 4]
+    ///   v11 = array_get v10, index u32 0 -> Field      // Hoisted, as...
+    ///   v12 = array_set v10, index v2, value Field 1   // ...the induction variable `v2` is always >= 1
+    ///   store v12 at v1
+    /// ```
+    fn array_get_snapshot_address(
+        &mut self,
+        instruction_id: InstructionId,
+        loop_: &Loop,
+    ) -> Option<ValueId> {
+        let Instruction::ArrayGet { array, index } = self.inserter.function.dfg[instruction_id]
+        else {
+            return None;
+        };
+
+        let index = self.inserter.resolve(index);
+        let dfg = &self.inserter.function.dfg;
+        let index = dfg.get_numeric_constant(index)?;
+        let Type::Array(element_types, len) = dfg.type_of_value(array) else {
+            return None;
+        };
+        if index.to_u128() >= element_types.len() as u128 * u128::from(len) {
+            return None;
+        }
+
+        let address = self.loaded_address(array)?;
+        if !self.non_escaping_allocations.contains(&address) {
+            return None;
+        }
+
+        let stored_values = self.stores_in_loop.get(&address).cloned().unwrap_or_default();
+        stored_values
+            .into_iter()
+            .all(|value| self.is_disjoint_array_set_chain(value, address, index, loop_))
+            .then_some(address)
+    }
+
+    /// Returns the address `value` was loaded from, if it is the result of a `load`.
+    fn loaded_address(&mut self, value: ValueId) -> Option<ValueId> {
+        let Value::Instruction { instruction, .. } = self.inserter.function.dfg[value] else {
+            return None;
+        };
+        let Instruction::Load { address } = self.inserter.function.dfg[instruction] else {
+            return None;
+        };
+        Some(self.inserter.resolve(address))
+    }
+
+    /// True if `value` is the result of a chain of `array_set`s on an array loaded from `address`,
+    /// none of which can write to `index`.
+    fn is_disjoint_array_set_chain(
+        &mut self,
+        mut value: ValueId,
+        address: ValueId,
+        index: FieldElement,
+        loop_: &Loop,
+    ) -> bool {
+        loop {
+            if self.loaded_address(value) == Some(address) {
+                return true;
+            }
+            let Value::Instruction { instruction, .. } = self.inserter.function.dfg[value] else {
+                return false;
+            };
+            let Instruction::ArraySet { array, index: set_index, .. } =
+                self.inserter.function.dfg[instruction]
+            else {
+                return false;
+            };
+            if !self.is_index_disjoint(set_index, index, loop_) {
+                return false;
+            }
+            value = array;
+        }
+    }
+
+    /// True if `set_index` can never be equal to `index`: either it is a different constant,
+    /// or it is the induction variable of the current loop and `index` lies outside the loop bounds.
+    fn is_index_disjoint(&mut self, set_index: ValueId, index: FieldElement, loop_: &Loop) -> bool {
+        let set_index = self.inserter.resolve(set_index);
+        if let Some(set_index) = self.inserter.function.dfg.get_numeric_constant(set_index) {
+            return set_index != index;
+        }
+
+        let induction_variable = loop_.get_induction_variable(self.inserter.function);
+        if set_index != self.inserter.resolve(induction_variable) {
+            return false;
+        }
+
+        // The induction variable starts at the lower bound and only increases from there,
+        // so the lower bound is enough when the upper bound is not a constant.
+        let lower_bound = match self.inserter.function.dfg[self.pre_header()].terminator() {
+            Some(TerminatorInstruction::Jmp { arguments, .. }) if arguments.len() == 1 => {
+                self.inserter.function.dfg.get_numeric_constant(arguments[0])
+            }
+            _ => None,
+        };
+        let upper_bound = self.current_induction_variables.get(&set_index).map(|bounds| bounds.1);
+
+        lower_bound.is_some_and(|lower_bound| index.to_u128() < lower_bound.to_u128())
+            || upper_bound.is_some_and(|upper_bound| index.to_u128() >= upper_bound.to_u128())
+    }
+
+    /// Replaces an `array_get` with a read from a snapshot of the array taken in the pre-header.
+    fn hoist_array_get_from_snapshot(&mut self, instruction_id: InstructionId, address: ValueId) {
+        let Instruction::ArrayGet { array, index } = self.inserter.function.dfg[instruction_id]
+        else {
+            unreachable!("Expected an array_get instruction");
+        };
+        let index = self.inserter.resolve(index);
+        let pre_header = self.pre_header();
+        let dfg = &mut self.inserter.function.dfg;
+        let array_type = dfg.type_of_value(array);
+        let call_stack = dfg.get_instruction_call_stack_id(instruction_id);
+
+        let load = Instruction::Load { address };
+        let snapshot = dfg
+            .insert_instruction_and_results(load, pre_header, Some(vec![array_type]), call_stack)
+            .first();
+
+        let array_get = Instruction::ArrayGet { array: snapshot, index };
+        self.inserter.push_instruction_value(array_get, instruction_id, pre_header, call_stack);
+    }
+
     /// Some instructions can take advantage of that our induction variable has a fixed minimum/maximum,
     /// For instance operations can be transformed from a checked operation to an unchecked operation.
     ///
@@ -440,6 +1181,7 @@ impl<'f> LoopInvariantContext<'f> {
                     self.inserter.function.dfg.instruction_results(instruction_id).to_vec();
                 assert!(results.len() == 1);
                 self.inserter.function.dfg.set_value_from_id(results[0], id);
+                self.values_known_from_loop_bounds.insert(results[0]);
                 true
             }
             SimplifyResult::SimplifiedToInstruction(instruction) => {
@@ -601,7 +1343,7 @@ impl<'f> LoopInvariantContext<'f> {
             }
             Instruction::Constrain(x, y, err) => {
                 // Ensure the loop is fully executed
-                if self.no_break && self.can_be_hoisted_from_loop_bounds(&instruction) {
+                if self.no_break && self.can_be_hoisted_from_loop_bounds(&instruction).is_ok() {
                     self.simplify_induction_in_constrain(*x, *y, err, call_stack)
                 } else {
                     SimplifyResult::None
@@ -609,7 +1351,7 @@ impl<'f> LoopInvariantContext<'f> {
             }
             Instruction::ConstrainNotEqual(x, y, err) => {
                 // Ensure the loop is fully executed
-                if self.no_break && self.can_be_hoisted_from_loop_bounds(&instruction) {
+                if self.no_break && self.can_be_hoisted_from_loop_bounds(&instruction).is_ok() {
                     self.simplify_not_equal_constraint(x, y, err, call_stack)
                 } else {
                     SimplifyResult::None
@@ -699,6 +1441,10 @@ impl<'f> LoopInvariantContext<'f> {
             BinaryOp::Div | BinaryOp::Mod => return SimplifyResult::None,
             _ => None,
         } {
+            if self.keep_overflow_checks {
+                return SimplifyResult::None;
+            }
+
             // We evaluate this expression using the upper bounds (or lower in the case of sub)
             // of its inputs to check whether it will ever overflow.
             // If so, this will cause `eval_constant_binary_op` to return `None`.
@@ -749,6 +1495,16 @@ impl<'f> LoopInvariantContext<'f> {
             | BinaryOp::Mul { unchecked: true }
             | BinaryOp::Sub { unchecked: true } => true,
             BinaryOp::Div | BinaryOp::Mod => {
+                // Unsigned division by a non-zero constant can never fail, whatever the dividend
+                let dfg = &self.inserter.function.dfg;
+                if dfg.type_of_value(binary.rhs).is_unsigned()
+                    && dfg
+                        .get_numeric_constant(binary.rhs)
+                        .is_some_and(|divisor| !divisor.is_zero())
+                {
+                    return true;
+                }
+
                 // Division can be evaluated if we ensure that the divisor cannot be zero
                 let Some((left, value, lower, _)) =
                     self.match_induction_and_constant(&binary.lhs, &binary.rhs, true)
@@ -792,34 +1548,127 @@ impl<'f> LoopInvariantContext<'f> {
     }
 }
 
-/// Indicates if the instruction can be safely hoisted out of a loop.
-/// If `hoist_with_predicate` is set, we assume we're hoisting the instruction
-/// and its predicate, rather than just the instruction. Setting this means instructions that
-/// rely on predicates can be hoisted as well.
+/// Collects the results of `MakeArray` instructions, and the array results of calls,
+/// which are only ever read from.
 ///
-/// Certain instructions can be hoisted because they implicitly depend on a predicate.
-/// However, to avoid tight coupling between passes, we make the hoisting
-/// conditional on whether the caller wants the predicate to be taken into account or not.
-///
-/// This differs from `can_be_deduplicated` as that method assumes there is a matching instruction
-/// with the same inputs. Hoisting is for lone instructions, meaning a mislabeled hoist could cause
-/// unexpected failures if the instruction was never meant to be executed.
-fn can_be_hoisted(
-    instruction: &Instruction,
+/// This is deliberately conservative: any use of the array other than as the array
+/// of an `ArrayGet` or the value of a reference count instruction (e.g. an `array_set`,
+/// a `store`, a nested `make_array` or a terminator argument) is treated as a potential
+/// mutation. A call argument is only treated as one if the parameter it is passed to isn't
+/// known to be read-only.
+fn find_immutable_arrays(
     function: &Function,
-    hoist_with_predicate: bool,
-) -> bool {
-    use Instruction::*;
-
-    match instruction {
-        // These either have side-effects or interact with memory
-        EnableSideEffectsIf { .. }
-        | Allocate
+    read_only_parameters: &ReadOnlyParameters,
+) -> HashSet<ValueId> {
+    let mut arrays = HashSet::default();
+    let mut possibly_mutated = HashSet::default();
+
+    for block in function.reachable_blocks() {
+        for instruction_id in function.dfg[block].instructions() {
+            let instruction = &function.dfg[*instruction_id];
+            match instruction {
+                Instruction::MakeArray { .. } => {
+                    arrays.extend(function.dfg.instruction_results(*instruction_id));
+                    instruction.for_each_value(|value| possibly_mutated.insert(value));
+                }
+                Instruction::Call { .. } => {
+                    let results = function.dfg.instruction_results(*instruction_id);
+                    arrays.extend(results.iter().filter(|result| {
+                        matches!(
+                            function.dfg.type_of_value(**result),
+                            Type::Array(..) | Type::Slice(..)
+                        )
+                    }));
+                    instruction.for_each_value(|value| {
+                        let only_read = read_only_parameters.only_passed_to_read_only_parameters(
+                            function,
+                            instruction,
+                            value,
+                        );
+                        if !only_read {
+                            possibly_mutated.insert(value);
+                        }
+                    });
+                }
+                Instruction::ArrayGet { .. }
+                | Instruction::IncrementRc { .. }
+                | Instruction::DecrementRc { .. } => (),
+                _ => instruction.for_each_value(|value| possibly_mutated.insert(value)),
+            }
+        }
+        if let Some(terminator) = function.dfg[block].terminator() {
+            terminator.for_each_value(|value| possibly_mutated.insert(value));
+        }
+    }
+
+    arrays.retain(|array| !possibly_mutated.contains(array));
+    arrays
+}
+
+/// Collects the results of `Allocate` instructions which are only ever used as the address
+/// of a `load` or a `store`.
+///
+/// Any other use, such as storing the reference itself, passing it to a call or to another
+/// block, could create an alias through which the allocation may be written to.
+fn find_non_escaping_allocations(function: &Function) -> HashSet<ValueId> {
+    let mut allocations = HashSet::default();
+    let mut escaped = HashSet::default();
+
+    for block in function.reachable_blocks() {
+        for instruction_id in function.dfg[block].instructions() {
+            match &function.dfg[*instruction_id] {
+                Instruction::Allocate => {
+                    allocations.extend(function.dfg.instruction_results(*instruction_id));
+                }
+                Instruction::Load { .. } => (),
+                Instruction::Store { value, .. } => {
+                    escaped.insert(*value);
+                }
+                instruction => instruction.for_each_value(|value| {
+                    escaped.insert(value);
+                }),
+            }
+        }
+        if let Some(terminator) = function.dfg[block].terminator() {
+            terminator.for_each_value(|value| {
+                escaped.insert(value);
+            });
+        }
+    }
+
+    allocations.retain(|allocation| !escaped.contains(allocation));
+    allocations
+}
+
+/// Indicates if the instruction can be safely hoisted out of a loop, returning why if it can't.
+/// If `hoist_with_predicate` is set, we assume we're hoisting the instruction
+/// and its predicate, rather than just the instruction. Setting this means instructions that
+/// rely on predicates can be hoisted as well.
+///
+/// Certain instructions can be hoisted because they implicitly depend on a predicate.
+/// However, to avoid tight coupling between passes, we make the hoisting
+/// conditional on whether the caller wants the predicate to be taken into account or not.
+///
+/// This differs from `can_be_deduplicated` as that method assumes there is a matching instruction
+/// with the same inputs. Hoisting is for lone instructions, meaning a mislabeled hoist could cause
+/// unexpected failures if the instruction was never meant to be executed.
+fn can_be_hoisted(
+    instruction: &Instruction,
+    function: &Function,
+    hoist_with_predicate: bool,
+) -> Result<(), NotHoistedReason> {
+    use Instruction::*;
+
+    match instruction {
+        // These either have side-effects or interact with memory
+        EnableSideEffectsIf { .. }
+        | Allocate
         | Load { .. }
         | Store { .. }
         | IncrementRc { .. }
-        | DecrementRc { .. } => false,
+        | DecrementRc { .. } => Err(NotHoistedReason::SideEffects),
 
+        // Pure calls, including intrinsics such as `array_len`, only depend on their arguments.
         Call { func, .. } => {
             let purity = match function.dfg[*func] {
                 Value::Intrinsic(intrinsic) => Some(intrinsic.purity()),
@@ -827,10 +1676,10 @@ fn can_be_hoisted(
                 _ => None,
             };
             match purity {
-                Some(Purity::Pure) => true,
-                Some(Purity::PureWithPredicate) => false,
-                Some(Purity::Impure) => false,
-                None => false,
+                Some(Purity::Pure) => Ok(()),
+                Some(Purity::PureWithPredicate) => Err(NotHoistedReason::SideEffects),
+                Some(Purity::Impure) => Err(NotHoistedReason::SideEffects),
+                None => Err(NotHoistedReason::SideEffects),
             }
         }
 
@@ -840,30 +1689,38 @@ fn can_be_hoisted(
         // If we know a loop's upper bound is greater than its lower bound we can hoist these instructions,
         // but we do not want to assume that the caller of this method has accounted
         // for this case. Thus, we block hoisting on these instructions.
-        Constrain(..) | ConstrainNotEqual(..) | RangeCheck { .. } => false,
+        Constrain(..) | ConstrainNotEqual(..) | RangeCheck { .. } => {
+            Err(NotHoistedReason::DynamicBounds)
+        }
 
         // Noop instructions can always be hoisted, although they're more likely to be
         // removed entirely.
-        Noop => true,
+        Noop => Ok(()),
 
         // These instructions can always be hoisted
-        Cast(_, _) | Not(_) | Truncate { .. } | IfElse { .. } => true,
+        Cast(_, _) | Not(_) | Truncate { .. } | IfElse { .. } => Ok(()),
 
         // Arrays can be mutated in unconstrained code so code that handles this case must
         // take care to track whether the array was possibly mutated or not before
         // hoisted. Since we don't know if the containing pass checks for this, we
         // can only assume these are safe to hoist in constrained code.
-        MakeArray { .. } => function.runtime().is_acir(),
+        MakeArray { .. } if function.runtime().is_acir() => Ok(()),
+        MakeArray { .. } => Err(NotHoistedReason::SideEffects),
 
         // These can have different behavior depending on the predicate.
         Binary(_) | ArrayGet { .. } | ArraySet { .. } => {
-            hoist_with_predicate || !instruction.requires_acir_gen_predicate(&function.dfg)
+            if hoist_with_predicate || !instruction.requires_acir_gen_predicate(&function.dfg) {
+                Ok(())
+            } else {
+                Err(NotHoistedReason::ChangingPredicate)
+            }
         }
     }
 }
 
 #[cfg(test)]
 mod test {
+    use crate::errors::{InternalWarning, SsaReport};
     use crate::ssa::Ssa;
     use crate::ssa::opt::assert_normalized_ssa_equals;
 
@@ -1049,6 +1906,49 @@ mod test {
         assert_normalized_ssa_equals(ssa, expected);
     }
 
+    #[test]
+    fn hoist_invariant_operand_of_loop_bound_comparison() {
+        // The loop's upper bound `v3 = mul v0, v1` is computed in the header.
+        // The comparison depends on the induction variable and must stay in the header,
+        // but its invariant operand should be hoisted to the pre-header.
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32, v1: u32):
+            jmp b1(u32 0)
+          b1(v2: u32):
+            v3 = mul v0, v1
+            v4 = lt v2, v3
+            jmpif v4 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v6 = unchecked_add v2, u32 1
+            jmp b1(v6)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32, v1: u32):
+            v3 = mul v0, v1
+            jmp b1(u32 0)
+          b1(v2: u32):
+            v4 = lt v2, v3
+            jmpif v4 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v6 = unchecked_add v2, u32 1
+            jmp b1(v6)
+        }
+        ";
+
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
     #[test]
     fn do_not_hoist_instructions_with_side_effects() {
         // In `v12 = load v5` in `b3`, `v5` is defined outside the loop.
@@ -1194,6 +2094,87 @@ mod test {
         assert_normalized_ssa_equals(ssa, expected);
     }
 
+    #[test]
+    fn hoist_using_loop_bounds_from_numeric_generic() {
+        // SSA for the following program once `sum` has been monomorphized with `N = 4`:
+        //
+        // unconstrained fn sum<let N: u32>(arr: [u32; N], x: u32, c: bool) {
+        //   for i in 0..N {
+        //       for _ in 0..N {
+        //           if c {
+        //               assert_eq(arr[i], x);
+        //           }
+        //       }
+        //   }
+        // }
+        //
+        // After monomorphization `N` is a constant, so both loops have known bounds:
+        // `arr[i]` can be hoisted to the outer loop body even though it is in a control dependent block,
+        // and the increments of both induction variables are turned into unchecked additions.
+        let src = "
+        brillig(inline) fn sum f0 {
+          entry(v0: [u32; 4], v1: u32, v2: u1):
+            jmp outer(u32 0)
+          outer(v3: u32):
+            v6 = lt v3, u32 4
+            jmpif v6 then: outer_body, else: exit
+          outer_body():
+            jmp inner(u32 0)
+          inner(v4: u32):
+            v7 = lt v4, u32 4
+            jmpif v7 then: inner_body, else: outer_end
+          inner_body():
+            jmpif v2 then: then_block, else: inner_end
+          then_block():
+            v8 = array_get v0, index v3 -> u32
+            constrain v8 == v1
+            jmp inner_end()
+          inner_end():
+            v10 = add v4, u32 1
+            jmp inner(v10)
+          outer_end():
+            v11 = add v3, u32 1
+            jmp outer(v11)
+          exit():
+            return
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        brillig(inline) fn sum f0 {
+          entry(v0: [u32; 4], v1: u32, v2: u1):
+            jmp outer(u32 0)
+          outer(v3: u32):
+            v6 = lt v3, u32 4
+            jmpif v6 then: outer_body, else: exit
+          outer_body():
+            v8 = array_get v0, index v3 -> u32
+            jmp inner(u32 0)
+          inner(v4: u32):
+            v7 = lt v4, u32 4
+            jmpif v7 then: inner_body, else: outer_end
+          inner_body():
+            jmpif v2 then: then_block, else: inner_end
+          then_block():
+            constrain v8 == v1
+            jmp inner_end()
+          inner_end():
+            v10 = unchecked_add v4, u32 1
+            jmp inner(v10)
+          outer_end():
+            v11 = unchecked_add v3, u32 1
+            jmp outer(v11)
+          exit():
+            return
+        }
+        ";
+
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
     #[test]
     fn insert_inc_rc_when_moving_make_array() {
         // SSA for the following program:
@@ -1285,43 +2266,54 @@ mod test {
     }
 
     #[test]
-    fn transform_safe_ops_to_unchecked_during_code_motion() {
-        // This test is identical to `simple_loop_invariant_code_motion`, except this test
-        // uses a checked add in `b3`.
+    fn insert_inc_rc_when_moving_make_array_of_loop_invariant_values() {
+        // Unlike `insert_inc_rc_when_moving_make_array`, the elements of the array are not
+        // constants but the function parameters, which are just as invariant. The array is
+        // mutated inside the loop, so it needs an `inc_rc` once hoisted.
         let src = "
         brillig(inline) fn main f0 {
-          b0(v0: i32, v1: i32):
-              jmp b1(i32 0)
-          b1(v2: i32):
-              v5 = lt v2, i32 4
-              jmpif v5 then: b3, else: b2
+          b0(v0: Field, v1: Field):
+            jmp b1(u32 0)
+          b1(v2: u32):
+            v5 = lt v2, u32 2
+            jmpif v5 then: b3, else: b2
           b2():
-              return
+            return
           b3():
-              v6 = mul v0, v1
-              constrain v6 == i32 6
-              v8 = add v2, i32 1
-              jmp b1(v8)
+            v6 = make_array [v0, v1] : [Field; 2]
+            v8 = array_set v6, index v2, value Field 0
+            call f1(v8)
+            v11 = unchecked_add v2, u32 1
+            jmp b1(v11)
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: [Field; 2]):
+            return
         }
         ";
 
         let ssa = Ssa::from_str(src).unwrap();
 
-        // `v8 = add v2, i32 1` in b3 should now be `v9 = unchecked_add v2, i32 1` in b3
         let expected = "
         brillig(inline) fn main f0 {
-          b0(v0: i32, v1: i32):
-            v3 = mul v0, v1
-            constrain v3 == i32 6
-            jmp b1(i32 0)
-          b1(v2: i32):
-            v7 = lt v2, i32 4
-            jmpif v7 then: b3, else: b2
+          b0(v0: Field, v1: Field):
+            v6 = make_array [v0, v1] : [Field; 2]
+            jmp b1(u32 0)
+          b1(v2: u32):
+            v5 = lt v2, u32 2
+            jmpif v5 then: b3, else: b2
           b2():
             return
           b3():
-            v9 = unchecked_add v2, i32 1
-            jmp b1(v9)
+            inc_rc v6
+            v8 = array_set v6, index v2, value Field 0
+            call f1(v8)
+            v11 = unchecked_add v2, u32 1
+            jmp b1(v11)
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: [Field; 2]):
+            return
         }
         ";
 
@@ -1330,67 +2322,100 @@ mod test {
     }
 
     #[test]
-    fn do_not_transform_unsafe_sub_to_unchecked() {
-        // This test is identical to `simple_loop_invariant_code_motion`, except this test
-        // uses a checked sub in `b3`.
-        // We want to make sure that our sub operation has the induction variable (`v2`) on the lhs.
-        // The induction variable `v2` is placed on the lhs of the sub operation
-        // to test that we are checking against the loop's lower bound
-        // rather than the upper bound (add/mul only check against the upper bound).
+    fn do_not_insert_inc_rc_when_moving_immutable_make_array() {
+        // Unlike `insert_inc_rc_when_moving_make_array`, the array in `b3` is only ever read from,
+        // so no `inc_rc` is needed when it is hoisted.
         let src = "
         brillig(inline) fn main f0 {
-          b0(v0: u32, v1: u32):
+          b0(v0: Field):
             jmp b1(u32 0)
-          b1(v2: u32):
-            v5 = lt v2, u32 4
-            jmpif v5 then: b3, else: b2
+          b1(v1: u32):
+            v4 = lt v1, u32 5
+            jmpif v4 then: b3, else: b2
           b2():
             return
           b3():
-            v7 = sub v2, u32 1
-            jmp b1(v7)
+            v10 = make_array [Field 1, Field 2, Field 3, Field 4, Field 5] : [Field; 5]
+            v11 = array_get v10, index v1 -> Field
+            constrain v11 == v0
+            v13 = unchecked_add v1, u32 1
+            jmp b1(v13)
         }
         ";
 
         let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0(v0: Field):
+            v10 = make_array [Field 1, Field 2, Field 3, Field 4, Field 5] : [Field; 5]
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v4 = lt v1, u32 5
+            jmpif v4 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v11 = array_get v10, index v1 -> Field
+            constrain v11 == v0
+            v13 = unchecked_add v1, u32 1
+            jmp b1(v13)
+        }
+        ";
+
         let ssa = ssa.loop_invariant_code_motion();
-        assert_normalized_ssa_equals(ssa, src);
+        assert_normalized_ssa_equals(ssa, expected);
     }
 
     #[test]
-    fn transform_safe_sub_to_unchecked() {
-        // This test is identical to `do_not_transform_unsafe_sub_to_unchecked`, except the loop
-        // in this test starts with a lower bound of `1`.
+    fn do_not_insert_inc_rc_when_moving_array_passed_to_read_only_parameter() {
+        // The hoisted array is passed to `foo`, which only ever reads from it,
+        // so no `inc_rc` is needed to protect it from being mutated.
         let src = "
         brillig(inline) fn main f0 {
-          b0(v0: u32, v1: u32):
-              jmp b1(u32 1)
-          b1(v2: u32):
-              v5 = lt v2, u32 4
-              jmpif v5 then: b3, else: b2
+          b0(v0: Field):
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v4 = lt v1, u32 5
+            jmpif v4 then: b3, else: b2
           b2():
-              return
+            return
           b3():
-              v8 = sub v2, u32 1
-              jmp b1(v8)
+            v10 = make_array [Field 1, Field 2, Field 3, Field 4, Field 5] : [Field; 5]
+            v11 = call f1(v10, v1) -> Field
+            constrain v11 == v0
+            v13 = unchecked_add v1, u32 1
+            jmp b1(v13)
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: [Field; 5], v1: u32):
+            v2 = array_get v0, index v1 -> Field
+            return v2
         }
         ";
 
         let ssa = Ssa::from_str(src).unwrap();
 
-        // `v8 = sub v2, u32 1` in b3 should now be `v9 = unchecked_sub v2, u32 1` in b3
         let expected = "
         brillig(inline) fn main f0 {
-          b0(v0: u32, v1: u32):
-            jmp b1(u32 1)
-          b1(v2: u32):
-            v5 = lt v2, u32 4
-            jmpif v5 then: b3, else: b2
+          b0(v0: Field):
+            v10 = make_array [Field 1, Field 2, Field 3, Field 4, Field 5] : [Field; 5]
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v4 = lt v1, u32 5
+            jmpif v4 then: b3, else: b2
           b2():
             return
           b3():
-            v6 = unchecked_sub v2, u32 1
-            jmp b1(v6)
+            v11 = call f1(v10, v1) -> Field
+            constrain v11 == v0
+            v13 = unchecked_add v1, u32 1
+            jmp b1(v13)
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: [Field; 5], v1: u32):
+            v2 = array_get v0, index v1 -> Field
+            return v2
         }
         ";
 
@@ -1399,74 +2424,299 @@ mod test {
     }
 
     #[test]
-    fn do_not_hoist_unsafe_div() {
-        // This test is similar to `nested_loop_invariant_code_motion`, except that
-        // the loop logic is under a dynamic predicate.
-        // Divisions are only reliant upon predicates and do not have other side effects.
-        //
-        // If an unsafe division occurs in a loop block that is not control dependent,
-        // we can still safely hoist that division as that instruction is always going to be hit.
-        // Thus, we place the unsafe division under a predicate to ensure that we are testing
-        // division hoisting based upon loop bounds and nothing else.
-        //
-        // The operation in question we are trying to hoist is `v12 = div u32 10, v1`.
-        // Check whether the lower bound of the outer loop is zero and that we do not
-        // hoist an operation that can potentially error with a division by zero.
+    fn hoist_pure_function_call() {
+        // `foo` is pure and called with a loop invariant argument,
+        // so the call can be moved to the loop pre-header.
         let src = "
         brillig(inline) fn main f0 {
           b0(v0: u32):
-            v4 = eq v0, u32 5
             jmp b1(u32 0)
           b1(v1: u32):
-            v7 = lt v1, u32 4
-            jmpif v7 then: b2, else: b3
+            v4 = lt v1, u32 4
+            jmpif v4 then: b3, else: b2
           b2():
-            jmp b4(u32 0)
-          b3():
             return
-          b4(v2: u32):
-            v8 = lt v2, u32 4
-            jmpif v8 then: b5, else: b6
-          b5():
-            jmpif v4 then: b7, else: b8
-          b6():
-            v10 = unchecked_add v1, u32 1
-            jmp b1(v10)
-          b7():
-            v12 = div u32 10, v1
-            constrain v12 == u32 6
-            jmp b8()
-          b8():
-            v14 = unchecked_add v2, u32 1
-            jmp b4(v14)
+          b3():
+            v6 = call f1(v0) -> u32
+            constrain v6 == v1
+            v8 = unchecked_add v1, u32 1
+            jmp b1(v8)
+        }
+        brillig(inline) pure fn foo f1 {
+          b0(v0: u32):
+            return v0
         }
         ";
 
         let ssa = Ssa::from_str(src).unwrap();
 
-        let ssa = ssa.loop_invariant_code_motion();
-        assert_normalized_ssa_equals(ssa, src);
-    }
-
-    #[test]
-    fn hoist_safe_div() {
-        // This test is identical to `do_not_hoist_unsafe_div`, except the loop
-        // in this test starts with a lower bound of `1`.
-        let src = "
+        let expected = "
         brillig(inline) fn main f0 {
           b0(v0: u32):
-            v4 = eq v0, u32 5
-            jmp b1(u32 1)
+            v3 = call f1(v0) -> u32
+            jmp b1(u32 0)
           b1(v1: u32):
-            v7 = lt v1, u32 4
-            jmpif v7 then: b2, else: b3
+            v6 = lt v1, u32 4
+            jmpif v6 then: b3, else: b2
           b2():
-            jmp b4(u32 0)
+            return
+          b3():
+            constrain v3 == v1
+            v8 = unchecked_add v1, u32 1
+            jmp b1(v8)
+        }
+        brillig(inline) pure fn foo f1 {
+          b0(v0: u32):
+            return v0
+        }
+        ";
+
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn insert_inc_rc_when_moving_pure_call_returning_array() {
+        // The array returned by the hoisted call is mutated inside the loop,
+        // so it needs an `inc_rc` just like a hoisted `make_array` would.
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32):
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v4 = lt v1, u32 4
+            jmpif v4 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v6 = call f1(v0) -> [u32; 2]
+            v7 = array_set v6, index u32 0, value v1
+            v9 = unchecked_add v1, u32 1
+            jmp b1(v9)
+        }
+        brillig(inline) pure fn foo f1 {
+          b0(v0: u32):
+            v1 = make_array [v0, v0] : [u32; 2]
+            return v1
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32):
+            v3 = call f1(v0) -> [u32; 2]
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v6 = lt v1, u32 4
+            jmpif v6 then: b3, else: b2
+          b2():
+            return
+          b3():
+            inc_rc v3
+            v7 = array_set v3, index u32 0, value v1
+            v9 = unchecked_add v1, u32 1
+            jmp b1(v9)
+        }
+        brillig(inline) pure fn foo f1 {
+          b0(v0: u32):
+            v1 = make_array [v0, v0] : [u32; 2]
+            return v1
+        }
+        ";
+
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn transform_safe_ops_to_unchecked_during_code_motion() {
+        // This test is identical to `simple_loop_invariant_code_motion`, except this test
+        // uses a checked add in `b3`.
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: i32, v1: i32):
+              jmp b1(i32 0)
+          b1(v2: i32):
+              v5 = lt v2, i32 4
+              jmpif v5 then: b3, else: b2
+          b2():
+              return
+          b3():
+              v6 = mul v0, v1
+              constrain v6 == i32 6
+              v8 = add v2, i32 1
+              jmp b1(v8)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        // `v8 = add v2, i32 1` in b3 should now be `v9 = unchecked_add v2, i32 1` in b3
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0(v0: i32, v1: i32):
+            v3 = mul v0, v1
+            constrain v3 == i32 6
+            jmp b1(i32 0)
+          b1(v2: i32):
+            v7 = lt v2, i32 4
+            jmpif v7 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v9 = unchecked_add v2, i32 1
+            jmp b1(v9)
+        }
+        ";
+
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn keep_brillig_overflow_checks_during_code_motion() {
+        // This test is identical to `transform_safe_ops_to_unchecked_during_code_motion`,
+        // except overflow checks must be kept so the add stays checked.
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: i32, v1: i32):
+              jmp b1(i32 0)
+          b1(v2: i32):
+              v5 = lt v2, i32 4
+              jmpif v5 then: b3, else: b2
+          b2():
+              return
+          b3():
+              v6 = mul v0, v1
+              constrain v6 == i32 6
+              v8 = add v2, i32 1
+              jmp b1(v8)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0(v0: i32, v1: i32):
+            v3 = mul v0, v1
+            constrain v3 == i32 6
+            jmp b1(i32 0)
+          b1(v2: i32):
+            v7 = lt v2, i32 4
+            jmpif v7 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v9 = add v2, i32 1
+            jmp b1(v9)
+        }
+        ";
+
+        let ssa = ssa.loop_invariant_code_motion_with_reports(None, None, None, true);
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn do_not_transform_unsafe_sub_to_unchecked() {
+        // This test is identical to `simple_loop_invariant_code_motion`, except this test
+        // uses a checked sub in `b3`.
+        // We want to make sure that our sub operation has the induction variable (`v2`) on the lhs.
+        // The induction variable `v2` is placed on the lhs of the sub operation
+        // to test that we are checking against the loop's lower bound
+        // rather than the upper bound (add/mul only check against the upper bound).
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32, v1: u32):
+            jmp b1(u32 0)
+          b1(v2: u32):
+            v5 = lt v2, u32 4
+            jmpif v5 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v7 = sub v2, u32 1
+            jmp b1(v7)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+
+    #[test]
+    fn transform_safe_sub_to_unchecked() {
+        // This test is identical to `do_not_transform_unsafe_sub_to_unchecked`, except the loop
+        // in this test starts with a lower bound of `1`.
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32, v1: u32):
+              jmp b1(u32 1)
+          b1(v2: u32):
+              v5 = lt v2, u32 4
+              jmpif v5 then: b3, else: b2
+          b2():
+              return
+          b3():
+              v8 = sub v2, u32 1
+              jmp b1(v8)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        // `v8 = sub v2, u32 1` in b3 should now be `v9 = unchecked_sub v2, u32 1` in b3
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32, v1: u32):
+            jmp b1(u32 1)
+          b1(v2: u32):
+            v5 = lt v2, u32 4
+            jmpif v5 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v6 = unchecked_sub v2, u32 1
+            jmp b1(v6)
+        }
+        ";
+
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn do_not_hoist_unsafe_div() {
+        // This test is similar to `nested_loop_invariant_code_motion`, except that
+        // the loop logic is under a dynamic predicate.
+        // Divisions are only reliant upon predicates and do not have other side effects.
+        //
+        // If an unsafe division occurs in a loop block that is not control dependent,
+        // we can still safely hoist that division as that instruction is always going to be hit.
+        // Thus, we place the unsafe division under a predicate to ensure that we are testing
+        // division hoisting based upon loop bounds and nothing else.
+        //
+        // The operation in question we are trying to hoist is `v12 = div u32 10, v1`.
+        // Check whether the lower bound of the outer loop is zero and that we do not
+        // hoist an operation that can potentially error with a division by zero.
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32):
+            v4 = eq v0, u32 5
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v7 = lt v1, u32 4
+            jmpif v7 then: b2, else: b3
+          b2():
+            jmp b4(u32 0)
           b3():
             return
           b4(v2: u32):
-            v9 = lt v2, u32 4
-            jmpif v9 then: b5, else: b6
+            v8 = lt v2, u32 4
+            jmpif v8 then: b5, else: b6
           b5():
             jmpif v4 then: b7, else: b8
           b6():
@@ -1485,7 +2735,14 @@ mod test {
         let ssa = Ssa::from_str(src).unwrap();
 
         let ssa = ssa.loop_invariant_code_motion();
-        let expected = "
+        assert_normalized_ssa_equals(ssa, src);
+    }
+
+    #[test]
+    fn hoist_safe_div() {
+        // This test is identical to `do_not_hoist_unsafe_div`, except the loop
+        // in this test starts with a lower bound of `1`.
+        let src = "
         brillig(inline) fn main f0 {
           b0(v0: u32):
             v4 = eq v0, u32 5
@@ -1494,20 +2751,20 @@ mod test {
             v7 = lt v1, u32 4
             jmpif v7 then: b2, else: b3
           b2():
-            v9 = div u32 10, v1
             jmp b4(u32 0)
           b3():
             return
           b4(v2: u32):
-            v11 = lt v2, u32 4
-            jmpif v11 then: b5, else: b6
+            v9 = lt v2, u32 4
+            jmpif v9 then: b5, else: b6
           b5():
             jmpif v4 then: b7, else: b8
           b6():
-            v12 = unchecked_add v1, u32 1
-            jmp b1(v12)
+            v10 = unchecked_add v1, u32 1
+            jmp b1(v10)
           b7():
-            constrain v9 == u32 6
+            v12 = div u32 10, v1
+            constrain v12 == u32 6
             jmp b8()
           b8():
             v14 = unchecked_add v2, u32 1
@@ -1515,6 +2772,610 @@ mod test {
         }
         ";
 
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let ssa = ssa.loop_invariant_code_motion();
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32):
+            v4 = eq v0, u32 5
+            jmp b1(u32 1)
+          b1(v1: u32):
+            v7 = lt v1, u32 4
+            jmpif v7 then: b2, else: b3
+          b2():
+            v9 = div u32 10, v1
+            jmp b4(u32 0)
+          b3():
+            return
+          b4(v2: u32):
+            v11 = lt v2, u32 4
+            jmpif v11 then: b5, else: b6
+          b5():
+            jmpif v4 then: b7, else: b8
+          b6():
+            v12 = unchecked_add v1, u32 1
+            jmp b1(v12)
+          b7():
+            constrain v9 == u32 6
+            jmp b8()
+          b8():
+            v14 = unchecked_add v2, u32 1
+            jmp b4(v14)
+        }
+        ";
+
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn warns_on_assertion_always_true_from_loop_bounds() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: u32):
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v4 = lt v1, u32 4
+            jmpif v4 then: b2, else: b3
+          b2():
+            v6 = lt v1, u32 10
+            constrain v6 == u1 1
+            constrain v1 != v0
+            v9 = unchecked_add v1, u32 1
+            jmp b1(v9)
+          b3():
+            return
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+        let mut warnings = Vec::new();
+        let _ = ssa.loop_invariant_code_motion_with_reports(None, Some(&mut warnings), None, false);
+
+        assert_eq!(warnings.len(), 1);
+        assert!(matches!(
+            warnings[0],
+            SsaReport::Warning(InternalWarning::AlwaysTrueAssertion { .. })
+        ));
+    }
+
+    #[test]
+    fn warns_on_assertion_in_loop_which_never_executes() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: u32):
+            jmp b1(u32 5)
+          b1(v1: u32):
+            v3 = lt v1, u32 5
+            jmpif v3 then: b2, else: b3
+          b2():
+            constrain v0 == u32 5
+            v6 = unchecked_add v1, u32 1
+            jmp b1(v6)
+          b3():
+            return
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+        let mut warnings = Vec::new();
+        let _ = ssa.loop_invariant_code_motion_with_reports(None, Some(&mut warnings), None, false);
+
+        assert_eq!(warnings.len(), 1);
+        assert!(matches!(
+            warnings[0],
+            SsaReport::Warning(InternalWarning::UnreachableAssertion { .. })
+        ));
+    }
+
+    #[test]
+    fn hoist_and_fold_invariant_array_len() {
+        // `array_len` is pure, so querying the length of the loop invariant `v0` is hoisted
+        // to the pre-header, where it is folded into the array's known length.
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: [Field; 3], v1: u32):
+            jmp b1(u32 0)
+          b1(v2: u32):
+            v5 = lt v2, u32 4
+            jmpif v5 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v6 = call array_len(v0) -> u32
+            v7 = unchecked_add v2, v6
+            constrain v7 == v1
+            v9 = unchecked_add v2, u32 1
+            jmp b1(v9)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        acir(inline) fn main f0 {
+          b0(v0: [Field; 3], v1: u32):
+            jmp b1(u32 0)
+          b1(v2: u32):
+            v5 = lt v2, u32 4
+            jmpif v5 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v7 = unchecked_add v2, u32 3
+            constrain v7 == v1
+            v9 = unchecked_add v2, u32 1
+            jmp b1(v9)
+        }
+        ";
+
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn hoist_balanced_rc_of_loop_invariant_array() {
+        // Every iteration increments and then decrements the reference count of `v0`
+        // around the call, so the pair can be issued once around the whole loop instead.
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: [Field; 2]):
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v4 = lt v1, u32 4
+            jmpif v4 then: b3, else: b2
+          b2():
+            return
+          b3():
+            inc_rc v0
+            call f1(v0)
+            dec_rc v0
+            v7 = unchecked_add v1, u32 1
+            jmp b1(v7)
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: [Field; 2]):
+            return
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0(v0: [Field; 2]):
+            inc_rc v0
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v4 = lt v1, u32 4
+            jmpif v4 then: b3, else: b2
+          b2():
+            dec_rc v0
+            return
+          b3():
+            call f1(v0)
+            v7 = unchecked_add v1, u32 1
+            jmp b1(v7)
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: [Field; 2]):
+            return
+        }
+        ";
+
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn hoist_array_get_disjoint_from_array_sets_in_loop() {
+        // The array is written to at index `i` for `i` starting at 1,
+        // so `arr[0]` can be read from a snapshot of the array before the loop.
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: [Field; 4], v1: u32):
+            v3 = allocate -> &mut [Field; 4]
+            store v0 at v3
+            jmp b1(u32 1)
+          b1(v2: u32):
+            v5 = lt v2, v1
+            jmpif v5 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v6 = load v3 -> [Field; 4]
+            v8 = array_get v6, index u32 0 -> Field
+            v9 = array_set v6, index v2, value v8
+            store v9 at v3
+            v10 = unchecked_add v2, u32 1
+            jmp b1(v10)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0(v0: [Field; 4], v1: u32):
+            v3 = allocate -> &mut [Field; 4]
+            store v0 at v3
+            v4 = load v3 -> [Field; 4]
+            v6 = array_get v4, index u32 0 -> Field
+            jmp b1(u32 1)
+          b1(v2: u32):
+            v8 = lt v2, v1
+            jmpif v8 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v9 = load v3 -> [Field; 4]
+            v10 = array_set v9, index v2, value v6
+            store v10 at v3
+            v11 = unchecked_add v2, u32 1
+            jmp b1(v11)
+        }
+        ";
+
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn do_not_hoist_array_get_overlapping_array_sets_in_loop() {
+        // The loop starts at 0, so the first iteration writes to `arr[0]`
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: [Field; 4], v1: u32):
+            v3 = allocate -> &mut [Field; 4]
+            store v0 at v3
+            jmp b1(u32 0)
+          b1(v2: u32):
+            v5 = lt v2, v1
+            jmpif v5 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v6 = load v3 -> [Field; 4]
+            v7 = array_get v6, index u32 0 -> Field
+            v8 = array_set v6, index v2, value v7
+            store v8 at v3
+            v10 = unchecked_add v2, u32 1
+            jmp b1(v10)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+
+    #[test]
+    fn hoist_allocate_initialized_with_constant() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32):
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v4 = lt v1, v0
+            jmpif v4 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v5 = allocate -> &mut Field
+            store Field 5 at v5
+            v7 = load v5 -> Field
+            constrain v7 == Field 5
+            v9 = unchecked_add v1, u32 1
+            jmp b1(v9)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32):
+            v1 = allocate -> &mut Field
+            store Field 5 at v1
+            jmp b1(u32 0)
+          b1(v2: u32):
+            v5 = lt v2, v0
+            jmpif v5 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v6 = load v1 -> Field
+            constrain v6 == Field 5
+            v8 = unchecked_add v2, u32 1
+            jmp b1(v8)
+        }
+        ";
+
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn do_not_hoist_allocate_stored_to_again_in_loop() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32):
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v4 = lt v1, v0
+            jmpif v4 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v5 = allocate -> &mut Field
+            store Field 5 at v5
+            v7 = load v5 -> Field
+            v8 = add v7, Field 1
+            store v8 at v5
+            v10 = unchecked_add v1, u32 1
+            jmp b1(v10)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+
+    #[test]
+    fn hoist_truncate_of_loop_invariant_value() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32):
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v4 = lt v1, u32 4
+            jmpif v4 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v5 = truncate v0 to 8 bits, max_bit_size: 32
+            constrain v5 == u32 3
+            v8 = unchecked_add v1, u32 1
+            jmp b1(v8)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32):
+            v1 = truncate v0 to 8 bits, max_bit_size: 32
+            constrain v1 == u32 3
+            jmp b1(u32 0)
+          b1(v2: u32):
+            v5 = lt v2, u32 4
+            jmpif v5 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v8 = unchecked_add v2, u32 1
+            jmp b1(v8)
+        }
+        ";
+
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn do_not_hoist_truncate_of_induction_variable() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32):
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v4 = lt v1, u32 4
+            jmpif v4 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v5 = truncate v1 to 8 bits, max_bit_size: 32
+            constrain v5 == v0
+            v7 = unchecked_add v1, u32 1
+            jmp b1(v7)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+
+    #[test]
+    fn hoist_bit_decomposition_of_loop_invariant_value() {
+        // The loop is known to execute, so decomposing `v0` can be done once before it
+        // without introducing a failure the program wouldn't otherwise have.
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: Field):
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v4 = lt v1, u32 4
+            jmpif v4 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v6 = call to_le_bits(v0) -> [u1; 8]
+            v7 = array_get v6, index v1 -> u1
+            constrain v7 == u1 0
+            v10 = unchecked_add v1, u32 1
+            jmp b1(v10)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        acir(inline) fn main f0 {
+          b0(v0: Field):
+            v2 = call to_le_bits(v0) -> [u1; 8]
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v5 = lt v1, u32 4
+            jmpif v5 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v6 = array_get v2, index v1 -> u1
+            constrain v6 == u1 0
+            v9 = unchecked_add v1, u32 1
+            jmp b1(v9)
+        }
+        ";
+
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn do_not_hoist_bit_decomposition_of_induction_variable() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0():
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v4 = lt v1, u32 4
+            jmpif v4 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v5 = cast v1 as Field
+            v7 = call to_le_radix(v5, u32 256) -> [u8; 1]
+            v9 = array_get v7, index u32 0 -> u8
+            constrain v9 == u8 0
+            v12 = unchecked_add v1, u32 1
+            jmp b1(v12)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+
+    #[test]
+    fn hoist_radix_decomposition_of_loop_invariant_value() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: Field):
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v4 = lt v1, u32 2
+            jmpif v4 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v7 = call to_le_radix(v0, u32 256) -> [u8; 2]
+            v8 = array_get v7, index v1 -> u8
+            constrain v8 == u8 0
+            v11 = unchecked_add v1, u32 1
+            jmp b1(v11)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        acir(inline) fn main f0 {
+          b0(v0: Field):
+            v3 = call to_le_radix(v0, u32 256) -> [u8; 2]
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v6 = lt v1, u32 2
+            jmpif v6 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v7 = array_get v3, index v1 -> u8
+            constrain v7 == u8 0
+            v10 = unchecked_add v1, u32 1
+            jmp b1(v10)
+        }
+        ";
+
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn do_not_hoist_radix_decomposition_with_non_constant_radix() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: Field, v1: u32):
+            jmp b1(u32 0)
+          b1(v2: u32):
+            v5 = lt v2, u32 2
+            jmpif v5 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v6 = call to_le_radix(v0, v1) -> [u8; 2]
+            v7 = array_get v6, index v2 -> u8
+            constrain v7 == u8 0
+            v10 = unchecked_add v2, u32 1
+            jmp b1(v10)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+
+    #[test]
+    fn reuse_loop_condition_recomputed_in_body() {
+        // `v6` recomputes the header's condition `v4`, only to negate it
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32):
+            v2 = allocate -> &mut u1
+            store u1 0 at v2
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v4 = lt v1, v0
+            jmpif v4 then: b3, else: b2
+          b2():
+            v5 = load v2 -> u1
+            return v5
+          b3():
+            v6 = lt v1, v0
+            v7 = not v6
+            store v7 at v2
+            v9 = unchecked_add v1, u32 1
+            jmp b1(v9)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32):
+            v2 = allocate -> &mut u1
+            store u1 0 at v2
+            jmp b1(u32 0)
+          b1(v1: u32):
+            v4 = lt v1, v0
+            jmpif v4 then: b3, else: b2
+          b2():
+            v5 = load v2 -> u1
+            return v5
+          b3():
+            v7 = not v4
+            store v7 at v2
+            v9 = unchecked_add v1, u32 1
+            jmp b1(v9)
+        }
+        ";
+
+        let ssa = ssa.loop_invariant_code_motion();
         assert_normalized_ssa_equals(ssa, expected);
     }
 }
@@ -1553,6 +3414,170 @@ mod control_dependence {
         assert_normalized_ssa_equals(ssa, src);
     }
 
+    #[test]
+    fn hoist_comparison_feeding_constrain_in_control_dependent_block() {
+        let src = "
+        brillig(inline) fn main f0 {
+          entry(v0: u32, v1: u32, v2: u1):
+            jmp loop(u32 0)
+          loop(v3: u32):
+            v6 = lt v3, u32 4
+            jmpif v6 then: loop_cond, else: exit
+          loop_cond():
+            jmpif v2 then: loop_body, else: loop_end
+          exit():
+            return
+          loop_body():
+            v7 = lt v0, v1
+            constrain v7 == u1 1
+            jmp loop_end()
+          loop_end():
+            v10 = unchecked_add v3, u32 1
+            jmp loop(v10)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.loop_invariant_code_motion();
+
+        // The comparison is pure so it is hoisted, while the constrain which may fail
+        // is only executed when `v2` is true.
+        let expected = "
+        brillig(inline) fn main f0 {
+          entry(v0: u32, v1: u32, v2: u1):
+            v4 = lt v0, v1
+            jmp loop(u32 0)
+          loop(v3: u32):
+            v7 = lt v3, u32 4
+            jmpif v7 then: loop_cond, else: exit
+          loop_cond():
+            jmpif v2 then: loop_body, else: loop_end
+          exit():
+            return
+          loop_body():
+            constrain v4 == u1 1
+            jmp loop_end()
+          loop_end():
+            v10 = unchecked_add v3, u32 1
+            jmp loop(v10)
+        }
+        ";
+
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn hoist_invariant_branch_condition() {
+        let src = "
+        brillig(inline) fn main f0 {
+          entry(v0: u32, v1: u32):
+            v3 = allocate -> &mut u32
+            store u32 0 at v3
+            jmp loop(u32 0)
+          loop(v4: u32):
+            v7 = lt v4, u32 4
+            jmpif v7 then: loop_body, else: exit
+          exit():
+            v8 = load v3 -> u32
+            return v8
+          loop_body():
+            v9 = lt v0, v1
+            jmpif v9 then: loop_then, else: loop_end
+          loop_then():
+            store v4 at v3
+            jmp loop_end()
+          loop_end():
+            v11 = unchecked_add v4, u32 1
+            jmp loop(v11)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.loop_invariant_code_motion();
+
+        // The branch takes the same side on every iteration, so its condition is computed
+        // once before the loop, while the `jmpif` itself stays in the loop body.
+        let expected = "
+        brillig(inline) fn main f0 {
+          entry(v0: u32, v1: u32):
+            v3 = allocate -> &mut u32
+            store u32 0 at v3
+            v5 = lt v0, v1
+            jmp loop(u32 0)
+          loop(v4: u32):
+            v7 = lt v4, u32 4
+            jmpif v7 then: loop_body, else: exit
+          exit():
+            v8 = load v3 -> u32
+            return v8
+          loop_body():
+            jmpif v5 then: loop_then, else: loop_end
+          loop_then():
+            store v4 at v3
+            jmp loop_end()
+          loop_end():
+            v11 = unchecked_add v4, u32 1
+            jmp loop(v11)
+        }
+        ";
+
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn hoist_invariant_field_less_than() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: Field, v1: Field):
+            v3 = allocate -> &mut u1
+            store u1 0 at v3
+            jmp b1(u32 0)
+          b1(v4: u32):
+            v7 = lt v4, u32 4
+            jmpif v7 then: b3, else: b2
+          b2():
+            v8 = load v3 -> u1
+            return v8
+          b3():
+            v10 = call field_less_than(v0, v1) -> u1
+            v11 = load v3 -> u1
+            v12 = or v11, v10
+            store v12 at v3
+            v14 = unchecked_add v4, u32 1
+            jmp b1(v14)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.loop_invariant_code_motion();
+
+        // `field_less_than` is pure, so with loop invariant arguments it is computed once
+        // before the loop.
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0(v0: Field, v1: Field):
+            v3 = allocate -> &mut u1
+            store u1 0 at v3
+            v5 = call field_less_than(v0, v1) -> u1
+            jmp b1(u32 0)
+          b1(v4: u32):
+            v8 = lt v4, u32 4
+            jmpif v8 then: b3, else: b2
+          b2():
+            v9 = load v3 -> u1
+            return v9
+          b3():
+            v10 = load v3 -> u1
+            v11 = or v10, v5
+            store v11 at v3
+            v13 = unchecked_add v4, u32 1
+            jmp b1(v13)
+        }
+        ";
+
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
     #[test]
     fn hoist_safe_mul_that_is_non_control_dependent() {
         let src = "
@@ -2093,4 +4118,288 @@ mod control_dependence {
 
         assert_normalized_ssa_equals(ssa, expected);
     }
+
+    #[test]
+    fn hoist_under_always_true_predicate_only() {
+        // The first `div` runs under a predicate which changes between iterations, so it must stay
+        // in the loop. The block then resets the predicate to `u1 1`, under which the second `div`
+        // always runs and can be hoisted.
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: u32, v1: u32):
+            jmp b1(u32 0)
+          b1(v2: u32):
+            v5 = lt v2, u32 4
+            jmpif v5 then: b2, else: b3
+          b2():
+            v7 = eq v2, u32 2
+            enable_side_effects v7
+            v8 = div v0, v1
+            enable_side_effects u1 1
+            v10 = div v1, v0
+            v12 = unchecked_add v2, u32 1
+            jmp b1(v12)
+          b3():
+            return
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let ssa = ssa.loop_invariant_code_motion();
+        let expected = "
+        acir(inline) fn main f0 {
+          b0(v0: u32, v1: u32):
+            v3 = div v1, v0
+            jmp b1(u32 0)
+          b1(v2: u32):
+            v6 = lt v2, u32 4
+            jmpif v6 then: b2, else: b3
+          b2():
+            v8 = eq v2, u32 2
+            enable_side_effects v8
+            v9 = div v0, v1
+            enable_side_effects u1 1
+            v12 = unchecked_add v2, u32 1
+            jmp b1(v12)
+          b3():
+            return
+        }
+        ";
+
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn hoist_invariant_predicate_comparison() {
+        // `v6` only depends on the loop invariant `v0`, so it is hoisted. The predicate it sets
+        // isn't known to be `u1 1`, so the `div` under it stays in the loop.
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: u1, v1: u32, v2: u32):
+            jmp b1(u32 0)
+          b1(v3: u32):
+            v6 = lt v3, u32 4
+            jmpif v6 then: b2, else: b3
+          b2():
+            v8 = eq v0, u1 1
+            enable_side_effects v8
+            v9 = div v1, v2
+            enable_side_effects u1 1
+            v11 = unchecked_add v3, u32 1
+            jmp b1(v11)
+          b3():
+            return
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let ssa = ssa.loop_invariant_code_motion();
+        let expected = "
+        acir(inline) fn main f0 {
+          b0(v0: u1, v1: u32, v2: u32):
+            v4 = eq v0, u1 1
+            jmp b1(u32 0)
+          b1(v3: u32):
+            v7 = lt v3, u32 4
+            jmpif v7 then: b2, else: b3
+          b2():
+            enable_side_effects v4
+            v8 = div v1, v2
+            enable_side_effects u1 1
+            v10 = unchecked_add v3, u32 1
+            jmp b1(v10)
+          b3():
+            return
+        }
+        ";
+
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn hoist_under_constant_predicate_comparison() {
+        // The flag is a constant, so the predicate comparison folds to `u1 1` as it is hoisted.
+        // The `div` under it then always runs and can be hoisted too, leaving the two
+        // `enable_side_effects` next to each other, where the second replaces the first.
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: u32, v1: u32):
+            jmp b1(u32 0)
+          b1(v2: u32):
+            v5 = lt v2, u32 4
+            jmpif v5 then: b2, else: b3
+          b2():
+            v7 = eq u1 1, u1 1
+            enable_side_effects v7
+            v8 = div v0, v1
+            enable_side_effects u1 1
+            v10 = unchecked_add v2, u32 1
+            jmp b1(v10)
+          b3():
+            return
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let ssa = ssa.loop_invariant_code_motion();
+        let expected = "
+        acir(inline) fn main f0 {
+          b0(v0: u32, v1: u32):
+            v3 = div v0, v1
+            jmp b1(u32 0)
+          b1(v2: u32):
+            v6 = lt v2, u32 4
+            jmpif v6 then: b2, else: b3
+          b2():
+            enable_side_effects u1 1
+            v9 = unchecked_add v2, u32 1
+            jmp b1(v9)
+          b3():
+            return
+        }
+        ";
+
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn hoist_rotated_array_access_from_control_dependent_block() {
+        // SSA for the following program:
+        //
+        // unconstrained fn main(arr: [u32; 4], k: u32, c: bool) {
+        //     for i in 0..4 {
+        //         let rotated = i + k;
+        //         for j in 0..4 {
+        //             if c {
+        //                 assert_eq(arr[rotated % 4], j);
+        //             }
+        //         }
+        //     }
+        // }
+        //
+        // The modulo by a non-zero constant can't fail and its result is always in bounds
+        // of `arr`, so both are hoisted out of the inner loop even though they are only
+        // executed under `c`.
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: [u32; 4], v1: u32, v2: u1):
+            jmp b1(u32 0)
+          b1(v3: u32):
+            v7 = lt v3, u32 4
+            jmpif v7 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v8 = add v3, v1
+            jmp b4(u32 0)
+          b4(v4: u32):
+            v9 = lt v4, u32 4
+            jmpif v9 then: b6, else: b5
+          b5():
+            v10 = unchecked_add v3, u32 1
+            jmp b1(v10)
+          b6():
+            jmpif v2 then: b7, else: b8
+          b7():
+            v11 = mod v8, u32 4
+            v12 = array_get v0, index v11 -> u32
+            constrain v12 == v4
+            jmp b8()
+          b8():
+            v13 = unchecked_add v4, u32 1
+            jmp b4(v13)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0(v0: [u32; 4], v1: u32, v2: u1):
+            jmp b1(u32 0)
+          b1(v3: u32):
+            v7 = lt v3, u32 4
+            jmpif v7 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v8 = add v3, v1
+            v11 = mod v8, u32 4
+            v12 = array_get v0, index v11 -> u32
+            jmp b4(u32 0)
+          b4(v4: u32):
+            v9 = lt v4, u32 4
+            jmpif v9 then: b6, else: b5
+          b5():
+            v10 = unchecked_add v3, u32 1
+            jmp b1(v10)
+          b6():
+            jmpif v2 then: b7, else: b8
+          b7():
+            constrain v12 == v4
+            jmp b8()
+          b8():
+            v13 = unchecked_add v4, u32 1
+            jmp b4(v13)
+        }
+        ";
+
+        let ssa = ssa.loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn loop_report_explains_control_dependent_constrain() {
+        use crate::ssa::{
+            LoopOptimization, LoopReport, NotHoistedInstruction, NotHoistedReason,
+            ir::function::FunctionId,
+        };
+
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: u32, v1: u1):
+            jmp b1(u32 0)
+          b1(v2: u32):
+            v5 = lt v2, u32 4
+            jmpif v5 then: b2, else: b3
+          b2():
+            jmpif v1 then: b4, else: b5
+          b3():
+            return
+          b4():
+            constrain v0 == u32 5
+            jmp b5()
+          b5():
+            v8 = unchecked_add v2, u32 1
+            jmp b1(v8)
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+        let mut report = LoopReport::default();
+        let _ = ssa.loop_invariant_code_motion_with_reports(None, None, Some(&mut report), false);
+
+        // The constrain's operands are loop invariant, but it is only executed when `v1` is set
+        let expected = LoopOptimization {
+            function: "main".to_string(),
+            function_id: Some(FunctionId::test_new(0)),
+            location: None,
+            unrolled: false,
+            instructions_hoisted: 0,
+            not_hoisted: vec![NotHoistedInstruction {
+                instruction: "constrain v0 == u32 5".to_string(),
+                location: None,
+                reason: NotHoistedReason::ControlDependent,
+            }],
+        };
+        assert_eq!(report.loops, vec![expected]);
+
+        let rendered = report.render(|_| unreachable!("the SSA has no locations"));
+        assert!(rendered.contains(
+            "not hoisted: `constrain v0 == u32 5` at unknown location, because it is only executed on some paths of the loop"
+        ));
+    }
 }
@@ -11,6 +11,8 @@ mod basic_conditional;
 mod brillig_array_gets;
 pub(crate) mod brillig_entry_points;
 mod check_u128_mul_overflow;
+mod combine_bound_checks;
+mod constant_block_params;
 mod constant_folding;
 mod defunctionalize;
 mod die;
@@ -20,16 +22,24 @@ pub(crate) mod inlining;
 mod loop_invariant;
 mod make_constrain_not_equal;
 mod mem2reg;
+mod narrow_integer_types;
 mod normalize_value_ids;
 mod preprocess_fns;
 pub(crate) mod pure;
 mod rc;
+mod read_only_parameters;
+mod reassociate_loop_invariants;
 mod remove_bit_shifts;
 mod remove_enable_side_effects;
+mod remove_identity_casts;
 mod remove_if_else;
+mod remove_self_assigning_stores;
 mod remove_truncate_after_range_check;
 mod remove_unreachable;
 mod simplify_cfg;
+mod simplify_constant_constraints;
+mod specialize_constant_arguments;
+mod tail_call_to_loop;
 mod unrolling;
 
 /// Asserts that the given SSA, after normalizing its IDs and printing it,
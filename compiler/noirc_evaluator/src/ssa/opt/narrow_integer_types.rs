@@ -0,0 +1,337 @@
+//! This file contains a Brillig-only pass narrowing integer arithmetic derived from loop
+//! induction variables to smaller integer types, when the loop bounds prove it safe.
+//!
+//! An induction variable of a loop with constant bounds `lower..upper` always lies within
+//! `[lower, upper]`, the upper bound being reached when the loop exits. Starting from these
+//! ranges, the range of each `add`, `sub` or `mul` whose operands are induction variables,
+//! constants or values derived from them is evaluated from the bounds of its operands.
+//! If that evaluation can overflow, the range of the result is unknown.
+//!
+//! When the range of a result and of its operands fit in a smaller unsigned type (`u8`, `u16`,
+//! `u32` or `u64`) than the one of the operation, the operation is performed in that type.
+//! Operands which are not themselves narrowed are cast to the smaller type, and the result
+//! is cast back to the original type for its other uses, so that a chain of narrowed
+//! operations only needs casts at its boundaries. Unused casts are left for dead instruction
+//! elimination to remove.
+//!
+//! The increment of an induction variable is passed back to the loop header, so it is never
+//! narrowed as it would always need to be cast back.
+use acvm::{FieldElement, acir::AcirField};
+use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use crate::ssa::{
+    ir::{
+        basic_block::BasicBlockId,
+        call_stack::CallStackId,
+        function::Function,
+        instruction::{
+            Binary, BinaryOp, Instruction, InstructionId, binary::eval_constant_binary_op,
+        },
+        post_order::PostOrder,
+        types::{NumericType, Type},
+        value::ValueId,
+    },
+    ssa_gen::Ssa,
+};
+
+use super::unrolling::Loops;
+
+/// The smallest and largest values a value can take, inclusive.
+type Range = (FieldElement, FieldElement);
+
+/// The bit sizes which arithmetic can be narrowed to, from smallest to largest.
+const NARROW_BIT_SIZES: [u32; 4] = [8, 16, 32, 64];
+
+impl Ssa {
+    /// Performs arithmetic derived from loop induction variables in the smallest unsigned
+    /// type which the loop bounds prove it fits in.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) fn narrow_integer_types(mut self) -> Self {
+        for function in self.functions.values_mut() {
+            if function.runtime().is_brillig() {
+                function.narrow_integer_types();
+            }
+        }
+        self
+    }
+}
+
+impl Function {
+    pub(crate) fn narrow_integer_types(&mut self) {
+        let mut context = NarrowingContext::new(self);
+        if context.ranges.is_empty() {
+            return;
+        }
+
+        let mut blocks = PostOrder::with_function(self).into_vec();
+        blocks.reverse();
+        for block in blocks {
+            context.narrow_block(self, block);
+        }
+    }
+}
+
+struct NarrowingContext {
+    /// The known range of induction variables and of values derived from them
+    ranges: HashMap<ValueId, Range>,
+    /// The increments of induction variables, which are passed back to their loop header
+    increments: HashSet<ValueId>,
+    /// Maps a narrowed result to the result of the narrowed operation and its type
+    narrowed: HashMap<ValueId, (ValueId, NumericType)>,
+    /// Values cast to a smaller type in the current block
+    casts: HashMap<(ValueId, NumericType), ValueId>,
+}
+
+impl NarrowingContext {
+    fn new(function: &Function) -> Self {
+        let loops = Loops::find_all(function);
+        let mut ranges = HashMap::default();
+        let mut increments = HashSet::default();
+
+        for loop_ in &loops.yet_to_unroll {
+            let Ok(pre_header) = loop_.get_pre_header(function, &loops.cfg) else {
+                continue;
+            };
+            let Some((lower, upper)) = loop_.get_const_bounds(function, pre_header) else {
+                continue;
+            };
+            let induction_variable = loop_.get_induction_variable(function);
+            if !function.dfg.type_of_value(induction_variable).is_unsigned() {
+                continue;
+            }
+            let upper = if upper.to_u128() < lower.to_u128() { lower } else { upper };
+            ranges.insert(induction_variable, (lower, upper));
+
+            for predecessor in loops.cfg.predecessors(loop_.header) {
+                if loop_.blocks.contains(&predecessor) {
+                    let terminator = function.dfg[predecessor].unwrap_terminator();
+                    terminator.for_each_value(|value| {
+                        increments.insert(value);
+                    });
+                }
+            }
+        }
+
+        Self { ranges, increments, narrowed: HashMap::default(), casts: HashMap::default() }
+    }
+
+    fn narrow_block(&mut self, function: &mut Function, block: BasicBlockId) {
+        self.casts.clear();
+
+        // Instructions are pushed back in order, with narrowed operations and the casts
+        // around them pushed in place of the operation they replace.
+        for instruction_id in function.dfg[block].take_instructions() {
+            if !self.narrow_instruction(function, block, instruction_id) {
+                function.dfg[block].insert_instruction(instruction_id);
+            }
+        }
+    }
+
+    /// Records the range of the result of `instruction_id` if it can be evaluated, and pushes a
+    /// narrowed version of the instruction to `block` if its result fits in a smaller type.
+    ///
+    /// Returns true if the instruction was replaced.
+    fn narrow_instruction(
+        &mut self,
+        function: &mut Function,
+        block: BasicBlockId,
+        instruction_id: InstructionId,
+    ) -> bool {
+        let Instruction::Binary(binary) = &function.dfg[instruction_id] else {
+            return false;
+        };
+        let binary = binary.clone();
+        let Type::Numeric(typ @ NumericType::Unsigned { bit_size }) =
+            function.dfg.type_of_value(binary.lhs)
+        else {
+            return false;
+        };
+        // Operations on constants only are left to constant folding
+        if function.dfg.is_constant(binary.lhs) && function.dfg.is_constant(binary.rhs) {
+            return false;
+        }
+
+        let (Some(lhs_range), Some(rhs_range)) =
+            (self.range(function, binary.lhs), self.range(function, binary.rhs))
+        else {
+            return false;
+        };
+        let Some(range) = binary_range(binary.operator, lhs_range, rhs_range, typ) else {
+            return false;
+        };
+        let result = function.dfg.instruction_results(instruction_id)[0];
+        self.ranges.insert(result, range);
+
+        if self.increments.contains(&result) {
+            return false;
+        }
+
+        let max = range.1.to_u128().max(lhs_range.1.to_u128()).max(rhs_range.1.to_u128());
+        let Some(narrow_bit_size) = NARROW_BIT_SIZES
+            .into_iter()
+            .find(|narrow_bit_size| *narrow_bit_size < bit_size && max < 1 << narrow_bit_size)
+        else {
+            return false;
+        };
+        let narrow_type = NumericType::Unsigned { bit_size: narrow_bit_size };
+
+        let call_stack = function.dfg.get_instruction_call_stack_id(instruction_id);
+        let lhs = self.narrow_value(function, block, binary.lhs, narrow_type, call_stack);
+        let rhs = self.narrow_value(function, block, binary.rhs, narrow_type, call_stack);
+        let narrowed = Instruction::Binary(Binary { lhs, rhs, operator: binary.operator });
+        let narrowed = insert_instruction(function, block, narrowed, call_stack);
+        self.narrowed.insert(result, (narrowed, narrow_type));
+
+        let widened =
+            insert_instruction(function, block, Instruction::Cast(narrowed, typ), call_stack);
+        function.dfg.set_value_from_id(result, widened);
+        true
+    }
+
+    /// Returns the range of `value` if it is a constant or its range is known.
+    fn range(&self, function: &Function, value: ValueId) -> Option<Range> {
+        match function.dfg.get_numeric_constant(value) {
+            Some(constant) => Some((constant, constant)),
+            None => self.ranges.get(&value).copied(),
+        }
+    }
+
+    /// Returns a version of `value` of type `narrow_type`, casting it if it wasn't already narrowed.
+    fn narrow_value(
+        &mut self,
+        function: &mut Function,
+        block: BasicBlockId,
+        value: ValueId,
+        narrow_type: NumericType,
+        call_stack: CallStackId,
+    ) -> ValueId {
+        if let Some(constant) = function.dfg.get_numeric_constant(value) {
+            return function.dfg.make_constant(constant, narrow_type);
+        }
+        match self.narrowed.get(&value) {
+            Some((narrowed, typ)) if *typ == narrow_type => return *narrowed,
+            _ => (),
+        }
+        if let Some(cast) = self.casts.get(&(value, narrow_type)) {
+            return *cast;
+        }
+
+        let cast =
+            insert_instruction(function, block, Instruction::Cast(value, narrow_type), call_stack);
+        self.casts.insert((value, narrow_type), cast);
+        cast
+    }
+}
+
+/// Returns the range of the result of `operator` applied to operands in the given ranges, or
+/// `None` if the operation may overflow or its range can't be evaluated from its bounds.
+fn binary_range(operator: BinaryOp, lhs: Range, rhs: Range, typ: NumericType) -> Option<Range> {
+    let (min, max) = match operator {
+        BinaryOp::Add { .. } | BinaryOp::Mul { .. } => ((lhs.0, rhs.0), (lhs.1, rhs.1)),
+        BinaryOp::Sub { .. } => ((lhs.0, rhs.1), (lhs.1, rhs.0)),
+        _ => return None,
+    };
+    let evaluate = |(lhs, rhs)| eval_constant_binary_op(lhs, rhs, operator, typ);
+    Some((evaluate(min)?.0, evaluate(max)?.0))
+}
+
+fn insert_instruction(
+    function: &mut Function,
+    block: BasicBlockId,
+    instruction: Instruction,
+    call_stack: CallStackId,
+) -> ValueId {
+    function
+        .dfg
+        .insert_instruction_and_results_without_simplification(instruction, block, None, call_stack)
+        .first()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{opt::assert_normalized_ssa_equals, ssa_gen::Ssa};
+
+    #[test]
+    fn narrows_value_derived_from_counter_to_u8() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0():
+            jmp b1(u32 0)
+          b1(v0: u32):
+            v3 = lt v0, u32 100
+            jmpif v3 then: b2, else: b3
+          b2():
+            v5 = unchecked_mul v0, u32 2
+            v7 = unchecked_add v5, u32 50
+            call f1(v7)
+            v10 = unchecked_add v0, u32 1
+            jmp b1(v10)
+          b3():
+            return
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: u32):
+            return
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        // `v0 * 2 + 50` is at most 250, which fits in a u8
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0():
+            jmp b1(u32 0)
+          b1(v0: u32):
+            v3 = lt v0, u32 100
+            jmpif v3 then: b2, else: b3
+          b2():
+            v4 = cast v0 as u8
+            v6 = unchecked_mul v4, u8 2
+            v7 = cast v6 as u32
+            v9 = unchecked_add v6, u8 50
+            v10 = cast v9 as u32
+            call f1(v10)
+            v13 = unchecked_add v0, u32 1
+            jmp b1(v13)
+          b3():
+            return
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: u32):
+            return
+        }
+        ";
+
+        let ssa = ssa.narrow_integer_types();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn does_not_narrow_value_which_may_not_fit() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0():
+            jmp b1(u32 0)
+          b1(v0: u32):
+            v3 = lt v0, u32 100
+            jmpif v3 then: b2, else: b3
+          b2():
+            v5 = unchecked_mul v0, u32 1000
+            call f1(v5)
+            v8 = unchecked_add v0, u32 1
+            jmp b1(v8)
+          b3():
+            return
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: u32):
+            return
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        // `v0 * 1000` can be as large as 100000, which doesn't fit in a u16
+        let ssa = ssa.narrow_integer_types();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+}
@@ -9,7 +9,14 @@ use super::inlining::{self, InlineInfo};
 
 impl Ssa {
     /// Run pre-processing steps on functions in isolation.
-    pub(crate) fn preprocess_functions(mut self, aggressiveness: i64) -> Ssa {
+    ///
+    /// If `keep_brillig_overflow_checks` is set, checked arithmetic in Brillig functions is
+    /// never turned into unchecked arithmetic.
+    pub(crate) fn preprocess_functions(
+        mut self,
+        aggressiveness: i64,
+        keep_brillig_overflow_checks: bool,
+    ) -> Ssa {
         // Bottom-up order, starting with the "leaf" functions, so we inline already optimized code into the ones that call them.
         let bottom_up = inlining::inline_info::compute_bottom_up_order(&self);
 
@@ -51,7 +58,7 @@ impl Ssa {
             // Help unrolling determine bounds.
             function.as_slice_optimization();
             // Prepare for unrolling
-            function.loop_invariant_code_motion();
+            function.loop_invariant_code_motion(keep_brillig_overflow_checks);
             // We might not be able to unroll all loops without fully inlining them, so ignore errors.
             let _ = function.unroll_loops_iteratively();
             // Reduce the number of redundant stores/loads after unrolling
@@ -0,0 +1,186 @@
+//! An interprocedural analysis finding the array parameters which a function only ever reads.
+//!
+//! A parameter is read-only if every use of it within its function is an `array_get`,
+//! an `inc_rc` or `dec_rc`, or an argument to a call to a function whose corresponding
+//! parameter is itself read-only. Any other use, such as an `array_set`, a `store`,
+//! passing it to another block, returning it or passing it to an intrinsic or an unknown
+//! function, could mutate the array or let it escape, so the parameter is not read-only.
+//!
+//! Since functions may be (mutually) recursive, every parameter starts out as read-only
+//! and parameters are marked as possibly mutated until a fixpoint is reached.
+//!
+//! Passing an array to a read-only parameter does not require an `inc_rc` to guard
+//! against the callee mutating it in place.
+use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use crate::ssa::{
+    ir::{
+        function::{Function, FunctionId},
+        instruction::Instruction,
+        types::Type,
+        value::{Value, ValueId},
+    },
+    ssa_gen::Ssa,
+};
+
+/// For each function, whether each of its parameters is a read-only array.
+#[derive(Debug, Default)]
+pub(crate) struct ReadOnlyParameters(HashMap<FunctionId, Vec<bool>>);
+
+impl ReadOnlyParameters {
+    pub(crate) fn compute(ssa: &Ssa) -> Self {
+        let mut read_only = ReadOnlyParameters(
+            ssa.functions
+                .iter()
+                .map(|(id, function)| {
+                    let parameters = function.parameters();
+                    let is_array = |parameter: &ValueId| {
+                        matches!(
+                            function.dfg.type_of_value(*parameter),
+                            Type::Array(..) | Type::Slice(..)
+                        )
+                    };
+                    (*id, parameters.iter().map(is_array).collect())
+                })
+                .collect(),
+        );
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (id, function) in &ssa.functions {
+                let mutated = read_only.possibly_mutated_parameters(function);
+                for (index, is_read_only) in read_only.0.get_mut(id).unwrap().iter_mut().enumerate()
+                {
+                    if *is_read_only && mutated.contains(&function.parameters()[index]) {
+                        *is_read_only = false;
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        read_only
+    }
+
+    /// Returns true if the parameter at `index` of `function` is an array which is only ever read.
+    pub(crate) fn is_read_only(&self, function: FunctionId, index: usize) -> bool {
+        self.0.get(&function).and_then(|parameters| parameters.get(index)).copied().unwrap_or(false)
+    }
+
+    /// Returns true if `instruction` passes `value` as an argument to a read-only parameter of
+    /// the function it calls, and doesn't use it in any other way.
+    pub(crate) fn only_passed_to_read_only_parameters(
+        &self,
+        function: &Function,
+        instruction: &Instruction,
+        value: ValueId,
+    ) -> bool {
+        let Instruction::Call { func, arguments } = instruction else {
+            return false;
+        };
+        let Value::Function(callee) = function.dfg[*func] else {
+            return false;
+        };
+        arguments
+            .iter()
+            .enumerate()
+            .all(|(index, argument)| *argument != value || self.is_read_only(callee, index))
+    }
+
+    /// Returns the parameters of `function` which, given what is currently known about
+    /// other functions, may be mutated or escape.
+    fn possibly_mutated_parameters(&self, function: &Function) -> HashSet<ValueId> {
+        let mut mutated = HashSet::default();
+
+        for block in function.reachable_blocks() {
+            for instruction_id in function.dfg[block].instructions() {
+                let instruction = &function.dfg[*instruction_id];
+                match instruction {
+                    Instruction::ArrayGet { index, .. } => {
+                        mutated.insert(*index);
+                    }
+                    Instruction::IncrementRc { .. } | Instruction::DecrementRc { .. } => (),
+                    Instruction::Call { .. } => instruction.for_each_value(|value| {
+                        if !self.only_passed_to_read_only_parameters(function, instruction, value) {
+                            mutated.insert(value);
+                        }
+                    }),
+                    _ => instruction.for_each_value(|value| {
+                        mutated.insert(value);
+                    }),
+                }
+            }
+            if let Some(terminator) = function.dfg[block].terminator() {
+                terminator.for_each_value(|value| {
+                    mutated.insert(value);
+                });
+            }
+        }
+
+        mutated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::ssa_gen::Ssa;
+
+    use super::ReadOnlyParameters;
+
+    #[test]
+    fn finds_read_only_parameters_through_calls() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: [Field; 2], v1: [Field; 2]):
+            call f1(v0, v1)
+            return
+        }
+        brillig(inline) fn read f1 {
+          b0(v0: [Field; 2], v1: [Field; 2]):
+            v3 = array_get v0, index u32 0 -> Field
+            call f2(v1)
+            return
+        }
+        brillig(inline) fn write f2 {
+          b0(v0: [Field; 2]):
+            v3 = array_set v0, index u32 0, value Field 1
+            return
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let read_only = ReadOnlyParameters::compute(&ssa);
+        let [f0, f1, f2] = ssa.functions.keys().copied().collect::<Vec<_>>()[..] else {
+            panic!("Expected three functions");
+        };
+        assert!(read_only.is_read_only(f0, 0));
+        assert!(!read_only.is_read_only(f0, 1));
+        assert!(read_only.is_read_only(f1, 0));
+        assert!(!read_only.is_read_only(f1, 1));
+        assert!(!read_only.is_read_only(f2, 0));
+    }
+
+    #[test]
+    fn recursive_function_parameter_is_read_only() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: [Field; 2], v1: u32):
+            v3 = eq v1, u32 0
+            jmpif v3 then: b1, else: b2
+          b1():
+            return
+          b2():
+            v5 = array_get v0, index u32 0 -> Field
+            v7 = unchecked_sub v1, u32 1
+            call f0(v0, v7)
+            return
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let read_only = ReadOnlyParameters::compute(&ssa);
+        assert!(read_only.is_read_only(ssa.main_id, 0));
+        assert!(!read_only.is_read_only(ssa.main_id, 1));
+    }
+}
@@ -0,0 +1,233 @@
+//! This module defines an SSA pass which reassociates operations in loops so that their loop
+//! invariant operands are combined with each other first.
+//!
+//! An expression such as `(a + i) + b` in a loop, where `a` and `b` are loop invariant and `i`
+//! isn't, can't be hoisted out of the loop, and neither can any of its parts. Grouping the
+//! invariant operands together as `i + (a + b)` lets loop invariant code motion hoist `a + b`:
+//!
+//! ```text
+//! v3 = unchecked_add v0, v2
+//! v4 = unchecked_add v3, v1
+//! ```
+//!
+//! becomes
+//!
+//! ```text
+//! v3 = unchecked_add v0, v2
+//! v5 = unchecked_add v0, v1
+//! v4 = unchecked_add v2, v5
+//! ```
+//!
+//! The original inner operation is left for dead instruction elimination to remove if it
+//! isn't used elsewhere.
+//!
+//! Reassociating mustn't introduce an overflow which the original expression didn't have, so
+//! only these operations are reassociated:
+//! - additions and multiplications of fields, which wrap around the field modulus anyway.
+//! - unchecked additions of unsigned integers, as `a + b` is at most `(a + i) + b`, which is
+//!   known not to overflow. Unchecked multiplications aren't, as `(a * i) * b` doesn't overflow
+//!   when `i` is zero while `a * b` may.
+//! - bitwise `and`, `or` and `xor`, which can't overflow.
+//!
+//! Checked arithmetic is left unchanged so that its overflow checks fail on the same values.
+//!
+//! This pass must run before loop invariant code motion, which hoists the grouped operations.
+use fxhash::FxHashSet as HashSet;
+
+use crate::ssa::{
+    ir::{
+        function::Function,
+        instruction::{Binary, BinaryOp, Instruction, InstructionId},
+        types::NumericType,
+        value::ValueId,
+    },
+    ssa_gen::Ssa,
+};
+
+use super::unrolling::{Loop, Loops};
+
+impl Ssa {
+    /// Reassociates the associative operations in loops which combine a loop invariant value
+    /// with the result of the same operation on a variant and an invariant value, so that the
+    /// invariant values are combined first.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) fn reassociate_loop_invariants(mut self) -> Ssa {
+        for function in self.functions.values_mut() {
+            function.reassociate_loop_invariants();
+        }
+        self
+    }
+}
+
+impl Function {
+    pub(crate) fn reassociate_loop_invariants(&mut self) {
+        let loops = Loops::find_all(self);
+        for loop_ in &loops.yet_to_unroll {
+            self.reassociate_loop_invariants_in_loop(loop_);
+        }
+    }
+
+    fn reassociate_loop_invariants_in_loop(&mut self, loop_: &Loop) {
+        // The values defined in the loop, which may change between iterations. The values
+        // inserted by this pass aren't included, so that chains such as `((a + i) + b) + c`
+        // are reassociated one operation at a time.
+        let mut variant_values = HashSet::default();
+        for block in &loop_.blocks {
+            variant_values.extend(self.dfg[*block].parameters().iter().copied());
+            for instruction in self.dfg[*block].instructions() {
+                variant_values.extend(self.dfg.instruction_results(*instruction).iter().copied());
+            }
+        }
+
+        for block in &loop_.blocks {
+            let instructions = self.dfg[*block].take_instructions();
+            for instruction_id in instructions {
+                if let Some((variant, (lhs, rhs), operator)) =
+                    self.reassociation(instruction_id, &variant_values)
+                {
+                    let call_stack = self.dfg.get_instruction_call_stack_id(instruction_id);
+                    let invariant = Instruction::Binary(Binary { lhs, rhs, operator });
+                    let invariant = self
+                        .dfg
+                        .insert_instruction_and_results(invariant, *block, None, call_stack)
+                        .first();
+                    self.dfg[instruction_id] =
+                        Instruction::Binary(Binary { lhs: variant, rhs: invariant, operator });
+                }
+                self.dfg[*block].insert_instruction(instruction_id);
+            }
+        }
+    }
+
+    /// Returns the variant operand and the two invariant operands of an instruction which
+    /// combines an invariant value with the result of the same operation on a variant and an
+    /// invariant value, if the operation can be reassociated.
+    fn reassociation(
+        &self,
+        instruction_id: InstructionId,
+        variant_values: &HashSet<ValueId>,
+    ) -> Option<(ValueId, (ValueId, ValueId), BinaryOp)> {
+        let Instruction::Binary(Binary { lhs, rhs, operator }) = &self.dfg[instruction_id] else {
+            return None;
+        };
+        if !self.can_reassociate(*lhs, *operator) {
+            return None;
+        }
+        let (inner, outer_invariant) = split_variant_operand(*lhs, *rhs, variant_values)?;
+
+        let Instruction::Binary(inner) = self.dfg.get_local_or_global_instruction(inner)? else {
+            return None;
+        };
+        if inner.operator != *operator {
+            return None;
+        }
+        let (variant, inner_invariant) =
+            split_variant_operand(inner.lhs, inner.rhs, variant_values)?;
+
+        Some((variant, (inner_invariant, outer_invariant), *operator))
+    }
+
+    /// Returns whether grouping the operands of `operator` differently can't introduce an
+    /// overflow, where `operand` is one of its operands.
+    fn can_reassociate(&self, operand: ValueId, operator: BinaryOp) -> bool {
+        let typ = self.dfg.type_of_value(operand).unwrap_numeric();
+        match operator {
+            BinaryOp::And | BinaryOp::Or | BinaryOp::Xor => true,
+            BinaryOp::Add { unchecked } => {
+                typ == NumericType::NativeField
+                    || (unchecked && matches!(typ, NumericType::Unsigned { .. }))
+            }
+            BinaryOp::Mul { .. } => typ == NumericType::NativeField,
+            _ => false,
+        }
+    }
+}
+
+/// Returns the variant and the invariant operand, in that order, if exactly one of the two
+/// operands is variant.
+fn split_variant_operand(
+    lhs: ValueId,
+    rhs: ValueId,
+    variant_values: &HashSet<ValueId>,
+) -> Option<(ValueId, ValueId)> {
+    match (variant_values.contains(&lhs), variant_values.contains(&rhs)) {
+        (true, false) => Some((lhs, rhs)),
+        (false, true) => Some((rhs, lhs)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{opt::assert_normalized_ssa_equals, ssa_gen::Ssa};
+
+    #[test]
+    fn reassociates_and_hoists_invariant_additions() {
+        // `(v0 + v2) + v1` in the loop, where `v2` is the induction variable
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: u32, v1: u32):
+            jmp b1(u32 0)
+          b1(v2: u32):
+            v5 = lt v2, u32 4
+            jmpif v5 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v6 = unchecked_add v0, v2
+            v7 = unchecked_add v6, v1
+            constrain v7 == u32 10
+            v9 = unchecked_add v2, u32 1
+            jmp b1(v9)
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        acir(inline) fn main f0 {
+          b0(v0: u32, v1: u32):
+            v3 = unchecked_add v0, v1
+            jmp b1(u32 0)
+          b1(v2: u32):
+            v6 = lt v2, u32 4
+            jmpif v6 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v7 = unchecked_add v0, v2
+            v8 = unchecked_add v2, v3
+            constrain v8 == u32 10
+            v10 = unchecked_add v2, u32 1
+            jmp b1(v10)
+        }
+        ";
+        let ssa = ssa.reassociate_loop_invariants().loop_invariant_code_motion();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn does_not_reassociate_checked_additions() {
+        // Computing `v0 + v1` first could overflow when `v0 + v2` doesn't
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: i32, v1: i32):
+            jmp b1(i32 0)
+          b1(v2: i32):
+            v5 = lt v2, i32 4
+            jmpif v5 then: b3, else: b2
+          b2():
+            return
+          b3():
+            v6 = add v0, v2
+            v7 = add v6, v1
+            constrain v7 == i32 10
+            v9 = unchecked_add v2, i32 1
+            jmp b1(v9)
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let ssa = ssa.reassociate_loop_invariants();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+}
@@ -0,0 +1,94 @@
+use fxhash::FxHashSet as HashSet;
+
+use crate::ssa::{
+    ir::{
+        function::Function, function_inserter::FunctionInserter, instruction::Instruction,
+        post_order::PostOrder, types::Type,
+    },
+    ssa_gen::Ssa,
+};
+
+impl Ssa {
+    /// This SSA pass removes `cast` instructions whose target type is the type of the value
+    /// being cast, e.g. `v1 = cast v0 as i32` where `v0: i32`, replacing all uses of their
+    /// result with the value being cast.
+    ///
+    /// Such casts are simplified away when they are inserted, but passes which move
+    /// instructions without re-inserting them may leave some behind.
+    pub(crate) fn remove_identity_casts(mut self) -> Self {
+        for function in self.functions.values_mut() {
+            function.remove_identity_casts();
+        }
+        self
+    }
+}
+
+impl Function {
+    pub(crate) fn remove_identity_casts(&mut self) {
+        // Blocks are visited in reverse post order so that a value is always mapped before
+        // any of the instructions using it are.
+        let mut block_order = PostOrder::with_function(self).into_vec();
+        block_order.reverse();
+
+        let mut inserter = FunctionInserter::new(self);
+        for block in block_order {
+            let mut instructions_to_remove = HashSet::default();
+
+            for instruction_id in inserter.function.dfg[block].instructions().to_vec() {
+                inserter.map_instruction_in_place(instruction_id);
+
+                let dfg = &inserter.function.dfg;
+                let Instruction::Cast(value, typ) = dfg[instruction_id] else {
+                    continue;
+                };
+                if dfg.type_of_value(value) != Type::Numeric(typ) {
+                    continue;
+                }
+
+                let result = dfg.instruction_results(instruction_id)[0];
+                inserter.map_value(result, value);
+                instructions_to_remove.insert(instruction_id);
+            }
+
+            inserter.function.dfg[block]
+                .instructions_mut()
+                .retain(|instruction| !instructions_to_remove.contains(instruction));
+            inserter.map_terminator_in_place(block);
+        }
+        inserter.map_data_bus_in_place();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{opt::assert_normalized_ssa_equals, ssa_gen::Ssa};
+
+    #[test]
+    fn removes_identity_cast() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: i32, v1: u8):
+            v2 = cast v0 as i32
+            v3 = cast v1 as u32
+            jmp b1()
+          b1():
+            v4 = unchecked_add v2, v2
+            return v4, v3
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let ssa = ssa.remove_identity_casts();
+        let expected = "
+        acir(inline) fn main f0 {
+          b0(v0: i32, v1: u8):
+            v2 = cast v1 as u32
+            jmp b1()
+          b1():
+            v3 = unchecked_add v0, v0
+            return v3, v2
+        }
+        ";
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+}
@@ -0,0 +1,133 @@
+use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use crate::ssa::{
+    ir::{function::Function, instruction::Instruction, value::ValueId},
+    ssa_gen::Ssa,
+};
+
+impl Ssa {
+    /// This SSA pass removes `store` instructions which write back the value that was just
+    /// loaded from the same address, e.g. `v1 = load v0; store v1 at v0`.
+    ///
+    /// The analysis is local to a block and conservative: any other `store` (which may write
+    /// through an aliasing reference) or any `call` (which may write through references it has
+    /// access to) invalidates every load seen so far.
+    pub(crate) fn remove_self_assigning_stores(mut self) -> Self {
+        for function in self.functions.values_mut() {
+            function.remove_self_assigning_stores();
+        }
+        self
+    }
+}
+
+impl Function {
+    pub(crate) fn remove_self_assigning_stores(&mut self) {
+        for block in self.reachable_blocks() {
+            // Maps an address to the result of the most recent load from it
+            let mut last_loads: HashMap<ValueId, ValueId> = HashMap::default();
+            let mut instructions_to_remove = HashSet::default();
+
+            for instruction_id in self.dfg[block].instructions() {
+                match &self.dfg[*instruction_id] {
+                    Instruction::Load { address } => {
+                        let address = self.dfg.resolve(*address);
+                        let result = self.dfg.instruction_results(*instruction_id)[0];
+                        last_loads.insert(address, result);
+                    }
+                    Instruction::Store { address, value } => {
+                        let address = self.dfg.resolve(*address);
+                        let value = self.dfg.resolve(*value);
+                        if last_loads.get(&address) == Some(&value) {
+                            // Memory is left unchanged so the loads we know about are still valid
+                            instructions_to_remove.insert(*instruction_id);
+                        } else {
+                            last_loads.clear();
+                        }
+                    }
+                    Instruction::Call { .. } => last_loads.clear(),
+                    _ => (),
+                }
+            }
+
+            if instructions_to_remove.is_empty() {
+                continue;
+            }
+
+            self.dfg[block]
+                .instructions_mut()
+                .retain(|instruction| !instructions_to_remove.contains(instruction));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{opt::assert_normalized_ssa_equals, ssa_gen::Ssa};
+
+    #[test]
+    fn removes_store_of_just_loaded_value() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: &mut Field, v1: &mut Field):
+            v2 = load v0 -> Field
+            v3 = load v1 -> Field
+            store v2 at v0
+            return v2, v3
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0(v0: &mut Field, v1: &mut Field):
+            v2 = load v0 -> Field
+            v3 = load v1 -> Field
+            return v2, v3
+        }
+        ";
+
+        let ssa = ssa.remove_self_assigning_stores();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn does_not_remove_store_after_intervening_store() {
+        // `v1` may alias `v0`, so the store to `v1` may have changed the value behind `v0`
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: &mut Field, v1: &mut Field):
+            v2 = load v0 -> Field
+            store Field 1 at v1
+            store v2 at v0
+            return v2
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.remove_self_assigning_stores();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+
+    #[test]
+    fn does_not_remove_store_after_call() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: &mut Field):
+            v1 = load v0 -> Field
+            call f1(v0)
+            store v1 at v0
+            return v1
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: &mut Field):
+            store Field 1 at v0
+            return
+        }
+        ";
+
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.remove_self_assigning_stores();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+}
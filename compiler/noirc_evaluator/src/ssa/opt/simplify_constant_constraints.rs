@@ -0,0 +1,260 @@
+//! This pass evaluates `constrain` instructions whose operands are both constants.
+//!
+//! A constraint which holds is removed. A constraint which can never hold is reported as a
+//! compile-time error, but only if it is in a block which is executed every time the function
+//! is called. Constraints in conditionally executed blocks, such as the body of an `if` or of a
+//! loop, are left in place to fail at runtime, as are constraints in loops whose constant bounds
+//! leave no iterations to execute.
+use acvm::{FieldElement, acir::AcirField};
+use fxhash::FxHashSet as HashSet;
+
+use crate::{
+    errors::RuntimeError,
+    ssa::{
+        ir::{
+            basic_block::BasicBlockId,
+            function::Function,
+            instruction::{ConstrainError, Instruction, InstructionId, TerminatorInstruction},
+        },
+        ssa_gen::Ssa,
+    },
+};
+
+use super::unrolling::Loops;
+
+impl Ssa {
+    /// See [`simplify_constant_constraints`][self] module for more information.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) fn simplify_constant_constraints(mut self) -> Result<Ssa, RuntimeError> {
+        for function in self.functions.values_mut() {
+            function.simplify_constant_constraints()?;
+        }
+        Ok(self)
+    }
+}
+
+impl Function {
+    pub(crate) fn simplify_constant_constraints(&mut self) -> Result<(), RuntimeError> {
+        let always_executed = self.always_executed_blocks();
+
+        for block in self.reachable_blocks() {
+            // We can't use instructions.retain(...) here since a failing constraint is an error
+            let instructions = self.dfg[block].take_instructions();
+            let mut filtered_instructions = Vec::with_capacity(instructions.len());
+
+            for instruction in instructions {
+                match self.evaluate_constant_constraint(instruction) {
+                    Some(true) => (),
+                    Some(false) if always_executed.contains(&block) => {
+                        return Err(self.failed_constraint_error(instruction));
+                    }
+                    _ => filtered_instructions.push(instruction),
+                }
+            }
+
+            *self.dfg[block].instructions_mut() = filtered_instructions;
+        }
+        Ok(())
+    }
+
+    /// Returns whether the given instruction is a constraint on two constants which holds,
+    /// or None if it isn't a constraint or either side isn't constant.
+    fn evaluate_constant_constraint(&self, instruction: InstructionId) -> Option<bool> {
+        let Instruction::Constrain(lhs, rhs, _) = &self.dfg[instruction] else {
+            return None;
+        };
+        let lhs = self.dfg.get_numeric_constant(*lhs)?;
+        let rhs = self.dfg.get_numeric_constant(*rhs)?;
+        Some(lhs == rhs)
+    }
+
+    fn failed_constraint_error(&self, instruction: InstructionId) -> RuntimeError {
+        let Instruction::Constrain(_, _, assert_message) = &self.dfg[instruction] else {
+            unreachable!("Expected a constrain instruction");
+        };
+        let message = match assert_message {
+            Some(ConstrainError::StaticString(message)) => {
+                format!("Assertion is always false: {message}")
+            }
+            _ => "Assertion is always false".to_string(),
+        };
+        let call_stack = self.dfg.get_instruction_call_stack(instruction);
+        RuntimeError::ConstantConstraintFailed { message, call_stack }
+    }
+
+    /// Returns the blocks which are executed every time this function is called: those reached
+    /// from the entry block through unconditional jumps or branches on constant conditions.
+    ///
+    /// A loop with constant bounds which leave no iterations to execute is skipped over,
+    /// so its body is never considered to be executed.
+    fn always_executed_blocks(&self) -> HashSet<BasicBlockId> {
+        let loops = Loops::find_all(self);
+        let mut blocks = HashSet::default();
+        let mut block = self.entry_block();
+
+        // A block is only revisited through a loop which never exits, so we stop there
+        while blocks.insert(block) {
+            block = match self.dfg[block].terminator() {
+                Some(TerminatorInstruction::Jmp { destination, .. }) => *destination,
+                Some(TerminatorInstruction::JmpIf {
+                    condition,
+                    then_destination,
+                    else_destination,
+                    ..
+                }) => match self.dfg.get_numeric_constant(*condition) {
+                    Some(condition) if condition.is_zero() => *else_destination,
+                    Some(_) => *then_destination,
+                    None => match self.never_executed_loop_exit(&loops, block) {
+                        Some(exit) => exit,
+                        None => break,
+                    },
+                },
+                Some(TerminatorInstruction::Return { .. }) | None => break,
+            };
+        }
+        blocks
+    }
+
+    /// If `header` is the header of a loop whose constant bounds leave no iterations to
+    /// execute, returns the block the loop exits to.
+    fn never_executed_loop_exit(
+        &self,
+        loops: &Loops,
+        header: BasicBlockId,
+    ) -> Option<BasicBlockId> {
+        let loop_ = loops.yet_to_unroll.iter().find(|loop_| loop_.header == header)?;
+        let pre_header = loop_.get_pre_header(self, &loops.cfg).ok()?;
+        let (lower_bound, upper_bound) = loop_.get_const_bounds(self, pre_header)?;
+        if !loop_never_executes(lower_bound, upper_bound) {
+            return None;
+        }
+        loops.cfg.successors(header).find(|successor| !loop_.blocks.contains(successor))
+    }
+}
+
+fn loop_never_executes(lower_bound: FieldElement, upper_bound: FieldElement) -> bool {
+    upper_bound.to_u128() <= lower_bound.to_u128()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        errors::RuntimeError,
+        ssa::{opt::assert_normalized_ssa_equals, ssa_gen::Ssa},
+    };
+
+    #[test]
+    fn removes_constraint_on_equal_constants() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32):
+            v2 = eq v0, u32 1
+            jmpif v2 then: b1, else: b2
+          b1():
+            constrain u32 5 == u32 5
+            jmp b2()
+          b2():
+            constrain u1 1 == u1 1, \"always holds\"
+            return
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        brillig(inline) fn main f0 {
+          b0(v0: u32):
+            v2 = eq v0, u32 1
+            jmpif v2 then: b1, else: b2
+          b1():
+            jmp b2()
+          b2():
+            return
+        }
+        ";
+        let ssa = ssa.simplify_constant_constraints().unwrap();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn errors_on_always_failing_constraint() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: u32):
+            constrain v0 == u32 3
+            constrain u1 0 == u1 1, \"unreachable\"
+            return
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let Err(RuntimeError::ConstantConstraintFailed { message, .. }) =
+            ssa.simplify_constant_constraints()
+        else {
+            panic!("Expected a failed constraint error");
+        };
+        assert_eq!(message, "Assertion is always false: unreachable");
+    }
+
+    #[test]
+    fn does_not_error_on_failing_constraint_in_conditional_block() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0(v0: u1):
+            jmpif v0 then: b1, else: b2
+          b1():
+            constrain u1 0 == u1 1
+            jmp b2()
+          b2():
+            return
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.simplify_constant_constraints().unwrap();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+
+    #[test]
+    fn does_not_error_on_failing_constraint_in_never_executed_loop() {
+        // for i in 5..3 { assert(false) }
+        let src = "
+        brillig(inline) fn main f0 {
+          b0():
+            jmp b1(u32 5)
+          b1(v0: u32):
+            v3 = lt v0, u32 3
+            jmpif v3 then: b2, else: b3
+          b2():
+            constrain u1 0 == u1 1
+            v6 = unchecked_add v0, u32 1
+            jmp b1(v6)
+          b3():
+            return
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.simplify_constant_constraints().unwrap();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+
+    #[test]
+    fn errors_on_failing_constraint_after_never_executed_loop() {
+        let src = "
+        brillig(inline) fn main f0 {
+          b0():
+            jmp b1(u32 5)
+          b1(v0: u32):
+            v3 = lt v0, u32 3
+            jmpif v3 then: b2, else: b3
+          b2():
+            v5 = unchecked_add v0, u32 1
+            jmp b1(v5)
+          b3():
+            constrain u1 0 == u1 1
+            return
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let result = ssa.simplify_constant_constraints();
+        assert!(matches!(result, Err(RuntimeError::ConstantConstraintFailed { .. })));
+    }
+}
@@ -0,0 +1,553 @@
+//! This module defines an SSA pass which specializes functions for the constant arguments
+//! they are called with.
+//!
+//! Functions which aren't inlined, such as Brillig functions, are often called with a numeric
+//! constant for one of their parameters, e.g. a flag which is always `true`. When every call
+//! to a function passes the same constant for a parameter, this pass clones the function,
+//! replaces the uses of those parameters in the clone with the constants and removes the
+//! parameters. The calls are then rewritten to call the clone without them, so that later
+//! passes such as constant folding can simplify the clone's body.
+//!
+//! As every call is rewritten, including the calls made by the clones themselves, the original
+//! function is no longer called and is left for unreachable function removal to remove, so the
+//! pass doesn't grow the program. Functions whose calls disagree on their constant arguments, or
+//! which are used as values rather than only called, aren't specialized.
+//!
+//! `main`, the ACIR functions compiled into their own circuits and the functions marked
+//! `#[fold]` to keep them out of line are never specialized.
+//! At most [MAX_SPECIALIZATIONS] clones are made in a single run of the pass.
+use std::collections::{BTreeMap, BTreeSet};
+
+use acvm::FieldElement;
+use noirc_frontend::monomorphization::ast::InlineType;
+
+use crate::ssa::{
+    ir::{
+        dfg::DataFlowGraph,
+        function::{Function, FunctionId, RuntimeType},
+        function_inserter::FunctionInserter,
+        instruction::{Instruction, InstructionId},
+        post_order::PostOrder,
+        types::NumericType,
+        value::{Value, ValueId},
+    },
+    ssa_gen::Ssa,
+};
+
+/// The maximum number of specialized functions created by this pass.
+const MAX_SPECIALIZATIONS: usize = 32;
+
+/// The positions of a call's constant arguments, along with their values.
+type ConstantArguments = Vec<(usize, FieldElement, NumericType)>;
+
+/// The calls to a function, as the function making the call and the call instruction.
+type CallSites = Vec<(FunctionId, InstructionId)>;
+
+impl Ssa {
+    /// Specializes the functions always called with the same constant arguments for those
+    /// arguments, calling a clone with the arguments baked in as constants instead.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) fn specialize_constant_arguments(self) -> Ssa {
+        self.specialize_constant_arguments_with_budget(MAX_SPECIALIZATIONS)
+    }
+
+    /// Runs the pass, creating at most `max_specializations` specialized functions.
+    fn specialize_constant_arguments_with_budget(mut self, max_specializations: usize) -> Ssa {
+        let call_sites: Vec<_> =
+            self.constant_argument_call_sites().into_iter().take(max_specializations).collect();
+
+        // Every function is cloned before any call is rewritten, so that the calls made by a
+        // clone are still those of the function it was cloned from and can be rewritten with them.
+        let mut specializations = BTreeMap::new();
+        for (callee, (constants, _)) in &call_sites {
+            let function = self.functions[callee].clone();
+            let specialized_id = self.add_fn(|id| {
+                let mut function = Function::clone_with_id(id, &function);
+                function.bake_in_constant_parameters(constants);
+                function
+            });
+            specializations.insert(*callee, specialized_id);
+        }
+
+        for (callee, (constants, calls)) in call_sites {
+            let specialized_id = specializations[&callee];
+            for (caller, instruction_id) in calls {
+                // The call is made both by the caller and by the caller's specialization, if any
+                let callers = std::iter::once(caller).chain(specializations.get(&caller).copied());
+                for caller in callers {
+                    let caller =
+                        self.functions.get_mut(&caller).expect("ICE: Function does not exist");
+                    caller.call_specialization(instruction_id, specialized_id, &constants);
+                }
+            }
+        }
+        self
+    }
+
+    /// Returns the calls to each function which can be specialized, along with the numeric
+    /// constants which all of those calls pass to it.
+    fn constant_argument_call_sites(&self) -> BTreeMap<FunctionId, (ConstantArguments, CallSites)> {
+        let mut call_sites: BTreeMap<FunctionId, CallSites> = BTreeMap::new();
+        // Functions used as values may be called indirectly, so not all of their calls are known
+        let mut used_as_values = BTreeSet::new();
+        for (caller_id, caller) in &self.functions {
+            let mut mark_used_as_value = |value: ValueId| {
+                if let Value::Function(function) = caller.dfg[value] {
+                    used_as_values.insert(function);
+                }
+            };
+            for block in caller.reachable_blocks() {
+                for instruction_id in caller.dfg[block].instructions() {
+                    match &caller.dfg[*instruction_id] {
+                        Instruction::Call { func, arguments } => {
+                            if let Value::Function(callee) = caller.dfg[*func] {
+                                let call_site = (*caller_id, *instruction_id);
+                                call_sites.entry(callee).or_default().push(call_site);
+                            }
+                            arguments.iter().copied().for_each(&mut mark_used_as_value);
+                        }
+                        instruction => instruction.for_each_value(&mut mark_used_as_value),
+                    }
+                }
+                caller.dfg[block].unwrap_terminator().for_each_value(&mut mark_used_as_value);
+            }
+        }
+
+        call_sites
+            .into_iter()
+            .filter(|(callee, _)| {
+                !used_as_values.contains(callee) && self.can_be_specialized(*callee)
+            })
+            .filter_map(|(callee, calls)| {
+                let mut calls_constants = calls.iter().map(|(caller, instruction_id)| {
+                    let dfg = &self.functions[caller].dfg;
+                    let Instruction::Call { arguments, .. } = &dfg[*instruction_id] else {
+                        unreachable!("ICE: Expected a call instruction");
+                    };
+                    constant_arguments(dfg, arguments)
+                });
+                let mut constants = calls_constants.next()?;
+                for call_constants in calls_constants {
+                    constants.retain(|constant| call_constants.contains(constant));
+                }
+                (!constants.is_empty()).then_some((callee, (constants, calls)))
+            })
+            .collect()
+    }
+
+    fn can_be_specialized(&self, function: FunctionId) -> bool {
+        // Brillig functions count as entry points when called from ACIR, but those calls can be
+        // rewritten like any other.
+        let runtime = self.functions[&function].runtime();
+        let is_acir_entry_point = runtime.is_acir() && runtime.is_entry_point();
+        let is_fold = matches!(
+            runtime,
+            RuntimeType::Acir(InlineType::Fold) | RuntimeType::Brillig(InlineType::Fold)
+        );
+        function != self.main_id && !is_acir_entry_point && !is_fold
+    }
+}
+
+/// Returns the numeric constants among the arguments of a call.
+fn constant_arguments(dfg: &DataFlowGraph, arguments: &[ValueId]) -> ConstantArguments {
+    arguments
+        .iter()
+        .enumerate()
+        .filter_map(|(index, argument)| {
+            let (constant, typ) = dfg.get_numeric_constant_with_type(*argument)?;
+            Some((index, constant, typ))
+        })
+        .collect()
+}
+
+impl Function {
+    /// Rewrites the call instruction `instruction_id` to call `specialized_id`, dropping the
+    /// arguments at the positions of `constants`.
+    fn call_specialization(
+        &mut self,
+        instruction_id: InstructionId,
+        specialized_id: FunctionId,
+        constants: &ConstantArguments,
+    ) {
+        let Instruction::Call { arguments, .. } = &self.dfg[instruction_id] else {
+            unreachable!("ICE: Expected a call instruction");
+        };
+        let arguments = arguments
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !constants.iter().any(|(i, ..)| i == index))
+            .map(|(_, argument)| *argument)
+            .collect();
+        let func = self.dfg.import_function(specialized_id);
+        self.dfg[instruction_id] = Instruction::Call { func, arguments };
+    }
+
+    /// Replaces the uses of the parameters at the positions of `constants` with their constant
+    /// values, then removes those parameters.
+    fn bake_in_constant_parameters(&mut self, constants: &ConstantArguments) {
+        let entry_block = self.entry_block();
+        let mut parameters = Vec::new();
+        let mut constant_values = Vec::with_capacity(constants.len());
+        for (index, parameter) in self.dfg[entry_block].take_parameters().into_iter().enumerate() {
+            match constants.iter().find(|(i, ..)| *i == index) {
+                Some((_, constant, typ)) => {
+                    constant_values.push((parameter, self.dfg.make_constant(*constant, *typ)));
+                }
+                None => parameters.push(parameter),
+            }
+        }
+        self.dfg[entry_block].set_parameters(parameters);
+
+        // Blocks are visited in reverse post order so that a value is always mapped before
+        // any of the instructions using it are.
+        let mut block_order = PostOrder::with_function(self).into_vec();
+        block_order.reverse();
+
+        let mut inserter = FunctionInserter::new(self);
+        for (parameter, constant) in constant_values {
+            inserter.map_value(parameter, constant);
+        }
+        for block in block_order {
+            for instruction_id in inserter.function.dfg[block].instructions().to_vec() {
+                inserter.map_instruction_in_place(instruction_id);
+            }
+            inserter.map_terminator_in_place(block);
+        }
+        inserter.map_data_bus_in_place();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{opt::assert_normalized_ssa_equals, ssa_gen::Ssa};
+
+    #[test]
+    fn specializes_function_always_called_with_true_flag() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: Field):
+            v3 = call f1(v0, u1 1) -> Field
+            v4 = call f1(v3, u1 1) -> Field
+            return v4
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: Field, v1: u1):
+            jmpif v1 then: b1, else: b2
+          b1():
+            v3 = add v0, Field 1
+            jmp b3(v3)
+          b2():
+            jmp b3(v0)
+          b3(v2: Field):
+            return v2
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        // The original `foo` is no longer called, and is left for unreachable function removal
+        let expected = "
+        acir(inline) fn main f0 {
+          b0(v0: Field):
+            v2 = call f2(v0) -> Field
+            v3 = call f2(v2) -> Field
+            return v3
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: Field, v1: u1):
+            jmpif v1 then: b1, else: b2
+          b1():
+            v3 = add v0, Field 1
+            jmp b3(v3)
+          b2():
+            jmp b3(v0)
+          b3(v2: Field):
+            return v2
+        }
+        brillig(inline) fn foo f2 {
+          b0(v0: Field):
+            jmpif u1 1 then: b1, else: b2
+          b1():
+            v3 = add v0, Field 1
+            jmp b3(v3)
+          b2():
+            jmp b3(v0)
+          b3(v1: Field):
+            return v1
+        }
+        ";
+        let ssa = ssa.specialize_constant_arguments();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn specializes_self_recursive_function() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: u32):
+            v3 = call f1(v0, u1 1) -> u32
+            return v3
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: u32, v1: u1):
+            v3 = eq v0, u32 0
+            jmpif v3 then: b1, else: b2
+          b1():
+            jmp b3(v0)
+          b2():
+            v5 = sub v0, u32 1
+            v7 = call f1(v5, u1 1) -> u32
+            jmp b3(v7)
+          b3(v2: u32):
+            return v2
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        // The clone calls itself rather than the original `foo`, which is no longer called
+        let expected = "
+        acir(inline) fn main f0 {
+          b0(v0: u32):
+            v2 = call f2(v0) -> u32
+            return v2
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: u32, v1: u1):
+            v4 = eq v0, u32 0
+            jmpif v4 then: b1, else: b2
+          b1():
+            jmp b3(v0)
+          b2():
+            v6 = sub v0, u32 1
+            v7 = call f2(v6) -> u32
+            jmp b3(v7)
+          b3(v2: u32):
+            return v2
+        }
+        brillig(inline) fn foo f2 {
+          b0(v0: u32):
+            v3 = eq v0, u32 0
+            jmpif v3 then: b1, else: b2
+          b1():
+            jmp b3(v0)
+          b2():
+            v5 = sub v0, u32 1
+            v6 = call f2(v5) -> u32
+            jmp b3(v6)
+          b3(v1: u32):
+            return v1
+        }
+        ";
+        let ssa = ssa.specialize_constant_arguments();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn specialized_caller_calls_specialized_callee() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: Field):
+            v3 = call f1(v0, u1 1) -> Field
+            return v3
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: Field, v1: u1):
+            v4 = call f2(v0, u1 0) -> Field
+            jmpif v1 then: b1, else: b2
+          b1():
+            v6 = add v4, Field 1
+            jmp b3(v6)
+          b2():
+            jmp b3(v4)
+          b3(v2: Field):
+            return v2
+        }
+        brillig(inline) fn bar f2 {
+          b0(v0: Field, v1: u1):
+            jmpif v1 then: b1, else: b2
+          b1():
+            v4 = add v0, Field 2
+            jmp b3(v4)
+          b2():
+            jmp b3(v0)
+          b3(v2: Field):
+            return v2
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        // Neither `foo` nor `bar` is called anymore, as `foo`'s clone calls `bar`'s clone
+        let expected = "
+        acir(inline) fn main f0 {
+          b0(v0: Field):
+            v2 = call f3(v0) -> Field
+            return v2
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: Field, v1: u1):
+            v4 = call f4(v0) -> Field
+            jmpif v1 then: b1, else: b2
+          b1():
+            v6 = add v4, Field 1
+            jmp b3(v6)
+          b2():
+            jmp b3(v4)
+          b3(v2: Field):
+            return v2
+        }
+        brillig(inline) fn bar f2 {
+          b0(v0: Field, v1: u1):
+            jmpif v1 then: b1, else: b2
+          b1():
+            v4 = add v0, Field 2
+            jmp b3(v4)
+          b2():
+            jmp b3(v0)
+          b3(v2: Field):
+            return v2
+        }
+        brillig(inline) fn foo f3 {
+          b0(v0: Field):
+            v3 = call f4(v0) -> Field
+            jmpif u1 1 then: b1, else: b2
+          b1():
+            v5 = add v3, Field 1
+            jmp b3(v5)
+          b2():
+            jmp b3(v3)
+          b3(v1: Field):
+            return v1
+        }
+        brillig(inline) fn bar f4 {
+          b0(v0: Field):
+            jmpif u1 0 then: b1, else: b2
+          b1():
+            v3 = add v0, Field 2
+            jmp b3(v3)
+          b2():
+            jmp b3(v0)
+          b3(v1: Field):
+            return v1
+        }
+        ";
+        let ssa = ssa.specialize_constant_arguments();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn does_not_specialize_calls_without_constant_arguments() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: Field, v1: u1):
+            v2 = call f1(v0, v1) -> Field
+            return v2
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: Field, v1: u1):
+            jmpif v1 then: b1, else: b2
+          b1():
+            v3 = add v0, Field 1
+            jmp b3(v3)
+          b2():
+            jmp b3(v0)
+          b3(v2: Field):
+            return v2
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let ssa = ssa.specialize_constant_arguments();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+
+    #[test]
+    fn does_not_specialize_function_called_with_different_constants() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: Field):
+            v3 = call f1(v0, u1 1) -> Field
+            v5 = call f1(v3, u1 0) -> Field
+            return v5
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: Field, v1: u1):
+            jmpif v1 then: b1, else: b2
+          b1():
+            v3 = add v0, Field 1
+            jmp b3(v3)
+          b2():
+            jmp b3(v0)
+          b3(v2: Field):
+            return v2
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let ssa = ssa.specialize_constant_arguments();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+
+    #[test]
+    fn does_not_specialize_fold_function() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: Field):
+            v3 = call f1(v0, u1 1) -> Field
+            return v3
+        }
+        brillig(fold) fn foo f1 {
+          b0(v0: Field, v1: u1):
+            jmpif v1 then: b1, else: b2
+          b1():
+            v3 = add v0, Field 1
+            jmp b3(v3)
+          b2():
+            jmp b3(v0)
+          b3(v2: Field):
+            return v2
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let ssa = ssa.specialize_constant_arguments();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+
+    #[test]
+    fn creates_at_most_the_budgeted_number_of_specializations() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: Field):
+            v3 = call f1(v0, u1 1) -> Field
+            v4 = call f2(v3, u1 1) -> Field
+            return v4
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: Field, v1: u1):
+            jmpif v1 then: b1, else: b2
+          b1():
+            v3 = add v0, Field 1
+            jmp b3(v3)
+          b2():
+            jmp b3(v0)
+          b3(v2: Field):
+            return v2
+        }
+        brillig(inline) fn bar f2 {
+          b0(v0: Field, v1: u1):
+            jmpif v1 then: b1, else: b2
+          b1():
+            v3 = add v0, Field 2
+            jmp b3(v3)
+          b2():
+            jmp b3(v0)
+          b3(v2: Field):
+            return v2
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        // Only one of the two functions is specialized, adding a single function
+        let ssa = ssa.specialize_constant_arguments_with_budget(1);
+        assert_eq!(ssa.functions.len(), 4);
+    }
+}
@@ -0,0 +1,236 @@
+//! This pass converts self-recursive tail calls in Brillig functions into loops.
+//!
+//! A Brillig function whose only recursive call passes the call's results straight back to
+//! its caller doesn't need a new stack frame for that call. Instead, the body of the function
+//! becomes a loop: the old entry block is used as the loop header, a new entry block jumps
+//! into it with the function's parameters, and the tail call is replaced by a jump back to
+//! the header with the call's arguments. Besides avoiding stack growth, this exposes the
+//! recursion to loop optimizations such as loop invariant code motion.
+//!
+//! Functions with more than one recursive call, or whose recursive call is not in tail
+//! position, are left unchanged.
+use crate::ssa::{
+    ir::{
+        basic_block::BasicBlockId,
+        call_stack::CallStackId,
+        function::Function,
+        instruction::{Instruction, InstructionId, TerminatorInstruction},
+        value::Value,
+    },
+    ssa_gen::Ssa,
+};
+
+impl Ssa {
+    /// See [`tail_call_to_loop`][self] module for more information.
+    pub(crate) fn tail_call_to_loop(mut self) -> Self {
+        for function in self.functions.values_mut() {
+            if function.runtime().is_brillig() {
+                function.tail_call_to_loop();
+            }
+        }
+        self
+    }
+}
+
+impl Function {
+    pub(crate) fn tail_call_to_loop(&mut self) {
+        let Some((tail_call_block, tail_call)) = self.find_tail_call() else {
+            return;
+        };
+
+        // The current entry block becomes the loop header. A new entry block with the same
+        // parameters takes its place and jumps into the loop with the function's arguments.
+        let loop_header = self.entry_block();
+        let entry_block = self.dfg.make_block_with_parameters_from_block(loop_header);
+        let parameters = self.dfg.block_parameters(entry_block).to_vec();
+        self.dfg.set_block_terminator(
+            entry_block,
+            TerminatorInstruction::Jmp {
+                destination: loop_header,
+                arguments: parameters,
+                call_stack: CallStackId::root(),
+            },
+        );
+        self.set_entry_block(entry_block);
+
+        // Replace the tail call with a jump back to the loop header
+        let Instruction::Call { arguments, .. } = &self.dfg[tail_call] else {
+            unreachable!("Expected tail call to be a call instruction");
+        };
+        let arguments = arguments.clone();
+        let call_stack = self.dfg.get_instruction_call_stack_id(tail_call);
+
+        self.dfg[tail_call_block].instructions_mut().pop();
+        self.dfg.set_block_terminator(
+            tail_call_block,
+            TerminatorInstruction::Jmp { destination: loop_header, arguments, call_stack },
+        );
+    }
+
+    /// Returns the only recursive call in this function, along with the block containing it,
+    /// if that call is in tail position.
+    fn find_tail_call(&self) -> Option<(BasicBlockId, InstructionId)> {
+        let mut recursive_calls = Vec::new();
+        for block in self.reachable_blocks() {
+            for instruction in self.dfg[block].instructions() {
+                if self.is_recursive_call(*instruction) {
+                    recursive_calls.push((block, *instruction));
+                }
+            }
+        }
+
+        let [(block, call)] = recursive_calls[..] else {
+            return None;
+        };
+
+        let is_last_instruction = self.dfg[block].instructions().last() == Some(&call);
+        (is_last_instruction && self.returns_call_results(block, call)).then_some((block, call))
+    }
+
+    fn is_recursive_call(&self, instruction: InstructionId) -> bool {
+        let Instruction::Call { func, .. } = &self.dfg[instruction] else {
+            return false;
+        };
+        matches!(self.dfg[*func], Value::Function(id) if id == self.id())
+    }
+
+    /// True if the given block immediately returns the results of `call`, either directly
+    /// or by jumping to an exit block which only returns its parameters.
+    fn returns_call_results(&self, block: BasicBlockId, call: InstructionId) -> bool {
+        let results = self.dfg.instruction_results(call);
+        match self.dfg[block].unwrap_terminator() {
+            TerminatorInstruction::Return { return_values, .. } => return_values == results,
+            TerminatorInstruction::Jmp { destination, arguments, .. } => {
+                let exit_block = &self.dfg[*destination];
+                arguments == results
+                    && exit_block.instructions().is_empty()
+                    && matches!(
+                        exit_block.unwrap_terminator(),
+                        TerminatorInstruction::Return { return_values, .. }
+                            if return_values == exit_block.parameters()
+                    )
+            }
+            TerminatorInstruction::JmpIf { .. } => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{opt::assert_normalized_ssa_equals, ssa_gen::Ssa};
+
+    #[test]
+    fn converts_tail_recursive_accumulator_into_loop() {
+        let src = "
+        brillig(inline) fn sum f0 {
+          b0(v0: u32, v1: u32):
+            v3 = eq v0, u32 0
+            jmpif v3 then: b1, else: b2
+          b1():
+            jmp b3(v1)
+          b2():
+            v5 = sub v0, u32 1
+            v6 = add v1, v0
+            v7 = call f0(v5, v6) -> u32
+            jmp b3(v7)
+          b3(v2: u32):
+            return v2
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let expected = "
+        brillig(inline) fn sum f0 {
+          b0(v0: u32, v1: u32):
+            jmp b1(v0, v1)
+          b1(v2: u32, v3: u32):
+            v6 = eq v2, u32 0
+            jmpif v6 then: b2, else: b3
+          b2():
+            jmp b4(v3)
+          b3():
+            v8 = sub v2, u32 1
+            v9 = add v3, v2
+            jmp b1(v8, v9)
+          b4(v4: u32):
+            return v4
+        }
+        ";
+
+        let mut ssa = ssa.tail_call_to_loop();
+        // The new entry block is created after all other blocks, so normalize once
+        // to make it the first block before comparing against the expected SSA.
+        ssa.normalize_ids();
+        assert_normalized_ssa_equals(ssa, expected);
+    }
+
+    #[test]
+    fn does_not_convert_acir_function() {
+        let src = "
+        acir(inline) fn sum f0 {
+          b0(v0: u32, v1: u32):
+            v3 = eq v0, u32 0
+            jmpif v3 then: b1, else: b2
+          b1():
+            jmp b3(v1)
+          b2():
+            v5 = sub v0, u32 1
+            v6 = add v1, v0
+            v7 = call f0(v5, v6) -> u32
+            jmp b3(v7)
+          b3(v2: u32):
+            return v2
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.tail_call_to_loop();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+
+    #[test]
+    fn does_not_convert_non_tail_recursion() {
+        // The result of the recursive call is used before being returned
+        let src = "
+        brillig(inline) fn factorial f0 {
+          b0(v0: u32):
+            v2 = eq v0, u32 0
+            jmpif v2 then: b1, else: b2
+          b1():
+            jmp b3(u32 1)
+          b2():
+            v4 = sub v0, u32 1
+            v5 = call f0(v4) -> u32
+            v6 = mul v0, v5
+            jmp b3(v6)
+          b3(v1: u32):
+            return v1
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.tail_call_to_loop();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+
+    #[test]
+    fn does_not_convert_multiple_recursive_calls() {
+        let src = "
+        brillig(inline) fn foo f0 {
+          b0(v0: u32):
+            v2 = eq v0, u32 0
+            jmpif v2 then: b1, else: b2
+          b1():
+            jmp b3(u32 0)
+          b2():
+            v4 = sub v0, u32 1
+            v5 = call f0(v4) -> u32
+            v6 = call f0(v5) -> u32
+            jmp b3(v6)
+          b3(v1: u32):
+            return v1
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+        let ssa = ssa.tail_call_to_loop();
+        assert_normalized_ssa_equals(ssa, src);
+    }
+}
@@ -22,17 +22,19 @@ use std::collections::BTreeSet;
 
 use acvm::{FieldElement, acir::AcirField};
 use im::HashSet;
+use noirc_errors::Location;
 
 use crate::{
     errors::RuntimeError,
     ssa::{
+        LoopReport, PassStatistics,
         ir::{
             basic_block::BasicBlockId,
             call_stack::{CallStack, CallStackId},
             cfg::ControlFlowGraph,
             dfg::DataFlowGraph,
             dom::DominatorTree,
-            function::Function,
+            function::{Function, FunctionId},
             function_inserter::{ArrayCache, FunctionInserter},
             instruction::{Binary, BinaryOp, Instruction, InstructionId, TerminatorInstruction},
             post_order::PostOrder,
@@ -53,8 +55,18 @@ impl Ssa {
     /// fewer SSA instructions, but that can still result in more Brillig opcodes.
     #[tracing::instrument(level = "trace", skip(self))]
     pub(crate) fn unroll_loops_iteratively(
+        self,
+        max_bytecode_increase_percent: Option<i32>,
+    ) -> Result<Ssa, RuntimeError> {
+        self.unroll_loops_iteratively_with_statistics(max_bytecode_increase_percent, None)
+    }
+
+    /// Unrolls loops as [`Ssa::unroll_loops_iteratively`] does, recording the number of loop
+    /// iterations unrolled in each function into `statistics` if it is set.
+    pub(crate) fn unroll_loops_iteratively_with_statistics(
         mut self,
         max_bytecode_increase_percent: Option<i32>,
+        mut statistics: Option<&mut PassStatistics>,
     ) -> Result<Ssa, RuntimeError> {
         for function in self.functions.values_mut() {
             let is_brillig = function.runtime().is_brillig();
@@ -64,7 +76,8 @@ impl Ssa {
                 (max_bytecode_increase_percent.is_some() && is_brillig).then(|| function.clone());
 
             // We must be able to unroll ACIR loops at this point, so exit on failure to unroll.
-            let has_unrolled = function.unroll_loops_iteratively()?;
+            let mut unrolled_iterations = function.unroll_loops_iteratively()?;
+            let has_unrolled = unrolled_iterations > 0;
 
             // Check if the size increase is acceptable
             // This is here now instead of in `Function::unroll_loops_iteratively` because we'd need
@@ -77,12 +90,42 @@ impl Ssa {
                     let orig_size = orig_function.num_instructions();
                     if !is_new_size_ok(orig_size, new_size, max_incr_pct) {
                         *function = orig_function;
+                        unrolled_iterations = 0;
                     }
                 }
             }
+
+            if let Some(statistics) = statistics.as_deref_mut() {
+                let name = function.name().to_string();
+                *statistics.loop_iterations_unrolled.entry(name).or_default() +=
+                    unrolled_iterations;
+            }
         }
         Ok(self)
     }
+
+    /// Marks the loops of `report` as unrolled if their function no longer contains a loop
+    /// at the same source location. Loops without a known location are left as they are.
+    pub(crate) fn record_unrolled_loops(&self, report: &mut LoopReport) {
+        let mut remaining_loops: HashMap<FunctionId, Vec<Option<Location>>> = HashMap::default();
+        for (function_id, function) in &self.functions {
+            let loops = Loops::find_all(function);
+            let locations = loops.yet_to_unroll.iter().map(|loop_| loop_.location(function));
+            remaining_loops.entry(*function_id).or_default().extend(locations);
+        }
+
+        for loop_ in &mut report.loops {
+            if loop_.location.is_none() {
+                continue;
+            }
+            let Some(function_id) = loop_.function_id else {
+                continue;
+            };
+            if let Some(locations) = remaining_loops.get(&function_id) {
+                loop_.unrolled = !locations.contains(&loop_.location);
+            }
+        }
+    }
 }
 
 impl Function {
@@ -92,10 +135,10 @@ impl Function {
     /// cannot be determined at compile time. This can happen during pre-processing,
     /// but it should still leave the function in a partially unrolled, but valid state.
     ///
-    /// If successful, returns a flag indicating whether any loops have been unrolled.
-    pub(super) fn unroll_loops_iteratively(&mut self) -> Result<bool, RuntimeError> {
+    /// If successful, returns the number of loop iterations which have been unrolled.
+    pub(super) fn unroll_loops_iteratively(&mut self) -> Result<usize, RuntimeError> {
         // Try to unroll loops first:
-        let (mut has_unrolled, mut unroll_errors) = self.try_unroll_loops();
+        let (mut unrolled_iterations, mut unroll_errors) = self.try_unroll_loops();
 
         // Keep unrolling until no more errors are found
         while !unroll_errors.is_empty() {
@@ -107,7 +150,7 @@ impl Function {
             // Unroll again
             let (new_unrolled, new_errors) = self.try_unroll_loops();
             unroll_errors = new_errors;
-            has_unrolled |= new_unrolled;
+            unrolled_iterations += new_unrolled;
 
             // If we didn't manage to unroll any more loops, exit
             if unroll_errors.len() >= prev_unroll_err_count {
@@ -115,14 +158,14 @@ impl Function {
             }
         }
 
-        Ok(has_unrolled)
+        Ok(unrolled_iterations)
     }
 
     // Loop unrolling in brillig can lead to a code explosion currently.
     // This can also be true for ACIR, but we have no alternative to unrolling in ACIR.
     // Brillig also generally prefers smaller code rather than faster code,
     // so we only attempt to unroll small loops, which we decide on a case-by-case basis.
-    fn try_unroll_loops(&mut self) -> (bool, Vec<RuntimeError>) {
+    fn try_unroll_loops(&mut self) -> (usize, Vec<RuntimeError>) {
         Loops::find_all(self).unroll_each(self)
     }
 }
@@ -215,10 +258,10 @@ impl Loops {
 
     /// Unroll all loops within a given function.
     /// Any loops which fail to be unrolled (due to using non-constant indices) will be unmodified.
-    /// Returns whether any blocks have been modified
-    fn unroll_each(mut self, function: &mut Function) -> (bool, Vec<RuntimeError>) {
+    /// Returns the number of loop iterations which have been unrolled.
+    fn unroll_each(mut self, function: &mut Function) -> (usize, Vec<RuntimeError>) {
         let mut unroll_errors = vec![];
-        let mut has_unrolled = false;
+        let mut unrolled_iterations = 0;
         while let Some(next_loop) = self.yet_to_unroll.pop() {
             if function.runtime().is_brillig() && !next_loop.is_small_loop(function, &self.cfg) {
                 continue;
@@ -229,14 +272,15 @@ impl Loops {
                 let mut new_loops = Self::find_all(function);
                 new_loops.failed_to_unroll = self.failed_to_unroll;
                 let (new_unrolled, new_errors) = new_loops.unroll_each(function);
-                return (has_unrolled || new_unrolled, [unroll_errors, new_errors].concat());
+                let unrolled_iterations = unrolled_iterations + new_unrolled;
+                return (unrolled_iterations, [unroll_errors, new_errors].concat());
             }
 
             // Don't try to unroll the loop again if it is known to fail
             if !self.failed_to_unroll.contains(&next_loop.header) {
                 match next_loop.unroll(function, &self.cfg) {
-                    Ok(_) => {
-                        has_unrolled = true;
+                    Ok(iterations) => {
+                        unrolled_iterations += iterations;
                         self.modified_blocks.extend(next_loop.blocks);
                     }
                     Err(call_stack) => {
@@ -246,11 +290,17 @@ impl Loops {
                 }
             }
         }
-        (has_unrolled, unroll_errors)
+        (unrolled_iterations, unroll_errors)
     }
 }
 
 impl Loop {
+    /// Returns the source location of the loop, which is the location of its header's terminator.
+    pub(super) fn location(&self, function: &Function) -> Option<Location> {
+        let terminator = function.dfg[self.header].terminator()?;
+        function.dfg.get_call_stack(terminator.call_stack()).last().copied()
+    }
+
     /// Return each block that is in a loop starting in the given header block.
     /// Expects back_edge_start -> header to be the back edge of the loop.
     pub(crate) fn find_blocks_in_loop(
@@ -418,10 +468,13 @@ impl Loop {
     ///
     /// When e.g. `v8 = lt v5 v1` cannot be evaluated to a constant, the loop signals by returning `Err`
     /// that a few SSA passes are required to evaluate and simplify these values.
-    fn unroll(&self, function: &mut Function, cfg: &ControlFlowGraph) -> Result<(), CallStack> {
+    ///
+    /// Returns the number of iterations which have been unrolled.
+    fn unroll(&self, function: &mut Function, cfg: &ControlFlowGraph) -> Result<usize, CallStack> {
         let mut unroll_into = self.get_pre_header(function, cfg)?;
         let mut jump_value = get_induction_variable(function, unroll_into)?;
         let mut array_cache = Some(ArrayCache::default());
+        let mut iterations = 0;
 
         while let Some(mut context) = self.unroll_header(function, unroll_into, jump_value)? {
             // The inserter's array cache must be explicitly enabled. This is to
@@ -436,9 +489,10 @@ impl Loop {
             // from previous iterations but not the current iteration.
             context.inserter.set_array_cache(array_cache, unroll_into);
             (unroll_into, jump_value, array_cache) = context.unroll_loop_iteration();
+            iterations += 1;
         }
 
-        Ok(())
+        Ok(iterations)
     }
 
     /// The loop pre-header is the block that comes before the loop begins. Generally a header block
@@ -0,0 +1,132 @@
+//! A per-function summary of the effect of the SSA optimization passes, meant to be emitted
+//! as JSON to track the optimization quality of the compiler over a benchmark suite.
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::PassStatistics;
+
+/// The effect of the SSA optimization passes on each function of a program, keyed by
+/// function name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OptimizationSummary {
+    pub functions: BTreeMap<String, FunctionOptimizationSummary>,
+}
+
+/// The effect of the SSA optimization passes on a single function.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionOptimizationSummary {
+    pub instructions_before: usize,
+    /// `None` if the function was removed, for example because it was inlined everywhere.
+    pub instructions_after: Option<usize>,
+    pub instructions_hoisted: usize,
+    pub calls_inlined: usize,
+    pub loop_iterations_unrolled: usize,
+}
+
+impl OptimizationSummary {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("an optimization summary is always serializable")
+    }
+}
+
+impl From<&PassStatistics> for OptimizationSummary {
+    fn from(statistics: &PassStatistics) -> Self {
+        let mut functions = BTreeMap::<String, FunctionOptimizationSummary>::new();
+        let mut add = |counts: &BTreeMap<String, usize>,
+                       set: fn(&mut FunctionOptimizationSummary, usize)| {
+            for (name, count) in counts {
+                set(functions.entry(name.clone()).or_default(), *count);
+            }
+        };
+
+        add(&statistics.instructions_before, |function, count| {
+            function.instructions_before = count;
+        });
+        add(&statistics.instructions_after, |function, count| {
+            function.instructions_after = Some(count);
+        });
+        add(&statistics.loop_invariants_hoisted, |function, count| {
+            function.instructions_hoisted = count;
+        });
+        add(&statistics.calls_inlined, |function, count| function.calls_inlined = count);
+        add(&statistics.loop_iterations_unrolled, |function, count| {
+            function.loop_iterations_unrolled = count;
+        });
+
+        OptimizationSummary { functions }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::{PassStatistics, Ssa, SsaBuilder, SsaEvaluatorOptions, optimize_all};
+
+    use super::OptimizationSummary;
+
+    #[test]
+    fn summarizes_each_function_as_json() {
+        // `main` sums `x * y` in a loop of 3 iterations, then calls `double`
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: Field, v1: Field):
+            jmp b1(u32 0, Field 0)
+          b1(v2: u32, v3: Field):
+            v6 = lt v2, u32 3
+            jmpif v6 then: b2, else: b3
+          b2():
+            v7 = mul v0, v1
+            v8 = add v3, v7
+            v10 = unchecked_add v2, u32 1
+            jmp b1(v10, v8)
+          b3():
+            v12 = call f1(v3) -> Field
+            return v12
+        }
+        acir(inline) fn double f1 {
+          b0(v0: Field):
+            v1 = add v0, v0
+            v2 = mul v1, Field 1
+            return v2
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let options = SsaEvaluatorOptions {
+            collect_pass_statistics: true,
+            ..SsaEvaluatorOptions::for_tests()
+        };
+
+        let mut statistics = PassStatistics::default();
+        let builder = SsaBuilder {
+            ssa,
+            ssa_logging: options.ssa_logging.clone(),
+            print_codegen_timings: false,
+            pass_timings: None,
+            selected_pass: None,
+        };
+        optimize_all(builder, &options, Some(&mut statistics), None, None).unwrap();
+
+        let summary = OptimizationSummary::from(&statistics);
+        let json: serde_json::Value = serde_json::from_str(&summary.to_json()).unwrap();
+
+        let functions = json["functions"].as_object().unwrap();
+        assert_eq!(functions.keys().collect::<Vec<_>>(), ["double", "main"]);
+
+        let main = &functions["main"];
+        assert_eq!(main["instructions_before"], 9);
+        assert!(main["instructions_after"].is_u64());
+        assert_eq!(main["instructions_hoisted"], 1);
+        assert_eq!(main["calls_inlined"], 1);
+        assert_eq!(main["loop_iterations_unrolled"], 3);
+
+        // `double` is inlined into `main` then removed
+        let double = &functions["double"];
+        assert_eq!(double["instructions_before"], 3);
+        assert!(double["instructions_after"].is_null());
+
+        // Deserializing the JSON gives back the same summary
+        let deserialized: OptimizationSummary = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized, summary);
+    }
+}
@@ -49,6 +49,7 @@ impl Ssa {
 
     /// Creates an Ssa object from the given string but trying to simplify
     /// each parsed instruction as it's inserted into the final SSA.
+    #[cfg(test)]
     pub(crate) fn from_str_simplifying(src: &str) -> Result<Ssa, SsaErrorWithSource> {
         Self::from_str_impl(src, true)
     }
@@ -64,7 +65,16 @@ impl Ssa {
     }
 }
 
-pub(crate) struct SsaErrorWithSource {
+/// Parses the given SSA text and prints it back in canonical form: functions, blocks and
+/// values are renumbered in order and whitespace is normalized by the printer.
+/// Snippets which only differ in their numbering or layout normalize to the same text.
+pub fn normalize_ssa_text(src: &str) -> Result<String, SsaErrorWithSource> {
+    let mut ssa = Ssa::from_str(src)?;
+    ssa.normalize_ids();
+    Ok(ssa.to_string())
+}
+
+pub struct SsaErrorWithSource {
     src: String,
     error: SsaError,
 }
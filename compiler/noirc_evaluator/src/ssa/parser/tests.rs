@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use crate::{
-    ssa::{Ssa, opt::assert_normalized_ssa_equals},
+    ssa::{Ssa, opt::assert_normalized_ssa_equals, parser::normalize_ssa_text},
     trim_leading_whitespace_from_lines,
 };
 
@@ -610,3 +610,30 @@ fn parses_purity() {
     ";
     assert_ssa_roundtrip(src);
 }
+
+#[test]
+fn normalizes_differently_numbered_ssa_to_the_same_text() {
+    let src = "
+        acir(inline) fn main f0 {
+          b0(v0: Field):
+            v1 = add v0, Field 1
+            jmp b1(v1)
+          b1(v2: Field):
+            return v2
+        }
+        ";
+    let renumbered_src = "
+      acir(inline)   fn main f3 {
+        b4(v10: Field):
+          v20 = add v10, Field 1
+          jmp b7(v20)
+        b7(v30: Field):
+          return v30
+      }
+    ";
+
+    let normalized = normalize_ssa_text(src).unwrap();
+    let renumbered = normalize_ssa_text(renumbered_src).unwrap();
+    assert_eq!(normalized, renumbered);
+    assert_eq!(normalized, normalize_ssa_text(&normalized).unwrap());
+}
@@ -0,0 +1,128 @@
+//! Wall-clock timings of the SSA passes, collected to find out which passes dominate the
+//! compilation time of a program.
+use std::{fmt::Display, time::Duration};
+
+use iter_extended::vecmap;
+
+/// The wall-clock time spent in each SSA pass, in the order the passes ran.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PassTimings {
+    pub passes: Vec<(String, Duration)>,
+}
+
+impl PassTimings {
+    pub(crate) fn record(&mut self, pass: &str, duration: Duration) {
+        self.passes.push((pass.to_string(), duration));
+    }
+
+    /// Returns the passes sorted from the slowest to the fastest. Passes which took the same
+    /// time are kept in the order they ran.
+    pub fn sorted(&self) -> Vec<(&str, Duration)> {
+        let mut passes = vecmap(&self.passes, |(pass, duration)| (pass.as_str(), *duration));
+        passes.sort_by(|(_, a), (_, b)| b.cmp(a));
+        passes
+    }
+
+    /// Returns the time spent in all passes.
+    pub fn total(&self) -> Duration {
+        self.passes.iter().map(|(_, duration)| *duration).sum()
+    }
+}
+
+impl Display for PassTimings {
+    /// Formats the timings as a table sorted from the slowest pass to the fastest.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const PASS_HEADER: &str = "SSA pass";
+        let width =
+            self.passes.iter().map(|(pass, _)| pass.len()).fold(PASS_HEADER.len(), usize::max);
+        let total = self.total();
+
+        writeln!(f, "{PASS_HEADER:<width$}  {:>10}  {:>6}", "Time (ms)", "%")?;
+        for (pass, duration) in self.sorted() {
+            let percent = if total.is_zero() {
+                0.0
+            } else {
+                duration.as_secs_f64() / total.as_secs_f64() * 100.0
+            };
+            let millis = duration.as_secs_f64() * 1000.0;
+            writeln!(f, "{pass:<width$}  {millis:>10.3}  {percent:>5.1}%")?;
+        }
+        write!(f, "{:<width$}  {:>10.3}", "Total", total.as_secs_f64() * 1000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::ssa::{
+        Ssa, SsaBuilder, SsaEvaluatorOptions, SsaLogging, optimize_all,
+        single_pass::{SelectedPass, pass_name},
+    };
+
+    use super::PassTimings;
+
+    #[test]
+    fn every_pass_of_the_default_pipeline_is_timed() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: u32):
+            v2 = add v0, u32 1
+            return v2
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        let options =
+            SsaEvaluatorOptions { print_pass_timings: true, ..SsaEvaluatorOptions::for_tests() };
+
+        let mut pass_timings = PassTimings::default();
+        let builder = SsaBuilder {
+            ssa: ssa.clone(),
+            ssa_logging: SsaLogging::None,
+            print_codegen_timings: false,
+            pass_timings: Some(&mut pass_timings),
+            selected_pass: None,
+        };
+        optimize_all(builder, &options, None, None, None).unwrap();
+
+        // Selecting a pass which doesn't exist runs nothing, but records every step of the pipeline
+        let mut selected_pass = SelectedPass::new("no such pass");
+        let builder = SsaBuilder {
+            ssa,
+            ssa_logging: SsaLogging::None,
+            print_codegen_timings: false,
+            pass_timings: None,
+            selected_pass: Some(&mut selected_pass),
+        };
+        optimize_all(builder, &options, None, None, None).unwrap();
+
+        let mut timed_passes: Vec<&str> = Vec::new();
+        for (pass, _) in &pass_timings.passes {
+            let pass = pass_name(pass);
+            if !timed_passes.contains(&pass) {
+                timed_passes.push(pass);
+            }
+        }
+        assert_eq!(timed_passes, selected_pass.pass_names());
+    }
+
+    #[test]
+    fn sorts_passes_from_slowest() {
+        let mut pass_timings = PassTimings::default();
+        pass_timings.record("Inlining", Duration::from_millis(2));
+        pass_timings.record("Unrolling", Duration::from_millis(5));
+        pass_timings.record("Mem2Reg", Duration::from_millis(2));
+
+        let sorted = pass_timings.sorted();
+        assert_eq!(
+            sorted,
+            vec![
+                ("Unrolling", Duration::from_millis(5)),
+                ("Inlining", Duration::from_millis(2)),
+                ("Mem2Reg", Duration::from_millis(2)),
+            ]
+        );
+        assert_eq!(pass_timings.total(), Duration::from_millis(9));
+    }
+}
@@ -0,0 +1,119 @@
+//! Runs a single SSA pass on the initial SSA of a program, so that a pass can be iterated on
+//! without writing a test harness for it.
+//!
+//! The passes are the ones [optimize_all] runs, so they are selected by the names it uses for
+//! its steps rather than by a separate list of passes.
+use noirc_frontend::monomorphization::ast::Program;
+
+use crate::errors::RuntimeError;
+
+use super::{SsaBuilder, SsaEvaluatorOptions, SsaLogging, optimize_all};
+
+/// The SSA of a program before and after running a single pass on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SsaPassOutput {
+    pub before: String,
+    pub after: String,
+}
+
+/// Returned by [run_single_ssa_pass] when no step of the pipeline runs a pass with the
+/// requested name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownSsaPass {
+    /// The names of the passes of the pipeline, in the order they first run.
+    pub pass_names: Vec<String>,
+}
+
+/// Restricts the pipeline run by [optimize_all] to the first step running the pass named
+/// `name`. The names of all the passes of the pipeline are recorded along the way.
+pub(super) struct SelectedPass {
+    name: String,
+    /// Whether a step running the pass was found
+    found: bool,
+    pass_names: Vec<String>,
+}
+
+impl SelectedPass {
+    pub(super) fn new(name: &str) -> Self {
+        Self { name: name.trim().to_string(), found: false, pass_names: Vec::new() }
+    }
+
+    /// Records the step of the pipeline with the given message, returning whether it should run.
+    pub(super) fn select(&mut self, msg: &str) -> bool {
+        let name = pass_name(msg);
+        if !self.pass_names.iter().any(|pass_name| pass_name == name) {
+            self.pass_names.push(name.to_string());
+        }
+
+        if self.found || !name.eq_ignore_ascii_case(&self.name) {
+            return false;
+        }
+        self.found = true;
+        true
+    }
+
+    /// The names of the passes of the pipeline recorded so far, in the order they first ran.
+    #[cfg(test)]
+    pub(super) fn pass_names(&self) -> &[String] {
+        &self.pass_names
+    }
+}
+
+/// Returns the name of the pass run by the step of the pipeline with the given message, i.e. the
+/// message without the ordinal telling apart the steps running the same pass.
+/// For example both `Mem2Reg (2nd)` and `Mem2Reg (3rd)` run the `Mem2Reg` pass.
+pub(super) fn pass_name(msg: &str) -> &str {
+    let msg = msg.strip_suffix(':').unwrap_or(msg);
+    match msg.rsplit_once(" (") {
+        Some((name, ordinal)) if ordinal.ends_with(')') => name,
+        _ => msg,
+    }
+}
+
+/// Generates the initial SSA of the program and runs the first step of the pipeline running the
+/// SSA pass named `pass` on it, returning the SSA before and after the pass. Pass names are
+/// matched case-insensitively.
+///
+/// No other pass is run first, so passes which rely on earlier passes of the pipeline
+/// (e.g. `Flattening` expecting calls to be inlined) may fail. Passes which only run when an
+/// option enables them leave the SSA unchanged unless that option is set.
+pub fn run_single_ssa_pass(
+    program: Program,
+    options: &SsaEvaluatorOptions,
+    pass: &str,
+) -> Result<Result<SsaPassOutput, UnknownSsaPass>, RuntimeError> {
+    let mut ssa = super::ssa_gen::generate_ssa(program, &options.observed_variables)?;
+    ssa.normalize_ids();
+    let before = ssa.to_string();
+
+    let mut selected_pass = SelectedPass::new(pass);
+    let builder = SsaBuilder {
+        ssa,
+        ssa_logging: SsaLogging::None,
+        print_codegen_timings: false,
+        pass_timings: None,
+        selected_pass: Some(&mut selected_pass),
+    };
+    let mut ssa = optimize_all(builder, options, None, None, None)?;
+    if !selected_pass.found {
+        return Ok(Err(UnknownSsaPass { pass_names: selected_pass.pass_names }));
+    }
+
+    ssa.normalize_ids();
+    let after = ssa.to_string();
+
+    Ok(Ok(SsaPassOutput { before, after }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pass_name;
+
+    #[test]
+    fn pass_name_drops_the_ordinal_of_the_step() {
+        assert_eq!(pass_name("Mem2Reg (2nd)"), "Mem2Reg");
+        assert_eq!(pass_name("Simplifying (3rd):"), "Simplifying");
+        assert_eq!(pass_name("Inlining small fold functions"), "Inlining small fold functions");
+        assert_eq!(pass_name("`as_slice` optimization"), "`as_slice` optimization");
+    }
+}
@@ -24,7 +24,7 @@ use crate::ssa::ir::value::ValueId;
 
 use super::GlobalsGraph;
 use super::value::{Tree, Value, Values};
-use fxhash::FxHashMap as HashMap;
+use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 /// The FunctionContext is the main context object for translating a
 /// function into SSA form during the SSA-gen pass.
@@ -86,6 +86,10 @@ pub(super) struct SharedContext {
 
     /// The entire monomorphized source program
     pub(super) program: Program,
+
+    /// Names of the local variables whose values are kept alive through optimizations,
+    /// so that they can be inspected when debugging.
+    pub(super) observed_variables: HashSet<String>,
 }
 
 #[derive(Copy, Clone)]
@@ -666,6 +670,11 @@ impl<'a> FunctionContext<'a> {
         self.definitions.get(&id).expect("lookup: variable not defined").clone()
     }
 
+    /// True if the values of local variables with the given name should be kept alive.
+    pub(super) fn is_observed(&self, name: &str) -> bool {
+        self.shared_context.observed_variables.contains(name)
+    }
+
     pub(super) fn lookup_global(&self, id: GlobalId) -> Values {
         self.shared_context.globals.get(&id).expect("lookup_global: variable not defined").clone()
     }
@@ -1005,7 +1014,7 @@ fn convert_operator(op: BinaryOpKind) -> BinaryOp {
 
 impl SharedContext {
     /// Create a new SharedContext for the given monomorphized program.
-    pub(super) fn new(program: Program) -> Self {
+    pub(super) fn new(program: Program, observed_variables: HashSet<String>) -> Self {
         let globals_shared_context = SharedContext::new_for_globals();
 
         let globals_id = Program::global_space_id();
@@ -1033,6 +1042,7 @@ impl SharedContext {
             program,
             globals_context: context.builder.current_function,
             globals,
+            observed_variables,
         }
     }
 
@@ -1046,6 +1056,7 @@ impl SharedContext {
             program: Default::default(),
             globals_context,
             globals: Default::default(),
+            observed_variables: Default::default(),
         }
     }
 
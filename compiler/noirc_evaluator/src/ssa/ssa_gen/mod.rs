@@ -5,6 +5,7 @@ mod value;
 
 use acvm::AcirField;
 use noirc_frontend::hir_def::expr::Constructor;
+use noirc_frontend::hir_def::stmt::HirPattern;
 use noirc_frontend::token::FmtStrFragment;
 pub(crate) use program::Ssa;
 
@@ -18,7 +19,10 @@ use noirc_frontend::shared::Visibility;
 
 use crate::{
     errors::RuntimeError,
-    ssa::{function_builder::data_bus::DataBusBuilder, ir::instruction::Intrinsic},
+    ssa::{
+        function_builder::data_bus::DataBusBuilder,
+        ir::instruction::{Hint, Intrinsic},
+    },
 };
 
 use self::{
@@ -45,14 +49,21 @@ pub(crate) const SSA_WORD_SIZE: u32 = 32;
 /// Generates SSA for the given monomorphized program.
 ///
 /// This function will generate the SSA but does not perform any optimizations on it.
-pub(crate) fn generate_ssa(program: Program) -> Result<Ssa, RuntimeError> {
+///
+/// The values bound to local variables named in `observed_variables` are passed to a
+/// `black_box` hint, so that they are not eliminated by later optimizations.
+pub(crate) fn generate_ssa(
+    program: Program,
+    observed_variables: &[String],
+) -> Result<Ssa, RuntimeError> {
     // see which parameter has call_data/return_data attribute
     let is_databus = DataBusBuilder::is_databus(&program.main_function_signature);
 
     let is_return_data = matches!(program.return_visibility, Visibility::ReturnData);
 
     let return_location = program.return_location;
-    let mut context = SharedContext::new(program);
+    let observed_variables = observed_variables.iter().cloned().collect();
+    let mut context = SharedContext::new(program, observed_variables);
 
     let globals_dfg = std::mem::take(&mut context.globals_context.dfg);
     let globals = GlobalsGraph::from_dfg(globals_dfg);
@@ -133,6 +144,45 @@ pub(crate) fn generate_ssa(program: Program) -> Result<Ssa, RuntimeError> {
     Ok(ssa)
 }
 
+/// A parameter of the `main` function as written in the source program, along with the
+/// number of SSA parameters it is flattened into.
+#[derive(Debug, Clone)]
+pub(crate) struct MainParameter {
+    /// The names of the variables bound by the parameter's pattern, joined by commas.
+    pub(crate) name: String,
+    pub(crate) location: Location,
+    pub(crate) num_values: usize,
+}
+
+/// Returns the parameters of the program's `main` function, in the order of the parameters
+/// of the `main` SSA function generated for it.
+pub(crate) fn main_parameters(program: &Program) -> Vec<MainParameter> {
+    let mut monomorphized_parameters = program.main().parameters.iter();
+    vecmap(&program.main_function_signature.0, |(pattern, _, _)| {
+        let parameters: Vec<_> =
+            monomorphized_parameters.by_ref().take(count_pattern_identifiers(pattern)).collect();
+        let name = vecmap(&parameters, |(_, _, name, _)| name.as_str()).join(", ");
+        let num_values = parameters
+            .iter()
+            .map(|(_, _, _, typ)| FunctionContext::map_type(typ, |_| ()).count_leaves())
+            .sum();
+        MainParameter { name, location: pattern.location(), num_values }
+    })
+}
+
+/// Returns the number of variables bound by a pattern, which the monomorphizer turns into
+/// separate parameters.
+fn count_pattern_identifiers(pattern: &HirPattern) -> usize {
+    match pattern {
+        HirPattern::Identifier(_) => 1,
+        HirPattern::Mutable(pattern, _) => count_pattern_identifiers(pattern),
+        HirPattern::Tuple(fields, _) => fields.iter().map(count_pattern_identifiers).sum(),
+        HirPattern::Struct(_, fields, _) => {
+            fields.iter().map(|(_, field)| count_pattern_identifiers(field)).sum()
+        }
+    }
+}
+
 impl FunctionContext<'_> {
     /// Codegen a function's body and set its return value to that of its last parameter.
     /// For functions returning nothing, this will be an empty list.
@@ -778,8 +828,7 @@ impl FunctionContext<'_> {
 
         for i in 0..last_case {
             let case = &match_expr.cases[i];
-            let variant_tag = self.variant_index_value(&case.constructor, tag_type)?;
-            let eq = self.builder.insert_binary(tag, BinaryOp::Eq, variant_tag);
+            let eq = self.case_condition(tag, &case.constructor, tag_type)?;
 
             let case_block = self.builder.insert_block();
             let else_block = self.builder.insert_block();
@@ -854,14 +903,36 @@ impl FunctionContext<'_> {
         Ok(end_results)
     }
 
-    fn variant_index_value(
+    /// Returns a boolean value which is true if `tag` matches the given case constructor.
+    fn case_condition(
         &mut self,
+        tag: ValueId,
         constructor: &Constructor,
         typ: NumericType,
     ) -> Result<ValueId, RuntimeError> {
         match constructor {
-            Constructor::Int(value) => self.checked_numeric_constant(*value, typ),
-            other => Ok(self.builder.numeric_constant(other.variant_index(), typ)),
+            Constructor::Range(start, end) => {
+                let start = self.checked_numeric_constant(*start, typ)?;
+                let below_start = self.builder.insert_binary(tag, BinaryOp::Lt, start);
+                let mut in_range = self.builder.insert_not(below_start);
+
+                // The end of a range covering the maximum value of `typ` doesn't fit in it, in
+                // which case there is no upper bound to check.
+                if typ.value_is_outside_limits(*end).is_none() {
+                    let end = self.checked_numeric_constant(*end, typ)?;
+                    let below_end = self.builder.insert_binary(tag, BinaryOp::Lt, end);
+                    in_range = self.builder.insert_binary(in_range, BinaryOp::And, below_end);
+                }
+                Ok(in_range)
+            }
+            Constructor::Int(value) => {
+                let value = self.checked_numeric_constant(*value, typ)?;
+                Ok(self.builder.insert_binary(tag, BinaryOp::Eq, value))
+            }
+            other => {
+                let variant_index = self.builder.numeric_constant(other.variant_index(), typ);
+                Ok(self.builder.insert_binary(tag, BinaryOp::Eq, variant_index))
+            }
         }
     }
 
@@ -1014,9 +1085,13 @@ impl FunctionContext<'_> {
     /// the initial value before returning the allocate instruction.
     fn codegen_let(&mut self, let_expr: &ast::Let) -> Result<Values, RuntimeError> {
         let mut values = self.codegen_expression(&let_expr.expression)?;
+        let observed = self.is_observed(&let_expr.name);
 
         values = values.map(|value| {
             let value = value.eval(self);
+            if observed {
+                self.observe_value(value);
+            }
 
             Tree::Leaf(if let_expr.mutable {
                 self.new_mutable_variable(value)
@@ -1029,6 +1104,14 @@ impl FunctionContext<'_> {
         Ok(Self::unit_value())
     }
 
+    /// Inserts a `black_box` hint using `value`. The hint is never removed, so the
+    /// instructions computing `value` survive dead instruction elimination.
+    fn observe_value(&mut self, value: ValueId) {
+        let black_box = self.builder.import_intrinsic_id(Intrinsic::Hint(Hint::BlackBox));
+        let typ = self.builder.type_of_value(value);
+        self.builder.insert_call(black_box, vec![value], vec![typ]);
+    }
+
     fn codegen_constrain(
         &mut self,
         expr: &Expression,
@@ -19,7 +19,7 @@ fn get_initial_ssa(src: &str, test_path: &str) -> Result<Ssa, RuntimeError> {
         }
     };
 
-    generate_ssa(program)
+    generate_ssa(program, &[])
 }
 
 #[named]
@@ -107,3 +107,28 @@ fn basic_loop() {
 
     assert_normalized_ssa_equals(ssa, expected);
 }
+
+#[named]
+#[test]
+fn observed_variable_is_not_eliminated() {
+    let src = "
+    fn main(x: Field) {
+        let _dead = x * 3;
+        let _observed = x * 5;
+    }
+    ";
+    let program = get_monomorphized(src, function_path!(), Expect::Success).unwrap();
+    let ssa = generate_ssa(program, &["_observed".to_string()]).unwrap();
+
+    // Both values are unused, but `_observed` is kept alive by the `black_box` hint
+    let ssa = ssa.dead_instruction_elimination();
+    let expected = "
+    acir(inline) fn main f0 {
+      b0(v0: Field):
+        v2 = mul v0, Field 5
+        v3 = call black_box(v2) -> Field
+        return
+    }
+    ";
+    assert_normalized_ssa_equals(ssa, expected);
+}
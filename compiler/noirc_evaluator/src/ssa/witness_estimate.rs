@@ -0,0 +1,143 @@
+//! An estimate of the number of witnesses each ACIR function needs, computed from the
+//! optimized SSA without generating ACIR.
+//!
+//! This is a heuristic and the actual count may be noticeably higher or lower. Each value
+//! defined in an ACIR function is assumed to need a witness for each of its flattened
+//! elements if ACIR generation can't express it as a linear combination of other values:
+//! - the function's parameters,
+//! - the results of non-linear operations (multiplying two non-constant values, division,
+//!   comparisons, bitwise operations and shifts),
+//! - the results of arithmetic which must be range checked for overflow,
+//! - the results of truncations, conditional selections and calls,
+//! - the results of reading from or writing to an array at a non-constant index.
+//!
+//! Additions, subtractions of field elements, casts, constraints and reads at constant
+//! indices don't add witnesses. The intermediate witnesses ACIR generation needs to
+//! decompose values into bits for range checks, comparisons or bitwise operations are not
+//! counted, nor are the witnesses of the functions' Brillig calls, which are counted as
+//! a single result each. Slices are counted as a single value as their length isn't known.
+use std::collections::BTreeMap;
+
+use crate::ssa::{
+    ir::{
+        function::Function,
+        instruction::{Binary, BinaryOp, Instruction},
+        types::Type,
+        value::ValueId,
+    },
+    ssa_gen::Ssa,
+};
+
+impl Ssa {
+    /// Returns an estimate of the number of witnesses needed by each ACIR function, keyed by
+    /// function name. Functions sharing a name have their estimates added together.
+    pub(crate) fn estimate_witness_counts(&self) -> BTreeMap<String, usize> {
+        let mut counts = BTreeMap::new();
+        for function in self.functions.values() {
+            if function.runtime().is_acir() {
+                *counts.entry(function.name().to_string()).or_default() +=
+                    function.estimate_witness_count();
+            }
+        }
+        counts
+    }
+}
+
+impl Function {
+    fn estimate_witness_count(&self) -> usize {
+        let mut count = self.parameters().iter().map(|value| self.flattened_size(*value)).sum();
+
+        for block in self.reachable_blocks() {
+            for instruction_id in self.dfg[block].instructions() {
+                if self.needs_witness(&self.dfg[*instruction_id]) {
+                    count += self
+                        .dfg
+                        .instruction_results(*instruction_id)
+                        .iter()
+                        .map(|result| self.flattened_size(*result))
+                        .sum::<usize>();
+                }
+            }
+        }
+
+        count
+    }
+
+    /// Returns true if ACIR generation is expected to assign the results of `instruction`
+    /// to new witnesses.
+    fn needs_witness(&self, instruction: &Instruction) -> bool {
+        let is_constant = |value: &ValueId| self.dfg.is_constant(*value);
+        // Integer arithmetic is range checked unless it is known not to overflow
+        let is_range_checked = |lhs: &ValueId, unchecked: &bool| {
+            !unchecked && self.dfg.type_of_value(*lhs) != Type::field()
+        };
+        match instruction {
+            Instruction::Binary(Binary { lhs, rhs, operator }) => match operator {
+                BinaryOp::Add { unchecked } | BinaryOp::Sub { unchecked } => {
+                    is_range_checked(lhs, unchecked)
+                }
+                BinaryOp::Mul { unchecked } => {
+                    (!is_constant(lhs) && !is_constant(rhs)) || is_range_checked(lhs, unchecked)
+                }
+                BinaryOp::Div
+                | BinaryOp::Mod
+                | BinaryOp::Eq
+                | BinaryOp::Lt
+                | BinaryOp::And
+                | BinaryOp::Or
+                | BinaryOp::Xor
+                | BinaryOp::Shl
+                | BinaryOp::Shr => true,
+            },
+            Instruction::Truncate { .. }
+            | Instruction::Call { .. }
+            | Instruction::IfElse { .. } => true,
+            Instruction::ArrayGet { index, .. } | Instruction::ArraySet { index, .. } => {
+                !is_constant(index)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the number of field elements `value` is made of, counting slices as one.
+    fn flattened_size(&self, value: ValueId) -> usize {
+        match self.dfg.type_of_value(value) {
+            Type::Slice(_) => 1,
+            typ if typ.contains_slice_element() => 1,
+            typ => typ.flattened_size() as usize,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ssa::ssa_gen::Ssa;
+
+    #[test]
+    fn estimates_witnesses_of_non_linear_operations() {
+        let src = "
+        acir(inline) fn main f0 {
+          b0(v0: Field, v1: Field, v2: [Field; 3]):
+            v3 = add v0, v1
+            v4 = mul v3, Field 2
+            v5 = mul v4, v1
+            v6 = array_get v2, index u32 0 -> Field
+            v7 = cast v0 as u32
+            v8 = array_get v2, index v7 -> Field
+            constrain v5 == v8
+            return
+        }
+        brillig(inline) fn foo f1 {
+          b0(v0: Field):
+            v1 = mul v0, v0
+            return v1
+        }
+        ";
+        let ssa = Ssa::from_str(src).unwrap();
+
+        // 5 parameter elements, `v5` and `v8`
+        let counts = ssa.estimate_witness_counts();
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts["main"], 7);
+    }
+}
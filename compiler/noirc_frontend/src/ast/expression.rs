@@ -33,6 +33,8 @@ pub enum ExpressionKind {
     Infix(Box<InfixExpression>),
     If(Box<IfExpression>),
     Match(Box<MatchExpression>),
+    /// A range such as `0..10` or `0..=9`. Ranges are only parsed as match patterns.
+    Range(Box<RangeExpression>),
     Variable(Path),
     Tuple(Vec<Expression>),
     Lambda(Box<Lambda>),
@@ -285,6 +287,7 @@ impl Expression {
             | ExpressionKind::Infix(..)
             | ExpressionKind::If(..)
             | ExpressionKind::Match(..)
+            | ExpressionKind::Range(..)
             | ExpressionKind::Variable(..)
             | ExpressionKind::Tuple(..)
             | ExpressionKind::Lambda(..)
@@ -491,6 +494,14 @@ pub struct MatchExpression {
     pub rules: Vec<(/*pattern*/ Expression, /*branch*/ Expression)>,
 }
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RangeExpression {
+    pub start: Expression,
+    pub end: Expression,
+    /// True for `start..=end`, false for `start..end`
+    pub inclusive: bool,
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Lambda {
     pub parameters: Vec<(Pattern, UnresolvedType)>,
@@ -684,6 +695,7 @@ impl Display for ExpressionKind {
             Infix(infix) => infix.fmt(f),
             If(if_expr) => if_expr.fmt(f),
             Match(match_expr) => match_expr.fmt(f),
+            Range(range) => range.fmt(f),
             Variable(path) => path.fmt(f),
             Constructor(constructor) => constructor.fmt(f),
             MemberAccess(access) => access.fmt(f),
@@ -869,6 +881,13 @@ impl Display for MatchExpression {
     }
 }
 
+impl Display for RangeExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let operator = if self.inclusive { "..=" } else { ".." };
+        write!(f, "{}{operator}{}", self.start, self.end)
+    }
+}
+
 impl Display for Lambda {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let parameters = vecmap(&self.parameters, |(name, r#type)| format!("{name}: {type}"));
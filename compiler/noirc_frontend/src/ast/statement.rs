@@ -6,9 +6,9 @@ use iter_extended::vecmap;
 use noirc_errors::{Located, Location, Span};
 
 use super::{
-    BinaryOpKind, BlockExpression, ConstructorExpression, Expression, ExpressionKind,
-    GenericTypeArgs, IndexExpression, InfixExpression, ItemVisibility, MemberAccessExpression,
-    MethodCallExpression, UnresolvedType,
+    BinaryOpKind, BlockExpression, CallExpression, ConstructorExpression, Expression,
+    ExpressionKind, GenericTypeArgs, IndexExpression, InfixExpression, ItemVisibility,
+    MemberAccessExpression, MethodCallExpression, UnresolvedType,
 };
 use crate::ast::UnresolvedTypeData;
 use crate::elaborator::Turbofish;
@@ -585,14 +585,87 @@ pub struct AssignStatement {
     pub expression: Expression,
 }
 
+impl AssignStatement {
+    /// Desugars an assignment to a tuple of places into a block which first binds the
+    /// assigned value to fresh variables, then assigns each of them to its place:
+    ///
+    /// ```text
+    /// (a, (b, c)) = value;
+    ///
+    /// // becomes
+    ///
+    /// {
+    ///     let ($a0, ($a1, $a2)) = value;
+    ///     a = $a0;
+    ///     b = $a1;
+    ///     c = $a2;
+    /// }
+    /// ```
+    ///
+    /// Every value is read before any place is written to, so `(a, b) = (b, a)` swaps `a`
+    /// and `b`.
+    pub(crate) fn into_destructuring(self, location: Location) -> Statement {
+        fn destructure(lvalue: LValue, assignments: &mut Vec<Statement>) -> Pattern {
+            match lvalue {
+                LValue::Tuple(lvalues, location) => {
+                    let patterns = vecmap(lvalues, |lvalue| destructure(lvalue, assignments));
+                    Pattern::Tuple(patterns, location)
+                }
+                lvalue => {
+                    let location = lvalue.location();
+                    let name = format!("$a{}", assignments.len());
+                    let ident = Ident::new(name, location);
+
+                    let variable = ExpressionKind::Variable(Path::from_ident(ident.clone()));
+                    let expression = Expression::new(variable, location);
+                    let assignment = AssignStatement { lvalue, expression };
+                    assignments
+                        .push(Statement { kind: StatementKind::Assign(assignment), location });
+                    Pattern::Identifier(ident)
+                }
+            }
+        }
+
+        let mut assignments = Vec::new();
+        let pattern = destructure(self.lvalue, &mut assignments);
+
+        let let_statement = Statement {
+            kind: StatementKind::new_let(
+                pattern,
+                UnresolvedTypeData::Unspecified.with_dummy_location(),
+                self.expression,
+                vec![],
+            ),
+            location,
+        };
+
+        let mut statements = vec![let_statement];
+        statements.extend(assignments);
+
+        let block = ExpressionKind::Block(BlockExpression { statements });
+        Statement { kind: StatementKind::Semi(Expression::new(block, location)), location }
+    }
+}
+
 /// Represents an Ast form that can be assigned to
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum LValue {
     Ident(Ident),
-    MemberAccess { object: Box<LValue>, field_name: Ident, location: Location },
-    Index { array: Box<LValue>, index: Expression, location: Location },
+    MemberAccess {
+        object: Box<LValue>,
+        field_name: Ident,
+        location: Location,
+    },
+    Index {
+        array: Box<LValue>,
+        index: Expression,
+        location: Location,
+    },
     Dereference(Box<LValue>, Location),
     Interned(InternedExpressionKind, Location),
+    /// A tuple of places assigned to at once, like `(a, b)` in `(a, b) = (b, a)`.
+    /// Only valid as the whole left-hand side of an assignment.
+    Tuple(Vec<LValue>, Location),
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -600,7 +673,12 @@ pub enum Pattern {
     Identifier(Ident),
     Mutable(Box<Pattern>, Location, /*is_synthesized*/ bool),
     Tuple(Vec<Pattern>, Location),
-    Struct(Path, Vec<(Ident, Pattern)>, Location),
+    /// A struct pattern, ending with `..` if it has a rest pattern ignoring the other fields
+    Struct(Path, Vec<(Ident, Pattern)>, Location, /*has_rest*/ bool),
+    /// A tuple struct pattern such as `Foo(a, b)`, matching the fields of a tuple struct by position
+    TupleStruct(Path, Vec<Pattern>, Location),
+    /// A pattern with a type ascription, such as the `a: Field` in `let (a: Field, b) = ...;`
+    Typed(Box<Pattern>, UnresolvedType),
     Interned(InternedPattern, Location),
 }
 
@@ -613,8 +691,10 @@ impl Pattern {
             Pattern::Identifier(ident) => ident.location(),
             Pattern::Mutable(_, location, _)
             | Pattern::Tuple(_, location)
-            | Pattern::Struct(_, _, location)
+            | Pattern::Struct(_, _, location, _)
+            | Pattern::TupleStruct(_, _, location)
             | Pattern::Interned(_, location) => *location,
+            Pattern::Typed(pattern, typ) => pattern.location().merge(typ.location),
         }
     }
 
@@ -625,7 +705,7 @@ impl Pattern {
     pub fn name_ident(&self) -> &Ident {
         match self {
             Pattern::Identifier(name_ident) => name_ident,
-            Pattern::Mutable(pattern, ..) => pattern.name_ident(),
+            Pattern::Mutable(pattern, ..) | Pattern::Typed(pattern, _) => pattern.name_ident(),
             _ => panic!("Only the Identifier or Mutable patterns can return a name"),
         }
     }
@@ -636,7 +716,7 @@ impl Pattern {
                 kind: ExpressionKind::Variable(Path::from_ident(ident.clone())),
                 location: ident.location(),
             }),
-            Pattern::Mutable(_, _, _) => None,
+            Pattern::Mutable(_, _, _) | Pattern::Typed(..) => None,
             Pattern::Tuple(patterns, location) => {
                 let mut expressions = Vec::new();
                 for pattern in patterns {
@@ -644,7 +724,9 @@ impl Pattern {
                 }
                 Some(Expression { kind: ExpressionKind::Tuple(expressions), location: *location })
             }
-            Pattern::Struct(path, patterns, location) => {
+            // The fields ignored by `..` have no expression to construct them with
+            Pattern::Struct(_, _, _, true) => None,
+            Pattern::Struct(path, patterns, location, false) => {
                 let mut fields = Vec::new();
                 for (field, pattern) in patterns {
                     let expression = pattern.try_as_expression(interner)?;
@@ -658,6 +740,24 @@ impl Pattern {
                     location: *location,
                 })
             }
+            Pattern::TupleStruct(path, patterns, location) => {
+                let mut arguments = Vec::new();
+                for pattern in patterns {
+                    arguments.push(pattern.try_as_expression(interner)?);
+                }
+                let func = Expression {
+                    kind: ExpressionKind::Variable(path.clone()),
+                    location: path.location,
+                };
+                Some(Expression {
+                    kind: ExpressionKind::Call(Box::new(CallExpression {
+                        func: Box::new(func),
+                        arguments,
+                        is_macro_call: false,
+                    })),
+                    location: *location,
+                })
+            }
             Pattern::Interned(id, _) => interner.get_pattern(*id).try_as_expression(interner),
         }
     }
@@ -686,6 +786,9 @@ impl LValue {
                 }))
             }
             LValue::Interned(id, _) => ExpressionKind::Interned(*id),
+            LValue::Tuple(lvalues, _) => {
+                ExpressionKind::Tuple(vecmap(lvalues, Self::as_expression))
+            }
         };
         Expression::new(kind, self.location())
     }
@@ -695,15 +798,31 @@ impl LValue {
     }
 
     pub fn from_expression_kind(expr: ExpressionKind, location: Location) -> Option<LValue> {
+        match expr {
+            ExpressionKind::Tuple(elements) => {
+                let lvalues = elements.into_iter().map(LValue::from_expression).collect();
+                Some(LValue::Tuple(lvalues?, location))
+            }
+            ExpressionKind::Parenthesized(expr) => LValue::from_expression(*expr),
+            _ => LValue::from_place_expression_kind(expr, location),
+        }
+    }
+
+    /// Converts an expression to an [`LValue`] referring to a single place, so not a tuple.
+    fn from_place_expression(expr: Expression) -> Option<LValue> {
+        LValue::from_place_expression_kind(expr.kind, expr.location)
+    }
+
+    fn from_place_expression_kind(expr: ExpressionKind, location: Location) -> Option<LValue> {
         match expr {
             ExpressionKind::Variable(path) => Some(LValue::Ident(path.as_ident().unwrap().clone())),
             ExpressionKind::MemberAccess(member_access) => Some(LValue::MemberAccess {
-                object: Box::new(LValue::from_expression(member_access.lhs)?),
+                object: Box::new(LValue::from_place_expression(member_access.lhs)?),
                 field_name: member_access.rhs,
                 location,
             }),
             ExpressionKind::Index(index) => Some(LValue::Index {
-                array: Box::new(LValue::from_expression(index.collection)?),
+                array: Box::new(LValue::from_place_expression(index.collection)?),
                 index: index.index,
                 location,
             }),
@@ -713,14 +832,14 @@ impl LValue {
                     crate::ast::UnaryOp::Dereference { implicitly_added: false }
                 ) {
                     Some(LValue::Dereference(
-                        Box::new(LValue::from_expression(prefix.rhs)?),
+                        Box::new(LValue::from_place_expression(prefix.rhs)?),
                         location,
                     ))
                 } else {
                     None
                 }
             }
-            ExpressionKind::Parenthesized(expr) => LValue::from_expression(*expr),
+            ExpressionKind::Parenthesized(expr) => LValue::from_place_expression(*expr),
             ExpressionKind::Interned(id) => Some(LValue::Interned(id, location)),
             _ => None,
         }
@@ -732,7 +851,8 @@ impl LValue {
             LValue::MemberAccess { location, .. }
             | LValue::Index { location, .. }
             | LValue::Dereference(_, location)
-            | LValue::Interned(_, location) => *location,
+            | LValue::Interned(_, location)
+            | LValue::Tuple(_, location) => *location,
         }
     }
 
@@ -975,6 +1095,14 @@ impl Display for LValue {
             LValue::Index { array, index, location: _ } => write!(f, "{array}[{index}]"),
             LValue::Dereference(lvalue, _span) => write!(f, "*{lvalue}"),
             LValue::Interned(_, _) => write!(f, "?Interned"),
+            LValue::Tuple(lvalues, _) => {
+                if lvalues.len() == 1 {
+                    write!(f, "({},)", lvalues[0])
+                } else {
+                    let lvalues = vecmap(lvalues, ToString::to_string);
+                    write!(f, "({})", lvalues.join(", "))
+                }
+            }
         }
     }
 }
@@ -1020,10 +1148,18 @@ impl Display for Pattern {
                 let fields = vecmap(fields, ToString::to_string);
                 write!(f, "({})", fields.join(", "))
             }
-            Pattern::Struct(typename, fields, _) => {
-                let fields = vecmap(fields, |(name, pattern)| format!("{name}: {pattern}"));
+            Pattern::Struct(typename, fields, _, has_rest) => {
+                let mut fields = vecmap(fields, |(name, pattern)| format!("{name}: {pattern}"));
+                if *has_rest {
+                    fields.push("..".to_string());
+                }
                 write!(f, "{} {{ {} }}", typename, fields.join(", "))
             }
+            Pattern::TupleStruct(typename, fields, _) => {
+                let fields = vecmap(fields, ToString::to_string);
+                write!(f, "{}({})", typename, fields.join(", "))
+            }
+            Pattern::Typed(pattern, typ) => write!(f, "{pattern}: {typ}"),
             Pattern::Interned(_, _) => {
                 write!(f, "?Interned")
             }
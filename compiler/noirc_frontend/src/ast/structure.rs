@@ -17,12 +17,21 @@ pub struct NoirStruct {
     pub generics: UnresolvedGenerics,
     pub fields: Vec<Documented<StructField>>,
     pub location: Location,
+    /// True if this struct was declared as a tuple struct, e.g. `struct Foo(Field, u8);`.
+    /// Its fields are then named after their position (`0`, `1`, ...).
+    pub is_tuple_struct: bool,
 }
 
 impl NoirStruct {
     pub fn is_abi(&self) -> bool {
         self.attributes.iter().any(|attr| attr.is_abi())
     }
+
+    /// The visibility of a tuple struct's constructor function. Like in Rust, the constructor
+    /// is only as visible as the struct and all of its fields.
+    pub fn constructor_visibility(&self) -> ItemVisibility {
+        self.fields.iter().map(|field| field.item.visibility).fold(self.visibility, std::cmp::min)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -37,6 +46,11 @@ impl Display for NoirStruct {
         let generics = vecmap(&self.generics, |generic| generic.to_string());
         let generics = if generics.is_empty() { "".into() } else { generics.join(", ") };
 
+        if self.is_tuple_struct {
+            let fields = vecmap(&self.fields, |field| field.item.typ.to_string());
+            return write!(f, "struct {}{}({});", self.name, generics, fields.join(", "));
+        }
+
         writeln!(f, "struct {}{} {{", self.name, generics)?;
 
         for field in self.fields.iter() {
@@ -1,4 +1,5 @@
 use super::{Ident, ItemVisibility, UnresolvedGenerics, UnresolvedType};
+use crate::token::SecondaryAttribute;
 use iter_extended::vecmap;
 use noirc_errors::Location;
 use std::fmt::Display;
@@ -7,6 +8,7 @@ use std::fmt::Display;
 #[derive(Clone, Debug)]
 pub struct NoirTypeAlias {
     pub name: Ident,
+    pub attributes: Vec<SecondaryAttribute>,
     pub generics: UnresolvedGenerics,
     pub typ: UnresolvedType,
     pub visibility: ItemVisibility,
@@ -23,9 +23,9 @@ use crate::{
 
 use super::{
     ForBounds, FunctionReturnType, GenericTypeArgs, IntegerBitSize, ItemVisibility,
-    MatchExpression, NoirEnumeration, Pattern, Signedness, TraitBound, TraitImplItemKind, TypePath,
-    UnresolvedGeneric, UnresolvedGenerics, UnresolvedTraitConstraint, UnresolvedType,
-    UnresolvedTypeData, UnresolvedTypeExpression, UnsafeExpression,
+    MatchExpression, NoirEnumeration, Pattern, RangeExpression, Signedness, TraitBound,
+    TraitImplItemKind, TypePath, UnresolvedGeneric, UnresolvedGenerics, UnresolvedTraitConstraint,
+    UnresolvedType, UnresolvedTypeData, UnresolvedTypeExpression, UnsafeExpression,
 };
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -227,6 +227,10 @@ pub trait Visitor {
         true
     }
 
+    fn visit_range_expression(&mut self, _: &RangeExpression, _: Span) -> bool {
+        true
+    }
+
     fn visit_tuple(&mut self, _: &[Expression], _: Span) -> bool {
         true
     }
@@ -353,6 +357,10 @@ pub trait Visitor {
 
     fn visit_lvalue_interned(&mut self, _id: InternedExpressionKind, _span: Span) {}
 
+    fn visit_lvalue_tuple(&mut self, _lvalues: &[LValue], _span: Span) -> bool {
+        true
+    }
+
     fn visit_for_range(&mut self, _: &ForRange) -> bool {
         true
     }
@@ -516,6 +524,14 @@ pub trait Visitor {
         true
     }
 
+    fn visit_tuple_struct_pattern(&mut self, _: &Path, _: &[Pattern], _: Span) -> bool {
+        true
+    }
+
+    fn visit_typed_pattern(&mut self, _: &Pattern, _: &UnresolvedType) -> bool {
+        true
+    }
+
     fn visit_interned_pattern(&mut self, _: &InternedPattern, _: Span) {}
 
     fn visit_secondary_attribute(
@@ -923,6 +939,9 @@ impl Expression {
             ExpressionKind::Match(match_expression) => {
                 match_expression.accept(span, visitor);
             }
+            ExpressionKind::Range(range_expression) => {
+                range_expression.accept(span, visitor);
+            }
             ExpressionKind::Tuple(expressions) => {
                 if visitor.visit_tuple(expressions, span) {
                     visit_expressions(expressions, visitor);
@@ -1148,6 +1167,19 @@ impl MatchExpression {
     }
 }
 
+impl RangeExpression {
+    pub fn accept(&self, span: Span, visitor: &mut impl Visitor) {
+        if visitor.visit_range_expression(self, span) {
+            self.accept_children(visitor);
+        }
+    }
+
+    pub fn accept_children(&self, visitor: &mut impl Visitor) {
+        self.start.accept(visitor);
+        self.end.accept(visitor);
+    }
+}
+
 impl Lambda {
     pub fn accept(&self, span: Span, visitor: &mut impl Visitor) {
         if visitor.visit_lambda(self, span) {
@@ -1319,6 +1351,13 @@ impl LValue {
                 }
             }
             LValue::Interned(id, location) => visitor.visit_lvalue_interned(*id, location.span),
+            LValue::Tuple(lvalues, location) => {
+                if visitor.visit_lvalue_tuple(lvalues, location.span) {
+                    for lvalue in lvalues {
+                        lvalue.accept(visitor);
+                    }
+                }
+            }
         }
     }
 }
@@ -1599,7 +1638,7 @@ impl Pattern {
                     }
                 }
             }
-            Pattern::Struct(path, fields, location) => {
+            Pattern::Struct(path, fields, location, _) => {
                 if visitor.visit_struct_pattern(path, fields, location.span) {
                     path.accept(visitor);
                     for (_, pattern) in fields {
@@ -1607,6 +1646,20 @@ impl Pattern {
                     }
                 }
             }
+            Pattern::TupleStruct(path, patterns, location) => {
+                if visitor.visit_tuple_struct_pattern(path, patterns, location.span) {
+                    path.accept(visitor);
+                    for pattern in patterns {
+                        pattern.accept(visitor);
+                    }
+                }
+            }
+            Pattern::Typed(pattern, typ) => {
+                if visitor.visit_typed_pattern(pattern, typ) {
+                    pattern.accept(visitor);
+                    typ.accept(visitor);
+                }
+            }
             Pattern::Interned(id, location) => {
                 visitor.visit_interned_pattern(id, location.span);
             }
@@ -349,6 +349,9 @@ impl DebugInstrumenter {
                         ast::LValue::Interned(..) => {
                             unimplemented![]
                         }
+                        ast::LValue::Tuple(..) => {
+                            unreachable!("tuple assignments are desugared before being walked")
+                        }
                     }
                 }
                 build_assign_member_stmt(
@@ -483,6 +486,13 @@ impl DebugInstrumenter {
             ast::StatementKind::Let(let_stmt) => {
                 *stmt = self.walk_let_statement(let_stmt, stmt.location);
             }
+            ast::StatementKind::Assign(assign_stmt)
+                if matches!(assign_stmt.lvalue, ast::LValue::Tuple(..)) =>
+            {
+                // Each place of the tuple is instrumented in the desugared assignment
+                *stmt = assign_stmt.clone().into_destructuring(stmt.location);
+                self.walk_statement(stmt);
+            }
             ast::StatementKind::Assign(assign_stmt) => {
                 *stmt = self.walk_assign_statement(assign_stmt, stmt.location);
             }
@@ -719,10 +729,16 @@ fn pattern_vars(pattern: &ast::Pattern) -> Vec<(ast::Ident, bool)> {
             ast::Pattern::Tuple(patterns, _) => {
                 stack.extend(patterns.iter().map(|pattern| (pattern, false)));
             }
-            ast::Pattern::Struct(_, pids, _) => {
+            ast::Pattern::Struct(_, pids, _, _) => {
                 stack.extend(pids.iter().map(|(_, pattern)| (pattern, is_mut)));
                 vars.extend(pids.iter().map(|(id, _)| (id.clone(), false)));
             }
+            ast::Pattern::TupleStruct(_, patterns, _) => {
+                stack.extend(patterns.iter().map(|pattern| (pattern, is_mut)));
+            }
+            ast::Pattern::Typed(pattern, _) => {
+                stack.push_back((pattern, is_mut));
+            }
             ast::Pattern::Interned(_, _) => (),
         }
     }
@@ -737,7 +753,7 @@ fn pattern_to_string(pattern: &ast::Pattern) -> String {
             "({})",
             elements.iter().map(pattern_to_string).collect::<Vec<String>>().join(", ")
         ),
-        ast::Pattern::Struct(name, fields, _) => {
+        ast::Pattern::Struct(name, fields, _, _) => {
             format!(
                 "{} {{ {} }}",
                 name,
@@ -750,6 +766,12 @@ fn pattern_to_string(pattern: &ast::Pattern) -> String {
                     .join(", "),
             )
         }
+        ast::Pattern::TupleStruct(name, fields, _) => format!(
+            "{}({})",
+            name,
+            fields.iter().map(pattern_to_string).collect::<Vec<String>>().join(", ")
+        ),
+        ast::Pattern::Typed(pattern, typ) => format!("{}: {typ}", pattern_to_string(pattern)),
         ast::Pattern::Interned(_, _) => "?Interned".to_string(),
     }
 }
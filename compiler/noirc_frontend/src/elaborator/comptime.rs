@@ -253,6 +253,9 @@ impl<'context> Elaborator<'context> {
             let items =
                 value.into_top_level_items(location, self).map_err(CompilationError::from)?;
 
+            for item in &items {
+                self.interner.add_generated_item(item.location, function, location);
+            }
             self.add_items(items, generated_items, location);
         }
 
@@ -9,19 +9,25 @@ use crate::{
     DataType, Kind, Shared, Type,
     ast::{
         ConstructorExpression, EnumVariant, Expression, ExpressionKind, FunctionKind, Ident,
-        Literal, NoirEnumeration, StatementKind, UnresolvedType,
+        Literal, NoirEnumeration, RangeExpression, StatementKind, UnresolvedType,
+        UnresolvedTypeData,
     },
     elaborator::path_resolution::PathResolutionItem,
-    hir::{comptime::Value, resolution::errors::ResolverError, type_check::TypeCheckError},
+    hir::{
+        comptime::Value, def_collector::dc_crate::UnresolvedStruct, def_map::ModuleId,
+        resolution::errors::ResolverError, type_check::TypeCheckError,
+    },
     hir_def::{
         expr::{
-            Case, Constructor, HirBlockExpression, HirEnumConstructorExpression, HirExpression,
-            HirIdent, HirMatch,
+            Case, Constructor, HirBlockExpression, HirConstructorExpression,
+            HirEnumConstructorExpression, HirExpression, HirIdent, HirMatch,
         },
         function::{FuncMeta, FunctionBody, HirFunction, Parameters},
         stmt::{HirLetStatement, HirPattern, HirStatement},
     },
-    node_interner::{DefinitionId, DefinitionKind, ExprId, FunctionModifiers, GlobalValue, TypeId},
+    node_interner::{
+        DefinitionId, DefinitionKind, ExprId, FuncId, FunctionModifiers, GlobalValue, TypeId,
+    },
     shared::Visibility,
     signed_field::SignedField,
     token::Attributes,
@@ -55,8 +61,7 @@ enum Pattern {
     Or(Vec<Pattern>),
 
     /// An integer range pattern such as `1..20` which will match any integer n such that
-    /// 1 <= n < 20.
-    #[allow(unused)]
+    /// 1 <= n < 20. Inclusive ranges such as `1..=19` are converted to this form.
     Range(SignedField, SignedField),
 
     /// An error occurred while translating this pattern. This Pattern kind always translates
@@ -250,6 +255,7 @@ impl Elaborator<'_> {
             trait_id: None,
             trait_impl: None,
             enum_variant_index: Some(variant_index),
+            is_tuple_struct_constructor: false,
             is_entry_point: false,
             has_inline_attribute: false,
             function_body: FunctionBody::Resolved,
@@ -268,6 +274,104 @@ impl Elaborator<'_> {
             .ok();
     }
 
+    /// Defines the constructor function of a tuple struct, which was declared during
+    /// def collection. E.g. for `struct Foo(u32, u8);` this defines (pseudocode):
+    /// ```text
+    /// fn Foo(a: u32, b: u8) -> Foo {
+    ///     Foo { 0: a, 1: b }
+    /// }
+    /// ```
+    pub(super) fn define_tuple_struct_constructor(
+        &mut self,
+        unresolved: &UnresolvedStruct,
+        type_id: TypeId,
+        func_id: FuncId,
+    ) {
+        let datatype = self.interner.get_type(type_id);
+        let datatype_ref = datatype.borrow();
+        let name = &unresolved.struct_def.name;
+        let location = name.location();
+
+        let fields = datatype_ref.fields_raw().expect("Expected a struct").to_vec();
+        let field_types = vecmap(&fields, |field| field.typ.clone());
+
+        let module_id = ModuleId { krate: self.crate_id, local_id: unresolved.module_id };
+        let modifiers = FunctionModifiers {
+            name: name.to_string(),
+            visibility: unresolved.struct_def.constructor_visibility(),
+            attributes: Attributes { function: None, secondary: Vec::new() },
+            is_unconstrained: false,
+            generic_count: datatype_ref.generics.len(),
+            is_comptime: false,
+            name_location: location,
+        };
+        let definition_id =
+            self.interner.push_function_definition(func_id, modifiers, module_id, location);
+
+        let hir_name = HirIdent::non_trait_method(definition_id, location);
+        let parameters = self.make_enum_variant_parameters(field_types.clone(), location);
+
+        let generics = datatype_ref.generic_types();
+        let self_type = Type::DataType(datatype.clone(), generics.clone());
+
+        let constructor_fields = vecmap(fields.iter().zip(&parameters.0), |(field, parameter)| {
+            let (HirPattern::Identifier(ident), typ, _) = parameter else { unreachable!() };
+            let id = self.interner.push_expr(HirExpression::Ident(ident.clone(), None));
+            self.interner.push_expr_type(id, typ.clone());
+            self.interner.push_expr_location(id, location);
+            (field.name.clone(), id)
+        });
+
+        let constructor = HirExpression::Constructor(HirConstructorExpression {
+            r#type: datatype.clone(),
+            struct_generics: generics,
+            fields: constructor_fields,
+        });
+        let body = self.interner.push_expr(constructor);
+        self.interner.push_expr_type(body, self_type.clone());
+        self.interner.push_expr_location(body, location);
+        self.interner.update_fn(func_id, HirFunction::unchecked_from_expr(body));
+
+        let typevars = vecmap(&datatype_ref.generics, |generic| generic.type_var.clone());
+        let function_type =
+            Type::Function(field_types, Box::new(self_type.clone()), Box::new(Type::Unit), false);
+        let function_type = Type::Forall(typevars, Box::new(function_type));
+        self.interner.push_definition_type(definition_id, function_type.clone());
+
+        let self_type_id = self.interner.push_quoted_type(self_type);
+        let return_type =
+            UnresolvedType { typ: UnresolvedTypeData::Resolved(self_type_id), location };
+
+        let meta = FuncMeta {
+            name: hir_name,
+            kind: FunctionKind::Normal,
+            parameters,
+            parameter_idents: Vec::new(),
+            return_type: crate::ast::FunctionReturnType::Ty(return_type),
+            return_visibility: Visibility::Private,
+            typ: function_type,
+            direct_generics: datatype_ref.generics.clone(),
+            all_generics: datatype_ref.generics.clone(),
+            location,
+            has_body: false,
+            trait_constraints: Vec::new(),
+            type_id: None,
+            trait_id: None,
+            trait_impl: None,
+            enum_variant_index: None,
+            is_tuple_struct_constructor: true,
+            is_entry_point: false,
+            has_inline_attribute: false,
+            function_body: FunctionBody::Resolved,
+            source_crate: self.crate_id,
+            source_module: unresolved.module_id,
+            source_file: unresolved.file_id,
+            self_type: None,
+        };
+
+        self.interner.push_fn_meta(meta, func_id);
+    }
+
     // Given:
     // ```
     // enum FooEnum { Foo(u32, u8), ... }
@@ -460,6 +564,9 @@ impl Elaborator<'_> {
                 Pattern::Constructor(Constructor::Tuple(field_types.clone()), fields)
             }
 
+            ExpressionKind::Range(range) => {
+                self.range_to_pattern(*range, expected_type, expression.location)
+            }
             ExpressionKind::Parenthesized(expr) => {
                 self.expression_to_pattern(*expr, expected_type, variables_defined)
             }
@@ -499,6 +606,50 @@ impl Elaborator<'_> {
         }
     }
 
+    /// Convert a range such as `0..10` or `0..=9` into a Pattern. Both ends must be integer
+    /// literals of the type being matched on, and the range must not be empty.
+    fn range_to_pattern(
+        &mut self,
+        range: RangeExpression,
+        expected_type: &Type,
+        location: Location,
+    ) -> Pattern {
+        let actual = self.interner.next_type_variable_with_kind(Kind::Integer);
+        self.unify(&actual, expected_type, || TypeCheckError::TypeMismatch {
+            expected_typ: expected_type.to_string(),
+            expr_typ: actual.to_string(),
+            expr_location: location,
+        });
+
+        let range_string = range.to_string();
+        let start = self.range_bound_in_pattern(range.start);
+        let end = self.range_bound_in_pattern(range.end);
+        let (Some(start), Some(end)) = (start, end) else {
+            return Pattern::Error;
+        };
+
+        // Our ranges are exclusive, so adjust for that
+        let end = if range.inclusive { end.add_one() } else { end };
+        if start >= end {
+            self.push_err(ResolverError::EmptyRangeInPattern { range: range_string, location });
+            return Pattern::Error;
+        }
+
+        Pattern::Range(start, end)
+    }
+
+    fn range_bound_in_pattern(&mut self, bound: Expression) -> Option<SignedField> {
+        match bound.kind {
+            ExpressionKind::Literal(Literal::Integer(value)) => Some(value),
+            ExpressionKind::Parenthesized(expr) => self.range_bound_in_pattern(*expr),
+            _ => {
+                let location = bound.location;
+                self.push_err(ResolverError::InvalidSyntaxInPattern { location });
+                None
+            }
+        }
+    }
+
     fn define_pattern_variable(
         &mut self,
         name: Ident,
@@ -544,8 +695,9 @@ impl Elaborator<'_> {
             let Some(field_index) =
                 expected_field_types.iter().position(|(name, _)| *name == field_name.as_str())
             else {
-                let error = if fields.contains_key(field_name.as_str()) {
-                    ResolverError::DuplicateField { field: field_name }
+                let error = if let Some((first_location, _)) = fields.get(field_name.as_str()) {
+                    let first_location = *first_location;
+                    ResolverError::DuplicateField { field: field_name, first_location }
                 } else {
                     let struct_definition = struct_name.clone();
                     ResolverError::NoSuchField { field: field_name, struct_definition }
@@ -554,21 +706,33 @@ impl Elaborator<'_> {
                 continue;
             };
 
+            let location = field_name.location();
             let (field_name, expected_field_type) = expected_field_types.swap_remove(field_index);
             let pattern =
                 self.expression_to_pattern(field, &expected_field_type, variables_defined);
-            fields.insert(field_name, pattern);
+            fields.insert(field_name, (location, pattern));
         }
 
         if !expected_field_types.is_empty() {
             let struct_definition = struct_name;
-            let missing_fields = vecmap(expected_field_types, |(name, _)| name);
-            let error =
-                ResolverError::MissingFields { location, missing_fields, struct_definition };
+            let missing_fields = match typ.follow_bindings_shallow().as_ref() {
+                Type::DataType(data_type, _) => data_type.borrow().field_locations(|field_name| {
+                    expected_field_types.iter().any(|(name, _)| name == field_name)
+                }),
+                // Without the struct definition we can't point at the declarations,
+                // so fall back to the location of the pattern itself.
+                _ => vecmap(expected_field_types, |(name, _)| (name, location)),
+            };
+            let error = ResolverError::MissingFields {
+                location,
+                missing_fields,
+                struct_definition,
+                suggest_rest: false,
+            };
             self.push_err(error);
         }
 
-        let args = vecmap(fields, |(_name, field)| field);
+        let args = vecmap(fields, |(_name, (_location, field))| field);
         Pattern::Constructor(Constructor::Variant(typ, 0), args)
     }
 
@@ -686,6 +850,19 @@ impl Elaborator<'_> {
 
                 (actual_type, expected_arg_types, variant_index)
             }
+            PathResolutionItem::ModuleFunction(func_id)
+                if self.interner.function_meta(func_id).is_tuple_struct_constructor =>
+            {
+                // A tuple struct is matched like a struct, as the only variant of its type
+                let meta = self.interner.function_meta(func_id);
+                let (actual_type, expected_arg_types) = match meta.typ.instantiate(self.interner).0
+                {
+                    Type::Function(args, ret, _env, _) => (*ret, args),
+                    other => unreachable!("Not a function! Found {other}"),
+                };
+
+                (actual_type, expected_arg_types, 0)
+            }
             PathResolutionItem::Module(_)
             | PathResolutionItem::Type(_)
             | PathResolutionItem::TypeAlias(_)
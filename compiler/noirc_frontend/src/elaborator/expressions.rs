@@ -1,7 +1,7 @@
 use acvm::{AcirField, FieldElement};
 use iter_extended::vecmap;
 use noirc_errors::{Located, Location};
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::FxHashMap as HashMap;
 
 use crate::{
     DataType, Kind, QuotedType, Shared, Type,
@@ -26,8 +26,8 @@ use crate::{
             HirArrayLiteral, HirBinaryOp, HirBlockExpression, HirCallExpression, HirCastExpression,
             HirConstrainExpression, HirConstructorExpression, HirExpression, HirIdent,
             HirIfExpression, HirIndexExpression, HirInfixExpression, HirLambda, HirLiteral,
-            HirMatch, HirMemberAccess, HirMethodCallExpression, HirPrefixExpression, ImplKind,
-            TraitMethod,
+            HirMatch, HirMemberAccess, HirMethodCallExpression, HirMethodReference,
+            HirPrefixExpression, ImplKind, TraitMethod,
         },
         stmt::{HirLetStatement, HirPattern, HirStatement},
         traits::{ResolvedTraitBound, TraitConstraint},
@@ -95,6 +95,10 @@ impl Elaborator<'_> {
                 self.push_err(ResolverError::UnquoteUsedOutsideQuote { location: expr.location });
                 (HirExpression::Error, Type::Error)
             }
+            ExpressionKind::Range(_) => {
+                self.push_err(ResolverError::RangeUsedOutsidePattern { location: expr.location });
+                (HirExpression::Error, Type::Error)
+            }
             ExpressionKind::AsTraitPath(path) => {
                 return self.elaborate_as_trait_path(path);
             }
@@ -545,10 +549,18 @@ impl Elaborator<'_> {
                         &mut object,
                     );
 
+                    let turbofish_location = method_call
+                        .generics
+                        .iter()
+                        .flatten()
+                        .map(|typ| typ.location)
+                        .reduce(|first, last| first.merge(last))
+                        .unwrap_or(location);
                     self.resolve_function_turbofish_generics(
                         &func_id,
                         method_call.generics,
                         location,
+                        turbofish_location,
                     )
                 } else {
                     None
@@ -617,6 +629,13 @@ impl Elaborator<'_> {
 
                 self.interner.add_function_reference(func_id, method_name_location);
 
+                // Trait methods are recorded once their impl is selected
+                if let (HirMethodReference::FuncId(func_id), Some(current_item)) =
+                    (&method_ref, self.current_item)
+                {
+                    self.interner.add_function_dependency(current_item, *func_id);
+                }
+
                 // Type check the new call now that it has been changed from a method call
                 // to a function call. This way we avoid duplicating code.
                 let mut typ =
@@ -854,7 +873,7 @@ impl Elaborator<'_> {
         location: Location,
     ) -> Vec<(Ident, ExprId)> {
         let mut ret = Vec::with_capacity(fields.len());
-        let mut seen_fields = HashSet::default();
+        let mut seen_fields = HashMap::default();
         let mut unseen_fields = struct_type
             .borrow()
             .field_names()
@@ -875,7 +894,7 @@ impl Elaborator<'_> {
 
             if unseen_fields.contains(&field_name) {
                 unseen_fields.remove(&field_name);
-                seen_fields.insert(field_name.clone());
+                seen_fields.insert(field_name.clone(), field_name.location());
 
                 self.unify_with_coercions(
                     &field_type,
@@ -888,9 +907,11 @@ impl Elaborator<'_> {
                         expr_location: field_location,
                     },
                 );
-            } else if seen_fields.contains(&field_name) {
+            } else if let Some(first_location) = seen_fields.get(&field_name) {
                 // duplicate field
-                self.push_err(ResolverError::DuplicateField { field: field_name.clone() });
+                let first_location = *first_location;
+                let field = field_name.clone();
+                self.push_err(ResolverError::DuplicateField { field, first_location });
             } else {
                 // field not required by struct
                 self.push_err(ResolverError::NoSuchField {
@@ -917,10 +938,13 @@ impl Elaborator<'_> {
         }
 
         if !unseen_fields.is_empty() {
+            let struct_type = struct_type.borrow();
             self.push_err(ResolverError::MissingFields {
                 location,
-                missing_fields: unseen_fields.into_iter().map(|field| field.to_string()).collect(),
-                struct_definition: struct_type.borrow().name.clone(),
+                missing_fields: struct_type
+                    .field_locations(|name| unseen_fields.iter().any(|field| field == name)),
+                struct_definition: struct_type.name.clone(),
+                suggest_rest: false,
             });
         }
 
@@ -22,7 +22,7 @@ use crate::{
     graph::CrateId,
     hir::{
         Context,
-        comptime::ComptimeError,
+        comptime::{ComptimeError, InterpreterError},
         def_collector::{
             dc_crate::{
                 CollectedItems, CompilationError, ImplMap, UnresolvedEnum, UnresolvedFunctions,
@@ -68,9 +68,11 @@ use im::HashSet;
 use iter_extended::vecmap;
 use noirc_errors::{Located, Location};
 pub(crate) use options::ElaboratorOptions;
-pub use options::{FrontendOptions, UnstableFeature};
+pub use options::{DEFAULT_PATTERN_DEPTH_LIMIT, FrontendOptions, UnstableFeature};
 pub use path_resolution::Turbofish;
 use path_resolution::{PathResolution, PathResolutionItem};
+use patterns::ResolvedStructFields;
+use rustc_hash::FxHashMap as HashMap;
 use types::bind_ordered_generics;
 
 use self::traits::check_trait_impl_method_matches_declaration;
@@ -202,6 +204,14 @@ pub struct Elaborator<'context> {
     /// The Elaborator keeps track of these reasons so that when an error is produced it will
     /// be wrapped in another error that will include this reason.
     pub(crate) elaborate_reasons: im::Vector<ElaborateReason>,
+
+    /// The fields of each struct type used in a struct pattern, keyed by the struct and the
+    /// generic arguments it was used with. Generated code can contain many identical
+    /// struct patterns, which can then share the same field types.
+    struct_pattern_fields: HashMap<(TypeId, Vec<Type>), ResolvedStructFields>,
+
+    /// How many struct patterns reused fields from `struct_pattern_fields`
+    pub(crate) struct_pattern_field_cache_hits: usize,
 }
 
 #[derive(Copy, Clone)]
@@ -297,6 +307,8 @@ impl<'context> Elaborator<'context> {
             silence_field_visibility_errors: 0,
             options,
             elaborate_reasons,
+            struct_pattern_fields: HashMap::default(),
+            struct_pattern_field_cache_hits: 0,
         }
     }
 
@@ -413,7 +425,28 @@ impl<'context> Elaborator<'context> {
             self.elaborate_trait_impl(trait_impl);
         }
 
-        self.push_errors(self.interner.check_for_dependency_cycles());
+        let cycle_errors = self.interner.check_for_dependency_cycles();
+
+        // Globals in a dependency cycle also fail to evaluate. The interpreter can't tell which
+        // globals are involved so those errors are dropped in favor of the cycle's error.
+        let has_global_cycle = cycle_errors.iter().any(|error| {
+            matches!(
+                error,
+                CompilationError::ResolverError(ResolverError::GlobalDependencyCycle { .. })
+            )
+        });
+        if has_global_cycle {
+            self.errors.retain(|error| {
+                !matches!(
+                    error,
+                    CompilationError::InterpreterError(
+                        InterpreterError::GlobalsDependencyCycle { .. }
+                    )
+                )
+            });
+        }
+
+        self.push_errors(cycle_errors);
     }
 
     /// True if we should use pedantic ACVM solving
@@ -1064,6 +1097,7 @@ impl<'context> Elaborator<'context> {
             trait_id,
             trait_impl: self.current_trait_impl,
             enum_variant_index: None,
+            is_tuple_struct_constructor: false,
             parameters: parameters.into(),
             parameter_idents,
             return_type: func.def.return_type.clone(),
@@ -1865,6 +1899,10 @@ impl<'context> Elaborator<'context> {
             self.interner.update_type(*type_id, |struct_def| {
                 struct_def.set_fields(fields);
             });
+
+            if let Some(constructor) = typ.constructor {
+                self.define_tuple_struct_constructor(typ, *type_id, constructor);
+            }
         }
 
         // Check whether the struct fields have nested slices
@@ -1993,6 +2031,10 @@ impl<'context> Elaborator<'context> {
             self.push_err(ResolverError::MutableGlobal { location });
         }
 
+        // Mark the global as being resolved so that referring to it from its own initializer,
+        // even through other globals, is reported as a dependency cycle.
+        self.interner.get_global_mut(global_id).value = GlobalValue::Resolving;
+
         let (let_statement, _typ) = self
             .elaborate_in_comptime_context(|this| this.elaborate_let(let_stmt, Some(global_id)));
 
@@ -2029,6 +2071,7 @@ impl<'context> Elaborator<'context> {
         if let Err(error) = interpreter.evaluate_let(let_statement) {
             let error: CompilationError = error.into();
             self.push_err(error);
+            self.interner.get_global_mut(global_id).value = GlobalValue::Unresolved;
         } else {
             let value = interpreter
                 .lookup_id(definition_id, location)
@@ -27,6 +27,9 @@ impl FromStr for UnstableFeature {
     }
 }
 
+/// The default value of [GenericOptions::pattern_depth_limit]
+pub const DEFAULT_PATTERN_DEPTH_LIMIT: usize = 256;
+
 /// Generic options struct meant to resolve to ElaboratorOptions below when
 /// we can resolve a file path to a file id later. This generic struct is used
 /// so that FrontendOptions doesn't need to duplicate fields and methods with ElaboratorOptions.
@@ -41,6 +44,15 @@ pub struct GenericOptions<'a, T> {
     /// Unstable compiler features that were explicitly enabled. Any unstable features
     /// that are not in this list result in an error when used.
     pub enabled_unstable_features: &'a [UnstableFeature],
+
+    /// How deeply patterns may be nested before the elaborator reports an error
+    /// instead of recursing further
+    pub pattern_depth_limit: usize,
+
+    /// Features enabled for conditional compilation of the crate being compiled. Items marked
+    /// `#[cfg(feature = "...")]` with any other feature are left out of it, and dependencies,
+    /// the standard library included, are compiled without any feature.
+    pub enabled_features: &'a [String],
 }
 
 /// Options from nargo_cli that need to be passed down to the elaborator
@@ -57,6 +69,8 @@ impl<T> GenericOptions<'_, T> {
             debug_comptime_in_file: None,
             pedantic_solving: true,
             enabled_unstable_features: &[UnstableFeature::Enums],
+            pattern_depth_limit: DEFAULT_PATTERN_DEPTH_LIMIT,
+            enabled_features: &[],
         }
     }
 }
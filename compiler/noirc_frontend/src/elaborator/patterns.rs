@@ -1,9 +1,11 @@
+use std::rc::Rc;
+
 use iter_extended::vecmap;
 use noirc_errors::Location;
-use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::FxHashMap as HashMap;
 
 use crate::{
-    DataType, Kind, Shared, Type, TypeAlias, TypeBindings,
+    DataType, Kind, ResolvedGeneric, Shared, Type, TypeAlias, TypeBindings,
     ast::{
         ERROR_IDENT, Expression, ExpressionKind, GenericTypeArgs, Ident, ItemVisibility, Path,
         Pattern, TypePath, UnresolvedType,
@@ -20,10 +22,15 @@ use crate::{
     node_interner::{
         DefinitionId, DefinitionInfo, DefinitionKind, ExprId, FuncId, GlobalId, TraitImplKind,
     },
+    token::SecondaryAttribute,
 };
 
 use super::{Elaborator, ResolverMeta, path_resolution::PathResolutionItem};
 
+/// The name, visibility and type of each field of a struct type, in declaration order,
+/// after applying the struct's generic arguments.
+pub(super) type ResolvedStructFields = Rc<Vec<(String, ItemVisibility, Type)>>;
+
 impl Elaborator<'_> {
     pub(super) fn elaborate_pattern(
         &mut self,
@@ -39,6 +46,7 @@ impl Elaborator<'_> {
             None,
             &mut Vec::new(),
             warn_if_unused,
+            0, // depth
         )
     }
 
@@ -59,9 +67,13 @@ impl Elaborator<'_> {
             None,
             created_ids,
             warn_if_unused,
+            0, // depth
         )
     }
 
+    /// `depth` is the number of patterns enclosing this one. Patterns nested deeper than
+    /// the configured limit are reported as an error rather than elaborated, so that
+    /// generated code can't overflow the stack here.
     #[allow(clippy::too_many_arguments)]
     fn elaborate_pattern_mut(
         &mut self,
@@ -71,7 +83,17 @@ impl Elaborator<'_> {
         mutable: Option<Location>,
         new_definitions: &mut Vec<HirIdent>,
         warn_if_unused: bool,
+        depth: usize,
     ) -> HirPattern {
+        let limit = self.options.pattern_depth_limit;
+        if depth > limit {
+            let location = pattern.location();
+            self.push_err(ResolverError::PatternTooDeep { limit, location });
+            return self.error_identifier_pattern(definition);
+        }
+
+        self.check_no_mutable_binding_through_mutable_reference(&pattern, &expected_type, mutable);
+
         match pattern {
             Pattern::Identifier(name) => {
                 // If this definition is mutable, do not store the rhs because it will
@@ -113,11 +135,12 @@ impl Elaborator<'_> {
                     Some(location),
                     new_definitions,
                     warn_if_unused,
+                    depth + 1,
                 );
                 HirPattern::Mutable(Box::new(pattern), location)
             }
             Pattern::Tuple(fields, location) => {
-                let field_types = match expected_type.follow_bindings() {
+                let field_types = match self.follow_bindings_through_references(&expected_type) {
                     Type::Tuple(fields) => fields,
                     Type::Error => Vec::new(),
                     expected_type => {
@@ -143,19 +166,63 @@ impl Elaborator<'_> {
                         mutable,
                         new_definitions,
                         warn_if_unused,
+                        depth + 1,
                     )
                 });
                 HirPattern::Tuple(fields, location)
             }
-            Pattern::Struct(name, fields, location) => self.elaborate_struct_pattern(
+            Pattern::Struct(name, fields, location, has_rest) => self.elaborate_struct_pattern(
                 name,
                 fields,
+                has_rest,
+                false, // is_tuple_pattern
                 location,
-                expected_type,
+                self.follow_bindings_through_references(&expected_type),
                 definition,
                 mutable,
                 new_definitions,
+                depth,
             ),
+            Pattern::TupleStruct(name, patterns, location) => {
+                // Tuple struct fields are named after their position
+                let fields = vecmap(patterns.into_iter().enumerate(), |(index, pattern)| {
+                    (Ident::new(index.to_string(), pattern.location()), pattern)
+                });
+                self.elaborate_struct_pattern(
+                    name,
+                    fields,
+                    false, // has_rest
+                    true,  // is_tuple_pattern
+                    location,
+                    self.follow_bindings_through_references(&expected_type),
+                    definition,
+                    mutable,
+                    new_definitions,
+                    depth,
+                )
+            }
+            Pattern::Typed(pattern, typ) => {
+                let location = typ.location;
+                let annotated_type = self.resolve_type(typ);
+                self.unify(&expected_type, &annotated_type, || {
+                    TypeCheckError::TypeMismatchWithSource {
+                        expected: annotated_type.clone(),
+                        actual: expected_type.clone(),
+                        location,
+                        source: Source::Assignment,
+                    }
+                });
+
+                self.elaborate_pattern_mut(
+                    *pattern,
+                    annotated_type,
+                    definition,
+                    mutable,
+                    new_definitions,
+                    warn_if_unused,
+                    depth + 1,
+                )
+            }
             Pattern::Interned(id, _) => {
                 let pattern = self.interner.get_pattern(id).clone();
                 self.elaborate_pattern_mut(
@@ -165,59 +232,174 @@ impl Elaborator<'_> {
                     mutable,
                     new_definitions,
                     warn_if_unused,
+                    depth + 1,
                 )
             }
         }
     }
 
+    /// Follows the bindings of `typ`. If it is still an unbound type variable which stands for
+    /// an associated type of a pending trait constraint, e.g. the `<T as Foo>::Output` returned
+    /// by a call to a generic function, and the impl of that constraint can already be found,
+    /// the associated type is bound to its concrete type so patterns can be matched against it.
+    fn follow_bindings_and_associated_types(&self, typ: &Type) -> Type {
+        let typ = typ.follow_bindings();
+        let Type::TypeVariable(variable) = &typ else {
+            return typ;
+        };
+        let Some(context) = self.function_context.last() else {
+            return typ;
+        };
+
+        for (constraint, _, _) in &context.trait_constraints {
+            let trait_generics = &constraint.trait_bound.trait_generics;
+            let is_associated_type = trait_generics.named.iter().any(|named| {
+                let associated_type = named.typ.follow_bindings();
+                matches!(associated_type, Type::TypeVariable(other) if other.id() == variable.id())
+            });
+            if !is_associated_type {
+                continue;
+            }
+
+            // The constraint is checked again once the function is elaborated, which is also
+            // when any error finding its impl is reported.
+            if let Ok((_, bindings)) = self.interner.try_lookup_trait_implementation(
+                &constraint.typ,
+                constraint.trait_bound.trait_id,
+                &trait_generics.ordered,
+                &trait_generics.named,
+            ) {
+                Type::apply_type_bindings(bindings);
+                return typ.follow_bindings();
+            }
+        }
+
+        typ
+    }
+
+    /// Follows the bindings of `typ` like [Self::follow_bindings_and_associated_types], then
+    /// peels off any references, so that a tuple or struct pattern matched against `&T` or
+    /// `&mut T` destructures the `T` behind it.
+    ///
+    /// The variables bound by such a pattern hold copies of the fields rather than references
+    /// to them. Unlike Rust's default binding modes, no reference to a field is created, as
+    /// documented in the "Destructuring references" section of the references docs.
+    fn follow_bindings_through_references(&self, typ: &Type) -> Type {
+        let mut typ = self.follow_bindings_and_associated_types(typ);
+        while let Type::Reference(element, _) = typ {
+            typ = self.follow_bindings_and_associated_types(&element);
+        }
+        typ
+    }
+
+    /// Reports an error if a tuple or struct pattern destructuring a mutable reference binds a
+    /// variable mutably. As the variable would hold a copy of its field, assigning to it would
+    /// silently leave the referenced value unchanged.
+    fn check_no_mutable_binding_through_mutable_reference(
+        &mut self,
+        pattern: &Pattern,
+        expected_type: &Type,
+        mutable: Option<Location>,
+    ) {
+        if !matches!(pattern, Pattern::Tuple(..) | Pattern::Struct(..) | Pattern::TupleStruct(..)) {
+            return;
+        }
+
+        let mut typ = self.follow_bindings_and_associated_types(expected_type);
+        let mut is_mutable_reference = false;
+        while let Type::Reference(element, mutable) = typ {
+            is_mutable_reference |= mutable;
+            typ = self.follow_bindings_and_associated_types(&element);
+        }
+        if !is_mutable_reference {
+            return;
+        }
+
+        if let Some(location) = mutable.or_else(|| self.first_mutable_binding(pattern)) {
+            self.push_err(ResolverError::MutableBindingThroughMutableReference { location });
+        }
+    }
+
+    /// Returns the location of the first `mut` in `pattern`, if any.
+    fn first_mutable_binding(&self, pattern: &Pattern) -> Option<Location> {
+        match pattern {
+            Pattern::Identifier(_) => None,
+            Pattern::Mutable(_, location, _) => Some(*location),
+            Pattern::Tuple(patterns, _) | Pattern::TupleStruct(_, patterns, _) => {
+                patterns.iter().find_map(|pattern| self.first_mutable_binding(pattern))
+            }
+            Pattern::Struct(_, fields, _, _) => {
+                fields.iter().find_map(|(_, pattern)| self.first_mutable_binding(pattern))
+            }
+            Pattern::Typed(pattern, _) => self.first_mutable_binding(pattern),
+            Pattern::Interned(id, _) => self.first_mutable_binding(self.interner.get_pattern(*id)),
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn elaborate_struct_pattern(
         &mut self,
         name: Path,
         fields: Vec<(Ident, Pattern)>,
+        has_rest: bool,
+        is_tuple_pattern: bool,
         location: Location,
         expected_type: Type,
         definition: DefinitionKind,
         mutable: Option<Location>,
         new_definitions: &mut Vec<HirIdent>,
+        depth: usize,
     ) -> HirPattern {
         let last_segment = name.last_segment();
         let name_location = last_segment.ident.location();
         let is_self_type = last_segment.ident.is_self_type_name();
 
-        let error_identifier = |this: &mut Self| {
-            // Must create a name here to return a HirPattern::Identifier. Allowing
-            // shadowing here lets us avoid further errors if we define ERROR_IDENT
-            // multiple times.
-            let name = ERROR_IDENT.into();
-            let identifier = this.add_variable_decl(name, false, true, true, definition.clone());
-            HirPattern::Identifier(identifier)
-        };
+        let turbofish_location = last_segment.turbofish_location();
 
         let (struct_type, generics) = match self.lookup_type_or_error(name) {
             Some(Type::DataType(struct_type, struct_generics))
                 if struct_type.borrow().is_struct() =>
             {
-                (struct_type, struct_generics)
+                let generics = self.resolve_struct_turbofish_generics(
+                    &struct_type.borrow(),
+                    struct_generics,
+                    last_segment.generics,
+                    turbofish_location,
+                );
+                (struct_type, generics)
             }
-            None => return error_identifier(self),
+            Some(Type::Alias(type_alias, alias_generics)) => {
+                // The turbofish generics are the alias' own, which may differ from the struct's,
+                // e.g. `type Foo<T> = Bar<T, i32>`
+                let type_alias = type_alias.borrow();
+                let alias_generics = self.resolve_alias_turbofish_generics(
+                    &type_alias,
+                    alias_generics,
+                    last_segment.generics,
+                    turbofish_location,
+                );
+                match type_alias.get_type(&alias_generics).follow_bindings_shallow().as_ref() {
+                    Type::DataType(struct_type, _) if struct_type.borrow().is_struct() => {
+                        let generics = get_type_alias_generics(&type_alias, &alias_generics);
+                        (struct_type.clone(), generics)
+                    }
+                    typ => {
+                        let typ = typ.to_string();
+                        self.push_err(ResolverError::NonStructUsedInConstructor { typ, location });
+                        return self.error_identifier_pattern(definition);
+                    }
+                }
+            }
+            None => return self.error_identifier_pattern(definition),
             Some(typ) => {
                 let typ = typ.to_string();
                 self.push_err(ResolverError::NonStructUsedInConstructor { typ, location });
-                return error_identifier(self);
+                return self.error_identifier_pattern(definition);
             }
         };
 
-        let turbofish_location = last_segment.turbofish_location();
-
-        let generics = self.resolve_struct_turbofish_generics(
-            &struct_type.borrow(),
-            generics,
-            last_segment.generics,
-            turbofish_location,
-        );
-
         let actual_type = Type::DataType(struct_type.clone(), generics);
+        let expected_type = self.follow_bindings_and_associated_types(&expected_type);
 
         self.unify(&actual_type, &expected_type, || TypeCheckError::TypeMismatchWithSource {
             expected: expected_type.clone(),
@@ -226,54 +408,102 @@ impl Elaborator<'_> {
             source: Source::Assignment,
         });
 
+        // A tuple struct pattern such as `Foo(a, b)` must list every field of the tuple struct,
+        // so a wrong number of fields is reported as an arity mismatch.
+        if is_tuple_pattern && struct_type.borrow().is_tuple_struct {
+            let expected = struct_type.borrow().fields_raw().map_or(0, |fields| fields.len());
+            let found = fields.len();
+            if expected != found {
+                self.push_err(TypeCheckError::ArityMisMatch { expected, found, location });
+
+                // Still define the variables in the pattern to avoid follow-up errors
+                let fields = vecmap(fields, |(name, pattern)| {
+                    let pattern = self.elaborate_pattern_mut(
+                        pattern,
+                        Type::Error,
+                        definition.clone(),
+                        mutable,
+                        new_definitions,
+                        true, // warn_if_unused
+                        depth + 1,
+                    );
+                    (name, pattern)
+                });
+                return HirPattern::Struct(expected_type, fields, location);
+            }
+        }
+
         let typ = struct_type.clone();
         let fields = self.resolve_constructor_pattern_fields(
             typ,
             fields,
+            has_rest,
             location,
             expected_type.clone(),
             definition,
             mutable,
             new_definitions,
+            depth,
         );
 
         let struct_id = struct_type.borrow().id;
 
         self.interner.add_type_reference(struct_id, name_location, is_self_type);
 
-        for (field_index, field) in fields.iter().enumerate() {
-            let reference_location = field.0.location();
-            self.interner.add_struct_member_reference(struct_id, field_index, reference_location);
-        }
-
         HirPattern::Struct(expected_type, fields, location)
     }
 
+    fn error_identifier_pattern(&mut self, definition: DefinitionKind) -> HirPattern {
+        // Must create a name here to return a HirPattern::Identifier. Allowing
+        // shadowing here lets us avoid further errors if we define ERROR_IDENT
+        // multiple times.
+        let name = ERROR_IDENT.into();
+        let identifier = self.add_variable_decl(name, false, true, true, definition);
+        HirPattern::Identifier(identifier)
+    }
+
     /// Resolve all the fields of a struct constructor expression.
     /// Ensures all fields are present, none are repeated, and all
     /// are part of the struct.
+    ///
+    /// The fields ignored by a `..` rest pattern are matched with wildcard patterns.
+    /// Outside of the crate defining it, a struct marked `#[non_exhaustive]` requires the rest
+    /// pattern, so that adding a field to it doesn't break the patterns on it in other crates.
+    ///
+    /// Field-init shorthand (`Foo { x }`) is parsed as `Foo { x: x }`, so both forms
+    /// go through the same visibility checks and struct member references here.
     #[allow(clippy::too_many_arguments)]
     fn resolve_constructor_pattern_fields(
         &mut self,
         struct_type: Shared<DataType>,
         fields: Vec<(Ident, Pattern)>,
+        has_rest: bool,
         location: Location,
         expected_type: Type,
         definition: DefinitionKind,
         mutable: Option<Location>,
         new_definitions: &mut Vec<HirIdent>,
+        depth: usize,
     ) -> Vec<(Ident, HirPattern)> {
         let mut ret = Vec::with_capacity(fields.len());
-        let mut seen_fields = HashSet::default();
+        let mut seen_fields = HashMap::default();
         let mut unseen_fields = struct_type
             .borrow()
             .field_names()
             .expect("This type should already be validated to be a struct");
+        let struct_fields = self.resolve_struct_pattern_fields(&expected_type);
 
         for (field, pattern) in fields {
-            let (field_type, visibility) = expected_type
-                .get_field_type_and_visibility(field.as_str())
-                .unwrap_or((Type::Error, ItemVisibility::Public));
+            let field_index = struct_fields.as_ref().and_then(|struct_fields| {
+                struct_fields.iter().position(|(name, ..)| name == field.as_str())
+            });
+            let (visibility, field_type) = match (&struct_fields, field_index) {
+                (Some(struct_fields), Some(index)) => {
+                    let (_, visibility, typ) = &struct_fields[index];
+                    (*visibility, typ.clone())
+                }
+                _ => (ItemVisibility::Public, Type::Error),
+            };
             let resolved = self.elaborate_pattern_mut(
                 pattern,
                 field_type,
@@ -281,21 +511,38 @@ impl Elaborator<'_> {
                 mutable,
                 new_definitions,
                 true, // warn_if_unused
+                depth + 1,
             );
 
             if unseen_fields.contains(&field) {
                 unseen_fields.remove(&field);
-                seen_fields.insert(field.clone());
+                seen_fields.insert(field.clone(), field.location());
 
+                let struct_type = struct_type.borrow();
                 self.check_struct_field_visibility(
-                    &struct_type.borrow(),
+                    &struct_type,
                     field.as_str(),
                     visibility,
                     field.location(),
                 );
-            } else if seen_fields.contains(&field) {
+
+                // The member reference must use the field's index in the struct declaration,
+                // which isn't necessarily its position in the pattern.
+                if let Some(field_index) = field_index {
+                    let location = field.location();
+                    self.interner.add_struct_member_reference(
+                        struct_type.id,
+                        field_index,
+                        location,
+                    );
+                }
+            } else if let Some(first_location) = seen_fields.get(&field) {
                 // duplicate field
-                self.push_err(ResolverError::DuplicateField { field: field.clone() });
+                let first_location = *first_location;
+                self.push_err(ResolverError::DuplicateField {
+                    field: field.clone(),
+                    first_location,
+                });
             } else {
                 // field not required by struct
                 self.push_err(ResolverError::NoSuchField {
@@ -307,17 +554,78 @@ impl Elaborator<'_> {
             ret.push((field, resolved));
         }
 
-        if !unseen_fields.is_empty() {
-            self.push_err(ResolverError::MissingFields {
+        let struct_id = struct_type.borrow().id;
+        let is_non_exhaustive = struct_id.krate() != self.crate_id
+            && self
+                .interner
+                .type_attributes(&struct_id)
+                .iter()
+                .any(|attr| matches!(attr, SecondaryAttribute::NonExhaustive));
+        if is_non_exhaustive && !has_rest {
+            self.push_err(ResolverError::NonExhaustiveStructPatternWithoutRest {
                 location,
-                missing_fields: unseen_fields.into_iter().map(|field| field.to_string()).collect(),
                 struct_definition: struct_type.borrow().name.clone(),
             });
         }
 
+        if has_rest || is_non_exhaustive {
+            // Match the other fields with wildcards, in declaration order
+            let rest_fields = struct_fields.iter().flat_map(|fields| fields.iter());
+            for (name, _, field_type) in rest_fields {
+                if !unseen_fields.iter().any(|field| field == name.as_str()) {
+                    continue;
+                }
+                let wildcard = Pattern::Identifier(Ident::new("_".to_string(), location));
+                let resolved = self.elaborate_pattern_mut(
+                    wildcard,
+                    field_type.clone(),
+                    definition.clone(),
+                    None,
+                    new_definitions,
+                    false, // warn_if_unused
+                    depth + 1,
+                );
+                ret.push((Ident::new(name.clone(), location), resolved));
+            }
+        } else if !unseen_fields.is_empty() {
+            let struct_type = struct_type.borrow();
+            self.push_err(ResolverError::MissingFields {
+                location,
+                missing_fields: struct_type
+                    .field_locations(|name| unseen_fields.iter().any(|field| field == name)),
+                struct_definition: struct_type.name.clone(),
+                suggest_rest: true,
+            });
+        }
+
         ret
     }
 
+    /// Returns the fields of the struct type `typ` resolves to, or `None` if it isn't a struct.
+    ///
+    /// The fields are computed once for each struct and list of generic arguments, so that
+    /// repeated identical struct patterns share them. Generic arguments which still contain
+    /// type variables aren't cached, as binding those variables later would change their hash.
+    fn resolve_struct_pattern_fields(&mut self, typ: &Type) -> Option<ResolvedStructFields> {
+        let Type::DataType(data_type, generics) = typ.follow_bindings() else {
+            return None;
+        };
+        let data_type = data_type.borrow();
+        if !generics.iter().all(has_no_type_variables) {
+            return data_type.get_fields_with_visibility(&generics).map(Rc::new);
+        }
+
+        let key = (data_type.id, generics);
+        if let Some(fields) = self.struct_pattern_fields.get(&key) {
+            self.struct_pattern_field_cache_hits += 1;
+            return Some(fields.clone());
+        }
+
+        let fields = Rc::new(data_type.get_fields_with_visibility(&key.1)?);
+        self.struct_pattern_fields.insert(key, fields.clone());
+        Some(fields)
+    }
+
     pub(super) fn add_variable_decl(
         &mut self,
         name: Ident,
@@ -420,27 +728,33 @@ impl Elaborator<'_> {
         }
     }
 
-    /// Resolve generics using the expected kinds of the function we are calling
+    /// Resolve generics using the expected kinds of the function we are calling.
+    ///
+    /// A turbofish on a function without generics is reported at `turbofish_location`.
     pub(super) fn resolve_function_turbofish_generics(
         &mut self,
         func_id: &FuncId,
         unresolved_turbofish: Option<Vec<UnresolvedType>>,
         location: Location,
+        turbofish_location: Location,
     ) -> Option<Vec<Type>> {
-        let direct_generic_kinds =
-            vecmap(&self.interner.function_meta(func_id).direct_generics, |generic| generic.kind());
+        let direct_generics = self.interner.function_meta(func_id).direct_generics.clone();
 
         unresolved_turbofish.map(|unresolved_turbofish| {
-            if unresolved_turbofish.len() != direct_generic_kinds.len() {
+            if direct_generics.is_empty() && !unresolved_turbofish.is_empty() {
+                let name = self.interner.function_name(func_id).to_string();
+                let location = turbofish_location;
+                self.push_err(TypeCheckError::TurbofishOnNonGenericFunction { name, location });
+            } else if unresolved_turbofish.len() != direct_generics.len() {
                 let type_check_err = TypeCheckError::IncorrectTurbofishGenericCount {
-                    expected_count: direct_generic_kinds.len(),
+                    expected_count: direct_generics.len(),
                     actual_count: unresolved_turbofish.len(),
                     location,
                 };
                 self.push_err(type_check_err);
             }
 
-            self.resolve_turbofish_generics(direct_generic_kinds, unresolved_turbofish)
+            self.resolve_turbofish_generics(direct_generics, unresolved_turbofish)
         })
     }
 
@@ -451,11 +765,10 @@ impl Elaborator<'_> {
         unresolved_turbofish: Option<Vec<UnresolvedType>>,
         location: Location,
     ) -> Vec<Type> {
-        let kinds = vecmap(&struct_type.generics, |generic| generic.kind());
         self.resolve_item_turbofish_generics(
             "struct",
             struct_type.name.as_str(),
-            kinds,
+            struct_type.generics.clone(),
             generics,
             unresolved_turbofish,
             location,
@@ -465,7 +778,7 @@ impl Elaborator<'_> {
     pub(super) fn resolve_trait_turbofish_generics(
         &mut self,
         trait_name: &str,
-        trait_generic_kinds: Vec<Kind>,
+        trait_generics: Vec<ResolvedGeneric>,
         generics: Vec<Type>,
         unresolved_turbofish: Option<Vec<UnresolvedType>>,
         location: Location,
@@ -473,7 +786,7 @@ impl Elaborator<'_> {
         self.resolve_item_turbofish_generics(
             "trait",
             trait_name,
-            trait_generic_kinds,
+            trait_generics,
             generics,
             unresolved_turbofish,
             location,
@@ -487,11 +800,10 @@ impl Elaborator<'_> {
         unresolved_turbofish: Option<Vec<UnresolvedType>>,
         location: Location,
     ) -> Vec<Type> {
-        let kinds = vecmap(&type_alias.generics, |generic| generic.kind());
         self.resolve_item_turbofish_generics(
             "alias",
             type_alias.name.as_str(),
-            kinds,
+            type_alias.generics.clone(),
             generics,
             unresolved_turbofish,
             location,
@@ -502,7 +814,7 @@ impl Elaborator<'_> {
         &mut self,
         item_kind: &'static str,
         item_name: &str,
-        item_generic_kinds: Vec<Kind>,
+        item_generics: Vec<ResolvedGeneric>,
         generics: Vec<Type>,
         unresolved_turbofish: Option<Vec<UnresolvedType>>,
         location: Location,
@@ -521,17 +833,49 @@ impl Elaborator<'_> {
             return generics;
         }
 
-        self.resolve_turbofish_generics(item_generic_kinds, turbofish_generics)
+        self.resolve_turbofish_generics(item_generics, turbofish_generics)
     }
 
+    /// Resolves each turbofish argument against the kind of the generic it is given for,
+    /// reporting a type given for a numeric generic (or vice versa) at the offending argument.
     pub(super) fn resolve_turbofish_generics(
         &mut self,
-        kinds: Vec<Kind>,
+        generics: Vec<ResolvedGeneric>,
         turbofish_generics: Vec<UnresolvedType>,
     ) -> Vec<Type> {
-        let kinds_with_types = kinds.into_iter().zip(turbofish_generics);
-        vecmap(kinds_with_types, |(kind, unresolved_type)| {
-            self.resolve_type_inner(unresolved_type, &kind)
+        let generics_with_types = generics.into_iter().zip(turbofish_generics);
+        vecmap(generics_with_types, |(generic, unresolved_type)| {
+            let kind = generic.kind();
+            let (typ, location) = self.resolve_type_without_kind_check(unresolved_type, &kind);
+
+            let provided_kind = typ.kind();
+            let kind_mismatch = match &kind {
+                Kind::Normal => {
+                    matches!(typ, Type::Constant(..) | Type::InfixExpr(..))
+                        || matches!(provided_kind, Kind::Numeric(_))
+                }
+                Kind::Numeric(_) => matches!(provided_kind, Kind::Normal),
+                _ => false,
+            };
+
+            if kind_mismatch {
+                self.push_err(TypeCheckError::TurbofishGenericKindMismatch {
+                    generic_name: generic.name.to_string(),
+                    expected_kind: kind,
+                    provided: typ,
+                    location,
+                });
+                Type::Error
+            } else if !kind.unifies(&provided_kind) {
+                self.push_err(TypeCheckError::TypeKindMismatch {
+                    expected_kind: kind,
+                    expr_kind: provided_kind,
+                    expr_location: location,
+                });
+                Type::Error
+            } else {
+                typ
+            }
         })
     }
 
@@ -552,7 +896,9 @@ impl Elaborator<'_> {
             }
         }
 
-        let unresolved_turbofish = variable.segments.last().unwrap().generics.clone();
+        let last_segment = variable.segments.last().unwrap();
+        let unresolved_turbofish = last_segment.generics.clone();
+        let turbofish_location = last_segment.turbofish_location();
 
         let location = variable.location;
         let (expr, item) = self.resolve_variable(variable);
@@ -570,7 +916,12 @@ impl Elaborator<'_> {
         // Resolve any generics if we the variable we have resolved is a function
         // and if the turbofish operator was used.
         let generics = if let Some(DefinitionKind::Function(func_id)) = &definition_kind {
-            self.resolve_function_turbofish_generics(func_id, unresolved_turbofish, location)
+            self.resolve_function_turbofish_generics(
+                func_id,
+                unresolved_turbofish,
+                location,
+                turbofish_location,
+            )
         } else {
             None
         };
@@ -666,13 +1017,14 @@ impl Elaborator<'_> {
             }
             PathResolutionItem::TraitFunction(trait_id, Some(generics), _func_id) => {
                 let trait_ = self.interner.get_trait(trait_id);
-                let kinds = vecmap(&trait_.generics, |generic| generic.kind());
-                let trait_generics =
-                    vecmap(&kinds, |kind| self.interner.next_type_variable_with_kind(kind.clone()));
+                let generics_of_trait = trait_.generics.clone();
+                let trait_generics = vecmap(&generics_of_trait, |generic| {
+                    self.interner.next_type_variable_with_kind(generic.kind())
+                });
 
                 self.resolve_trait_turbofish_generics(
                     &trait_.name.to_string(),
-                    kinds,
+                    generics_of_trait,
                     trait_generics,
                     Some(generics.generics),
                     generics.location,
@@ -738,6 +1090,9 @@ impl Elaborator<'_> {
                             let typ = self.type_variable_with_kind(type_var_kind);
                             self.interner.push_definition_type(hir_ident.id, typ);
                         }
+
+                        let declaration_location = self.interner.definition(hir_ident.id).location;
+                        self.interner.add_numeric_generic_reference(declaration_location, location);
                     }
                     DefinitionKind::Local(_) => {
                         // only local variables can be captured by closures.
@@ -825,6 +1180,7 @@ impl Elaborator<'_> {
                 let trait_generics = method.constraint.trait_bound.trait_generics.clone();
                 let object_type = method.constraint.typ;
                 let trait_impl = TraitImplKind::Assumed { object_type, trait_generics };
+                self.add_trait_method_dependency(expr_id, &trait_impl);
                 self.interner.select_impl_for_expression(expr_id, trait_impl);
             } else {
                 // Currently only one impl can be selected per expr_id, so this
@@ -853,12 +1209,16 @@ impl Elaborator<'_> {
         match turbofish_generics {
             Some(turbofish_generics) => {
                 if turbofish_generics.len() != function_generic_count {
-                    let type_check_err = TypeCheckError::IncorrectTurbofishGenericCount {
-                        expected_count: function_generic_count,
-                        actual_count: turbofish_generics.len(),
-                        location,
-                    };
-                    self.push_err(CompilationError::TypeError(type_check_err));
+                    // A turbofish on a function without generics has its own error, reported
+                    // when the turbofish was resolved
+                    if function_generic_count != 0 {
+                        let type_check_err = TypeCheckError::IncorrectTurbofishGenericCount {
+                            expected_count: function_generic_count,
+                            actual_count: turbofish_generics.len(),
+                            location,
+                        };
+                        self.push_err(CompilationError::TypeError(type_check_err));
+                    }
                     typ.instantiate_with_bindings(bindings, self.interner)
                 } else {
                     // Fetch the count of any implicit generics on the function, such as
@@ -971,3 +1331,37 @@ fn get_type_alias_generics(type_alias: &TypeAlias, generics: &[Type]) -> Vec<Typ
         _ => panic!("Expected type alias to point to struct or alias"),
     }
 }
+
+/// True if `typ` contains no type variables or named generics, which could still be bound.
+fn has_no_type_variables(typ: &Type) -> bool {
+    match typ {
+        Type::TypeVariable(..)
+        | Type::NamedGeneric(..)
+        | Type::TraitAsType(..)
+        | Type::Forall(..) => false,
+        Type::FieldElement
+        | Type::Integer(..)
+        | Type::Bool
+        | Type::Unit
+        | Type::Constant(..)
+        | Type::Quoted(..)
+        | Type::Error => true,
+        Type::Slice(typ) | Type::String(typ) | Type::Reference(typ, _) => {
+            has_no_type_variables(typ)
+        }
+        Type::Array(first, second)
+        | Type::FmtString(first, second)
+        | Type::CheckedCast { from: first, to: second }
+        | Type::InfixExpr(first, _, second, _) => {
+            has_no_type_variables(first) && has_no_type_variables(second)
+        }
+        Type::Tuple(types) | Type::DataType(_, types) | Type::Alias(_, types) => {
+            types.iter().all(has_no_type_variables)
+        }
+        Type::Function(args, ret, env, _) => {
+            args.iter().all(has_no_type_variables)
+                && has_no_type_variables(ret)
+                && has_no_type_variables(env)
+        }
+    }
+}
@@ -12,6 +12,7 @@ use crate::{
     node_interner::{DefinitionId, TraitId, TypeId},
 };
 use crate::{Type, TypeAlias};
+use iter_extended::vecmap;
 
 use super::path_resolution::PathResolutionItem;
 use super::types::SELF_TYPE_NAME;
@@ -198,9 +199,13 @@ impl Elaborator<'_> {
                 Some(Type::DataType(struct_type, generics))
             }
             Ok(PathResolutionItem::TypeAlias(alias_id)) => {
+                // The alias is kept, rather than replaced by the type it points to, so that any
+                // turbofish generics can be applied to the alias' own generics.
                 let alias = self.interner.get_type_alias(alias_id);
-                let alias = alias.borrow();
-                Some(alias.instantiate(self.interner))
+                let generics = vecmap(&alias.borrow().generics, |generic| {
+                    self.interner.next_type_variable_with_kind(generic.kind())
+                });
+                Some(Type::Alias(alias, generics))
             }
             Ok(other) => {
                 self.push_err(ResolverError::Expected {
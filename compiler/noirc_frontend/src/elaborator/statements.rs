@@ -149,7 +149,20 @@ impl Elaborator<'_> {
         (HirStatement::Let(let_), Type::Unit)
     }
 
-    pub(super) fn elaborate_assign(&mut self, assign: AssignStatement) -> (HirStatement, Type) {
+    pub(super) fn elaborate_assign(&mut self, mut assign: AssignStatement) -> (HirStatement, Type) {
+        // Tuples of places are only allowed as the whole left-hand side, which may be unquoted
+        if let LValue::Interned(id, location) = assign.lvalue {
+            let lvalue = self.interner.get_lvalue(id, location);
+            if matches!(lvalue, LValue::Tuple(..)) {
+                assign.lvalue = lvalue;
+            }
+        }
+
+        if let LValue::Tuple(_, location) = &assign.lvalue {
+            let location = location.merge(assign.expression.location);
+            return self.elaborate_statement_value(assign.into_destructuring(location));
+        }
+
         let expr_location = assign.expression.location;
         let (expression, expr_type) = self.elaborate_expression(assign.expression);
         let (lvalue, lvalue_type, mutable) = self.elaborate_lvalue(assign.lvalue);
@@ -394,7 +407,13 @@ impl Elaborator<'_> {
                 let object_ref = &mut object;
                 let mutable_ref = &mut mutable;
 
-                let dereference_lhs = move |_: &mut Self, _, element_type| {
+                let dereference_lhs = move |this: &mut Self, reference_type: Type, element_type| {
+                    if let Type::Reference(_, false) = reference_type.follow_bindings() {
+                        this.push_err(TypeCheckError::MutationThroughImmutableReference {
+                            location,
+                        });
+                    }
+
                     // We must create a temporary value first to move out of object_ref before
                     // we eventually reassign to it.
                     let id = DefinitionId::dummy_id();
@@ -440,12 +459,20 @@ impl Elaborator<'_> {
 
                 // Before we check that the lvalue is an array, try to dereference it as many times
                 // as needed to unwrap any `&` or `&mut` wrappers.
-                while let Type::Reference(element, _) = lvalue_type.follow_bindings() {
+                while let Type::Reference(element, reference_mutable) =
+                    lvalue_type.follow_bindings()
+                {
+                    if !reference_mutable {
+                        self.push_err(TypeCheckError::MutationThroughImmutableReference {
+                            location,
+                        });
+                    }
+
                     let element_type = element.as_ref().clone();
                     lvalue =
                         HirLValue::Dereference { lvalue: Box::new(lvalue), element_type, location };
                     lvalue_type = *element;
-                    // We know this value to be mutable now since we found an `&mut`
+                    // The value behind the reference is mutable, or an error was issued above
                     mutable = true;
                 }
 
@@ -503,6 +530,12 @@ impl Elaborator<'_> {
                 let lvalue = self.interner.get_lvalue(id, location).clone();
                 self.elaborate_lvalue(lvalue)
             }
+            LValue::Tuple(_, location) => {
+                self.push_err(ResolverError::NestedTupleInAssignment { location });
+                let ident = HirIdent::non_trait_method(DefinitionId::dummy_id(), location);
+                // Reported as mutable to avoid a second error about the same place
+                (HirLValue::Ident(ident, Type::Error), Type::Error, true)
+            }
         }
     }
 
@@ -24,16 +24,16 @@ use crate::{
     },
     hir_def::{
         expr::{
-            HirBinaryOp, HirCallExpression, HirExpression, HirLiteral, HirMemberAccess,
-            HirMethodReference, HirPrefixExpression, TraitMethod,
+            HirBinaryOp, HirCallExpression, HirExpression, HirIdent, HirLiteral, HirMemberAccess,
+            HirMethodReference, HirPrefixExpression, ImplKind, TraitMethod,
         },
         function::FuncMeta,
         stmt::HirStatement,
         traits::{NamedType, ResolvedTraitBound, Trait, TraitConstraint},
     },
     node_interner::{
-        DependencyId, ExprId, FuncId, GlobalValue, ImplSearchErrorKind, TraitId, TraitImplKind,
-        TraitMethodId,
+        DefinitionKind, DependencyId, ExprId, FuncId, GlobalValue, ImplSearchErrorKind, TraitId,
+        TraitImplKind, TraitMethodId,
     },
     shared::Signedness,
     signed_field::SignedField,
@@ -66,6 +66,29 @@ impl Elaborator<'_> {
     /// Translates an UnresolvedType into a Type and appends any
     /// freshly created TypeVariables created to new_variables.
     pub fn resolve_type_inner(&mut self, typ: UnresolvedType, kind: &Kind) -> Type {
+        let (resolved_type, location) = self.resolve_type_without_kind_check(typ, kind);
+
+        if !kind.unifies(&resolved_type.kind()) {
+            let expected_typ_err = CompilationError::TypeError(TypeCheckError::TypeKindMismatch {
+                expected_kind: kind.clone(),
+                expr_kind: resolved_type.kind(),
+                expr_location: location,
+            });
+            self.push_err(expected_typ_err);
+            return Type::Error;
+        }
+
+        resolved_type
+    }
+
+    /// Same as [Self::resolve_type_inner] but leaves it to the caller to check that the
+    /// resolved type has the expected kind, so that it can report a more specific error.
+    /// Also returns the location that error should be reported at.
+    pub(super) fn resolve_type_without_kind_check(
+        &mut self,
+        typ: UnresolvedType,
+        kind: &Kind,
+    ) -> (Type, Location) {
         use crate::ast::UnresolvedTypeData::*;
 
         let location = typ.location;
@@ -156,7 +179,8 @@ impl Elaborator<'_> {
             AsTraitPath(path) => self.resolve_as_trait_path(*path),
             Interned(id) => {
                 let typ = self.interner.get_unresolved_type_data(id).clone();
-                return self.resolve_type_inner(UnresolvedType { typ, location }, kind);
+                return self
+                    .resolve_type_without_kind_check(UnresolvedType { typ, location }, kind);
             }
         };
 
@@ -179,17 +203,7 @@ impl Elaborator<'_> {
             _ => (),
         }
 
-        if !kind.unifies(&resolved_type.kind()) {
-            let expected_typ_err = CompilationError::TypeError(TypeCheckError::TypeKindMismatch {
-                expected_kind: kind.clone(),
-                expr_kind: resolved_type.kind(),
-                expr_location: location,
-            });
-            self.push_err(expected_typ_err);
-            return Type::Error;
-        }
-
-        resolved_type
+        (resolved_type, location)
     }
 
     pub fn find_generic(&self, target_name: &str) -> Option<&ResolvedGeneric> {
@@ -438,6 +452,9 @@ impl Elaborator<'_> {
             let name = path.last_name();
             if let Some(generic) = self.find_generic(name) {
                 let generic = generic.clone();
+                if matches!(generic.kind(), Kind::Numeric(_)) {
+                    self.interner.add_numeric_generic_reference(generic.location, path.location);
+                }
                 return Some(Type::NamedGeneric(generic.type_var, generic.name));
             }
         } else if let Some(typ) = self.lookup_associated_type_on_self(path) {
@@ -562,6 +579,20 @@ impl Elaborator<'_> {
 
     fn check_kind(&mut self, typ: Type, expected_kind: &Kind, location: Location) -> Type {
         if !typ.kind().unifies(expected_kind) {
+            if let (
+                Type::NamedGeneric(_, name),
+                Kind::Numeric(generic_type),
+                Kind::Numeric(expected_type),
+            ) = (&typ, typ.kind(), expected_kind)
+            {
+                self.push_err(TypeCheckError::NumericGenericTypeMismatch {
+                    name: name.to_string(),
+                    generic_type: *generic_type,
+                    expected_type: expected_type.as_ref().clone(),
+                    location,
+                });
+                return Type::Error;
+            }
             self.push_err(TypeCheckError::TypeKindMismatch {
                 expected_kind: expected_kind.clone(),
                 expr_kind: typ.kind(),
@@ -905,6 +936,7 @@ impl Elaborator<'_> {
         fn_params: &[Type],
         fn_ret: &Type,
         callsite_args: &[(Type, ExprId, Location)],
+        turbofish_parameters: &[Option<(String, Type)>],
         location: Location,
     ) -> Type {
         if fn_params.len() != callsite_args.len() {
@@ -916,23 +948,46 @@ impl Elaborator<'_> {
             return Type::Error;
         }
 
-        for (param, (arg, arg_expr_id, arg_location)) in fn_params.iter().zip(callsite_args) {
-            self.unify_with_coercions(arg, param, *arg_expr_id, *arg_location, || {
-                TypeCheckError::TypeMismatch {
-                    expected_typ: param.to_string(),
-                    expr_typ: arg.to_string(),
-                    expr_location: *arg_location,
-                }
-            });
+        let args = fn_params.iter().zip(callsite_args).enumerate();
+        for (index, (param, (arg, arg_expr_id, arg_location))) in args {
+            let turbofish = turbofish_parameters.get(index).cloned().flatten();
+            self.unify_with_coercions(
+                arg,
+                param,
+                *arg_expr_id,
+                *arg_location,
+                || match turbofish {
+                    Some((generic_name, turbofish_type)) => {
+                        TypeCheckError::TurbofishArgumentMismatch {
+                            generic_name,
+                            turbofish_type,
+                            argument_type: arg.clone(),
+                            location: *arg_location,
+                        }
+                    }
+                    None => TypeCheckError::TypeMismatch {
+                        expected_typ: param.to_string(),
+                        expr_typ: arg.to_string(),
+                        expr_location: *arg_location,
+                    },
+                },
+            );
         }
 
         fn_ret.clone()
     }
 
+    /// Unifies the type of each argument of a call with the corresponding parameter of the
+    /// called `function` type, and returns the call's return type.
+    ///
+    /// `turbofish_parameters` holds, for each parameter declared with a generic type, the
+    /// name of that generic and the type a turbofish set it to, to report arguments which
+    /// conflict with the turbofish.
     pub(super) fn bind_function_type(
         &mut self,
         function: Type,
         args: Vec<(Type, ExprId, Location)>,
+        turbofish_parameters: &[Option<(String, Type)>],
         location: Location,
     ) -> Type {
         // Could do a single unification for the entire function type, but matching beforehand
@@ -940,7 +995,12 @@ impl Elaborator<'_> {
         match function {
             Type::TypeVariable(binding) if binding.kind() == Kind::Normal => {
                 if let TypeBinding::Bound(typ) = &*binding.borrow() {
-                    return self.bind_function_type(typ.clone(), args, location);
+                    return self.bind_function_type(
+                        typ.clone(),
+                        args,
+                        turbofish_parameters,
+                        location,
+                    );
                 }
 
                 let ret = self.interner.next_type_variable();
@@ -957,9 +1017,13 @@ impl Elaborator<'_> {
             }
             // The closure env is ignored on purpose: call arguments never place
             // constraints on closure environments.
-            Type::Function(parameters, ret, _env, _unconstrained) => {
-                self.bind_function_type_impl(&parameters, &ret, &args, location)
-            }
+            Type::Function(parameters, ret, _env, _unconstrained) => self.bind_function_type_impl(
+                &parameters,
+                &ret,
+                &args,
+                turbofish_parameters,
+                location,
+            ),
             Type::Error => Type::Error,
             found => {
                 self.push_err(TypeCheckError::ExpectedFunction { found, location });
@@ -968,6 +1032,34 @@ impl Elaborator<'_> {
         }
     }
 
+    /// If `func` refers to a function called with a turbofish, returns for each parameter of
+    /// that function declared with one of the function's generics as its type, the name of
+    /// the generic and the type the turbofish set it to.
+    fn turbofish_parameters(&self, func: ExprId) -> Vec<Option<(String, Type)>> {
+        let HirExpression::Ident(_, Some(turbofish)) = self.interner.expression(&func) else {
+            return Vec::new();
+        };
+        let Some(func_id) = self.interner.lookup_function_from_expr(&func) else {
+            return Vec::new();
+        };
+        let meta = self.interner.function_meta(&func_id);
+        // A turbofish with the wrong number of generics has already been reported
+        if turbofish.len() != meta.direct_generics.len() {
+            return Vec::new();
+        }
+
+        vecmap(&meta.parameters.0, |(_, typ, _)| {
+            let Type::NamedGeneric(type_var, name) = typ else {
+                return None;
+            };
+            let index = meta
+                .direct_generics
+                .iter()
+                .position(|generic| generic.type_var.id() == type_var.id())?;
+            Some((name.to_string(), turbofish[index].clone()))
+        })
+    }
+
     pub(super) fn check_cast(
         &mut self,
         from_expr_id: &ExprId,
@@ -1822,7 +1914,8 @@ impl Elaborator<'_> {
             }
         }
 
-        let return_type = self.bind_function_type(func_type, args, location);
+        let turbofish_parameters = self.turbofish_parameters(call.func);
+        let return_type = self.bind_function_type(func_type, args, &turbofish_parameters, location);
 
         if crossing_runtime_boundary {
             self.run_lint(|_| {
@@ -1991,6 +2084,7 @@ impl Elaborator<'_> {
         ) {
             Ok(impl_kind) => {
                 if select_impl {
+                    self.add_trait_method_dependency(function_ident_id, &impl_kind);
                     self.interner.select_impl_for_expression(function_ident_id, impl_kind);
                 }
             }
@@ -1998,6 +2092,54 @@ impl Elaborator<'_> {
         }
     }
 
+    /// Records that the current item depends on the trait method called through `expr_id`,
+    /// which may be a method call, a path to a trait method or an overloaded operator.
+    /// The dependency is on the selected impl's method, or on the trait's own method
+    /// declaration if the impl is only assumed to exist.
+    pub(super) fn add_trait_method_dependency(
+        &mut self,
+        expr_id: ExprId,
+        impl_kind: &TraitImplKind,
+    ) {
+        let Some(current_item) = self.current_item else {
+            return;
+        };
+
+        let method_id = match self.interner.expression(&expr_id) {
+            HirExpression::Ident(HirIdent { impl_kind: ImplKind::TraitMethod(method), .. }, _) => {
+                method.method_id
+            }
+            HirExpression::Infix(infix) => infix.trait_method_id,
+            HirExpression::Prefix(HirPrefixExpression {
+                trait_method_id: Some(method_id), ..
+            }) => method_id,
+            _ => return,
+        };
+
+        let func_id = match impl_kind {
+            TraitImplKind::Normal(impl_id) => {
+                let trait_impl = self.interner.get_trait_implementation(*impl_id);
+                let Some(func_id) =
+                    trait_impl.borrow().methods.get(method_id.method_index).copied()
+                else {
+                    return;
+                };
+                func_id
+            }
+            TraitImplKind::Assumed { .. } => {
+                let definition_id = self.interner.trait_method_id(method_id);
+                let DefinitionKind::Function(func_id) =
+                    self.interner.definition(definition_id).kind
+                else {
+                    return;
+                };
+                func_id
+            }
+        };
+
+        self.interner.add_function_dependency(current_item, func_id);
+    }
+
     fn push_trait_constraint_error(
         &mut self,
         object_type: &Type,
@@ -10,8 +10,9 @@ use crate::{
         CastExpression, ConstrainExpression, ConstructorExpression, Expression, ExpressionKind,
         ForBounds, ForLoopStatement, ForRange, GenericTypeArgs, IfExpression, IndexExpression,
         InfixExpression, LValue, Lambda, LetStatement, Literal, MatchExpression,
-        MemberAccessExpression, MethodCallExpression, Pattern, PrefixExpression, Statement,
-        StatementKind, UnresolvedType, UnresolvedTypeData, UnsafeExpression, WhileStatement,
+        MemberAccessExpression, MethodCallExpression, Pattern, PrefixExpression, RangeExpression,
+        Statement, StatementKind, UnresolvedType, UnresolvedTypeData, UnsafeExpression,
+        WhileStatement,
     },
     hir_def::traits::TraitConstraint,
     node_interner::{InternedStatementKind, NodeInterner},
@@ -655,6 +656,11 @@ fn remove_interned_in_expression_kind(
                 (pattern, branch)
             }),
         })),
+        ExpressionKind::Range(range) => ExpressionKind::Range(Box::new(RangeExpression {
+            start: remove_interned_in_expression(interner, range.start),
+            end: remove_interned_in_expression(interner, range.end),
+            inclusive: range.inclusive,
+        })),
         ExpressionKind::Variable(_) => expr,
         ExpressionKind::Tuple(expressions) => ExpressionKind::Tuple(vecmap(expressions, |expr| {
             remove_interned_in_expression(interner, expr)
@@ -841,6 +847,10 @@ fn remove_interned_in_lvalue(interner: &NodeInterner, lvalue: LValue) -> LValue
             let lvalue = interner.get_lvalue(id, span);
             remove_interned_in_lvalue(interner, lvalue)
         }
+        LValue::Tuple(lvalues, span) => LValue::Tuple(
+            vecmap(lvalues, |lvalue| remove_interned_in_lvalue(interner, lvalue)),
+            span,
+        ),
     }
 }
 
@@ -953,11 +963,19 @@ fn remove_interned_in_pattern(interner: &NodeInterner, pattern: Pattern) -> Patt
             vecmap(patterns, |pattern| remove_interned_in_pattern(interner, pattern)),
             span,
         ),
-        Pattern::Struct(path, patterns, span) => {
+        Pattern::Struct(path, patterns, span, has_rest) => {
             let patterns = vecmap(patterns, |(name, pattern)| {
                 (name, remove_interned_in_pattern(interner, pattern))
             });
-            Pattern::Struct(path, patterns, span)
+            Pattern::Struct(path, patterns, span, has_rest)
+        }
+        Pattern::TupleStruct(path, patterns, span) => Pattern::TupleStruct(
+            path,
+            vecmap(patterns, |pattern| remove_interned_in_pattern(interner, pattern)),
+            span,
+        ),
+        Pattern::Typed(pattern, typ) => {
+            Pattern::Typed(Box::new(remove_interned_in_pattern(interner, *pattern)), typ)
         }
         Pattern::Interned(id, _) => interner.get_pattern(id).clone(),
     }
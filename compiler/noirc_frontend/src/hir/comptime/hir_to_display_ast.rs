@@ -6,8 +6,8 @@ use crate::ast::{
     ArrayLiteral, AssignStatement, BlockExpression, CallExpression, CastExpression, ConstrainKind,
     ConstructorExpression, ExpressionKind, ForLoopStatement, ForRange, GenericTypeArgs, Ident,
     IfExpression, IndexExpression, InfixExpression, LValue, Lambda, Literal, MatchExpression,
-    MemberAccessExpression, Path, PathSegment, Pattern, PrefixExpression, UnresolvedType,
-    UnresolvedTypeData, UnresolvedTypeExpression, UnsafeExpression, WhileStatement,
+    MemberAccessExpression, Path, PathSegment, Pattern, PrefixExpression, RangeExpression,
+    UnresolvedType, UnresolvedTypeData, UnresolvedTypeExpression, UnsafeExpression, WhileStatement,
 };
 use crate::ast::{ConstrainExpression, Expression, Statement, StatementKind};
 use crate::hir_def::expr::{
@@ -234,7 +234,7 @@ impl HirMatch {
 
                 let mut rules = vecmap(cases, |case| {
                     let args = vecmap(&case.arguments, |arg| arg.to_display_ast(interner));
-                    let constructor = case.constructor.to_display_ast(args);
+                    let constructor = case.constructor.to_display_ast(args, location);
                     let constructor = Expression::new(constructor, location);
                     let branch = case.body.to_display_ast(interner, location);
                     (constructor, Expression::new(branch, location))
@@ -264,7 +264,7 @@ impl DefinitionId {
 }
 
 impl Constructor {
-    fn to_display_ast(&self, arguments: Vec<Expression>) -> ExpressionKind {
+    fn to_display_ast(&self, arguments: Vec<Expression>, location: Location) -> ExpressionKind {
         match self {
             Constructor::True => ExpressionKind::Literal(Literal::Bool(true)),
             Constructor::False => ExpressionKind::Literal(Literal::Bool(false)),
@@ -291,8 +291,13 @@ impl Constructor {
                 let is_macro_call = false;
                 ExpressionKind::Call(Box::new(CallExpression { func, arguments, is_macro_call }))
             }
-            Constructor::Range(_start, _end) => {
-                unreachable!("Range is unimplemented")
+            Constructor::Range(start, end) => {
+                let start =
+                    Expression::new(ExpressionKind::Literal(Literal::Integer(*start)), location);
+                let end =
+                    Expression::new(ExpressionKind::Literal(Literal::Integer(*end)), location);
+                let range = RangeExpression { start, end, inclusive: false };
+                ExpressionKind::Range(Box::new(range))
             }
         }
     }
@@ -325,19 +330,24 @@ impl HirPattern {
                 let patterns = vecmap(patterns, |(name, pattern)| {
                     (name.clone(), pattern.to_display_ast(interner))
                 });
-                let name = match typ.follow_bindings() {
+                let (name, is_tuple_struct) = match typ.follow_bindings() {
                     Type::DataType(struct_def, _) => {
                         let struct_def = struct_def.borrow();
-                        struct_def.name.to_string()
+                        (struct_def.name.to_string(), struct_def.is_tuple_struct)
                     }
                     // This pass shouldn't error so if the type isn't a struct we just get a string
                     // representation of any other type and use that. We're relying on name
                     // resolution to fail later when this Ast is re-converted to Hir.
-                    other => other.to_string(),
+                    other => (other.to_string(), false),
                 };
                 // The name span is lost here
                 let path = Path::from_single(name, *location);
-                Pattern::Struct(path, patterns, *location)
+                if is_tuple_struct {
+                    let patterns = vecmap(patterns, |(_, pattern)| pattern);
+                    Pattern::TupleStruct(path, patterns, *location)
+                } else {
+                    Pattern::Struct(path, patterns, *location, false)
+                }
             }
         }
     }
@@ -400,7 +400,8 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 self.define_pattern(pattern, typ, argument, location)
             }
             HirPattern::Tuple(pattern_fields, _) => {
-                let typ = &typ.follow_bindings();
+                let (argument, typ) = Self::dereference_pattern_argument(argument, typ);
+                let typ = &typ;
 
                 match (argument, typ) {
                     (Value::Tuple(fields), Type::Tuple(type_fields))
@@ -424,6 +425,7 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
                 }
             }
             HirPattern::Struct(struct_type, pattern_fields, _) => {
+                let (argument, _) = Self::dereference_pattern_argument(argument, typ);
                 self.push_scope();
 
                 let res = match argument {
@@ -459,6 +461,18 @@ impl<'local, 'interner> Interpreter<'local, 'interner> {
         }
     }
 
+    /// Dereferences a value destructured by a tuple or struct pattern while its type is a
+    /// reference, returning it along with its type.
+    fn dereference_pattern_argument(mut argument: Value, typ: &Type) -> (Value, Type) {
+        let mut typ = typ.follow_bindings();
+        while let (Type::Reference(element, _), Value::Pointer(pointee, ..)) = (&typ, &argument) {
+            let pointee = pointee.borrow().clone();
+            typ = element.follow_bindings();
+            argument = pointee;
+        }
+        (argument, typ)
+    }
+
     /// Define a new variable in the current scope
     fn define(&mut self, id: DefinitionId, argument: Value) {
         self.current_scope_mut().insert(id, argument);
@@ -2,7 +2,7 @@ mod display;
 mod errors;
 mod hir_to_display_ast;
 mod interpreter;
-mod tests;
+pub(crate) mod tests;
 mod value;
 
 pub use errors::{ComptimeError, InterpreterError};
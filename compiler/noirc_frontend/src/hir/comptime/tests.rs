@@ -17,13 +17,10 @@ use crate::hir::{Context, ParsedFiles};
 use crate::node_interner::FuncId;
 use crate::parse_program;
 
-/// Create an interpreter for a code snippet and pass it to a test function.
+/// Elaborate a code snippet and pass the elaborator and the `main` function to a test function.
 ///
 /// The stdlib is not made available as a dependency.
-pub(crate) fn with_interpreter<T>(
-    src: &str,
-    f: impl FnOnce(&mut Interpreter, FuncId, &[CompilationError]) -> T,
-) -> T {
+pub(crate) fn with_elaborator<T>(src: &str, f: impl FnOnce(&mut Elaborator, FuncId) -> T) -> T {
     let file = FileId::default();
 
     let location = Location::new(Default::default(), file);
@@ -49,7 +46,7 @@ pub(crate) fn with_interpreter<T>(
 
     let def_map = CrateDefMap::new(krate, root_module);
     let root_module_id = def_map.root();
-    let mut collector = DefCollector::new(def_map);
+    let mut collector = DefCollector::new(def_map, Vec::new());
 
     collect_defs(&mut collector, ast, FileId::dummy(), root_module_id, krate, &mut context);
     context.def_maps.insert(krate, collector.def_map);
@@ -63,11 +60,23 @@ pub(crate) fn with_interpreter<T>(
         ElaboratorOptions::test_default(),
     );
 
-    let errors = elaborator.errors.clone();
+    f(&mut elaborator, main)
+}
+
+/// Create an interpreter for a code snippet and pass it to a test function.
+///
+/// The stdlib is not made available as a dependency.
+pub(crate) fn with_interpreter<T>(
+    src: &str,
+    f: impl FnOnce(&mut Interpreter, FuncId, &[CompilationError]) -> T,
+) -> T {
+    with_elaborator(src, |elaborator, main| {
+        let errors = elaborator.errors.clone();
 
-    let mut interpreter = elaborator.setup_interpreter();
+        let mut interpreter = elaborator.setup_interpreter();
 
-    f(&mut interpreter, main, &errors)
+        f(&mut interpreter, main, &errors)
+    })
 }
 
 /// Evaluate a code snippet by calling the `main` function.
@@ -314,3 +323,26 @@ fn generic_functions() {
     let result = interpret(program);
     assert_eq!(result, Value::U8(2));
 }
+
+#[test]
+fn destructuring_mutable_reference_reads_fields() {
+    let program = "comptime fn main() -> pub u8 {
+        let pair = &mut (1, 2);
+        pair.0 = 10;
+        let (a, b) = pair;
+        a + b
+    }";
+    let result = interpret(program);
+    assert_eq!(result, Value::U8(12));
+}
+
+#[test]
+fn struct_pattern_with_rest() {
+    let program = "struct Foo { a: u8, b: u8, c: u8 }
+    comptime fn main() -> pub u8 {
+        let Foo { b, .. } = Foo { a: 1, b: 2, c: 3 };
+        b
+    }";
+    let result = interpret(program);
+    assert_eq!(result, Value::U8(2));
+}
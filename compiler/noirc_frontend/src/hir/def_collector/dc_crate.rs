@@ -64,6 +64,9 @@ pub struct UnresolvedStruct {
     pub file_id: FileId,
     pub module_id: LocalModuleId,
     pub struct_def: NoirStruct,
+    /// The constructor function of a tuple struct, declared alongside the struct so that
+    /// it can be imported. Its definition is filled in once the fields are resolved.
+    pub constructor: Option<FuncId>,
 }
 
 pub struct UnresolvedEnum {
@@ -143,6 +146,10 @@ pub struct DefCollector {
     pub(crate) def_map: CrateDefMap,
     pub(crate) imports: Vec<ImportDirective>,
     pub(crate) items: CollectedItems,
+
+    /// The features enabling items marked `#[cfg(feature = "...")]`. Other such items
+    /// aren't collected.
+    pub(crate) enabled_features: Vec<String>,
 }
 
 #[derive(Default)]
@@ -284,7 +291,7 @@ impl From<UnsupportedNumericGenericType> for CompilationError {
 }
 
 impl DefCollector {
-    pub fn new(def_map: CrateDefMap) -> DefCollector {
+    pub fn new(def_map: CrateDefMap, enabled_features: Vec<String>) -> DefCollector {
         DefCollector {
             def_map,
             imports: vec![],
@@ -299,6 +306,7 @@ impl DefCollector {
                 trait_impls: vec![],
                 module_attributes: vec![],
             },
+            enabled_features,
         }
     }
 
@@ -323,8 +331,10 @@ impl DefCollector {
         //
         let crate_graph = &context.crate_graph[crate_id];
 
+        // Features are enabled for the crate being compiled, not for its dependencies
+        let dependency_options = FrontendOptions { enabled_features: &[], ..options };
         for dep in crate_graph.dependencies.clone() {
-            errors.extend(CrateDefMap::collect_defs(dep.crate_id, context, options));
+            errors.extend(CrateDefMap::collect_defs(dep.crate_id, context, dependency_options));
 
             let dep_def_map =
                 context.def_map(&dep.crate_id).expect("ice: def map was just created");
@@ -348,7 +358,7 @@ impl DefCollector {
         //
         // It is now possible to collect all of the definitions of this crate.
         let crate_root = def_map.root();
-        let mut def_collector = DefCollector::new(def_map);
+        let mut def_collector = DefCollector::new(def_map, options.enabled_features.to_vec());
 
         let module_id = ModuleId { krate: crate_id, local_id: crate_root };
         context
@@ -496,6 +506,8 @@ impl DefCollector {
             debug_comptime_in_file,
             pedantic_solving: options.pedantic_solving,
             enabled_unstable_features: options.enabled_unstable_features,
+            pattern_depth_limit: options.pattern_depth_limit,
+            enabled_features: options.enabled_features,
         };
 
         let mut more_errors =
@@ -53,12 +53,14 @@ struct ModCollector<'a> {
 /// This performs the entirety of the definition collection phase of the name resolution pass.
 pub fn collect_defs(
     def_collector: &mut DefCollector,
-    ast: SortedModule,
+    mut ast: SortedModule,
     file_id: FileId,
     module_id: LocalModuleId,
     crate_id: CrateId,
     context: &mut Context,
 ) -> Vec<CompilationError> {
+    remove_disabled_items(&mut ast, &def_collector.enabled_features);
+
     let mut collector = ModCollector { def_collector, file_id, module_id };
     let mut errors: Vec<CompilationError> = vec![];
 
@@ -116,6 +118,37 @@ pub fn collect_defs(
     errors
 }
 
+/// Removes the items of `module` marked `#[cfg(feature = "...")]` with a feature which isn't
+/// enabled, so that they're neither collected nor elaborated. Submodules and module
+/// declarations are removed along with their contents, and methods are removed from both
+/// inherent and trait impls.
+fn remove_disabled_items(module: &mut SortedModule, enabled_features: &[String]) {
+    let is_enabled = |attributes: &[SecondaryAttribute]| {
+        attributes.iter().all(|attribute| match attribute {
+            SecondaryAttribute::Cfg(feature) => enabled_features.contains(feature),
+            _ => true,
+        })
+    };
+
+    module.functions.retain(|function| is_enabled(&function.item.attributes().secondary));
+    module.structs.retain(|typ| is_enabled(&typ.item.attributes));
+    module.enums.retain(|typ| is_enabled(&typ.item.attributes));
+    module.traits.retain(|noir_trait| is_enabled(&noir_trait.item.attributes));
+    module.globals.retain(|(global, _)| is_enabled(&global.item.attributes));
+    module.type_aliases.retain(|alias| is_enabled(&alias.item.attributes));
+    module.module_decls.retain(|decl| is_enabled(&decl.item.outer_attributes));
+    module.submodules.retain(|submodule| is_enabled(&submodule.item.outer_attributes));
+    for r#impl in &mut module.impls {
+        r#impl.methods.retain(|(method, _)| is_enabled(&method.item.attributes().secondary));
+    }
+    for trait_impl in &mut module.trait_impls {
+        trait_impl.items.retain(|item| match &item.item.kind {
+            TraitImplItemKind::Function(method) => is_enabled(&method.attributes().secondary),
+            TraitImplItemKind::Constant(..) | TraitImplItemKind::Type { .. } => true,
+        });
+    }
+}
+
 impl ModCollector<'_> {
     fn collect_attributes(
         &mut self,
@@ -1061,7 +1094,8 @@ pub fn collect_struct(
 
     let name = struct_definition.name.clone();
 
-    let unresolved = UnresolvedStruct { file_id, module_id, struct_def: struct_definition };
+    let mut unresolved =
+        UnresolvedStruct { file_id, module_id, struct_def: struct_definition, constructor: None };
 
     let resolved_generics =
         Context::resolve_generics(interner, &unresolved.struct_def.generics, definition_errors);
@@ -1127,6 +1161,25 @@ pub fn collect_struct(
         definition_errors.push(error.into());
     }
 
+    // Tuple structs also declare a constructor function with the same name
+    if unresolved.struct_def.is_tuple_struct {
+        interner.update_type(id, |data_type| data_type.is_tuple_struct = true);
+
+        let constructor_visibility = unresolved.struct_def.constructor_visibility();
+        let func_id = interner.push_empty_fn();
+        let result =
+            def_map[module_id].declare_function(name.clone(), constructor_visibility, func_id);
+        if let Err((first_def, second_def)) = result {
+            let error = DefCollectorErrorKind::Duplicate {
+                typ: DuplicateType::Function,
+                first_def,
+                second_def,
+            };
+            definition_errors.push(error.into());
+        }
+        unresolved.constructor = Some(func_id);
+    }
+
     if interner.is_in_lsp_mode() {
         interner.register_type(id, name.to_string(), location, visibility, parent_module_id);
     }
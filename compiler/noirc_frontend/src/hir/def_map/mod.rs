@@ -6,6 +6,7 @@ use crate::node_interner::{FuncId, NodeInterner};
 use crate::parse_program;
 use crate::parser::{ParsedModule, ParserError};
 use crate::token::{FunctionAttribute, FuzzingScope, TestScope};
+use acvm::FieldElement;
 use fm::{FileId, FileManager};
 use noirc_arena::{Arena, Index};
 use noirc_errors::Location;
@@ -391,13 +392,18 @@ impl TestFunction {
         self.location.file
     }
 
+    /// Returns the location of the test function's name
+    pub fn location(&self) -> Location {
+        self.location
+    }
+
     /// Returns true if the test function has been specified to fail
     /// This is done by annotating the function with `#[test(should_fail)]`
     /// or `#[test(should_fail_with = "reason")]`
     pub fn should_fail(&self) -> bool {
         match self.scope {
             TestScope::ShouldFailWith { .. } => true,
-            TestScope::None => false,
+            TestScope::Generic { .. } | TestScope::None => false,
         }
     }
 
@@ -405,10 +411,22 @@ impl TestFunction {
     /// by the user.
     pub fn failure_reason(&self) -> Option<&str> {
         match &self.scope {
-            TestScope::None => None,
+            TestScope::Generic { .. } | TestScope::None => None,
             TestScope::ShouldFailWith { reason } => reason.as_deref(),
         }
     }
+
+    /// Returns the values to bind the test function's numeric generics to, by name,
+    /// for each run of the test. This is done by annotating the function with one or more
+    /// `#[test(N = 8)]` attributes.
+    ///
+    /// This is empty if the test function isn't generic.
+    pub fn generic_instantiations(&self) -> &[Vec<(String, FieldElement)>] {
+        match &self.scope {
+            TestScope::Generic { instantiations } => instantiations,
+            TestScope::ShouldFailWith { .. } | TestScope::None => &[],
+        }
+    }
 }
 
 pub struct FuzzingHarness {
@@ -1,4 +1,5 @@
 use acvm::FieldElement;
+use iter_extended::vecmap;
 pub use noirc_errors::Span;
 use noirc_errors::{CustomDiagnostic as Diagnostic, Location};
 use thiserror::Error;
@@ -43,11 +44,22 @@ pub enum ResolverError {
     #[error("Expected")]
     Expected { location: Location, expected: &'static str, got: &'static str },
     #[error("Duplicate field in constructor")]
-    DuplicateField { field: Ident },
+    DuplicateField { field: Ident, first_location: Location },
     #[error("No such field in struct")]
     NoSuchField { field: Ident, struct_definition: Ident },
     #[error("Missing fields from struct")]
-    MissingFields { location: Location, missing_fields: Vec<String>, struct_definition: Ident },
+    MissingFields {
+        location: Location,
+        /// The name and declaration location of each missing field, in declaration order.
+        missing_fields: Vec<(String, Location)>,
+        struct_definition: Ident,
+        /// Whether the error is on a pattern, which can ignore the missing fields with `..`.
+        suggest_rest: bool,
+    },
+    #[error("Patterns on a non-exhaustive struct outside of its crate must end with `..`")]
+    NonExhaustiveStructPatternWithoutRest { location: Location, struct_definition: Ident },
+    #[error("Variables destructured from a mutable reference cannot be mutable")]
+    MutableBindingThroughMutableReference { location: Location },
     #[error("Unneeded 'mut', pattern is already marked as mutable")]
     UnnecessaryMut { first_mut: Location, second_mut: Location },
     #[error("Unneeded 'pub', function is not the main method")]
@@ -92,6 +104,8 @@ pub enum ResolverError {
     UnconstrainedOracleReturnToConstrained { location: Location },
     #[error("Dependency cycle found, '{item}' recursively depends on itself: {cycle} ")]
     DependencyCycle { location: Location, item: String, cycle: String },
+    #[error("Dependency cycle found between globals: {cycle}")]
+    GlobalDependencyCycle { globals: Vec<(String, Location)>, cycle: String },
     #[error("break/continue are only allowed in unconstrained functions")]
     JumpInConstrainedFn { is_break: bool, location: Location },
     #[error("`loop` is only allowed in unconstrained functions")]
@@ -130,6 +144,8 @@ pub enum ResolverError {
     ArrayLengthInterpreter { error: InterpreterError },
     #[error("The unquote operator '$' can only be used within a quote expression")]
     UnquoteUsedOutsideQuote { location: Location },
+    #[error("Tuples can only be assigned to as a whole")]
+    NestedTupleInAssignment { location: Location },
     #[error("Invalid syntax in macro call")]
     InvalidSyntaxInMacroCall { location: Location },
     #[error("Macros must be comptime functions")]
@@ -200,6 +216,12 @@ pub enum ResolverError {
         "The type parameter `{ident}` is not constrained by the impl trait, self type, or predicates"
     )]
     UnconstrainedTypeParameter { ident: Ident },
+    #[error("Pattern is nested too deeply")]
+    PatternTooDeep { limit: usize, location: Location },
+    #[error("Range pattern `{range}` is empty")]
+    EmptyRangeInPattern { range: String, location: Location },
+    #[error("Ranges can only be used as match patterns")]
+    RangeUsedOutsidePattern { location: Location },
 }
 
 impl ResolverError {
@@ -211,6 +233,8 @@ impl ResolverError {
             | ResolverError::Expected { location, .. }
             | ResolverError::VariableNotDeclared { location, .. }
             | ResolverError::MissingFields { location, .. }
+            | ResolverError::NonExhaustiveStructPatternWithoutRest { location, .. }
+            | ResolverError::MutableBindingThroughMutableReference { location }
             | ResolverError::UnnecessaryMut { second_mut: location, .. }
             | ResolverError::TypeIsMorePrivateThenItem { location, .. }
             | ResolverError::UnableToParseAttribute { location, .. }
@@ -247,6 +271,7 @@ impl ResolverError {
             | ResolverError::SelfReferentialType { location }
             | ResolverError::NumericGenericUsedForType { location, .. }
             | ResolverError::UnquoteUsedOutsideQuote { location }
+            | ResolverError::NestedTupleInAssignment { location }
             | ResolverError::InvalidSyntaxInMacroCall { location }
             | ResolverError::MacroIsNotComptime { location }
             | ResolverError::NonFunctionInAnnotation { location }
@@ -264,10 +289,13 @@ impl ResolverError {
             | ResolverError::UnexpectedItemInPattern { location, .. }
             | ResolverError::NoSuchMethodInTrait { location, .. }
             | ResolverError::VariableAlreadyDefinedInPattern { new_location: location, .. }
-            | ResolverError::NonU32Index { location } => *location,
+            | ResolverError::NonU32Index { location }
+            | ResolverError::PatternTooDeep { location, .. }
+            | ResolverError::EmptyRangeInPattern { location, .. }
+            | ResolverError::RangeUsedOutsidePattern { location } => *location,
             ResolverError::UnusedVariable { ident }
             | ResolverError::UnusedItem { ident, .. }
-            | ResolverError::DuplicateField { field: ident }
+            | ResolverError::DuplicateField { field: ident, .. }
             | ResolverError::NoSuchField { field: ident, .. }
             | ResolverError::UnnecessaryPub { ident, .. }
             | ResolverError::NecessaryPub { ident }
@@ -276,6 +304,7 @@ impl ResolverError {
             | ResolverError::NoPredicatesAttributeOnUnconstrained { ident }
             | ResolverError::FoldAttributeOnUnconstrained { ident }
             | ResolverError::UnconstrainedTypeParameter { ident } => ident.location(),
+            ResolverError::GlobalDependencyCycle { globals, .. } => globals[0].1,
             ResolverError::ArrayLengthInterpreter { error } => error.location(),
             ResolverError::PathResolutionError(path_resolution_error) => {
                 path_resolution_error.location()
@@ -366,11 +395,15 @@ impl<'a> From<&'a ResolverError> for Diagnostic {
                 String::new(),
                 *location,
             ),
-            ResolverError::DuplicateField { field } => Diagnostic::simple_error(
-                format!("duplicate field {field}"),
-                String::new(),
-                field.location(),
-            ),
+            ResolverError::DuplicateField { field, first_location } => {
+                let mut diagnostic = Diagnostic::simple_error(
+                    format!("duplicate field {field}"),
+                    String::new(),
+                    field.location(),
+                );
+                diagnostic.add_secondary("First field found here".to_string(), *first_location);
+                diagnostic
+            }
             ResolverError::NoSuchField { field, struct_definition } => {
                 Diagnostic::simple_error(
                     format!("no such field {field} defined in struct {struct_definition}"),
@@ -378,9 +411,18 @@ impl<'a> From<&'a ResolverError> for Diagnostic {
                     field.location(),
                 )
             }
-            ResolverError::MissingFields { location, missing_fields, struct_definition } => {
+            ResolverError::MissingFields {
+                location,
+                missing_fields,
+                struct_definition,
+                suggest_rest,
+            } => {
+                let mut missing_fields = vecmap(missing_fields, |(name, _)| name.clone());
+                missing_fields.sort();
+
                 let plural = if missing_fields.len() != 1 { "s" } else { "" };
                 let remaining_fields_names = match &missing_fields[..] {
+                    [] => String::new(),
                     [field1] => field1.clone(),
                     [field1, field2] => format!("{field1} and {field2}"),
                     [field1, field2, field3] => format!("{field1}, {field2} and {field3}"),
@@ -394,10 +436,30 @@ impl<'a> From<&'a ResolverError> for Diagnostic {
                     }
                 };
 
-                Diagnostic::simple_error(
+                let mut diagnostic = Diagnostic::simple_error(
                     format!("missing field{plural} {remaining_fields_names} in struct {struct_definition}"),
                     String::new(),
                     *location,
+                );
+                if *suggest_rest {
+                    diagnostic.add_note(format!(
+                        "If {struct_definition} gained new fields, this pattern may need updating. Use `..` to ignore the fields it doesn't list"
+                    ));
+                }
+                diagnostic
+            }
+            ResolverError::NonExhaustiveStructPatternWithoutRest { location, struct_definition } => {
+                Diagnostic::simple_error(
+                    format!("struct {struct_definition} is marked `#[non_exhaustive]`, so patterns on it outside of its crate must end with `..`"),
+                    "add `..` to ignore the fields which aren't listed".to_string(),
+                    *location,
+                )
+            }
+            ResolverError::MutableBindingThroughMutableReference { location } => {
+                Diagnostic::simple_error(
+                    "Variables destructured from a mutable reference cannot be mutable".to_string(),
+                    "They hold copies of the fields, so assigning to them wouldn't change the referenced value".to_string(),
+                    *location,
                 )
             }
             ResolverError::UnnecessaryMut { first_mut, second_mut } => {
@@ -522,6 +584,18 @@ impl<'a> From<&'a ResolverError> for Diagnostic {
                     *location,
                 )
             },
+            ResolverError::GlobalDependencyCycle { globals, cycle } => {
+                let (item, location) = &globals[0];
+                let mut diagnostic = Diagnostic::simple_error(
+                    "Dependency cycle found between globals".into(),
+                    format!("'{item}' recursively depends on itself: {cycle}"),
+                    *location,
+                );
+                for (item, location) in globals.iter().skip(1) {
+                    diagnostic.add_secondary(format!("'{item}' is part of this cycle"), *location);
+                }
+                diagnostic
+            },
             ResolverError::JumpInConstrainedFn { is_break, location } => {
                 let item = if *is_break { "break" } else { "continue" };
                 Diagnostic::simple_error(
@@ -645,6 +719,13 @@ impl<'a> From<&'a ResolverError> for Diagnostic {
                     *location,
                 )
             },
+            ResolverError::NestedTupleInAssignment { location } => {
+                Diagnostic::simple_error(
+                    "Tuples can only be assigned to as a whole".into(),
+                    "A tuple of places cannot be part of a field access, index or dereference".into(),
+                    *location,
+                )
+            },
             ResolverError::InvalidSyntaxInMacroCall { location } => {
                 Diagnostic::simple_error(
                     "Invalid syntax in macro call".into(),
@@ -823,6 +904,27 @@ impl<'a> From<&'a ResolverError> for Diagnostic {
                     ident.location(),
                 )
             }
+            ResolverError::PatternTooDeep { limit, location } => {
+                Diagnostic::simple_error(
+                    "Pattern is nested too deeply".to_string(),
+                    format!("Patterns may be nested at most {limit} levels deep"),
+                    *location,
+                )
+            }
+            ResolverError::EmptyRangeInPattern { range, location } => {
+                Diagnostic::simple_error(
+                    format!("Range pattern `{range}` is empty"),
+                    "The start of a range pattern must be lower than its end".to_string(),
+                    *location,
+                )
+            }
+            ResolverError::RangeUsedOutsidePattern { location } => {
+                Diagnostic::simple_error(
+                    "Ranges can only be used as match patterns".to_string(),
+                    String::new(),
+                    *location,
+                )
+            }
         }
     }
 }
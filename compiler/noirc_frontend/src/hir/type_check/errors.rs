@@ -67,6 +67,13 @@ pub enum TypeCheckError {
     TypeMismatchWithSource { expected: Type, actual: Type, location: Location, source: Source },
     #[error("Expected type {expected_kind:?} is not the same as {expr_kind:?}")]
     TypeKindMismatch { expected_kind: Kind, expr_kind: Kind, expr_location: Location },
+    #[error("Numeric generic `{name}: {generic_type}` used where `{expected_type}` is required")]
+    NumericGenericTypeMismatch {
+        name: String,
+        generic_type: Type,
+        expected_type: Type,
+        location: Location,
+    },
     #[error("Evaluating {to} resulted in {to_value}, but {from_value} was expected")]
     TypeCanonicalizationMismatch {
         to: Type,
@@ -192,6 +199,24 @@ pub enum TypeCheckError {
         actual_count: usize,
         location: Location,
     },
+    #[error("`{name}` takes no generic arguments")]
+    TurbofishOnNonGenericFunction { name: String, location: Location },
+    #[error("Generic `{generic_name}` expects kind {expected_kind}, but `{provided}` was provided")]
+    TurbofishGenericKindMismatch {
+        generic_name: String,
+        expected_kind: Kind,
+        provided: Type,
+        location: Location,
+    },
+    #[error(
+        "Generic `{generic_name}` was set to `{turbofish_type}` by turbofish but argument implies `{argument_type}`"
+    )]
+    TurbofishArgumentMismatch {
+        generic_name: String,
+        turbofish_type: Type,
+        argument_type: Type,
+        location: Location,
+    },
     #[error(
         "Cannot pass a mutable reference from a constrained runtime to an unconstrained runtime"
     )]
@@ -224,6 +249,8 @@ pub enum TypeCheckError {
     },
     #[error("Strings do not support indexed assignment")]
     StringIndexAssign { location: Location },
+    #[error("Cannot mutate through an immutable reference")]
+    MutationThroughImmutableReference { location: Location },
     #[error("Macro calls may only return `Quoted` values")]
     MacroReturningNonExpr { typ: Type, location: Location },
     #[error("`{name}` has already been specified")]
@@ -321,6 +348,10 @@ impl TypeCheckError {
             }
             | TypeCheckError::UnneededTraitConstraint { location, .. }
             | TypeCheckError::IncorrectTurbofishGenericCount { location, .. }
+            | TypeCheckError::TurbofishOnNonGenericFunction { location, .. }
+            | TypeCheckError::TurbofishGenericKindMismatch { location, .. }
+            | TypeCheckError::TurbofishArgumentMismatch { location, .. }
+            | TypeCheckError::NumericGenericTypeMismatch { location, .. }
             | TypeCheckError::ConstrainedReferenceToUnconstrained { location }
             | TypeCheckError::UnconstrainedReferenceToConstrained { location }
             | TypeCheckError::UnconstrainedSliceReturnToConstrained { location }
@@ -331,6 +362,7 @@ impl TypeCheckError {
             | TypeCheckError::InvalidTypeForEntryPoint { location }
             | TypeCheckError::MismatchTraitImplNumParameters { location, .. }
             | TypeCheckError::StringIndexAssign { location }
+            | TypeCheckError::MutationThroughImmutableReference { location }
             | TypeCheckError::MacroReturningNonExpr { location, .. }
             | TypeCheckError::MissingNamedTypeArg { location, .. }
             | TypeCheckError::UnspecifiedType { location }
@@ -422,6 +454,13 @@ impl<'a> From<&'a TypeCheckError> for Diagnostic {
                     }
                 }
             }
+            TypeCheckError::NumericGenericTypeMismatch { name, generic_type, expected_type, location } => {
+                Diagnostic::simple_error(
+                    format!("Numeric generic `{name}: {generic_type}` used where `{expected_type}` is required"),
+                    format!("consider declaring `{name}` as `let {name}: {expected_type}`"),
+                    *location,
+                )
+            }
             TypeCheckError::TypeCanonicalizationMismatch { to, from, to_value, from_value, location } => {
                 Diagnostic::simple_error(
                     format!("Evaluating {to} resulted in {to_value}, but {from_value} was expected"),
@@ -520,6 +559,7 @@ impl<'a> From<&'a TypeCheckError> for Diagnostic {
             | TypeCheckError::NonConstantEvaluated { location, .. }
             | TypeCheckError::NonConstantSliceLength { location }
             | TypeCheckError::StringIndexAssign { location }
+            | TypeCheckError::MutationThroughImmutableReference { location }
             | TypeCheckError::InvalidShiftSize { location } => {
                 Diagnostic::simple_error(error.to_string(), String::new(), *location)
             }
@@ -633,6 +673,21 @@ impl<'a> From<&'a TypeCheckError> for Diagnostic {
                 let msg = format!("Expected {expected_count} generic{expected_plural} from this function, but {actual_count} {actual_plural} provided");
                 Diagnostic::simple_error(msg, "".into(), *location)
             },
+            TypeCheckError::TurbofishOnNonGenericFunction { name, location } => {
+                let msg = format!("`{name}` takes no generic arguments");
+                Diagnostic::simple_error(msg, "".into(), *location)
+            },
+            TypeCheckError::TurbofishGenericKindMismatch { generic_name, expected_kind, provided, location } => {
+                let msg = match expected_kind {
+                    Kind::Numeric(typ) => format!("Generic `{generic_name}` expects a numeric value of type `{typ}`, but a type `{provided}` was provided"),
+                    _ => format!("Generic `{generic_name}` expects a type, but a numeric value `{provided}` was provided"),
+                };
+                Diagnostic::simple_error(msg, String::new(), *location)
+            },
+            TypeCheckError::TurbofishArgumentMismatch { generic_name, turbofish_type, argument_type, location } => {
+                let msg = format!("Generic `{generic_name}` was set to `{turbofish_type}` by turbofish but argument implies `{argument_type}`");
+                Diagnostic::simple_error(msg, String::new(), *location)
+            },
             TypeCheckError::MacroReturningNonExpr { typ, location } =>  {
                 let mut error = Diagnostic::simple_error(
                     format!("Expected macro call to return a `Quoted` but found a(n) `{typ}`"),
@@ -145,6 +145,10 @@ pub struct FuncMeta {
     /// If this function is the one related to an enum variant, this holds its index (relative to `type_id`)
     pub enum_variant_index: Option<usize>,
 
+    /// True if this function is the constructor of a tuple struct, e.g. `Foo` for
+    /// `struct Foo(Field);`. Its return type is the tuple struct.
+    pub is_tuple_struct_constructor: bool,
+
     /// True if this function is an entry point to the program.
     /// For non-contracts, this means the function is `main`.
     pub is_entry_point: bool,
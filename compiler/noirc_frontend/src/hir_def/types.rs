@@ -340,6 +340,10 @@ pub struct DataType {
 
     pub generics: Generics,
     pub location: Location,
+
+    /// True if this is a struct declared as a tuple struct, e.g. `struct Foo(Field);`.
+    /// The fields of a tuple struct are named after their position.
+    pub is_tuple_struct: bool,
 }
 
 enum TypeBody {
@@ -436,7 +440,7 @@ impl Ord for DataType {
 
 impl DataType {
     pub fn new(id: TypeId, name: Ident, location: Location, generics: Generics) -> DataType {
-        DataType { id, name, location, generics, body: TypeBody::None }
+        DataType { id, name, location, generics, body: TypeBody::None, is_tuple_struct: false }
     }
 
     /// To account for cyclic references between structs, a struct's
@@ -625,6 +629,20 @@ impl DataType {
         &self.variants_raw().unwrap()[index]
     }
 
+    /// Returns the name and declaration location of each of this type's fields accepted by
+    /// `include`, in declaration order. Returns an empty Vec if this is not a struct type.
+    pub fn field_locations(
+        &self,
+        mut include: impl FnMut(&str) -> bool,
+    ) -> Vec<(String, Location)> {
+        let fields = self.fields_raw().unwrap_or_default();
+        fields
+            .iter()
+            .filter(|field| include(field.name.as_str()))
+            .map(|field| (field.name.to_string(), field.name.location()))
+            .collect()
+    }
+
     /// Returns each of this type's field names. Returns None if this is not a struct type.
     pub fn field_names(&self) -> Option<BTreeSet<Ident>> {
         Some(self.fields_raw()?.iter().map(|field| field.name.clone()).collect())
@@ -24,6 +24,8 @@ pub enum LexerErrorKind {
     MalformedTestAttribute { location: Location },
     #[error("Malformed fuzz attribute")]
     MalformedFuzzAttribute { location: Location },
+    #[error("Malformed cfg attribute")]
+    MalformedCfgAttribute { location: Location },
     #[error("{:?} is not a valid inner attribute", found)]
     InvalidInnerAttribute { location: Location, found: String },
     #[error("Unterminated block comment")]
@@ -69,6 +71,7 @@ impl LexerErrorKind {
             LexerErrorKind::MalformedFuncAttribute { location, .. } => *location,
             LexerErrorKind::MalformedTestAttribute { location, .. } => *location,
             LexerErrorKind::MalformedFuzzAttribute { location, .. } => *location,
+            LexerErrorKind::MalformedCfgAttribute { location } => *location,
             LexerErrorKind::InvalidInnerAttribute { location, .. } => *location,
             LexerErrorKind::UnterminatedBlockComment { location } => *location,
             LexerErrorKind::UnterminatedStringLiteral { location } => *location,
@@ -128,6 +131,11 @@ impl LexerErrorKind {
                 "The fuzz attribute can be written in one of these forms: `#[fuzz]` or `#[fuzz(only_fail_with = \"message\")]`".to_string(),
                 *location,
             ),
+            LexerErrorKind::MalformedCfgAttribute { location } => (
+                "Malformed cfg attribute".to_string(),
+                "The cfg attribute must be written as `#[cfg(feature = \"name\")]`".to_string(),
+                *location,
+            ),
             LexerErrorKind::InvalidInnerAttribute { location, found } => (
                 "Invalid inner attribute".to_string(),
                 format!(" {found} is not a valid inner attribute"),
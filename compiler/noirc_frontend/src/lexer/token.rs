@@ -1,4 +1,5 @@
 use acvm::FieldElement;
+use iter_extended::vecmap;
 use noirc_errors::{Located, Location, Position, Span, Spanned};
 use std::fmt::{self, Display};
 
@@ -734,6 +735,11 @@ pub enum TestScope {
     /// if it fails with the specified reason. If the reason is None, then
     /// the test must unconditionally fail
     ShouldFailWith { reason: Option<String> },
+    /// If a test has a scope of Generic, then it is run once for each instantiation,
+    /// which binds the test's numeric generics by name, e.g. `#[test(N = 8)]`.
+    /// A test can be annotated with several such attributes, which are merged
+    /// into a single scope.
+    Generic { instantiations: Vec<Vec<(String, FieldElement)>> },
     /// No scope is applied and so the test must pass
     None,
 }
@@ -746,6 +752,13 @@ impl fmt::Display for TestScope {
                 Some(failure_reason) => write!(f, "(should_fail_with = {failure_reason:?})"),
                 None => write!(f, "(should_fail)"),
             },
+            TestScope::Generic { instantiations } => {
+                let instantiations = vecmap(instantiations, |bindings| {
+                    let bindings = vecmap(bindings, |(name, value)| format!("{name} = {value}"));
+                    format!("({})", bindings.join(", "))
+                });
+                write!(f, "{}", instantiations.join("] #[test"))
+            }
         }
     }
 }
@@ -1008,6 +1021,13 @@ pub enum SecondaryAttribute {
 
     /// Allow chosen warnings to happen so they are silenced.
     Allow(String),
+
+    /// Marks a struct as possibly gaining fields in the future, so that patterns on it outside
+    /// of its crate must ignore the fields they don't list with `..`: `#[non_exhaustive]`
+    NonExhaustive,
+
+    /// Only compile the item if the given feature is enabled: `#[cfg(feature = "foo")]`
+    Cfg(String),
 }
 
 impl SecondaryAttribute {
@@ -1025,6 +1045,8 @@ impl SecondaryAttribute {
             SecondaryAttribute::Varargs => Some("varargs".to_string()),
             SecondaryAttribute::UseCallersScope => Some("use_callers_scope".to_string()),
             SecondaryAttribute::Allow(_) => Some("allow".to_string()),
+            SecondaryAttribute::NonExhaustive => Some("non_exhaustive".to_string()),
+            SecondaryAttribute::Cfg(_) => Some("cfg".to_string()),
         }
     }
 
@@ -1054,6 +1076,8 @@ impl SecondaryAttribute {
             SecondaryAttribute::Varargs => "varargs".to_string(),
             SecondaryAttribute::UseCallersScope => "use_callers_scope".to_string(),
             SecondaryAttribute::Allow(k) => format!("allow({k})"),
+            SecondaryAttribute::NonExhaustive => "non_exhaustive".to_string(),
+            SecondaryAttribute::Cfg(feature) => format!("cfg(feature = {feature:?})"),
         }
     }
 }
@@ -110,6 +110,7 @@ impl NodeInterner {
                 Location::new(alias_type.name.span(), alias_type.location.file)
             }
             ReferenceId::Local(id) => self.definition(id).location,
+            ReferenceId::NumericGeneric(location) => location,
             ReferenceId::Reference(location, _) => location,
         }
     }
@@ -193,6 +194,15 @@ impl NodeInterner {
         self.add_reference(ReferenceId::Local(id), location, false);
     }
 
+    /// Records a use of the numeric generic declared at `declaration_location`.
+    pub(crate) fn add_numeric_generic_reference(
+        &mut self,
+        declaration_location: Location,
+        location: Location,
+    ) {
+        self.add_reference(ReferenceId::NumericGeneric(declaration_location), location, false);
+    }
+
     pub(crate) fn add_reference(
         &mut self,
         referenced: ReferenceId,
@@ -241,6 +251,32 @@ impl NodeInterner {
         index
     }
 
+    pub(crate) fn add_generated_item(
+        &mut self,
+        item_location: Location,
+        attribute: FuncId,
+        attribute_location: Location,
+    ) {
+        self.generated_items.push((item_location, attribute, attribute_location));
+    }
+
+    /// Returns the function of the attribute which generated the code at `location`, along with
+    /// the location of the attribute, or `None` if the code at `location` wasn't generated.
+    ///
+    /// The code generated by an attribute is located in the `quote` it was created from, so this
+    /// is the innermost item generated from that `quote` which contains `location`.
+    pub fn find_generating_attribute(&self, location: Location) -> Option<(ReferenceId, Location)> {
+        self.generated_items
+            .iter()
+            .filter(|(item_location, _, _)| item_location.contains(&location))
+            .min_by_key(|(item_location, _, _)| {
+                item_location.span.end() - item_location.span.start()
+            })
+            .map(|(_, attribute, attribute_location)| {
+                (ReferenceId::Function(*attribute), *attribute_location)
+            })
+    }
+
     // Given a reference location, find the location of the referenced node.
     pub fn find_referenced_location(&self, reference_location: Location) -> Option<Location> {
         self.location_indices
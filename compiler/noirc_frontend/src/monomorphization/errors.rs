@@ -1,3 +1,4 @@
+use acvm::FieldElement;
 use noirc_errors::{CustomDiagnostic, Location};
 
 use crate::{
@@ -12,6 +13,11 @@ pub enum MonomorphizationError {
         err: TypeCheckError,
         location: Location,
     },
+    ArrayLengthOverflow {
+        length: String,
+        generics: Vec<(String, FieldElement)>,
+        location: Location,
+    },
     UnknownConstant {
         location: Location,
     },
@@ -57,6 +63,7 @@ impl MonomorphizationError {
     fn location(&self) -> Location {
         match self {
             MonomorphizationError::UnknownArrayLength { location, .. }
+            | MonomorphizationError::ArrayLengthOverflow { location, .. }
             | MonomorphizationError::UnknownConstant { location }
             | MonomorphizationError::InternalError { location, .. }
             | MonomorphizationError::ComptimeFnInRuntimeCode { location, .. }
@@ -77,6 +84,14 @@ impl From<MonomorphizationError> for CustomDiagnostic {
             MonomorphizationError::UnknownArrayLength { length, err, .. } => {
                 format!("Could not determine array length `{length}`, encountered error: `{err}`")
             }
+            MonomorphizationError::ArrayLengthOverflow { length, generics, location } => {
+                let generics: Vec<_> =
+                    generics.iter().map(|(name, value)| format!("{name} = {value}")).collect();
+                let message =
+                    format!("Array length `{length}` overflows `u32` for {}", generics.join(", "));
+                let secondary = "Array lengths must fit in a `u32`".into();
+                return CustomDiagnostic::simple_error(message, secondary, *location);
+            }
             MonomorphizationError::UnknownConstant { .. } => {
                 "Could not resolve constant".to_string()
             }
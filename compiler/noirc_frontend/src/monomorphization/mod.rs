@@ -111,6 +111,9 @@ struct Monomorphizer<'interner> {
     debug_type_tracker: DebugTypeTracker,
 
     in_unconstrained_function: bool,
+
+    /// The generic arguments each generic function was monomorphized with.
+    generic_instances: GenericInstances,
 }
 
 /// Using nested HashMaps here lets us avoid cloning HirTypes when calling .get()
@@ -121,6 +124,13 @@ type Functions = HashMap<
 
 type HirType = crate::Type;
 
+/// The generic arguments of each monomorphic instance of a generic function, keyed by the
+/// function. Each generic argument is paired with the name of the generic it's bound to.
+///
+/// A function called from both constrained and unconstrained code is monomorphized separately
+/// for each, so the same generic arguments may appear twice.
+pub type GenericInstances = BTreeMap<node_interner::FuncId, Vec<Vec<(String, HirType)>>>;
+
 /// Starting from the given `main` function, monomorphize the entire program,
 /// replacing all references to type variables and NamedGenerics with concrete
 /// types, duplicating definitions as necessary to do so.
@@ -151,23 +161,7 @@ pub fn monomorphize_debug(
     let mut monomorphizer = Monomorphizer::new(interner, debug_type_tracker);
     monomorphizer.in_unconstrained_function = force_unconstrained;
     let function_sig = monomorphizer.compile_main(main)?;
-
-    while !monomorphizer.queue.is_empty() {
-        let (next_fn_id, new_id, bindings, trait_method, is_unconstrained, location) =
-            monomorphizer.queue.pop_front().unwrap();
-        monomorphizer.locals.clear();
-
-        monomorphizer.in_unconstrained_function = is_unconstrained;
-
-        perform_instantiation_bindings(&bindings);
-        let interner = &monomorphizer.interner;
-        let impl_bindings = perform_impl_bindings(interner, trait_method, next_fn_id, location)
-            .map_err(MonomorphizationError::InterpreterError)?;
-
-        monomorphizer.function(next_fn_id, new_id, location)?;
-        undo_instantiation_bindings(impl_bindings);
-        undo_instantiation_bindings(bindings);
-    }
+    monomorphizer.monomorphize_queue()?;
 
     let func_sigs = monomorphizer
         .finished_functions
@@ -205,6 +199,23 @@ pub fn monomorphize_debug(
     Ok(program.handle_ownership(monomorphizer.next_local_id))
 }
 
+/// Monomorphizes the program reachable from the given `main` function, returning the generic
+/// arguments of each monomorphic instance of a generic function which was created.
+///
+/// This can be used to find generic functions which are instantiated many times, as each
+/// instance is compiled separately.
+pub fn generic_instances(
+    main: node_interner::FuncId,
+    interner: &mut NodeInterner,
+    force_unconstrained: bool,
+) -> Result<GenericInstances, MonomorphizationError> {
+    let mut monomorphizer = Monomorphizer::new(interner, DebugTypeTracker::default());
+    monomorphizer.in_unconstrained_function = force_unconstrained;
+    monomorphizer.compile_main(main)?;
+    monomorphizer.monomorphize_queue()?;
+    Ok(monomorphizer.generic_instances)
+}
+
 impl<'interner> Monomorphizer<'interner> {
     fn new(interner: &'interner mut NodeInterner, debug_type_tracker: DebugTypeTracker) -> Self {
         Monomorphizer {
@@ -223,9 +234,47 @@ impl<'interner> Monomorphizer<'interner> {
             return_location: None,
             debug_type_tracker,
             in_unconstrained_function: false,
+            generic_instances: BTreeMap::new(),
         }
     }
 
+    /// Monomorphizes each function in the queue, until the queue is empty.
+    fn monomorphize_queue(&mut self) -> Result<(), MonomorphizationError> {
+        while let Some((next_fn_id, new_id, bindings, trait_method, is_unconstrained, location)) =
+            self.queue.pop_front()
+        {
+            self.locals.clear();
+
+            self.in_unconstrained_function = is_unconstrained;
+
+            perform_instantiation_bindings(&bindings);
+            let interner = &self.interner;
+            let impl_bindings = perform_impl_bindings(interner, trait_method, next_fn_id, location)
+                .map_err(MonomorphizationError::InterpreterError)?;
+
+            self.record_generic_instance(next_fn_id);
+            self.function(next_fn_id, new_id, location)?;
+            undo_instantiation_bindings(impl_bindings);
+            undo_instantiation_bindings(bindings);
+        }
+        Ok(())
+    }
+
+    /// Records the generic arguments `function` is being monomorphized with, if it is generic.
+    /// The function's generics must be bound to their arguments already.
+    fn record_generic_instance(&mut self, function: node_interner::FuncId) {
+        let generics = &self.interner.function_meta(&function).all_generics;
+        if generics.is_empty() {
+            return;
+        }
+
+        let arguments = vecmap(generics, |generic| {
+            let argument = generic.clone().as_named_generic().follow_bindings();
+            (generic.name.to_string(), argument)
+        });
+        self.generic_instances.entry(function).or_default().push(arguments);
+    }
+
     fn next_local_id(&mut self) -> LocalId {
         let id = self.next_local_id;
         self.next_local_id += 1;
@@ -390,8 +439,9 @@ impl<'interner> Monomorphizer<'interner> {
         let attributes = self.interner.function_attributes(&f);
         let inline_type = InlineType::from(attributes);
 
-        let parameters = self.parameters(&meta.parameters)?;
-        let body = self.expr(body_expr_id)?;
+        let mut parameter_unpacks = Vec::new();
+        let parameters = self.parameters(&meta.parameters, &mut parameter_unpacks)?;
+        let body = prepend_statements(parameter_unpacks, self.expr(body_expr_id)?);
         let function = ast::Function {
             id,
             name,
@@ -414,13 +464,17 @@ impl<'interner> Monomorphizer<'interner> {
 
     /// Monomorphize each parameter, expanding tuple/struct patterns into multiple parameters
     /// and binding any generic types found.
+    ///
+    /// Patterns destructuring a reference are kept as a single parameter, and the statements
+    /// unpacking it are pushed onto `unpacks`, to be prepended to the function body.
     fn parameters(
         &mut self,
         params: &Parameters,
+        unpacks: &mut Vec<ast::Expression>,
     ) -> Result<Vec<(ast::LocalId, bool, String, ast::Type)>, MonomorphizationError> {
         let mut new_params = Vec::with_capacity(params.len());
         for (parameter, typ, _) in &params.0 {
-            self.parameter(parameter, typ, &mut new_params)?;
+            self.parameter(parameter, typ, &mut new_params, unpacks)?;
         }
         Ok(new_params)
     }
@@ -430,8 +484,29 @@ impl<'interner> Monomorphizer<'interner> {
         param: &HirPattern,
         typ: &HirType,
         new_params: &mut Vec<(ast::LocalId, bool, String, ast::Type)>,
+        unpacks: &mut Vec<ast::Expression>,
     ) -> Result<(), MonomorphizationError> {
         match param {
+            // The caller passes a single reference, which can't be split into a parameter
+            // for each field, so it is unpacked at the start of the body instead.
+            HirPattern::Tuple(..) | HirPattern::Struct(..)
+                if matches!(typ.follow_bindings(), HirType::Reference(..)) =>
+            {
+                let location = param.location();
+                let new_id = self.next_local_id();
+                let name = "_".to_string();
+                let ast_type = Self::convert_type(typ, location)?;
+                new_params.push((new_id, false, name.clone(), ast_type.clone()));
+
+                let value = ast::Expression::Ident(ast::Ident {
+                    location: Some(location),
+                    mutable: false,
+                    definition: Definition::Local(new_id),
+                    name,
+                    typ: ast_type,
+                });
+                unpacks.push(self.unpack_pattern(param.clone(), value, typ)?);
+            }
             HirPattern::Identifier(ident) => {
                 let new_id = self.next_local_id();
                 let definition = self.interner.definition(ident.id);
@@ -440,12 +515,12 @@ impl<'interner> Monomorphizer<'interner> {
                 new_params.push((new_id, definition.mutable, name, typ));
                 self.define_local(ident.id, new_id);
             }
-            HirPattern::Mutable(pattern, _) => self.parameter(pattern, typ, new_params)?,
+            HirPattern::Mutable(pattern, _) => self.parameter(pattern, typ, new_params, unpacks)?,
             HirPattern::Tuple(fields, _) => {
                 let tuple_field_types = unwrap_tuple_type(typ);
 
                 for (field, typ) in fields.iter().zip(tuple_field_types) {
-                    self.parameter(field, &typ, new_params)?;
+                    self.parameter(field, &typ, new_params, unpacks)?;
                 }
             }
             HirPattern::Struct(_, fields, location) => {
@@ -461,7 +536,7 @@ impl<'interner> Monomorphizer<'interner> {
                         unreachable!("Expected a field named '{field_name}' in the struct pattern")
                     });
 
-                    self.parameter(field, &field_type, new_params)?;
+                    self.parameter(field, &field_type, new_params, unpacks)?;
                 }
             }
         }
@@ -646,10 +721,7 @@ impl<'interner> Monomorphizer<'interner> {
         let location = self.interner.expr_location(&array);
         let typ = Self::convert_type(&self.interner.id_type(array), location)?;
 
-        let length = length.evaluate_to_u32(location).map_err(|err| {
-            let location = self.interner.expr_location(&array);
-            MonomorphizationError::UnknownArrayLength { location, err, length }
-        })?;
+        let length = evaluate_array_length(&length, location)?;
 
         let contents = try_vecmap(0..length, |_| self.expr(repeated_element))?;
         if is_slice {
@@ -854,11 +926,14 @@ impl<'interner> Monomorphizer<'interner> {
                 }))
             }
             HirPattern::Mutable(pattern, _) => self.unpack_pattern(*pattern, value, typ),
-            HirPattern::Tuple(patterns, _) => {
-                let fields = unwrap_tuple_type(typ);
-                self.unpack_tuple_pattern(value, patterns.into_iter().zip(fields), typ)
+            HirPattern::Tuple(patterns, location) => {
+                let (value, typ) = Self::dereference_pattern_value(value, typ, location)?;
+                let fields = unwrap_tuple_type(&typ);
+                self.unpack_tuple_pattern(value, patterns.into_iter().zip(fields), &typ)
             }
             HirPattern::Struct(_, patterns, location) => {
+                let (value, typ) = Self::dereference_pattern_value(value, typ, location)?;
+                let typ = &typ;
                 let fields = unwrap_struct_type(typ, location)?;
                 assert_eq!(patterns.len(), fields.len());
 
@@ -876,6 +951,27 @@ impl<'interner> Monomorphizer<'interner> {
         }
     }
 
+    /// Dereferences a value destructured by a tuple or struct pattern until it is no longer a
+    /// reference, returning it along with its type. The elaborator allows these patterns to
+    /// match values behind references.
+    fn dereference_pattern_value(
+        mut value: ast::Expression,
+        typ: &HirType,
+        location: Location,
+    ) -> Result<(ast::Expression, HirType), MonomorphizationError> {
+        let mut typ = typ.follow_bindings();
+        while let HirType::Reference(element, _) = typ {
+            typ = element.follow_bindings();
+            value = ast::Expression::Unary(ast::Unary {
+                operator: UnaryOp::Dereference { implicitly_added: true },
+                rhs: Box::new(value),
+                result_type: Self::convert_type(&typ, location)?,
+                location,
+            });
+        }
+        Ok((value, typ))
+    }
+
     fn unpack_tuple_pattern(
         &mut self,
         value: ast::Expression,
@@ -1173,17 +1269,7 @@ impl<'interner> Monomorphizer<'interner> {
             HirType::Array(length, element) => {
                 let element =
                     Box::new(Self::convert_type_helper(element.as_ref(), location, seen_types)?);
-                let length = match length.evaluate_to_u32(location) {
-                    Ok(length) => length,
-                    Err(err) => {
-                        let length = length.as_ref().clone();
-                        return Err(MonomorphizationError::UnknownArrayLength {
-                            location,
-                            err,
-                            length,
-                        });
-                    }
-                };
+                let length = evaluate_array_length(length, location)?;
                 ast::Type::Array(length, element)
             }
             HirType::Slice(element) => {
@@ -1936,8 +2022,9 @@ impl<'interner> Monomorphizer<'interner> {
         let parameters =
             vecmap(lambda.parameters, |(pattern, typ)| (pattern, typ, Visibility::Private)).into();
 
-        let parameters = self.parameters(&parameters)?;
-        let body = self.expr(lambda.body)?;
+        let mut parameter_unpacks = Vec::new();
+        let parameters = self.parameters(&parameters, &mut parameter_unpacks)?;
+        let body = prepend_statements(parameter_unpacks, self.expr(lambda.body)?);
         let id = self.next_function_id();
 
         let function = ast::Function {
@@ -1998,7 +2085,8 @@ impl<'interner> Monomorphizer<'interner> {
         let parameters =
             vecmap(lambda.parameters, |(pattern, typ)| (pattern, typ, Visibility::Private)).into();
 
-        let mut converted_parameters = self.parameters(&parameters)?;
+        let mut parameter_unpacks = Vec::new();
+        let mut converted_parameters = self.parameters(&parameters, &mut parameter_unpacks)?;
 
         let id = self.next_function_id();
         let name = lambda_name.to_owned();
@@ -2053,7 +2141,7 @@ impl<'interner> Monomorphizer<'interner> {
 
         self.lambda_envs_stack
             .push(LambdaContext { env_ident: env_ident.clone(), captures: lambda.captures });
-        let body = self.expr(lambda.body)?;
+        let body = prepend_statements(parameter_unpacks, self.expr(lambda.body)?);
         self.lambda_envs_stack.pop();
 
         let lambda_fn_typ: ast::Type = ast::Type::Function(
@@ -2374,6 +2462,91 @@ impl<'interner> Monomorphizer<'interner> {
     }
 }
 
+/// Evaluates the length of an array, reporting the values of the numeric generics it is
+/// computed from if computing it overflows.
+fn evaluate_array_length(
+    length: &HirType,
+    location: Location,
+) -> Result<u32, MonomorphizationError> {
+    length.evaluate_to_u32(location).map_err(|err| {
+        let overflowed = match &err {
+            TypeCheckError::OverflowingConstant { .. } => true,
+            // Division and modulo only fail when dividing by zero
+            TypeCheckError::FailingBinaryOp { op, .. } => !matches!(
+                op,
+                types::BinaryTypeOperator::Division | types::BinaryTypeOperator::Modulo
+            ),
+            _ => false,
+        };
+        if overflowed {
+            let mut generics = Vec::new();
+            if let Some(expression) = length_expression(length, &mut generics) {
+                if !generics.is_empty() {
+                    return MonomorphizationError::ArrayLengthOverflow {
+                        length: expression,
+                        generics,
+                        location,
+                    };
+                }
+            }
+        }
+        MonomorphizationError::UnknownArrayLength { location, err, length: length.clone() }
+    })
+}
+
+/// Returns the arithmetic expression an array length is computed from, referring to numeric
+/// generics by name, and pushes the value each of them is bound to onto `generics`.
+fn length_expression(
+    length: &HirType,
+    generics: &mut Vec<(String, FieldElement)>,
+) -> Option<String> {
+    match length {
+        HirType::Constant(value, _) => Some(value.to_string()),
+        HirType::NamedGeneric(type_var, name) => {
+            let TypeBinding::Bound(binding) = &*type_var.borrow() else {
+                return None;
+            };
+            let value =
+                binding.evaluate_to_field_element(&type_var.kind(), Location::dummy()).ok()?;
+            if !generics.iter().any(|(generic, _)| generic == name.as_str()) {
+                generics.push((name.to_string(), value));
+            }
+            Some(name.to_string())
+        }
+        HirType::TypeVariable(type_var) => match &*type_var.borrow() {
+            TypeBinding::Bound(binding) => length_expression(binding, generics),
+            TypeBinding::Unbound(..) => None,
+        },
+        HirType::CheckedCast { to, .. } => length_expression(to, generics),
+        HirType::InfixExpr(lhs, op, rhs, _) => {
+            let mut operand = |typ: &HirType| {
+                let expression = length_expression(typ, generics)?;
+                if matches!(typ, HirType::InfixExpr(..)) {
+                    Some(format!("({expression})"))
+                } else {
+                    Some(expression)
+                }
+            };
+            let lhs = operand(lhs)?;
+            let rhs = operand(rhs)?;
+            Some(format!("{lhs} {op} {rhs}"))
+        }
+        _ => None,
+    }
+}
+
+/// Prepends `statements` to `body`, which is returned unchanged if there are none.
+fn prepend_statements(
+    mut statements: Vec<ast::Expression>,
+    body: ast::Expression,
+) -> ast::Expression {
+    if statements.is_empty() {
+        return body;
+    }
+    statements.push(body);
+    ast::Expression::Block(statements)
+}
+
 fn unwrap_tuple_type(typ: &HirType) -> Vec<HirType> {
     match typ.follow_bindings() {
         HirType::Tuple(fields) => fields.clone(),
@@ -1,7 +1,10 @@
 #![cfg(test)]
 use crate::{
-    check_monomorphization_error_using_features,
+    check_monomorphization_error, check_monomorphization_error_using_features,
     elaborator::UnstableFeature,
+    hir::type_check::TypeCheckError,
+    hir_def::types::BinaryTypeOperator,
+    monomorphization::errors::MonomorphizationError,
     test_utils::{Expect, get_monomorphized},
 };
 
@@ -141,3 +144,42 @@ fn lambda$f1(mut env$l1: (Field)) -> Field {
 "#;
     check_rewrite!(src, expected_rewrite);
 }
+
+#[named]
+#[test]
+fn overflowing_array_length_reports_numeric_generics() {
+    let src = "
+        fn main() {
+            foo::<65536, 65536>();
+        }
+
+        fn foo<let N: u32, let M: u32>() {
+            let _array = [0; N * M];
+                         ^^^^^^^^^^ Array length `N * M` overflows `u32` for N = 65536, M = 65536
+                         ~~~~~~~~~~ Array lengths must fit in a `u32`
+        }
+        ";
+    check_monomorphization_error!(src);
+}
+
+#[named]
+#[test]
+fn array_length_divided_by_zero_is_not_reported_as_overflow() {
+    let src = "
+        fn main() {
+            foo::<1, 0>();
+        }
+
+        fn foo<let N: u32, let M: u32>() {
+            let _array = [0; N / M];
+        }
+        ";
+    let error = get_monomorphized!(src, Expect::Error).unwrap_err();
+    assert!(matches!(
+        error,
+        MonomorphizationError::UnknownArrayLength {
+            err: TypeCheckError::FailingBinaryOp { op: BinaryTypeOperator::Division, rhs: 0, .. },
+            ..
+        }
+    ));
+}
@@ -254,6 +254,10 @@ pub struct NodeInterner {
     // (ReferenceId::Reference and ReferenceId::Local aren't included here)
     pub(crate) reference_modules: HashMap<ReferenceId, ModuleId>,
 
+    // The location of each item generated by an attribute, along with the attribute's function
+    // and the location of the attribute itself
+    pub(crate) generated_items: Vec<(Location, FuncId, Location)>,
+
     // All names (and their definitions) that can be offered for auto_import.
     // The third value in the tuple is the module where the definition is (only for pub use).
     // These include top-level functions, global variables and types, but excludes
@@ -310,6 +314,8 @@ pub enum ReferenceId {
     Function(FuncId),
     Alias(TypeAliasId),
     Local(DefinitionId),
+    /// A numeric generic such as `let N: u32`, identified by the location of its declaration
+    NumericGeneric(Location),
     Reference(Location, bool /* is Self */),
 }
 
@@ -711,6 +717,7 @@ impl Default for NodeInterner {
             reference_graph: petgraph::graph::DiGraph::new(),
             reference_graph_indices: HashMap::default(),
             reference_modules: HashMap::default(),
+            generated_items: Vec::new(),
             auto_import_names: HashMap::default(),
             comptime_scopes: vec![HashMap::default()],
             trait_impl_associated_types: HashMap::default(),
@@ -1098,6 +1105,11 @@ impl NodeInterner {
         }
     }
 
+    /// Returns the id of every function which has interned meta data, in no particular order.
+    pub fn function_ids(&self) -> impl Iterator<Item = FuncId> + '_ {
+        self.func_meta.keys().copied()
+    }
+
     /// Returns the interned meta data corresponding to `func_id`
     pub fn function_meta(&self, func_id: &FuncId) -> &FuncMeta {
         self.func_meta.get(func_id).expect("ice: all function ids should have metadata")
@@ -2052,6 +2064,37 @@ impl NodeInterner {
         self.dependency_graph.update_edge(dependent_index, dependency_index, ());
     }
 
+    /// Returns the functions referenced from the body of the given function. This includes
+    /// every function it calls directly, by path or as a method, and the trait impl methods
+    /// selected for its trait method calls and overloaded operators.
+    pub fn function_dependencies(&self, function: FuncId) -> Vec<FuncId> {
+        let Some(index) = self.dependency_graph_indices.get(&DependencyId::Function(function))
+        else {
+            return Vec::new();
+        };
+
+        self.dependency_graph
+            .neighbors(*index)
+            .filter_map(|index| match self.dependency_graph[index] {
+                DependencyId::Function(id) => Some(id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// True if the given function is referenced from an item other than a function,
+    /// such as the initializer of a global.
+    pub fn is_function_referenced_outside_functions(&self, function: FuncId) -> bool {
+        let Some(index) = self.dependency_graph_indices.get(&DependencyId::Function(function))
+        else {
+            return false;
+        };
+
+        self.dependency_graph
+            .neighbors_directed(*index, petgraph::Direction::Incoming)
+            .any(|index| !matches!(self.dependency_graph[index], DependencyId::Function(_)))
+    }
+
     pub fn get_or_insert_dependency(&mut self, id: DependencyId) -> PetGraphIndex {
         if let Some(index) = self.dependency_graph_indices.get(&id) {
             return *index;
@@ -2066,10 +2109,9 @@ impl NodeInterner {
         let strongly_connected_components = tarjan_scc(&self.dependency_graph);
         let mut errors = Vec::new();
 
-        let mut push_error = |item: String, scc: &[_], i, location: Location| {
+        let cycle_error = |item: String, scc: &[_], i, location: Location| -> CompilationError {
             let cycle = self.get_cycle_error_string(scc, i);
-            let error = ResolverError::DependencyCycle { item, cycle, location };
-            errors.push(error.into());
+            ResolverError::DependencyCycle { item, cycle, location }.into()
         };
 
         for scc in strongly_connected_components {
@@ -2080,13 +2122,16 @@ impl NodeInterner {
                         DependencyId::Struct(struct_id) => {
                             let struct_type = self.get_type(struct_id);
                             let struct_type = struct_type.borrow();
-                            push_error(struct_type.name.to_string(), &scc, i, struct_type.location);
+                            let name = struct_type.name.to_string();
+                            errors.push(cycle_error(name, &scc, i, struct_type.location));
                             break;
                         }
-                        DependencyId::Global(global_id) => {
-                            let global = self.get_global(global_id);
-                            let name = global.ident.to_string();
-                            push_error(name, &scc, i, global.location);
+                        DependencyId::Global(_) => {
+                            let cycle = self.get_cycle_error_string(&scc, i);
+                            let globals = self.get_cycle_globals(&scc, i);
+                            errors.push(
+                                ResolverError::GlobalDependencyCycle { globals, cycle }.into(),
+                            );
                             break;
                         }
                         DependencyId::Alias(alias_id) => {
@@ -2095,14 +2140,16 @@ impl NodeInterner {
                             // here to prevent infinite recursion in the type checker.
                             alias.borrow_mut().typ = Type::Error;
 
-                            // push_error will borrow the alias so we have to drop the mutable borrow
+                            // cycle_error will borrow the alias so we have to drop the mutable borrow
                             let alias = alias.borrow();
-                            push_error(alias.name.to_string(), &scc, i, alias.location);
+                            let name = alias.name.to_string();
+                            errors.push(cycle_error(name, &scc, i, alias.location));
                             break;
                         }
                         DependencyId::Trait(trait_id) => {
                             let the_trait = self.get_trait(trait_id);
-                            push_error(the_trait.name.to_string(), &scc, i, the_trait.location);
+                            let name = the_trait.name.to_string();
+                            errors.push(cycle_error(name, &scc, i, the_trait.location));
                             break;
                         }
                         // Mutually recursive functions are allowed
@@ -2148,6 +2195,26 @@ impl NodeInterner {
         cycle
     }
 
+    /// Returns the name and location of each global in the dependency cycle, in the same order
+    /// as [`Self::get_cycle_error_string`] lists them, starting from the element at the given
+    /// start index.
+    fn get_cycle_globals(
+        &self,
+        scc: &[PetGraphIndex],
+        start_index: usize,
+    ) -> Vec<(String, Location)> {
+        (0..scc.len())
+            .filter_map(|offset| {
+                let index = scc[(start_index + scc.len() - offset) % scc.len()];
+                let DependencyId::Global(global_id) = self.dependency_graph[index] else {
+                    return None;
+                };
+                let global = self.get_global(global_id);
+                Some((global.ident.to_string(), global.location))
+            })
+            .collect()
+    }
+
     pub fn push_quoted_type(&mut self, typ: Type) -> QuotedTypeId {
         QuotedTypeId(self.quoted_types.insert(typ))
     }
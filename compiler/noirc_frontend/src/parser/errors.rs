@@ -51,6 +51,8 @@ pub enum ParserErrorReason {
     MissingTypeForFunctionParameter,
     #[error("Missing type for numeric generic")]
     MissingTypeForNumericGeneric,
+    #[error("Expected an identifier after `let` in a numeric generic")]
+    MissingIdentifierForNumericGeneric,
     #[error("Expected a function body (`{{ ... }}`), not `;`")]
     ExpectedFunctionBody,
     #[error("Expected the global to have a value")]
@@ -111,6 +113,8 @@ pub enum ParserErrorReason {
         found
     )]
     WrongNumberOfAttributeArguments { name: String, min: usize, max: usize, found: usize },
+    #[error("Attributes cannot take generic arguments")]
+    AttributeWithGenericArguments,
     #[error("The `deprecated` attribute expects a string argument")]
     DeprecatedAttributeExpectsAStringArgument,
     #[error("Unsafe block must have a safety comment above it")]
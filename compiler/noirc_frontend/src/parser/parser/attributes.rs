@@ -1,3 +1,4 @@
+use acvm::FieldElement;
 use noirc_errors::Location;
 
 use crate::ast::{Expression, ExpressionKind, Ident, Literal, Path};
@@ -54,12 +55,14 @@ impl Parser<'_> {
     ///     | 'test'
     ///     | 'test' '(' 'should_fail' ')'
     ///     | 'test' '(' 'should_fail_with' '=' string ')'
+    ///     | 'test' '(' identifier '=' integer ( ',' identifier '=' integer )* ','? ')'
     ///     | 'fuzz'
     ///     | 'fuzz' '(' 'only_fail_with' '=' string ')'
     ///
     /// SecondaryAttribute
     ///     = 'abi' '(' AttributeValue ')'
     ///     | 'allow' '(' AttributeValue ')'
+    ///     | 'cfg' '(' 'feature' '=' string ')'
     ///     | 'deprecated'
     ///     | 'deprecated' '(' string ')'
     ///     | 'contract_library_method'
@@ -140,12 +143,15 @@ impl Parser<'_> {
             let path = Path::from_single(self.token.to_string(), self.current_token_location);
             self.bump();
             self.parse_meta_attribute(path, start_location)
-        } else if let Some(path) = self.parse_path_no_turbofish() {
+        } else if let Some(path) = self.parse_attribute_path() {
             if let Some(ident) = path.as_ident() {
                 if ident.as_str() == "test" {
                     // The test attribute is the only secondary attribute that has `a = b` in its syntax
                     // (`should_fail_with = "..."``) so we parse it differently.
                     self.parse_test_attribute(start_location)
+                } else if ident.as_str() == "cfg" {
+                    // The cfg attribute also has `a = b` in its syntax (`feature = "..."`)
+                    self.parse_cfg_attribute(start_location)
                 } else if ident.as_str() == "fuzz" {
                     // The fuzz attribute is a secondary attribute that has `a = b` in its syntax
                     // (`only_fail_with = "..."``) so we parse it differently.
@@ -164,6 +170,18 @@ impl Parser<'_> {
         }
     }
 
+    /// Attributes can't take generic arguments, but a path with a turbofish is parsed anyway so
+    /// that it's reported as such rather than as unexpected tokens. The turbofish is then dropped.
+    fn parse_attribute_path(&mut self) -> Option<Path> {
+        let mut path = self.parse_path()?;
+        for segment in &mut path.segments {
+            if segment.generics.take().is_some() {
+                self.push_error(ParserErrorReason::AttributeWithGenericArguments, segment.location);
+            }
+        }
+        Some(path)
+    }
+
     fn parse_meta_attribute(&mut self, name: Path, start_location: Location) -> Attribute {
         let arguments = self.parse_arguments().unwrap_or_default();
         self.skip_until_right_bracket();
@@ -222,6 +240,10 @@ impl Parser<'_> {
                 let attr = Attribute::Function(FunctionAttribute::NoPredicates);
                 self.parse_no_args_attribute(ident, arguments, attr)
             }
+            "non_exhaustive" => {
+                let attr = Attribute::Secondary(SecondaryAttribute::NonExhaustive);
+                self.parse_no_args_attribute(ident, arguments, attr)
+            }
             "oracle" => {
                 self.parse_single_name_attribute(ident, arguments, start_location, |name| {
                     Attribute::Function(FunctionAttribute::Oracle(name))
@@ -290,7 +312,9 @@ impl Parser<'_> {
                             Some(TestScope::ShouldFailWith { reason: None })
                         }
                     }
-                    _ => None,
+                    _ => self
+                        .parse_test_generic_bindings(ident)
+                        .map(|bindings| TestScope::Generic { instantiations: vec![bindings] }),
                 }
             } else {
                 None
@@ -318,6 +342,28 @@ impl Parser<'_> {
         Attribute::Function(FunctionAttribute::Test(scope))
     }
 
+    /// Parses the generic bindings of a `#[test(N = 8, M = 2)]` attribute, once the name
+    /// of the first generic has been parsed.
+    fn parse_test_generic_bindings(&mut self, first: Ident) -> Option<Vec<(String, FieldElement)>> {
+        let mut bindings = Vec::new();
+        let mut name = first;
+        loop {
+            if !self.eat_assign() {
+                return None;
+            }
+            bindings.push((name.into_string(), self.eat_int()?));
+
+            if !self.eat_comma() {
+                break;
+            }
+            match self.eat_ident() {
+                Some(ident) => name = ident,
+                None => break,
+            }
+        }
+        Some(bindings)
+    }
+
     fn parse_fuzz_attribute(&mut self, start_location: Location) -> Attribute {
         let scope = if self.eat_left_paren() {
             let scope = if let Some(ident) = self.eat_ident() {
@@ -354,6 +400,36 @@ impl Parser<'_> {
         Attribute::Function(FunctionAttribute::FuzzingHarness(scope))
     }
 
+    fn parse_cfg_attribute(&mut self, start_location: Location) -> Attribute {
+        let feature = if self.eat_left_paren() {
+            let feature = match self.eat_ident() {
+                Some(ident) if ident.as_str() == "feature" => {
+                    self.eat_or_error(Token::Assign);
+                    self.eat_str()
+                }
+                _ => None,
+            };
+            self.eat_or_error(Token::RightParen);
+            feature
+        } else {
+            None
+        };
+
+        self.skip_until_right_bracket();
+
+        let feature = feature.unwrap_or_else(|| {
+            self.errors.push(
+                LexerErrorKind::MalformedCfgAttribute {
+                    location: self.location_since(start_location),
+                }
+                .into(),
+            );
+            String::new()
+        });
+
+        Attribute::Secondary(SecondaryAttribute::Cfg(feature))
+    }
+
     fn parse_single_name_attribute<F>(
         &mut self,
         ident: &Ident,
@@ -526,6 +602,20 @@ mod tests {
         parse_inner_secondary_attribute_no_errors(src, expected);
     }
 
+    #[test]
+    fn parses_attribute_non_exhaustive() {
+        let src = "#[non_exhaustive]";
+        let expected = Attribute::Secondary(SecondaryAttribute::NonExhaustive);
+        parse_attribute_no_errors(src, expected);
+    }
+
+    #[test]
+    fn parses_attribute_cfg() {
+        let src = "#[cfg(feature = \"foo\")]";
+        let expected = Attribute::Secondary(SecondaryAttribute::Cfg("foo".to_string()));
+        parse_attribute_no_errors(src, expected);
+    }
+
     #[test]
     fn parses_attribute_abi() {
         let src = "#[abi(foo)]";
@@ -621,6 +711,16 @@ mod tests {
         parse_attribute_no_errors(src, expected);
     }
 
+    #[test]
+    fn parses_attribute_test_generic_bindings() {
+        let src = "#[test(N = 8, M = 2)]";
+        let bindings = vec![("N".to_string(), 8_u128.into()), ("M".to_string(), 2_u128.into())];
+        let expected = Attribute::Function(FunctionAttribute::Test(TestScope::Generic {
+            instantiations: vec![bindings],
+        }));
+        parse_attribute_no_errors(src, expected);
+    }
+
     #[test]
     fn parses_meta_attribute_single_identifier_no_arguments() {
         let src = "#[foo]";
@@ -6,7 +6,8 @@ use crate::{
         ArrayLiteral, BlockExpression, CallExpression, CastExpression, ConstrainExpression,
         ConstrainKind, ConstructorExpression, Expression, ExpressionKind, Ident, IfExpression,
         IndexExpression, Literal, MatchExpression, MemberAccessExpression, MethodCallExpression,
-        Statement, TypePath, UnaryOp, UnresolvedType, UnresolvedTypeData, UnsafeExpression,
+        RangeExpression, Statement, TypePath, UnaryOp, UnresolvedType, UnresolvedTypeData,
+        UnsafeExpression,
     },
     parser::{ParserErrorReason, labels::ParsingRuleLabel, parser::parse_many::separated_by_comma},
     token::{Keyword, Token, TokenKind},
@@ -595,9 +596,9 @@ impl Parser<'_> {
         Some(ExpressionKind::Match(Box::new(MatchExpression { expression, rules })))
     }
 
-    /// MatchRule = Expression '->' (Block ','?) | (Expression ',')
+    /// MatchRule = MatchPattern '->' (Block ','?) | (Expression ',')
     fn parse_match_rule(&mut self) -> Option<(Expression, Expression)> {
-        let pattern = self.parse_expression()?;
+        let pattern = self.parse_match_pattern()?;
         self.eat_or_error(Token::FatArrow);
 
         let start_location = self.current_token_location;
@@ -617,6 +618,25 @@ impl Parser<'_> {
         Some((pattern, branch))
     }
 
+    /// MatchPattern = Expression ( ( '..' | '..=' ) Expression )?
+    fn parse_match_pattern(&mut self) -> Option<Expression> {
+        let start_location = self.current_token_location;
+        let start = self.parse_expression()?;
+
+        let inclusive = if self.eat(Token::DoubleDot) {
+            false
+        } else if self.eat(Token::DoubleDotEqual) {
+            true
+        } else {
+            return Some(start);
+        };
+
+        let end = self.parse_expression_or_error();
+        let range = RangeExpression { start, end, inclusive };
+        let location = self.location_since(start_location);
+        Some(Expression::new(ExpressionKind::Range(Box::new(range)), location))
+    }
+
     /// ComptimeExpression = 'comptime' Block
     fn parse_comptime_expr(&mut self) -> Option<ExpressionKind> {
         if !self.eat_keyword(Keyword::Comptime) {
@@ -1550,6 +1570,26 @@ mod tests {
         assert!(!call.is_macro_call);
     }
 
+    #[test]
+    fn parses_call_after_unterminated_turbofish() {
+        let src = "
+        foo::<i32, Field(1, 2)
+                        ^
+        ";
+        let (src, span) = get_source_with_error_span(src);
+        let mut parser = Parser::for_str_with_dummy_file(&src);
+        let expr = parser.parse_expression_or_error();
+
+        let error = get_single_error(&parser.errors, span);
+        assert_eq!(error.to_string(), "Expected a '>' but found '('");
+
+        let ExpressionKind::Call(call) = expr.kind else {
+            panic!("Expected call expression");
+        };
+        assert_eq!(call.func.to_string(), "foo::<i32, Field>");
+        assert_eq!(call.arguments.len(), 2);
+    }
+
     #[test]
     fn parses_macro_call() {
         let src = "foo!(1, 2)";
@@ -3,7 +3,7 @@ use crate::ast::{
     UnresolvedType,
 };
 use crate::shared::Visibility;
-use crate::token::{Attribute, Attributes, Keyword, Token};
+use crate::token::{Attribute, Attributes, FunctionAttribute, Keyword, TestScope, Token};
 use crate::{ast::UnresolvedGenerics, parser::labels::ParsingRuleLabel};
 use crate::{
     ast::{
@@ -290,7 +290,7 @@ impl Parser<'_> {
                 Attribute::Function(attr) => {
                     if function.is_none() {
                         function = Some((attr, index));
-                    } else {
+                    } else if !merge_generic_test_attribute(&mut function, &attr, index) {
                         self.push_error(
                             ParserErrorReason::MultipleFunctionAttributesFound,
                             location,
@@ -305,6 +305,31 @@ impl Parser<'_> {
     }
 }
 
+/// A generic test can be annotated with several `#[test(N = ...)]` attributes, one for each
+/// instantiation to run. If `attribute` is such an attribute and immediately follows the
+/// previous ones, its instantiation is merged into `function` and true is returned.
+fn merge_generic_test_attribute(
+    function: &mut Option<(FunctionAttribute, usize)>,
+    attribute: &FunctionAttribute,
+    index: usize,
+) -> bool {
+    let (
+        Some((FunctionAttribute::Test(TestScope::Generic { instantiations }), first_index)),
+        FunctionAttribute::Test(TestScope::Generic { instantiations: new_instantiations }),
+    ) = (function, attribute)
+    else {
+        return false;
+    };
+
+    // Keeping the attributes together lets the formatter write them back in place
+    if *first_index + instantiations.len() != index {
+        return false;
+    }
+
+    instantiations.extend(new_instantiations.iter().cloned());
+    true
+}
+
 fn empty_function(location: Location) -> FunctionDefinitionWithOptionalBody {
     let span = Span::from(location.span.end()..location.span.end());
     FunctionDefinitionWithOptionalBody {
@@ -339,6 +364,7 @@ mod tests {
             },
         },
         shared::{Signedness, Visibility},
+        token::{FunctionAttribute, TestScope},
     };
 
     fn parse_function_no_error(src: &str) -> NoirFunction {
@@ -458,6 +484,19 @@ mod tests {
         assert!(matches!(reason, ParserErrorReason::MultipleFunctionAttributesFound));
     }
 
+    #[test]
+    fn parse_function_merges_generic_test_attributes() {
+        let src = "#[test(N = 8)] #[test(N = 16)] fn foo<let N: u32>() {}";
+        let noir_function = parse_function_no_error(src);
+        let Some(FunctionAttribute::Test(TestScope::Generic { instantiations })) =
+            noir_function.def.attributes.function()
+        else {
+            panic!("Expected generic test attribute");
+        };
+        assert_eq!(instantiations.len(), 2);
+        assert_eq!(instantiations[1], vec![("N".to_string(), 16_u128.into())]);
+    }
+
     #[test]
     fn parse_function_found_semicolon_instead_of_braces() {
         let src = "
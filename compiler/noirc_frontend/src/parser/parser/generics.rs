@@ -35,6 +35,10 @@ impl Parser<'_> {
     }
 
     fn parse_generic_in_list(&mut self, allow_trait_bounds: bool) -> Option<UnresolvedGeneric> {
+        if self.at_keyword(Keyword::Let) && !matches!(self.next_token.token(), Token::Ident(..)) {
+            return self.recover_from_numeric_generic_without_identifier(allow_trait_bounds);
+        }
+
         if let Some(generic) = self.parse_generic(allow_trait_bounds) {
             Some(generic)
         } else {
@@ -43,6 +47,28 @@ impl Parser<'_> {
         }
     }
 
+    /// Reports a numeric generic without an identifier after `let`, like `<let>` or
+    /// `<let: u32>`, then skips it and parses the next generic in the list, if any.
+    fn recover_from_numeric_generic_without_identifier(
+        &mut self,
+        allow_trait_bounds: bool,
+    ) -> Option<UnresolvedGeneric> {
+        self.push_error(
+            ParserErrorReason::MissingIdentifierForNumericGeneric,
+            self.current_token_location,
+        );
+
+        while !(self.at(Token::Comma) || self.at(Token::Greater) || self.at_eof()) {
+            self.bump();
+        }
+
+        if self.eat_comma() && !self.at(Token::Greater) {
+            self.parse_generic_in_list(allow_trait_bounds)
+        } else {
+            None
+        }
+    }
+
     /// Generic
     ///     = VariableGeneric
     ///     | NumericGeneric
@@ -142,11 +168,28 @@ impl Parser<'_> {
             return generic_type_args;
         }
 
+        // A `(`, `{` or `;` right after an argument can't continue the list, so it most likely
+        // follows a missing `>`, like in `foo::<i32, Field(1, 2)`. The list is then ended there
+        // so that what follows is parsed as usual.
+        let mut missing_greater = false;
         let generics = self.parse_many(
             "generic parameters",
             separated_by_comma().until(Token::Greater),
-            Self::parse_generic_type_arg,
+            |parser| {
+                if missing_greater {
+                    return None;
+                }
+
+                let generic = parser.parse_generic_type_arg()?;
+                missing_greater = parser.at(Token::LeftParen)
+                    || parser.at(Token::LeftBrace)
+                    || parser.at(Token::Semicolon);
+                Some(generic)
+            },
         );
+        if missing_greater {
+            self.expected_token(Token::Greater);
+        }
 
         for generic in generics {
             match generic {
@@ -252,6 +295,25 @@ mod tests {
         assert_eq!(trait_bounds[1].to_string(), "Y");
     }
 
+    #[test]
+    fn parses_trait_bound_referencing_earlier_generic() {
+        let src = "<T, U: From<T>>";
+        let generics = parse_generics_no_errors(src);
+        assert_eq!(generics.len(), 2);
+
+        let UnresolvedGeneric::Variable(ident, trait_bounds) = &generics[1] else {
+            panic!("Expected generic variable");
+        };
+        assert_eq!("U", ident.to_string());
+        assert_eq!(trait_bounds.len(), 1);
+
+        let trait_bound = &trait_bounds[0];
+        assert_eq!(trait_bound.trait_path.to_string(), "From");
+        assert_eq!(trait_bound.trait_generics.ordered_args.len(), 1);
+        assert_eq!(trait_bound.trait_generics.ordered_args[0].to_string(), "T");
+        assert!(trait_bound.trait_generics.named_args.is_empty());
+    }
+
     #[test]
     fn parses_no_generic_type_args() {
         let src = "1";
@@ -303,6 +365,67 @@ mod tests {
         assert!(matches!(reason, ParserErrorReason::ForbiddenNumericGenericType));
     }
 
+    #[test]
+    fn parse_numeric_generic_error_if_missing_identifier() {
+        let src = "
+        <let>
+         ^^^
+        ";
+        let (src, span) = get_source_with_error_span(src);
+        let mut parser = Parser::for_str_with_dummy_file(&src);
+        let generics = parser.parse_generics(true);
+        assert!(generics.is_empty());
+        let reason = get_single_error_reason(&parser.errors, span);
+        assert!(matches!(reason, ParserErrorReason::MissingIdentifierForNumericGeneric));
+    }
+
+    #[test]
+    fn parse_generics_after_numeric_generic_missing_identifier() {
+        let src = "
+        <let: u32, T, let M: u8>
+         ^^^
+        ";
+        let (src, span) = get_source_with_error_span(src);
+        let mut parser = Parser::for_str_with_dummy_file(&src);
+        let generics = parser.parse_generics(true);
+        let reason = get_single_error_reason(&parser.errors, span);
+        assert!(matches!(reason, ParserErrorReason::MissingIdentifierForNumericGeneric));
+
+        assert_eq!(generics.len(), 2);
+        assert!(
+            matches!(&generics[0], UnresolvedGeneric::Variable(ident, _) if ident.as_str() == "T")
+        );
+        assert!(
+            matches!(&generics[1], UnresolvedGeneric::Numeric { ident, .. } if ident.as_str() == "M")
+        );
+    }
+
+    #[test]
+    fn parse_generics_after_numeric_generic_missing_type() {
+        let src = "
+        <let N, T>
+              ^
+        ";
+        let (src, span) = get_source_with_error_span(src);
+        let mut parser = Parser::for_str_with_dummy_file(&src);
+        let generics = parser.parse_generics(true);
+        let reason = get_single_error_reason(&parser.errors, span);
+        assert!(matches!(reason, ParserErrorReason::MissingTypeForNumericGeneric));
+
+        assert_eq!(generics.len(), 2);
+        let UnresolvedGeneric::Numeric { ident, typ } = &generics[0] else {
+            panic!("Expected generic numeric");
+        };
+        assert_eq!(ident.as_str(), "N");
+        assert_eq!(
+            typ.typ,
+            UnresolvedTypeData::Integer(Signedness::Unsigned, IntegerBitSize::ThirtyTwo)
+        );
+        assert!(
+            matches!(&generics[1], UnresolvedGeneric::Variable(ident, _) if ident.as_str() == "T")
+        );
+    }
+
     #[test]
     fn parse_arithmetic_generic_on_variable() {
         let src = "<N - 1>";
@@ -214,9 +214,11 @@ impl<'a> Parser<'a> {
         if self.eat_keyword(Keyword::Type) {
             self.comptime_mutable_and_unconstrained_not_applicable(modifiers);
 
-            return vec![ItemKind::TypeAlias(
-                self.parse_type_alias(modifiers.visibility, start_location),
-            )];
+            return vec![ItemKind::TypeAlias(self.parse_type_alias(
+                attributes,
+                modifiers.visibility,
+                start_location,
+            ))];
         }
 
         let is_function = if self.eat_keyword(Keyword::Fn) {
@@ -111,6 +111,7 @@ impl Parser<'_> {
     ///     = InternedPattern
     ///     | TuplePattern
     ///     | StructPattern
+    ///     | TupleStructPattern
     ///     | IdentifierPattern
     ///
     /// IdentifierPattern = identifier
@@ -139,6 +140,10 @@ impl Parser<'_> {
             return Some(self.parse_struct_pattern(path, start_location));
         }
 
+        if self.eat_left_paren() {
+            return Some(self.parse_tuple_struct_pattern(path, start_location));
+        }
+
         if !path.is_ident() {
             self.push_error(ParserErrorReason::InvalidPattern, path.location);
 
@@ -164,7 +169,9 @@ impl Parser<'_> {
 
     /// TuplePattern = '(' PatternList? ')'
     ///
-    /// PatternList = Pattern ( ',' Pattern )* ','?
+    /// PatternList = TuplePatternElement ( ',' TuplePatternElement )* ','?
+    ///
+    /// TuplePatternElement = Pattern ( ':' Type )?
     fn parse_tuple_pattern(&mut self) -> Option<Pattern> {
         let start_location = self.current_token_location;
 
@@ -183,6 +190,10 @@ impl Parser<'_> {
 
     fn parse_tuple_pattern_element(&mut self) -> Option<Pattern> {
         if let Some(pattern) = self.parse_pattern() {
+            if self.eat_colon() {
+                let typ = self.parse_type_or_error();
+                return Some(Pattern::Typed(Box::new(pattern), typ));
+            }
             Some(pattern)
         } else {
             self.expected_label(ParsingRuleLabel::Pattern);
@@ -192,20 +203,59 @@ impl Parser<'_> {
 
     /// StructPattern = Path '{' StructPatternFields? '}'
     ///
-    /// StructPatternFields = StructPatternField ( ',' StructPatternField )? ','?
+    /// StructPatternFields = StructPatternField ( ',' StructPatternField )? ( ',' '..' )? ','?
+    ///                     | '..' ','?
     ///
     /// StructPatternField = identifier ( ':' Pattern )?
     fn parse_struct_pattern(&mut self, path: Path, start_location: Location) -> Pattern {
-        let fields = self.parse_many(
-            "struct fields",
-            separated_by_comma_until_right_brace(),
-            Self::parse_struct_pattern_field,
+        let mut has_rest = false;
+        let fields =
+            self.parse_many("struct fields", separated_by_comma_until_right_brace(), |parser| {
+                if parser.eat(Token::DoubleDot) {
+                    // The rest pattern must come last
+                    has_rest = true;
+                    parser.eat_commas();
+                    if !parser.at(Token::RightBrace) {
+                        parser.expected_token(Token::RightBrace);
+                    }
+                    return None;
+                }
+                parser.parse_struct_pattern_field()
+            });
+
+        Pattern::Struct(path, fields, self.location_since(start_location), has_rest)
+    }
+
+    /// TupleStructPattern = Path '(' PatternList? ')'
+    fn parse_tuple_struct_pattern(&mut self, path: Path, start_location: Location) -> Pattern {
+        let patterns = self.parse_many(
+            "tuple struct fields",
+            separated_by_comma_until_right_paren(),
+            Self::parse_tuple_pattern_element,
         );
 
-        Pattern::Struct(path, fields, self.location_since(start_location))
+        Pattern::TupleStruct(path, patterns, self.location_since(start_location))
     }
 
+    /// StructPatternField = identifier ( ':' Pattern )?
+    ///                    | 'mut' identifier
     fn parse_struct_pattern_field(&mut self) -> Option<(Ident, Pattern)> {
+        let start_location = self.current_token_location;
+        if self.eat_keyword(Keyword::Mut) {
+            // `Foo { mut a }` binds only the `a` field mutably
+            let Some(ident) = self.eat_ident() else {
+                self.expected_identifier();
+                return None;
+            };
+            let location = self.location_since(start_location);
+            let pattern = Pattern::Mutable(
+                Box::new(Pattern::Identifier(ident.clone())),
+                location,
+                false, // is synthesized
+            );
+            return Some((ident, pattern));
+        }
+
         let Some(ident) = self.eat_ident() else {
             self.expected_identifier();
             return None;
@@ -302,6 +352,22 @@ mod tests {
         assert_eq!(ident.to_string(), "bar");
     }
 
+    #[test]
+    fn parses_tuple_pattern_with_type_ascriptions() {
+        let src = "(foo: Field, mut bar)";
+        let pattern = parse_pattern_no_errors(src);
+        let Pattern::Tuple(mut patterns, _) = pattern else { panic!("Expected a tuple pattern") };
+        assert_eq!(patterns.len(), 2);
+
+        let pattern = patterns.remove(0);
+        let Pattern::Typed(pattern, typ) = pattern else { panic!("Expected a typed pattern") };
+        assert_eq!(pattern.to_string(), "foo");
+        assert_eq!(typ.to_string(), "Field");
+
+        let pattern = patterns.remove(0);
+        assert_eq!(pattern.to_string(), "mut bar");
+    }
+
     #[test]
     fn parses_unclosed_tuple_pattern() {
         let src = "(foo,";
@@ -318,7 +384,7 @@ mod tests {
         let mut parser = Parser::for_str_with_dummy_file(src);
         let pattern = parser.parse_pattern_or_error();
         expect_no_errors(&parser.errors);
-        let Pattern::Struct(path, patterns, _) = pattern else {
+        let Pattern::Struct(path, patterns, _, false) = pattern else {
             panic!("Expected a struct pattern")
         };
         assert_eq!(path.to_string(), "foo::Bar");
@@ -329,7 +395,7 @@ mod tests {
     fn parses_struct_pattern() {
         let src = "foo::Bar { x: one, y }";
         let pattern = parse_pattern_no_errors(src);
-        let Pattern::Struct(path, mut patterns, _) = pattern else {
+        let Pattern::Struct(path, mut patterns, _, false) = pattern else {
             panic!("Expected a struct pattern")
         };
         assert_eq!(path.to_string(), "foo::Bar");
@@ -344,6 +410,27 @@ mod tests {
         assert_eq!(pattern.to_string(), "y");
     }
 
+    #[test]
+    fn parses_struct_pattern_with_mutable_field_shorthand() {
+        let src = "foo::Bar { mut x, y }";
+        let pattern = parse_pattern_no_errors(src);
+        let Pattern::Struct(_, mut patterns, _, false) = pattern else {
+            panic!("Expected a struct pattern")
+        };
+        assert_eq!(patterns.len(), 2);
+
+        let (ident, pattern) = patterns.remove(0);
+        assert_eq!(ident.to_string(), "x");
+        let Pattern::Mutable(pattern, _, false) = pattern else {
+            panic!("Expected a mutable pattern")
+        };
+        assert_eq!(pattern.to_string(), "x");
+
+        let (ident, pattern) = patterns.remove(0);
+        assert_eq!(ident.to_string(), "y");
+        assert!(matches!(pattern, Pattern::Identifier(_)));
+    }
+
     #[test]
     fn parses_struct_pattern_recovers_if_assign_instead_of_colon() {
         let src = "
@@ -357,7 +444,7 @@ mod tests {
         let error = get_single_error(&parser.errors, span);
         assert_eq!(error.to_string(), "Expected a ':' but found '='");
 
-        let Pattern::Struct(path, mut patterns, _) = pattern else {
+        let Pattern::Struct(path, mut patterns, _, false) = pattern else {
             panic!("Expected a struct pattern")
         };
         assert_eq!(path.to_string(), "foo::Bar");
@@ -372,14 +459,55 @@ mod tests {
         assert_eq!(pattern.to_string(), "y");
     }
 
+    #[test]
+    fn parses_struct_pattern_with_rest() {
+        let src = "foo::Bar { x, .. }";
+        let pattern = parse_pattern_no_errors(src);
+        let Pattern::Struct(path, patterns, _, true) = pattern else {
+            panic!("Expected a struct pattern with a rest pattern")
+        };
+        assert_eq!(path.to_string(), "foo::Bar");
+        assert_eq!(patterns.len(), 1);
+        assert_eq!(patterns[0].0.to_string(), "x");
+    }
+
+    #[test]
+    fn errors_on_struct_pattern_field_after_rest() {
+        let src = "
+        foo::Bar { .., x }
+                       ^
+        ";
+        let (src, span) = get_source_with_error_span(src);
+        let mut parser = Parser::for_str_with_dummy_file(&src);
+        parser.parse_pattern_or_error();
+
+        let error = get_single_error(&parser.errors, span);
+        assert_eq!(error.to_string(), "Expected a '}' but found 'x'");
+    }
+
     #[test]
     fn parses_unclosed_struct_pattern() {
         let src = "foo::Bar { x";
         let mut parser = Parser::for_str_with_dummy_file(src);
         let pattern = parser.parse_pattern_or_error();
         assert_eq!(parser.errors.len(), 1);
-        let Pattern::Struct(path, _, _) = pattern else { panic!("Expected a struct pattern") };
+        let Pattern::Struct(path, _, _, false) = pattern else {
+            panic!("Expected a struct pattern")
+        };
+        assert_eq!(path.to_string(), "foo::Bar");
+    }
+
+    #[test]
+    fn parses_tuple_struct_pattern() {
+        let src = "foo::Bar(x, mut y)";
+        let pattern = parse_pattern_no_errors(src);
+        let Pattern::TupleStruct(path, mut patterns, _) = pattern else {
+            panic!("Expected a tuple struct pattern")
+        };
         assert_eq!(path.to_string(), "foo::Bar");
+        assert_eq!(patterns.len(), 2);
+        assert_eq!(patterns.remove(0).to_string(), "x");
+        assert_eq!(patterns.remove(0).to_string(), "mut y");
     }
 
     #[test]
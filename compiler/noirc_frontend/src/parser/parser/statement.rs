@@ -181,7 +181,10 @@ impl Parser<'_> {
         }
 
         if let Some(operator) = self.next_is_op_assign() {
-            if let Some(lvalue) = LValue::from_expression(expression.clone()) {
+            // Tuples of places can only be assigned to with a plain `=`
+            let lvalue = LValue::from_expression(expression.clone())
+                .filter(|lvalue| !matches!(lvalue, LValue::Tuple(..)));
+            if let Some(lvalue) = lvalue {
                 // Desugar `a <op>= b` to `a = a <op> b`. This relies on the evaluation of `a` having no side effects,
                 // which is currently enforced by the restricted syntax of LValues.
                 let infix = InfixExpression {
@@ -690,6 +693,33 @@ mod tests {
         assert_eq!(ident.to_string(), "x");
     }
 
+    #[test]
+    fn parses_tuple_assignment() {
+        let src = "(a, (b.c, d[0]), *e) = x";
+        let statement = parse_statement_no_errors(src);
+        let StatementKind::Assign(assign) = statement.kind else {
+            panic!("Expected assign");
+        };
+        let LValue::Tuple(lvalues, _) = &assign.lvalue else {
+            panic!("Expected tuple");
+        };
+        assert_eq!(lvalues.len(), 3);
+        assert_eq!(assign.to_string(), "(a, (b.c, d[0]), *e) = x");
+    }
+
+    #[test]
+    fn errors_on_op_assignment_to_tuple() {
+        let src = "
+        (a, b) += (1, 2)
+        ^^^^^^
+        ";
+        let (src, span) = get_source_with_error_span(src);
+        let mut parser = Parser::for_str_with_dummy_file(&src);
+        parser.parse_statement_or_error();
+        let reason = get_single_error_reason(&parser.errors, span);
+        assert!(matches!(reason, ParserErrorReason::InvalidLeftHandSideOfAssignment));
+    }
+
     #[test]
     fn parses_op_assignment() {
         let src = "x += 1";
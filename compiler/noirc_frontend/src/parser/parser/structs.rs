@@ -6,12 +6,17 @@ use crate::{
     token::{Attribute, SecondaryAttribute, Token},
 };
 
-use super::{Parser, parse_many::separated_by_comma_until_right_brace};
+use super::{
+    Parser,
+    parse_many::{separated_by_comma_until_right_brace, separated_by_comma_until_right_paren},
+};
 
 impl Parser<'_> {
-    /// Struct = 'struct' identifier Generics '{' StructField* '}'
+    /// Struct = 'struct' identifier Generics ( '{' StructField* '}' | '(' TupleStructField* ')' ';' )
     ///
     /// StructField = OuterDocComments identifier ':' Type
+    ///
+    /// TupleStructField = OuterDocComments ItemVisibility Type
     pub(crate) fn parse_struct(
         &mut self,
         attributes: Vec<(Attribute, Location)>,
@@ -37,6 +42,33 @@ impl Parser<'_> {
             return self.empty_struct(name, attributes, visibility, generics, start_location);
         }
 
+        if self.eat_left_paren() {
+            let mut index = 0;
+            let fields = self.parse_many(
+                "tuple struct fields",
+                separated_by_comma_until_right_paren(),
+                |parser| {
+                    let field = parser.parse_tuple_struct_field(index);
+                    index += 1;
+                    field
+                },
+            );
+
+            if !self.eat_semicolons() {
+                self.expected_token(Token::Semicolon);
+            }
+
+            return NoirStruct {
+                name,
+                attributes,
+                visibility,
+                generics,
+                fields,
+                is_tuple_struct: true,
+                location: self.location_since(start_location),
+            };
+        }
+
         if !self.eat_left_brace() {
             self.expected_token(Token::LeftBrace);
             return self.empty_struct(name, attributes, visibility, generics, start_location);
@@ -54,6 +86,7 @@ impl Parser<'_> {
             visibility,
             generics,
             fields,
+            is_tuple_struct: false,
             location: self.location_since(start_location),
         }
     }
@@ -107,6 +140,22 @@ impl Parser<'_> {
         Some(Documented::new(StructField { visibility, name, typ }, doc_comments))
     }
 
+    /// Tuple struct fields are named after their position, so `struct Foo(Field, u8);`
+    /// has the fields `0` and `1`.
+    fn parse_tuple_struct_field(&mut self, index: usize) -> Option<Documented<StructField>> {
+        let doc_comments = self.parse_outer_doc_comments();
+        let visibility = self.parse_item_visibility();
+
+        let typ = if doc_comments.is_empty() && visibility == ItemVisibility::Private {
+            self.parse_type()?
+        } else {
+            self.parse_type_or_error()
+        };
+
+        let name = Ident::new(index.to_string(), typ.location);
+        Some(Documented::new(StructField { visibility, name, typ }, doc_comments))
+    }
+
     fn empty_struct(
         &self,
         name: Ident,
@@ -121,6 +170,7 @@ impl Parser<'_> {
             visibility,
             generics,
             fields: Vec::new(),
+            is_tuple_struct: false,
             location: self.location_since(start_location),
         }
     }
@@ -129,7 +179,7 @@ impl Parser<'_> {
 #[cfg(test)]
 mod tests {
     use crate::{
-        ast::{IntegerBitSize, NoirStruct, UnresolvedGeneric, UnresolvedTypeData},
+        ast::{IntegerBitSize, ItemVisibility, NoirStruct, UnresolvedGeneric, UnresolvedTypeData},
         parse_program_with_dummy_file,
         parser::{
             ItemKind, ParserErrorReason,
@@ -215,6 +265,39 @@ mod tests {
         assert!(matches!(field.typ.typ, UnresolvedTypeData::FieldElement));
     }
 
+    #[test]
+    fn parse_tuple_struct() {
+        let src = "struct Foo(pub i32, Field);";
+        let mut noir_struct = parse_struct_no_errors(src);
+        assert_eq!("Foo", noir_struct.name.to_string());
+        assert_eq!(noir_struct.fields.len(), 2);
+
+        let field = noir_struct.fields.remove(0).item;
+        assert_eq!("0", field.name.to_string());
+        assert_eq!(field.visibility, ItemVisibility::Public);
+        assert!(matches!(
+            field.typ.typ,
+            UnresolvedTypeData::Integer(Signedness::Signed, IntegerBitSize::ThirtyTwo)
+        ));
+
+        let field = noir_struct.fields.remove(0).item;
+        assert_eq!("1", field.name.to_string());
+        assert_eq!(field.visibility, ItemVisibility::Private);
+        assert!(matches!(field.typ.typ, UnresolvedTypeData::FieldElement));
+    }
+
+    #[test]
+    fn parse_tuple_struct_without_semicolon() {
+        let src = "
+        struct Foo(Field) 
+                         ^
+        ";
+        let (src, span) = get_source_with_error_span(src);
+        let (_, errors) = parse_program_with_dummy_file(&src);
+        let error = get_single_error(&errors, span);
+        assert_eq!(error.to_string(), "Expected a ';' but found end of input");
+    }
+
     #[test]
     fn parse_empty_struct_with_doc_comments() {
         let src = "/// Hello\nstruct Foo {}";
@@ -2,7 +2,7 @@ use noirc_errors::Location;
 
 use crate::{
     ast::{Ident, ItemVisibility, NoirTypeAlias, UnresolvedType, UnresolvedTypeData},
-    token::Token,
+    token::{Attribute, Token},
 };
 
 use super::Parser;
@@ -11,14 +11,18 @@ impl Parser<'_> {
     /// TypeAlias = 'type' identifier Generics '=' Type ';'
     pub(crate) fn parse_type_alias(
         &mut self,
+        attributes: Vec<(Attribute, Location)>,
         visibility: ItemVisibility,
         start_location: Location,
     ) -> NoirTypeAlias {
+        let attributes = self.validate_secondary_attributes(attributes);
+
         let Some(name) = self.eat_ident() else {
             self.expected_identifier();
             return NoirTypeAlias {
                 visibility,
                 name: Ident::default(),
+                attributes,
                 generics: Vec::new(),
                 typ: UnresolvedType { typ: UnresolvedTypeData::Error, location: Location::dummy() },
                 location: start_location,
@@ -36,6 +40,7 @@ impl Parser<'_> {
             return NoirTypeAlias {
                 visibility,
                 name,
+                attributes,
                 generics,
                 typ: UnresolvedType { typ: UnresolvedTypeData::Error, location: Location::dummy() },
                 location,
@@ -48,7 +53,7 @@ impl Parser<'_> {
             self.expected_token(Token::Semicolon);
         }
 
-        NoirTypeAlias { visibility, name, generics, typ, location }
+        NoirTypeAlias { visibility, name, attributes, generics, typ, location }
     }
 }
 
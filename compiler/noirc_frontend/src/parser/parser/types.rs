@@ -473,8 +473,8 @@ mod tests {
     use strum::IntoEnumIterator;
 
     use crate::{
-        QuotedType,
-        ast::{IntegerBitSize, UnresolvedType, UnresolvedTypeData},
+        BinaryTypeOperator, QuotedType,
+        ast::{IntegerBitSize, UnresolvedType, UnresolvedTypeData, UnresolvedTypeExpression},
         parser::{
             Parser, ParserErrorReason,
             parser::tests::{expect_no_errors, get_single_error, get_source_with_error_span},
@@ -703,6 +703,44 @@ mod tests {
         assert_eq!(expr.to_string(), "10");
     }
 
+    #[test]
+    fn parses_array_type_with_numeric_generic_length() {
+        let src = "[Field; N]";
+        let typ = parse_type_no_errors(src);
+        let UnresolvedTypeData::Array(expr, element_typ) = &typ.typ else {
+            panic!("Expected an array type")
+        };
+        assert!(matches!(element_typ.typ, UnresolvedTypeData::FieldElement));
+        assert!(matches!(expr, UnresolvedTypeExpression::Variable(_)));
+        assert_eq!(expr.to_string(), "N");
+
+        let printed = typ.typ.to_string();
+        assert_eq!(printed, src);
+        assert_eq!(parse_type_no_errors(&printed).typ.to_string(), printed);
+    }
+
+    #[test]
+    fn parses_array_type_with_numeric_generic_length_expression() {
+        let src = "[Field; N + 1]";
+        let typ = parse_type_no_errors(src);
+        let UnresolvedTypeData::Array(expr, element_typ) = &typ.typ else {
+            panic!("Expected an array type")
+        };
+        assert!(matches!(element_typ.typ, UnresolvedTypeData::FieldElement));
+        let UnresolvedTypeExpression::BinaryOperation(lhs, BinaryTypeOperator::Addition, rhs, _) =
+            expr
+        else {
+            panic!("Expected an addition type expression")
+        };
+        assert_eq!(lhs.to_string(), "N");
+        assert_eq!(rhs.to_string(), "1");
+
+        // Binary type expressions are printed parenthesized, which parses back to the same type
+        let printed = typ.typ.to_string();
+        assert_eq!(printed, "[Field; (N + 1)]");
+        assert_eq!(parse_type_no_errors(&printed).typ.to_string(), printed);
+    }
+
     #[test]
     fn parses_reference_to_array_type() {
         let src = "&[Field; 10]";
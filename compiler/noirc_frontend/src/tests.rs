@@ -24,11 +24,14 @@ use crate::elaborator::{FrontendOptions, UnstableFeature};
 use crate::function_path;
 use crate::test_utils::{Expect, get_program, get_program_with_options};
 
+use iter_extended::vecmap;
 use noirc_errors::reporter::report_all;
 use noirc_errors::{CustomDiagnostic, Span};
 
 use crate::hir::Context;
+use crate::hir::comptime::tests::with_elaborator;
 use crate::hir::def_collector::dc_crate::CompilationError;
+use crate::hir::resolution::errors::ResolverError;
 use crate::node_interner::{NodeInterner, StmtId};
 
 use crate::ParsedModule;
@@ -295,7 +298,7 @@ fn get_error_line_span_and_message(
 #[macro_export]
 macro_rules! get_program {
     ($src:expr, $expect:expr) => {
-        $crate::tests::get_program($src, $crate::function_path!(), $expr)
+        $crate::tests::get_program($src, $crate::function_path!(), $expect)
     };
 }
 
@@ -1256,12 +1259,10 @@ fn resolve_fmt_strings() {
 fn deny_cyclic_globals() {
     let src = r#"
         global A: u32 = B;
-                        ^ This global recursively depends on itself
-               ^ Dependency cycle found
+               ^ Dependency cycle found between globals
                ~ 'A' recursively depends on itself: A -> B -> A
         global B: u32 = A;
-                        ^ Variable not in scope
-                        ~ Could not find variable
+               ~ 'B' is part of this cycle
 
         fn main() {}
     "#;
@@ -1557,6 +1558,39 @@ fn incorrect_turbofish_count_method_call() {
     check_errors!(src);
 }
 
+#[named]
+#[test]
+fn turbofish_on_non_generic_function() {
+    let src = r#"
+        fn foo() {}
+
+        fn main() {
+            foo::<i32>();
+               ^^^^^^^ `foo` takes no generic arguments
+        }
+    "#;
+    check_errors!(src);
+}
+
+#[named]
+#[test]
+fn turbofish_on_non_generic_method() {
+    let src = r#"
+        struct Foo {}
+
+        impl Foo {
+            fn bar(_self: Self) {}
+        }
+
+        fn main() {
+            let foo = Foo {};
+            foo.bar::<i32, u64>();
+                      ^^^^^^^^ `bar` takes no generic arguments
+        }
+    "#;
+    check_errors!(src);
+}
+
 #[named]
 #[test]
 fn struct_numeric_generic_in_function() {
@@ -1628,8 +1662,8 @@ fn bool_generic_as_loop_bound() {
                     ^ N has a type of bool. The only supported numeric generic types are `u1`, `u8`, `u16`, and `u32`.
                     ~ Unsupported numeric generic type
         let mut fields = [0; N];
-                             ^ The numeric generic is not of type `u32`
-                             ~ expected `u32`, found `bool`
+                             ^ Numeric generic `N: bool` used where `u32` is required
+                             ~ consider declaring `N` as `let N: u32`
         for i in 0..N { 
                     ^ Expected type Field, found type bool
             fields[i] = i + 1;
@@ -1878,6 +1912,73 @@ fn numeric_generic_in_trait_impl_with_extra_impl_generics() {
     assert_no_errors!(src);
 }
 
+#[named]
+#[test]
+fn impl_numeric_generic_used_in_method_body() {
+    let src = r#"
+    struct Foo<let N: u32> {
+        values: [Field; N],
+    }
+
+    impl<let N: u32> Foo<N> {
+        fn len(self) -> u32 {
+            self.values.len() + N
+        }
+
+        fn size() -> u32 {
+            let double = |x: u32| x + N;
+            double(N)
+        }
+
+        fn with_extra<let M: u32>(self) -> u32 {
+            let _ = self;
+            N + M
+        }
+    }
+
+    trait Length {
+        fn length(self) -> Field;
+    }
+
+    impl<let N: u32> Length for Foo<N> {
+        fn length(self) -> Field {
+            N as Field + self.values[0]
+        }
+    }
+
+    fn main() {
+        let foo = Foo { values: [1, 2, 3] };
+        assert_eq(foo.len(), 6);
+        assert_eq(Foo::<3>::size(), 6);
+        assert_eq(foo.with_extra::<4>(), 7);
+        assert_eq(foo.length(), 4);
+    }
+    "#;
+    assert_no_errors!(src);
+}
+
+#[named]
+#[test]
+fn impl_numeric_generic_in_method_body_has_its_declared_type() {
+    let src = r#"
+    struct Foo<let N: u8> {}
+
+    impl<let N: u8> Foo<N> {
+        fn check(self) {
+            let _ = self;
+            let _: bool = N;
+                          ^ Expected type bool, found type u8
+        }
+    }
+
+    fn main() {
+        let foo: Foo<3> = Foo {};
+        foo.check();
+    }
+    "#;
+    check_errors!(src);
+}
+
 #[named]
 #[test]
 fn numeric_generic_used_in_where_clause() {
@@ -1932,16 +2033,31 @@ fn numeric_generic_u16_array_size() {
 
     pub fn foo<let N: u16>() -> u32 {
         let fields: [Field; N] = [0; N];
-                                     ^ The numeric generic is not of type `u32`
-                                     ~ expected `u32`, found `u16`
-                    ^^^^^^^^^^ The numeric generic is not of type `u32`
-                    ~~~~~~~~~~ expected `u32`, found `u16`
+                                     ^ Numeric generic `N: u16` used where `u32` is required
+                                     ~ consider declaring `N` as `let N: u32`
+                    ^^^^^^^^^^ Numeric generic `N: u16` used where `u32` is required
+                    ~~~~~~~~~~ consider declaring `N` as `let N: u32`
         len(fields)
     }
     "#;
     check_errors!(src);
 }
 
+#[named]
+#[test]
+fn numeric_generic_u8_used_in_array_length() {
+    let src = r#"
+    pub fn foo<let N: u8>(_arr: [Field; N]) {}
+                                ^^^^^^^^^^ Numeric generic `N: u8` used where `u32` is required
+                                ~~~~~~~~~~ consider declaring `N` as `let N: u32`
+
+    pub fn bar<let N: u8>(_arr: [Field; N + 1]) {}
+                                        ^ Numeric generic `N: u8` used where `u32` is required
+                                        ~ consider declaring `N` as `let N: u32`
+    "#;
+    check_errors!(src);
+}
+
 #[named]
 #[test]
 fn numeric_generic_field_larger_than_u32() {
@@ -3618,12 +3734,10 @@ fn disallows_underscore_on_right_hand_side() {
 fn errors_on_cyclic_globals() {
     let src = r#"
     pub comptime global A: u32 = B;
-                                 ^ This global recursively depends on itself
-                        ^ Dependency cycle found
+                        ^ Dependency cycle found between globals
                         ~ 'A' recursively depends on itself: A -> B -> A
     pub comptime global B: u32 = A;
-                                 ^ Variable not in scope
-                                 ~ Could not find variable
+                        ~ 'B' is part of this cycle
 
     fn main() { }
     "#;
@@ -4619,3 +4733,414 @@ fn resolves_generic_type_argument_via_self() {
     ";
     check_monomorphization_error!(src);
 }
+
+#[named]
+#[test]
+fn struct_pattern_field_init_shorthand_binds_field_name() {
+    let src = r#"
+    struct Foo {
+        a: Field,
+        b: bool,
+    }
+
+    fn main() {
+        let foo = Foo { a: 1, b: true };
+        let Foo { b, a } = foo;
+        let Foo { a: x, b: y } = foo;
+        assert(b == y);
+        assert(a == x);
+    }
+    "#;
+    assert_no_errors!(src);
+}
+
+#[named]
+#[test]
+fn struct_pattern_field_init_shorthand_type_checks_binding() {
+    let src = r#"
+    struct Foo {
+        a: Field,
+    }
+
+    fn main() {
+        let Foo { a } = Foo { a: 1 };
+        let _: bool = a;
+                      ^ Expected type bool, found type Field
+    }
+    "#;
+    check_errors!(src);
+}
+
+#[named]
+#[test]
+fn tuple_struct_pattern_binds_fields_in_order() {
+    let src = r#"
+    struct Wrapper(Field);
+
+    struct Pair<T>(T, bool);
+
+    fn main() {
+        let w = Wrapper(1);
+        let Wrapper(x) = w;
+        assert(x == w.0);
+
+        let Pair(a, b) = Pair(2, true);
+        let _: Field = a;
+        assert(b);
+    }
+    "#;
+    assert_no_errors!(src);
+}
+
+#[named]
+#[test]
+fn tuple_struct_pattern_arity_mismatch() {
+    let src = r#"
+    struct Wrapper(Field);
+
+    fn main() {
+        let Wrapper(x, y) = Wrapper(1);
+            ^^^^^^^^^^^^^ Expected 1 argument, but found 2
+        let _ = (x, y);
+    }
+    "#;
+    check_errors!(src);
+}
+
+#[named]
+#[test]
+fn nested_tuple_pattern_type_ascriptions() {
+    let src = r#"
+    fn main() {
+        let ((a: Field, b: u8), mut c: bool) = ((1, 2), true);
+        c = !c;
+        assert(!c);
+        let _ = (a, b);
+    }
+    "#;
+    assert_no_errors!(src);
+}
+
+#[named]
+#[test]
+fn nested_tuple_pattern_type_ascription_mismatch() {
+    let src = r#"
+    fn main() {
+        let pair: (Field, u8) = (1, 2);
+        let (a: Field, b: bool) = pair;
+                          ^^^^ Cannot assign an expression of type u8 to a value of type bool
+        let _ = (a, b);
+    }
+    "#;
+    check_errors!(src);
+}
+
+#[named]
+#[test]
+fn missing_fields_in_struct_pattern_carry_declaration_locations() {
+    let src = r#"
+    struct Foo {
+        c: Field,
+        b: Field,
+        a: Field,
+    }
+
+    fn main() {
+        let Foo { b } = Foo { a: 1, b: 2, c: 3 };
+        let _ = b;
+    }
+    "#;
+    let mut errors = get_program_errors!(src);
+    assert_eq!(errors.len(), 1);
+
+    let CompilationError::ResolverError(ResolverError::MissingFields { missing_fields, .. }) =
+        errors.remove(0)
+    else {
+        panic!("Expected a MissingFields error");
+    };
+
+    // Missing fields are reported in declaration order, each with its declaration location
+    let names = vecmap(&missing_fields, |(name, _)| name.as_str());
+    assert_eq!(names, vec!["c", "a"]);
+
+    for (name, location) in missing_fields {
+        let declaration = format!("{name}: Field");
+        let start = src.find(&declaration).unwrap() as u32;
+        assert_eq!(location.span, Span::from(start..start + 1));
+    }
+}
+
+#[named]
+#[test]
+fn struct_pattern_with_rest_ignores_other_fields() {
+    let src = r#"
+    struct Foo {
+        a: Field,
+        b: Field,
+        c: Field,
+    }
+
+    fn main() {
+        let Foo { b, .. } = Foo { a: 1, b: 2, c: 3 };
+        let Foo { .. } = Foo { a: 1, b: 2, c: 3 };
+        let _ = b;
+    }
+    "#;
+    assert_no_errors!(src);
+}
+
+#[named]
+#[test]
+fn missing_fields_in_exhaustive_struct_pattern_suggest_rest() {
+    let src = r#"
+    struct Foo {
+        a: Field,
+        b: Field,
+    }
+
+    fn main() {
+        let Foo { a } = Foo { a: 1, b: 2 };
+        let _ = a;
+    }
+    "#;
+    let mut errors = get_program_errors!(src);
+    assert_eq!(errors.len(), 1);
+
+    let CompilationError::ResolverError(ResolverError::MissingFields { suggest_rest, .. }) =
+        errors.remove(0)
+    else {
+        panic!("Expected a MissingFields error");
+    };
+    assert!(suggest_rest);
+}
+
+#[named]
+#[test]
+fn non_exhaustive_struct_pattern_does_not_require_rest_in_its_crate() {
+    let src = r#"
+    #[non_exhaustive]
+    struct Foo {
+        a: Field,
+        b: Field,
+    }
+
+    fn main() {
+        let Foo { a, .. } = Foo { a: 1, b: 2 };
+        let Foo { b } = Foo { a: 1, b: 2 };
+        let Foo { a: _, b: _ } = Foo { a: 1, b: 2 };
+        let _ = (a, b);
+    }
+    "#;
+    let mut errors = get_program_errors!(src);
+
+    // Listing every field is allowed in the crate defining the struct, but a missing field is
+    // still reported
+    assert_eq!(errors.len(), 1);
+    let CompilationError::ResolverError(ResolverError::MissingFields { suggest_rest, .. }) =
+        errors.remove(0)
+    else {
+        panic!("Expected a MissingFields error");
+    };
+    assert!(suggest_rest);
+}
+
+#[named]
+#[test]
+fn mut_on_struct_pattern_field_only_makes_that_field_mutable() {
+    let src = r#"
+    struct Foo {
+        a: Field,
+        b: Field,
+    }
+
+    fn main() {
+        let Foo { mut a, b } = Foo { a: 1, b: 2 };
+        a = 3;
+        b = 4;
+        ^ Variable `b` must be mutable to be assigned to
+        assert(a + b == 7);
+    }
+    "#;
+    check_errors!(src);
+}
+
+#[named]
+#[test]
+fn duplicate_field_in_struct_pattern_reports_both_occurrences() {
+    let src = r#"
+    struct Foo {
+        a: Field,
+        b: Field,
+    }
+
+    fn main() {
+        let Foo { a, b, a: c } = Foo { a: 1, b: 2 };
+                  ~ First field found here
+                        ^ duplicate field a
+        assert(a + b + c == 4);
+    }
+    "#;
+    check_errors!(src);
+}
+
+#[named]
+#[test]
+fn duplicate_field_in_constructor_reports_both_occurrences() {
+    let src = r#"
+    struct Foo {
+        a: Field,
+    }
+
+    fn main() {
+        let foo = Foo { a: 1, a: 2 };
+                        ~ First field found here
+                              ^ duplicate field a
+        assert(foo.a == 1);
+    }
+    "#;
+    check_errors!(src);
+}
+
+#[named]
+#[test]
+fn tuple_assignment_swaps_values() {
+    let src = r#"
+    struct Foo {
+        x: Field,
+    }
+
+    fn main() {
+        comptime {
+            let mut a = 1;
+            let mut b = 2;
+            (a, b) = (b, a);
+            assert_eq(a, 2);
+            assert_eq(b, 1);
+
+            let mut foo = Foo { x: 3 };
+            let mut array = [4, 5];
+            (foo.x, (array[0], array[1])) = (array[1], (foo.x, array[0]));
+            assert_eq(foo.x, 5);
+            assert_eq(array, [3, 4]);
+        }
+    }
+    "#;
+    assert_no_errors!(src);
+}
+
+#[named]
+#[test]
+fn tuple_assignment_errors_if_place_is_not_mutable() {
+    let src = r#"
+    fn main() {
+        let mut a = 1;
+        let b = 2;
+        (a, b) = (b, a);
+            ^ Variable `b` must be mutable to be assigned to
+        assert(a == b);
+    }
+    "#;
+    check_errors!(src);
+}
+
+#[named]
+#[test]
+fn mut_on_struct_pattern_makes_all_fields_mutable() {
+    let src = r#"
+    struct Foo {
+        a: Field,
+        b: Field,
+    }
+
+    fn main() {
+        let mut Foo { a, b } = Foo { a: 1, b: 2 };
+        a = 3;
+        b = 4;
+        assert(a + b == 7);
+    }
+    "#;
+    assert_no_errors!(src);
+}
+
+#[named]
+#[test]
+fn repeated_struct_patterns_bind_fresh_variables_with_their_own_field_types() {
+    let src = r#"
+    struct Pair<T> {
+        first: T,
+        second: bool,
+    }
+
+    fn main() {
+        let Pair { first, second } = Pair { first: 1, second: true };
+        let _: Field = first;
+        assert(second);
+
+        let Pair { first, second } = Pair { first: 2, second: false };
+        let _: Field = first;
+        assert(!second);
+
+        let Pair { first, second } = Pair { first: true, second: true };
+        let _: Field = first;
+                       ^^^^^ Expected type Field, found type bool
+        assert(second);
+    }
+    "#;
+    check_errors!(src);
+}
+
+#[test]
+fn identical_struct_patterns_reuse_resolved_fields() {
+    let src = r#"
+    struct Pair<T> {
+        first: T,
+        second: bool,
+    }
+
+    fn main() {
+        let Pair { first: _, second: _ } = Pair { first: 1 as Field, second: true };
+        let Pair { first: _, second: _ } = Pair { first: 2 as Field, second: false };
+        let Pair { first: _, second: _ } = Pair { first: true, second: true };
+    }
+    "#;
+
+    with_elaborator(src, |elaborator, _| {
+        assert!(elaborator.errors.is_empty(), "{:?}", elaborator.errors);
+
+        // Only the second pattern has the same generic arguments as an earlier one
+        assert_eq!(elaborator.struct_pattern_field_cache_hits, 1);
+    });
+}
+
+#[named]
+#[test]
+fn errors_on_too_deeply_nested_tuple_pattern() {
+    // Builds `let (_x0, (_x1, ... (_x19, _x20) ...)) = (0, (0, ... (0, 0) ...));`
+    let depth = 20;
+    let mut pattern = format!("_x{depth}");
+    let mut value = "0".to_string();
+    for i in (0..depth).rev() {
+        pattern = format!("(_x{i}, {pattern})");
+        value = format!("(0, {value})");
+    }
+    let src = format!("fn main() {{ let {pattern} = {value}; }}");
+
+    let allow_parser_errors = false;
+    let options = FrontendOptions { pattern_depth_limit: 8, ..FrontendOptions::test_default() };
+    let (_, _, errors) =
+        get_program_with_options!(&src, Expect::Error, allow_parser_errors, options);
+    assert!(!errors.is_empty());
+
+    for error in errors {
+        let CompilationError::ResolverError(ResolverError::PatternTooDeep { limit, location }) =
+            &error
+        else {
+            panic!("Expected a PatternTooDeep error, got {error:?}");
+        };
+        assert_eq!(*limit, 8);
+
+        // Only the patterns just past the limit are reported
+        let reported = &src[location.span.start() as usize..location.span.end() as usize];
+        assert!(reported == "_x8" || reported.starts_with("(_x9,"), "{reported}");
+    }
+}
@@ -51,6 +51,50 @@ fn alias_in_let_pattern() {
     assert_no_errors!(src);
 }
 
+#[named]
+#[test]
+fn struct_alias_in_let_pattern() {
+    let src = r#"
+        struct Bar<T, U> { a: T, b: U }
+
+        type Foo = Bar<u8, i32>;
+        type FooAlias = Foo;
+
+        fn main() {
+            let Foo { a, b } = Bar { a: 1, b: 2 };
+            let _: u8 = a;
+            let _: i32 = b;
+
+            let FooAlias { a, b } = Bar { a: 1, b: 2 };
+            let _: u8 = a;
+            let _: i32 = b;
+        }
+    "#;
+    assert_no_errors!(src);
+}
+
+#[named]
+#[test]
+fn generic_struct_alias_in_let_pattern() {
+    let src = r#"
+        struct Bar<T, U> { a: T, b: U }
+
+        type Foo<T> = Bar<T, i32>;
+
+        fn main() {
+            let Foo { a, b } = Bar { a: [0], b: 2 };
+            let _: [Field; 1] = a;
+            let _: i32 = b;
+
+            // The turbofish generics are the alias' own, not the struct's
+            let Foo::<u8> { a, b } = Bar { a: 1, b: 2 };
+            let _: u8 = a;
+            let _: i32 = b;
+        }
+    "#;
+    assert_no_errors!(src);
+}
+
 #[named]
 #[test]
 fn double_alias_in_path() {
@@ -89,6 +89,7 @@ fn duplicate_field_in_match_struct_pattern() {
         let foo = Foo { x: 10, y: 20 };
         match foo {
             Foo { x: _, x: _, y: _ } => {}
+                  ~ First field found here
                         ^ duplicate field x
         }
     }
@@ -141,6 +142,39 @@ fn no_such_field_in_match_struct_pattern() {
     check_errors!(src);
 }
 
+#[named]
+#[test]
+fn match_tuple_struct_pattern() {
+    let src = r#"
+    fn main() {
+        let pair = Pair(1, 2);
+        match pair {
+            Pair(0, y) => assert_eq(y, 2),
+            Pair(x, _) => assert_eq(x, 1),
+        }
+    }
+
+    struct Pair(u32, u32);
+    "#;
+    assert_no_errors!(src);
+}
+
+#[named]
+#[test]
+fn tuple_struct_arity_mismatch_in_match_pattern() {
+    let src = r#"
+    fn main() {
+        match Wrapper(1) {
+            Wrapper(_, _) => (),
+            ^^^^^^^ Expected 1 argument, but found 2
+        }
+    }
+
+    struct Wrapper(Field);
+    "#;
+    check_errors!(src);
+}
+
 #[named]
 #[test]
 fn match_integer_type_mismatch_in_pattern() {
@@ -343,6 +377,74 @@ fn missing_int_ranges_with_negatives() {
     );
 }
 
+#[named]
+#[test]
+fn match_inclusive_range_pattern() {
+    let src = "
+        fn main() {
+            let x: u8 = 3;
+            match x {
+                0..=9 => (),
+                10..100 => (),
+                100..=255 => (),
+            }
+        }
+    ";
+    assert_no_errors!(src);
+}
+
+#[named]
+#[test]
+fn match_inverted_range_pattern() {
+    check_errors!(
+        "
+        fn main() {
+            let x: u8 = 3;
+            match x {
+                5..=3 => (),
+                ^^^^^ Range pattern `5..=3` is empty
+                ~~~~~ The start of a range pattern must be lower than its end
+                _ => (),
+            }
+        }
+    ",
+    );
+}
+
+#[named]
+#[test]
+fn missing_cases_with_range_patterns() {
+    check_errors!(
+        "
+        fn main() {
+            let x: u8 = 3;
+            match x {
+                  ^ Missing cases: `10..=19`, `50..=255`
+                0..=9 => (),
+                20..50 => (),
+            }
+        }
+    ",
+    );
+}
+
+#[named]
+#[test]
+fn match_range_pattern_on_field() {
+    check_errors!(
+        "
+        fn main() {
+            let x: Field = 3;
+            match x {
+                0..10 => (),
+                ^^^^^ Expected type Field, found type u32
+                _ => (),
+            }
+        }
+    ",
+    );
+}
+
 #[named]
 #[test]
 fn missing_cases_with_empty_match() {
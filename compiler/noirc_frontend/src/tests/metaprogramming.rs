@@ -1,5 +1,5 @@
 use crate::{
-    check_errors,
+    check_errors, get_program,
     hir::{
         comptime::ComptimeError,
         def_collector::{
@@ -7,6 +7,8 @@ use crate::{
             errors::{DefCollectorErrorKind, DuplicateType},
         },
     },
+    node_interner::ReferenceId,
+    test_utils::Expect,
 };
 
 use crate::{assert_no_errors, get_program_errors};
@@ -207,3 +209,53 @@ fn does_not_fail_to_parse_macro_on_parser_warning() {
     "#;
     check_errors!(src);
 }
+
+#[named]
+#[test]
+fn finds_attribute_which_generated_code() {
+    let src = r#"
+    comptime fn make_bar(_s: TypeDefinition) -> Quoted {
+        quote { fn bar() {} }
+    }
+
+    #[make_bar]
+    struct Foo {}
+
+    fn main() {
+        let _ = Foo {};
+        bar();
+    }
+    "#;
+    let (_, context, errors) = get_program!(src, Expect::Success);
+    assert!(errors.is_empty(), "{errors:?}");
+    let interner = &context.def_interner;
+
+    let bar = interner.find_function("bar").unwrap();
+    let bar_location = interner.function_meta(&bar).name.location;
+    let (attribute, attribute_location) = interner
+        .find_generating_attribute(bar_location)
+        .expect("expected `bar` to be generated by an attribute");
+
+    let make_bar = interner.find_function("make_bar").unwrap();
+    assert_eq!(attribute, ReferenceId::Function(make_bar));
+    let file = context.file_manager.fetch_file(attribute_location.file).unwrap();
+    let span = attribute_location.span;
+    assert!(file[span.start() as usize..span.end() as usize].contains("make_bar"));
+
+    let main = interner.find_function("main").unwrap();
+    let main_location = interner.function_meta(&main).name.location;
+    assert_eq!(interner.find_generating_attribute(main_location), None);
+}
+
+#[named]
+#[test]
+fn errors_on_attribute_with_turbofish() {
+    let src = r#"
+    #[foo::<i32>]
+      ^^^^^^^^^^ Attributes cannot take generic arguments
+    fn main() {}
+
+    comptime fn foo(_f: FunctionDefinition) {}
+    "#;
+    check_errors!(src);
+}
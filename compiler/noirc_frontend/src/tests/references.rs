@@ -1,4 +1,6 @@
-use crate::check_errors;
+use crate::{
+    assert_no_errors, check_errors, check_errors_using_features, elaborator::UnstableFeature,
+};
 
 #[named]
 #[test]
@@ -76,3 +78,111 @@ fn constrained_reference_to_unconstrained() {
     "#;
     check_errors!(src);
 }
+
+#[named]
+#[test]
+fn mutable_copy_of_immutable_place_can_be_assigned_to() {
+    let src = r#"
+    struct Foo {
+        x: Field
+    }
+
+    fn main() {
+        let foo = Foo { x: 0 };
+        let mut x = foo.x;
+        x = 1;
+        assert(x != foo.x);
+    }
+    "#;
+    assert_no_errors!(src);
+}
+
+#[named]
+#[test]
+fn cannot_mutate_through_immutable_reference() {
+    let src = r#"
+    struct Foo {
+        x: Field
+    }
+
+    unconstrained fn main() {
+        let foo = Foo { x: 0 };
+        let foo_ref = &foo;
+        foo_ref.x = 1;
+        ^^^^^^^^^ Cannot mutate through an immutable reference
+
+        let array = [1, 2];
+        let array_ref = &array;
+        array_ref[0] = 3;
+        ^^^^^^^^^^^^ Cannot mutate through an immutable reference
+    }
+    "#;
+    check_errors_using_features!(src, &[UnstableFeature::Ownership]);
+}
+
+#[named]
+#[test]
+fn destructures_reference_to_struct() {
+    let src = r#"
+    struct Foo {
+        x: Field,
+        y: Field,
+    }
+
+    fn main() {
+        let foo = Foo { x: 1, y: 2 };
+        let Foo { x, y } = &foo;
+        assert(x + y == sum(&foo));
+    }
+
+    fn sum(Foo { x, y }: &Foo) -> Field {
+        x + y
+    }
+    "#;
+    check_errors_using_features!(src, &[UnstableFeature::Ownership]);
+}
+
+#[named]
+#[test]
+fn destructures_mutable_reference_to_tuple_into_copies() {
+    let src = r#"
+    fn main() {
+        let mut pair = (1, 2);
+        let (a, b) = &mut pair;
+        b = 4;
+        ^ Variable `b` must be mutable to be assigned to
+        assert(a + b != pair.0);
+    }
+    "#;
+    check_errors!(src);
+}
+
+#[named]
+#[test]
+fn cannot_bind_mutable_variable_when_destructuring_mutable_reference() {
+    let src = r#"
+    struct Foo {
+        x: Field,
+        y: Field,
+    }
+
+    fn main() {
+        let mut pair = (1, 2);
+        let (mut a, b) = &mut pair;
+             ^^^^^ Variables destructured from a mutable reference cannot be mutable
+             ~~~~~ They hold copies of the fields, so assigning to them wouldn't change the referenced value
+        a += 10;
+        assert(a + b != pair.0);
+
+        let mut foo = Foo { x: 1, y: 2 };
+        increment(&mut foo);
+    }
+
+    fn increment(Foo { mut x, y: _ }: &mut Foo) {
+                       ^^^^^ Variables destructured from a mutable reference cannot be mutable
+                       ~~~~~ They hold copies of the fields, so assigning to them wouldn't change the referenced value
+        x += 1;
+    }
+    "#;
+    check_errors!(src);
+}
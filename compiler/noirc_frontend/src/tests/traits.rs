@@ -1495,3 +1495,72 @@ fn returns_self_in_trait_method_3() {
     ";
     assert_no_errors!(src);
 }
+
+#[named]
+#[test]
+fn destructures_associated_type_bound_to_tuple() {
+    let src = "
+    trait Split {
+        type Output;
+
+        fn split(self) -> Self::Output;
+    }
+
+    impl Split for Field {
+        type Output = (Field, Field);
+
+        fn split(self) -> Self::Output {
+            (self, self + 1)
+        }
+    }
+
+    fn split<T: Split>(x: T) -> <T as Split>::Output {
+        x.split()
+    }
+
+    fn main(x: Field) {
+        let (a, b) = split(x);
+        assert(a + 1 == b);
+
+        let (c, d) = x.split();
+        assert(c + 1 == d);
+    }
+    ";
+    assert_no_errors!(src);
+}
+
+#[named]
+#[test]
+fn destructures_associated_type_bound_to_struct() {
+    let src = "
+    struct Pair<T> {
+        first: T,
+        second: T,
+    }
+
+    trait Split {
+        type Output;
+
+        fn split(self) -> Self::Output;
+    }
+
+    impl Split for u8 {
+        type Output = Pair<u8>;
+
+        fn split(self) -> Self::Output {
+            Pair { first: self, second: self + 1 }
+        }
+    }
+
+    fn split<T: Split>(x: T) -> <T as Split>::Output {
+        x.split()
+    }
+
+    fn main(x: u8) {
+        let Pair { first, second } = split(x);
+        let first: u8 = first;
+        assert(first + 1 == second);
+    }
+    ";
+    assert_no_errors!(src);
+}
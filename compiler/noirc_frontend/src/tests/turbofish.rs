@@ -368,3 +368,50 @@ fn trait_function_with_turbofish_on_trait_gives_error() {
     "#;
     check_errors!(src);
 }
+
+#[named]
+#[test]
+fn turbofish_type_given_for_numeric_generic() {
+    let src = r#"
+    fn foo<let N: u32>() -> u32 {
+        N
+    }
+
+    fn main() {
+        let _ = foo::<Field>();
+                      ^^^^^ Generic `N` expects a numeric value of type `u32`, but a type `Field` was provided
+    }
+    "#;
+    check_errors!(src);
+}
+
+#[named]
+#[test]
+fn turbofish_numeric_value_given_for_type_generic() {
+    let src = r#"
+    fn foo<T>() {}
+
+    fn main() {
+        foo::<3>();
+              ^ Generic `T` expects a type, but a numeric value `3` was provided
+    }
+    "#;
+    check_errors!(src);
+}
+
+#[named]
+#[test]
+fn turbofish_conflicting_with_argument_type() {
+    let src = r#"
+    fn foo<T>(x: T) -> T {
+        x
+    }
+
+    fn main() {
+        let x: Field = 1;
+        let _ = foo::<i32>(x);
+                           ^ Generic `T` was set to `i32` by turbofish but argument implies `Field`
+    }
+    "#;
+    check_errors!(src);
+}
@@ -66,6 +66,13 @@ pub struct ExecuteCommand {
     /// Use pedantic ACVM solving, i.e. double-check some black-box function assumptions when solving.
     #[clap(long, default_value_t = false)]
     pub pedantic_solving: bool,
+
+    /// Path to a JSON file to write a trace of every opcode executed, for replaying
+    /// the execution in a debugger.
+    ///
+    /// Note that the trace can be very large, as it records every step of the execution.
+    #[clap(long, value_parser = parse_and_normalize_path)]
+    pub trace_file: Option<PathBuf>,
 }
 
 pub fn run(args: ExecuteCommand) -> Result<(), CliError> {
@@ -131,5 +138,11 @@ fn execute(circuit: &CompiledProgram, args: &ExecuteCommand) -> Result<Execution
 
     let blackbox_solver = Bn254BlackBoxSolver(args.pedantic_solving);
 
-    execution::execute(circuit, &blackbox_solver, &mut foreign_call_executor, &args.prover_file)
+    execution::execute(
+        circuit,
+        &blackbox_solver,
+        &mut foreign_call_executor,
+        &args.prover_file,
+        args.trace_file.as_deref(),
+    )
 }
@@ -2,14 +2,17 @@ use std::path::Path;
 
 use acir::{FieldElement, native_types::WitnessStack};
 use acvm::BlackBoxFunctionSolver;
-use nargo::{NargoError, foreign_calls::ForeignCallExecutor};
+use nargo::{NargoError, foreign_calls::ForeignCallExecutor, ops::ExecutionOptions};
 use noirc_abi::input_parser::InputValue;
 use noirc_artifacts::debug::DebugArtifact;
 use noirc_driver::CompiledProgram;
 
 use crate::{
     errors::CliError,
-    fs::{inputs::read_inputs_from_file, witness::save_witness_to_dir},
+    fs::{
+        inputs::read_inputs_from_file, trace::save_execution_trace_to_file,
+        witness::save_witness_to_dir,
+    },
 };
 
 /// Results of a circuit execution.
@@ -29,11 +32,14 @@ pub struct ReturnValues {
 }
 
 /// Execute a circuit and return the output witnesses.
+///
+/// If a `trace_file` is given, a trace of every executed opcode is written to it.
 pub fn execute<B, E>(
     circuit: &CompiledProgram,
     blackbox_solver: &B,
     foreign_call_executor: &mut E,
     prover_file: &Path,
+    trace_file: Option<&Path>,
 ) -> Result<ExecutionResults, CliError>
 where
     B: BlackBoxFunctionSolver<FieldElement>,
@@ -43,12 +49,27 @@ where
 
     let initial_witness = circuit.abi.encode(&input_map, None)?;
 
-    let witness_stack = nargo::ops::execute_program(
-        &circuit.program,
-        initial_witness,
-        blackbox_solver,
-        foreign_call_executor,
-    )?;
+    let witness_stack = match trace_file {
+        Some(trace_file) => {
+            let options = ExecutionOptions { record_trace: true, ..Default::default() };
+            let execution = nargo::ops::execute_program_with_options(
+                &circuit.program,
+                initial_witness,
+                blackbox_solver,
+                foreign_call_executor,
+                options,
+            )?;
+            let trace = execution.trace.expect("Expected a recorded trace");
+            save_execution_trace_to_file(&trace, trace_file)?;
+            execution.witness_stack
+        }
+        None => nargo::ops::execute_program(
+            &circuit.program,
+            initial_witness,
+            blackbox_solver,
+            foreign_call_executor,
+        )?,
+    };
 
     let main_witness =
         &witness_stack.peek().expect("Should have at least one witness on the stack").witness;
@@ -1,3 +1,4 @@
 pub mod artifact;
 pub mod inputs;
+pub mod trace;
 pub mod witness;
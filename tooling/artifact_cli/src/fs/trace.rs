@@ -0,0 +1,26 @@
+use std::path::Path;
+
+use acir::FieldElement;
+use nargo::ops::ExecutionTrace;
+
+use crate::errors::{CliError, FilesystemError};
+
+/// Write the execution trace as JSON to the given file.
+pub fn save_execution_trace_to_file(
+    trace: &ExecutionTrace<FieldElement>,
+    trace_path: &Path,
+) -> Result<(), CliError> {
+    if let Some(dir) = trace_path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let json = serde_json::to_vec(trace).map_err(|e| {
+        FilesystemError::OutputFileCreationFailed(trace_path.to_path_buf(), e.to_string())
+    })?;
+
+    std::fs::write(trace_path, json).map_err(|e| {
+        FilesystemError::OutputFileCreationFailed(trace_path.to_path_buf(), e.to_string())
+    })?;
+
+    Ok(())
+}
@@ -3,7 +3,7 @@ use color_eyre::eyre;
 use noir_ast_fuzzer::compare::{CompareResult, CompareSsa};
 use noirc_evaluator::{
     brillig::BrilligOptions,
-    ssa::{self, SsaEvaluatorOptions, SsaProgramArtifact},
+    ssa::{self, InliningPolicy, SsaEvaluatorOptions, SsaProgramArtifact},
 };
 use noirc_frontend::monomorphization::ast::Program;
 
@@ -21,6 +21,7 @@ pub fn default_ssa_options() -> SsaEvaluatorOptions {
         ssa_logging: if show_ssa() { ssa::SsaLogging::All } else { ssa::SsaLogging::None },
         brillig_options: BrilligOptions::default(),
         print_codegen_timings: false,
+        print_pass_timings: false,
         expression_width: ExpressionWidth::default(),
         emit_ssa: None,
         skip_underconstrained_check: true,
@@ -28,6 +29,23 @@ pub fn default_ssa_options() -> SsaEvaluatorOptions {
         enable_brillig_constraints_check_lookback: false,
         inliner_aggressiveness: 0,
         max_bytecode_increase_percent: None,
+        inlining_policy: InliningPolicy::default(),
+        collect_pass_statistics: false,
+        collect_loop_report: false,
+        warn_on_loop_assertions: false,
+        deny_failing_assertions: false,
+        brillig_keep_overflow_checks: false,
+        narrow_integer_types: false,
+        combine_bound_checks: false,
+        remove_identity_casts: false,
+        remove_self_assigning_stores: false,
+        simplify_constant_block_params: false,
+        tail_call_to_loop: false,
+        specialize_constant_arguments: false,
+        reassociate_loop_invariants: false,
+        observed_variables: Vec::new(),
+        deny_unconstrained_constraints: false,
+        warn_on_ineffective_inputs: false,
     }
 }
 
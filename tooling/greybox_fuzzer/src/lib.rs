@@ -29,10 +29,11 @@ use rayon::iter::ParallelIterator;
 use termcolor::{ColorChoice, StandardStream};
 pub use types::FuzzTestResult;
 use types::{
-    CounterExampleOutcome, DiscrepancyOutcome, HarnessExecutionOutcome, ProgramFailureResult,
-    SuccessfulCaseOutcome,
+    CounterExampleOutcome, DiscrepancyKind, DiscrepancyOutcome, HarnessExecutionOutcome,
+    ProgramFailureResult, SuccessfulCaseOutcome,
 };
 
+use noirc_abi::input_parser::InputValue;
 use noirc_artifacts::program::ProgramArtifact;
 use rand::prelude::*;
 use rand::{Rng, SeedableRng};
@@ -268,6 +269,9 @@ pub struct FuzzedExecutorExecutionConfiguration {
     pub num_threads: usize,
     /// Maximum time in seconds to spend fuzzing (default: no timeout)
     pub timeout: u64,
+    /// Execute every testcase both as ACIR and as brillig, and report a discrepancy if
+    /// their return values differ on top of one of them failing while the other doesn't
+    pub differential: bool,
 }
 
 pub struct FuzzedExecutorFailureConfiguration {
@@ -336,6 +340,9 @@ pub struct FuzzedExecutor<E, F> {
 
     /// Maximum time in seconds to spend fuzzing (default: no timeout)
     timeout: u64,
+
+    /// Execute every testcase both as ACIR and as brillig and compare their return values
+    differential: bool,
 }
 pub struct AcirAndBrilligPrograms {
     pub acir_program: ProgramArtifact,
@@ -398,6 +405,7 @@ impl<
                 folder_configuration.minimized_corpus_dir.unwrap_or_default(),
             ),
             timeout: fuzz_execution_config.timeout,
+            differential: fuzz_execution_config.differential,
             metrics: Metrics::default(),
         }
     }
@@ -549,7 +557,10 @@ impl<
             // The reason is that brillig can be hundreds of times faster than ACIR and we want to balance execution so we don't waste an opportunity
             // to execute a bunch of testcases while limiting information from ACIR, instead of getting all the information, but from very few testcases
             // We also do an ACIR+Brillig round if we haven't processed the starting corpus yet
-            let acir_round = self.metrics.is_brillig_dominating() || !processed_starting_corpus;
+            // In differential mode every round executes both ACIR and brillig
+            let acir_round = self.differential
+                || self.metrics.is_brillig_dominating()
+                || !processed_starting_corpus;
 
             if processed_starting_corpus {
                 // If this is a standard and not a starting round
@@ -838,7 +849,7 @@ impl<
                             }) => HarnessExecutionOutcome::Discrepancy(DiscrepancyOutcome {
                                 case_id,
                                 counterexample,
-                                acir_failed: true,
+                                kind: DiscrepancyKind::AcirFailed,
                                 exit_reason,
                             }),
                             HarnessExecutionOutcome::ForeignCallFailure(..) => {
@@ -966,16 +977,19 @@ impl<
             HarnessExecutionOutcome::Discrepancy(DiscrepancyOutcome {
                 case_id: _,
                 exit_reason: status,
-                acir_failed,
+                kind,
                 counterexample,
             }) => {
-                let reason = match acir_failed {
-                    true => {
+                let reason = match kind {
+                    DiscrepancyKind::AcirFailed => {
                         format!("ACIR failed while brillig executed with no issues: {}", status)
                     }
-                    false => {
+                    DiscrepancyKind::BrilligFailed => {
                         format!("brillig failed while ACIR executed with no issues: {}", status)
                     }
+                    DiscrepancyKind::DifferentReturnValues => {
+                        format!("ACIR and brillig returned different values: {}", status)
+                    }
                 };
 
                 FuzzTestResult::ProgramFailure(ProgramFailureResult {
@@ -1022,7 +1036,21 @@ impl<
 
         // Parse results
         match (result_acir, result_brillig) {
-            (Ok(witnesses), Ok((_map, brillig_coverage))) => {
+            (Ok(witnesses), Ok((brillig_witnesses, brillig_coverage))) => {
+                if self.differential {
+                    let acir_value = return_value(&self.acir_program, &witnesses);
+                    let brillig_value = return_value(&self.brillig_program, &brillig_witnesses);
+                    if acir_value != brillig_value {
+                        return HarnessExecutionOutcome::Discrepancy(DiscrepancyOutcome {
+                            case_id: testcase.id(),
+                            exit_reason: format!(
+                                "ACIR returned {acir_value:?}, brillig returned {brillig_value:?}"
+                            ),
+                            kind: DiscrepancyKind::DifferentReturnValues,
+                            counterexample: testcase.value().clone(),
+                        });
+                    }
+                }
                 // If both were OK, collect coverage and ACIR witnesses along with timings and return
                 HarnessExecutionOutcome::Case(SuccessfulCaseOutcome {
                     case_id: testcase.id(),
@@ -1037,13 +1065,13 @@ impl<
             (Err(err), Ok(_)) => HarnessExecutionOutcome::Discrepancy(DiscrepancyOutcome {
                 case_id: testcase.id(),
                 exit_reason: err,
-                acir_failed: true,
+                kind: DiscrepancyKind::AcirFailed,
                 counterexample: testcase.value().clone(),
             }),
             (Ok(_), Err((err, _))) => HarnessExecutionOutcome::Discrepancy(DiscrepancyOutcome {
                 case_id: testcase.id(),
                 exit_reason: err,
-                acir_failed: false,
+                kind: DiscrepancyKind::BrilligFailed,
                 counterexample: testcase.value().clone(),
             }),
             // If both failed, then we need to check
@@ -1187,6 +1215,15 @@ impl<
     }
 }
 
+/// Decodes the value returned by `program` from the witnesses of its execution
+fn return_value(
+    program: &ProgramArtifact,
+    witness_stack: &WitnessStack<FieldElement>,
+) -> Option<InputValue> {
+    let main_witness = &witness_stack.peek()?.witness;
+    program.abi.decode(main_witness).ok()?.1
+}
+
 // A method for pretty display starting information
 #[allow(clippy::too_many_arguments)]
 fn display_starting_info(
@@ -1369,3 +1406,138 @@ fn display_metrics(metrics: &Metrics) -> Result<(), std::io::Error> {
     writer.flush()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use acvm::{
+        FieldElement,
+        acir::{
+            circuit::{
+                Circuit, Opcode, Program,
+                brillig::{BrilligBytecode, BrilligFunctionId},
+            },
+            native_types::{Witness, WitnessMap, WitnessStack},
+        },
+    };
+    use noirc_abi::{
+        Abi, AbiParameter, AbiReturnType, AbiType, AbiVisibility, InputMap,
+        input_parser::InputValue,
+    };
+    use noirc_artifacts::program::ProgramArtifact;
+    use noirc_errors::debug_info::{DebugInfo, ProgramDebugInfo};
+
+    use crate::{
+        AcirAndBrilligPrograms, ErrorAndCoverage, FuzzedExecutor,
+        FuzzedExecutorExecutionConfiguration, FuzzedExecutorFailureConfiguration,
+        FuzzedExecutorFolderConfiguration, WitnessAndCoverage,
+        corpus::TestCase,
+        coverage::FeatureToIndexMap,
+        types::{DiscrepancyKind, HarnessExecutionOutcome},
+    };
+
+    /// An artifact of `fn main(x: Field) -> pub Field`, whose first opcode calls into Brillig
+    /// as the fuzzer expects.
+    fn artifact() -> ProgramArtifact {
+        let abi = Abi {
+            parameters: vec![AbiParameter {
+                name: "x".to_string(),
+                typ: AbiType::Field,
+                visibility: AbiVisibility::Private,
+            }],
+            return_type: Some(AbiReturnType {
+                abi_type: AbiType::Field,
+                visibility: AbiVisibility::Public,
+            }),
+            error_types: BTreeMap::new(),
+        };
+        let brillig_call = Opcode::BrilligCall {
+            id: BrilligFunctionId(0),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            predicate: None,
+        };
+        let main = Circuit { opcodes: vec![brillig_call], ..Circuit::default() };
+        ProgramArtifact {
+            noir_version: String::new(),
+            hash: 0,
+            abi,
+            bytecode: Program {
+                functions: vec![main],
+                unconstrained_functions: vec![BrilligBytecode::default()],
+            },
+            debug_symbols: ProgramDebugInfo { debug_infos: vec![DebugInfo::default()] },
+            file_map: BTreeMap::new(),
+            names: vec!["main".to_string()],
+            brillig_names: vec!["main".to_string()],
+            pass_statistics: None,
+            loop_report: None,
+            source_map: None,
+        }
+    }
+
+    /// Mocks an execution of `artifact` which returns `x + offset`.
+    fn execute(
+        initial_witness: WitnessMap<FieldElement>,
+        offset: u128,
+    ) -> WitnessStack<FieldElement> {
+        let x = initial_witness[&Witness(0)];
+        let mut witness = initial_witness;
+        witness.insert(Witness(1), x + FieldElement::from(offset));
+        WitnessStack::from(witness)
+    }
+
+    /// Runs a single case through ACIR and Brillig executions which return `x` and
+    /// `x + brillig_offset` respectively.
+    fn fuzz_once(differential: bool, brillig_offset: u128) -> HarnessExecutionOutcome {
+        let acir_executor = |_: &Program<FieldElement>,
+                             initial_witness: WitnessMap<FieldElement>|
+         -> Result<WitnessStack<FieldElement>, String> {
+            Ok(execute(initial_witness, 0))
+        };
+        let brillig_executor = |_: &Program<FieldElement>,
+                                initial_witness: WitnessMap<FieldElement>,
+                                _: &FeatureToIndexMap|
+         -> Result<WitnessAndCoverage, ErrorAndCoverage> {
+            Ok((execute(initial_witness, brillig_offset), Some(Vec::new())))
+        };
+        let fuzzer = FuzzedExecutor::new(
+            AcirAndBrilligPrograms { acir_program: artifact(), brillig_program: artifact() },
+            acir_executor,
+            brillig_executor,
+            "package",
+            "harness",
+            FuzzedExecutorExecutionConfiguration { num_threads: 1, timeout: 0, differential },
+            FuzzedExecutorFailureConfiguration {
+                fail_on_specific_asserts: false,
+                failure_reason: None,
+            },
+            FuzzedExecutorFolderConfiguration { corpus_dir: None, minimized_corpus_dir: None },
+        );
+
+        let input =
+            InputMap::from([("x".to_string(), InputValue::Field(FieldElement::from(3u128)))]);
+        fuzzer.single_fuzz_acir_and_brillig(&TestCase::from(&input))
+    }
+
+    #[test]
+    fn differential_fuzzing_reports_different_return_values() {
+        match fuzz_once(true, 1) {
+            HarnessExecutionOutcome::Discrepancy(outcome) => {
+                assert_eq!(outcome.kind, DiscrepancyKind::DifferentReturnValues);
+            }
+            _ => panic!("expected a discrepancy"),
+        }
+    }
+
+    #[test]
+    fn differential_fuzzing_accepts_equal_return_values() {
+        assert!(matches!(fuzz_once(true, 0), HarnessExecutionOutcome::Case(_)));
+    }
+
+    #[test]
+    fn return_values_are_not_compared_by_default() {
+        assert!(matches!(fuzz_once(false, 1), HarnessExecutionOutcome::Case(_)));
+    }
+}
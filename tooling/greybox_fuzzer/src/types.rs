@@ -65,13 +65,24 @@ pub struct DiscrepancyOutcome {
     /// Minimal reproduction test case for failing test
     pub counterexample: CounterExample,
 
-    // True if the failure came from ACIR, false if from brillig
-    pub acir_failed: bool,
+    /// How the executions differed
+    pub kind: DiscrepancyKind,
 
     /// The status of the call
     pub exit_reason: String,
 }
 
+/// The way in which ACIR and brillig executions of the same testcase differed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiscrepancyKind {
+    /// ACIR execution failed while brillig execution succeeded
+    AcirFailed,
+    /// Brillig execution failed while ACIR execution succeeded
+    BrilligFailed,
+    /// Both executions succeeded but returned different values
+    DifferentReturnValues,
+}
+
 /// Returned by a single fuzz when a counterexample has been discovered
 #[derive(Clone, Debug)]
 pub struct CounterExampleOutcome {
@@ -95,8 +95,9 @@ impl Visitor for AttributeReferenceFinder<'_> {
         }
 
         let path = attribute.name.clone();
-        // The path here must resolve to a function and it's a simple path (can't have turbofish)
-        // so it can (and must) be solved as an import.
+        // The path here must resolve to a function and it's a simple path (the parser reports
+        // a turbofish on an attribute as an error and drops it) so it can (and must) be solved
+        // as an import.
         let Ok(Some((module_def_id, _, _))) = resolve_import(
             path,
             self.module_id,
@@ -533,13 +533,15 @@ impl<'a> NodeFinder<'a> {
             Pattern::Identifier(ident) => {
                 self.local_variables.insert(ident.to_string(), ident.span());
             }
-            Pattern::Mutable(pattern, _, _) => self.collect_local_variables(pattern),
-            Pattern::Tuple(patterns, _) => {
+            Pattern::Mutable(pattern, _, _) | Pattern::Typed(pattern, _) => {
+                self.collect_local_variables(pattern);
+            }
+            Pattern::Tuple(patterns, _) | Pattern::TupleStruct(_, patterns, _) => {
                 for pattern in patterns {
                     self.collect_local_variables(pattern);
                 }
             }
-            Pattern::Struct(_, patterns, _) => {
+            Pattern::Struct(_, patterns, _, _) => {
                 for (_, pattern) in patterns {
                     self.collect_local_variables(pattern);
                 }
@@ -1098,8 +1100,13 @@ impl<'a> NodeFinder<'a> {
                     }
                 }
             }
-            Pattern::Mutable(pattern, ..) => self.try_set_self_type(pattern),
-            Pattern::Tuple(..) | Pattern::Struct(..) | Pattern::Interned(..) => (),
+            Pattern::Mutable(pattern, ..) | Pattern::Typed(pattern, _) => {
+                self.try_set_self_type(pattern);
+            }
+            Pattern::Tuple(..)
+            | Pattern::Struct(..)
+            | Pattern::TupleStruct(..)
+            | Pattern::Interned(..) => (),
         }
     }
 
@@ -1125,7 +1132,7 @@ impl<'a> NodeFinder<'a> {
                 get_array_element_type(typ)
             }
             LValue::Dereference(lvalue, ..) => self.get_lvalue_type(lvalue),
-            LValue::Interned(..) => None,
+            LValue::Interned(..) | LValue::Tuple(..) => None,
         }
     }
 
@@ -1989,6 +1996,7 @@ fn module_def_id_from_reference_id(reference_id: ReferenceId) -> Option<ModuleDe
         | ReferenceId::EnumVariant(_, _)
         | ReferenceId::Global(_)
         | ReferenceId::Local(_)
+        | ReferenceId::NumericGeneric(_)
         | ReferenceId::Reference(_, _) => None,
     }
 }
@@ -287,4 +287,32 @@ mod goto_definition_tests {
         )
         .await;
     }
+
+    #[test]
+    async fn goto_numeric_generic_from_array_length() {
+        expect_goto(
+            "go_to_definition",
+            Position { line: 38, character: 34 }, // "N" in "[Field; N]"
+            "src/main.nr",
+            Range {
+                start: Position { line: 38, character: 11 },
+                end: Position { line: 38, character: 17 },
+            },
+        )
+        .await;
+    }
+
+    #[test]
+    async fn goto_numeric_generic_from_expression() {
+        expect_goto(
+            "go_to_definition",
+            Position { line: 40, character: 16 }, // "N" in "0..N"
+            "src/main.nr",
+            Range {
+                start: Position { line: 38, character: 11 },
+                end: Position { line: 38, character: 17 },
+            },
+        )
+        .await;
+    }
 }
@@ -72,6 +72,7 @@ fn format_reference(reference: ReferenceId, args: &ProcessRequestCallbackArgs) -
         ReferenceId::Function(id) => Some(format_function(id, args)),
         ReferenceId::Alias(id) => Some(format_alias(id, args)),
         ReferenceId::Local(id) => Some(format_local(id, args)),
+        ReferenceId::NumericGeneric(_) => None,
         ReferenceId::Reference(location, _) => {
             format_reference(args.interner.find_referenced(location).unwrap(), args)
         }
@@ -112,6 +112,7 @@ impl<'a> InlayHintCollector<'a> {
                     | ReferenceId::Trait(_)
                     | ReferenceId::Function(_)
                     | ReferenceId::Alias(_)
+                    | ReferenceId::NumericGeneric(_)
                     | ReferenceId::Reference(..) => (),
                 }
             }
@@ -594,6 +595,7 @@ fn get_expression_name(expression: &Expression) -> Option<String> {
         | ExpressionKind::Literal(..)
         | ExpressionKind::Unsafe(..)
         | ExpressionKind::Match(_)
+        | ExpressionKind::Range(_)
         | ExpressionKind::Error => None,
     }
 }
@@ -683,6 +683,7 @@ fn get_reference_name(reference: ReferenceId, interner: &NodeInterner) -> Option
         ReferenceId::Local(definition_id) => {
             Some(interner.definition_name(definition_id).to_string())
         }
+        ReferenceId::NumericGeneric(_) => None,
         ReferenceId::Reference(location, _) => {
             get_reference_name(interner.find_referenced(location)?, interner)
         }
@@ -4,7 +4,7 @@ use crate::insert_all_files_for_workspace_into_file_manager;
 use async_lsp::{ErrorCode, ResponseError};
 use nargo::{
     foreign_calls::DefaultForeignCallBuilder,
-    ops::{TestStatus, run_test},
+    ops::{TestRunOptions, TestStatus, run_test},
 };
 use nargo_toml::{PackageSelection, find_package_manifest, resolve_workspace_from_toml};
 use noirc_driver::{CompileOptions, NOIR_ARTIFACT_VERSION_STRING, check_crate};
@@ -87,7 +87,7 @@ fn on_test_run_request_inner(
                 &mut context,
                 &test_function,
                 std::io::stdout(),
-                &CompileOptions::default(),
+                TestRunOptions::new(&CompileOptions::default()),
                 |output, base| {
                     DefaultForeignCallBuilder {
                         output,
@@ -100,7 +100,7 @@ fn on_test_run_request_inner(
                 },
             );
             let result = match test_result {
-                TestStatus::Pass => NargoTestRunResult {
+                TestStatus::Pass { .. } => NargoTestRunResult {
                     id: params.id.clone(),
                     result: "pass".to_string(),
                     message: None,
@@ -11,8 +11,8 @@ use noirc_frontend::{
         LValue, Lambda, LetStatement, Literal, MatchExpression, MemberAccessExpression,
         MethodCallExpression, ModuleDeclaration, NoirEnumeration, NoirFunction, NoirStruct,
         NoirTrait, NoirTraitImpl, NoirTypeAlias, Param, Path, PathSegment, Pattern,
-        PrefixExpression, Statement, StatementKind, StructField, TraitBound, TraitImplItem,
-        TraitImplItemKind, TraitItem, TypeImpl, TypePath, UnresolvedGeneric,
+        PrefixExpression, RangeExpression, Statement, StatementKind, StructField, TraitBound,
+        TraitImplItem, TraitImplItemKind, TraitItem, TypeImpl, TypePath, UnresolvedGeneric,
         UnresolvedTraitConstraint, UnresolvedType, UnresolvedTypeData, UnresolvedTypeExpression,
         UnsafeExpression, UseTree, UseTreeKind, WhileStatement,
     },
@@ -118,12 +118,22 @@ fn pattern_with_file(pattern: Pattern, file: FileId) -> Pattern {
         Pattern::Tuple(patterns, location) => {
             Pattern::Tuple(patterns_with_file(patterns, file), location_with_file(location, file))
         }
-        Pattern::Struct(path, items, location) => Pattern::Struct(
+        Pattern::Struct(path, items, location, has_rest) => Pattern::Struct(
             path_with_file(path, file),
             vecmap(items, |(ident, pattern)| {
                 (ident_with_file(ident, file), pattern_with_file(pattern, file))
             }),
             location_with_file(location, file),
+            has_rest,
+        ),
+        Pattern::TupleStruct(path, patterns, location) => Pattern::TupleStruct(
+            path_with_file(path, file),
+            patterns_with_file(patterns, file),
+            location_with_file(location, file),
+        ),
+        Pattern::Typed(pattern, typ) => Pattern::Typed(
+            Box::new(pattern_with_file(*pattern, file)),
+            unresolved_type_with_file(typ, file),
         ),
         Pattern::Interned(interned_pattern, location) => {
             Pattern::Interned(interned_pattern, location_with_file(location, file))
@@ -134,6 +144,7 @@ fn pattern_with_file(pattern: Pattern, file: FileId) -> Pattern {
 fn noir_type_alias_with_file(noir_type_alias: NoirTypeAlias, file: FileId) -> NoirTypeAlias {
     NoirTypeAlias {
         name: ident_with_file(noir_type_alias.name, file),
+        attributes: secondary_attributes_with_file(noir_type_alias.attributes, file),
         generics: unresolved_generics_with_file(noir_type_alias.generics, file),
         typ: unresolved_type_with_file(noir_type_alias.typ, file),
         visibility: noir_type_alias.visibility,
@@ -304,6 +315,7 @@ fn noir_struct_with_file(noir_struct: NoirStruct, file: FileId) -> NoirStruct {
         visibility: noir_struct.visibility,
         generics: unresolved_generics_with_file(noir_struct.generics, file),
         fields: documented_struct_fields_with_file(noir_struct.fields, file),
+        is_tuple_struct: noir_struct.is_tuple_struct,
         location: location_with_file(noir_struct.location, file),
     }
 }
@@ -589,7 +601,9 @@ fn secondary_attribute_with_file(
         | SecondaryAttribute::Abi(_)
         | SecondaryAttribute::Varargs
         | SecondaryAttribute::UseCallersScope
-        | SecondaryAttribute::Allow(_) => secondary_attribute,
+        | SecondaryAttribute::Allow(_)
+        | SecondaryAttribute::NonExhaustive
+        | SecondaryAttribute::Cfg(_) => secondary_attribute,
     }
 }
 
@@ -653,6 +667,9 @@ fn expression_kind_with_file(kind: ExpressionKind, file: FileId) -> ExpressionKi
         ExpressionKind::Match(match_expression) => {
             ExpressionKind::Match(Box::new(match_expression_with_file(*match_expression, file)))
         }
+        ExpressionKind::Range(range) => {
+            ExpressionKind::Range(Box::new(range_expression_with_file(*range, file)))
+        }
         ExpressionKind::Variable(path) => ExpressionKind::Variable(path_with_file(path, file)),
         ExpressionKind::Tuple(expressions) => {
             ExpressionKind::Tuple(expressions_with_file(expressions, file))
@@ -744,6 +761,14 @@ fn match_expression_with_file(expr: MatchExpression, file: FileId) -> MatchExpre
     }
 }
 
+fn range_expression_with_file(expr: RangeExpression, file: FileId) -> RangeExpression {
+    RangeExpression {
+        start: expression_with_file(expr.start, file),
+        end: expression_with_file(expr.end, file),
+        inclusive: expr.inclusive,
+    }
+}
+
 fn if_expression_with_file(expr: IfExpression, file: FileId) -> IfExpression {
     IfExpression {
         condition: expression_with_file(expr.condition, file),
@@ -963,6 +988,10 @@ fn lvalue_with_file(lvalue: LValue, file: FileId) -> LValue {
         LValue::Interned(interned_expression_kind, location) => {
             LValue::Interned(interned_expression_kind, location_with_file(location, file))
         }
+        LValue::Tuple(lvalues, location) => LValue::Tuple(
+            vecmap(lvalues, |lvalue| lvalue_with_file(lvalue, file)),
+            location_with_file(location, file),
+        ),
     }
 }
 
@@ -0,0 +1,141 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use noirc_abi::{Abi, AbiType, Sign};
+
+/// Generates a TypeScript type declaration for the inputs and return value of a program
+/// from its ABI, such as one produced by `compile_program`.
+///
+/// Fields and integers are represented as strings, as they are by `noir_js`, through aliases
+/// such as `Field` and `u32` so that the declaration keeps the Noir type names. Fixed-size
+/// arrays become tuples and structs become interfaces named after the struct. Structs from
+/// different modules which share a name are named after their full path instead, such as
+/// `foo_Point` for `foo::Point`. The inputs are declared as `Inputs` and the return value, if any, as `ReturnType`.
+pub fn abi_typescript_declaration(abi: &Abi) -> String {
+    let mut generator = TypeScriptGenerator::new(abi);
+
+    let mut inputs = String::from("export interface Inputs {\n");
+    for parameter in &abi.parameters {
+        let typ = generator.type_name(&parameter.typ);
+        writeln!(inputs, "  {}: {typ};", parameter.name).unwrap();
+    }
+    inputs.push_str("}\n");
+
+    let return_type = abi.return_type.as_ref().map(|return_type| {
+        format!("export type ReturnType = {};\n", generator.type_name(&return_type.abi_type))
+    });
+
+    let mut declaration = String::new();
+    for alias in &generator.aliases {
+        writeln!(declaration, "export type {alias} = string;").unwrap();
+    }
+    for interface in &generator.interfaces {
+        declaration.push('\n');
+        declaration.push_str(interface);
+    }
+    declaration.push('\n');
+    declaration.push_str(&inputs);
+    if let Some(return_type) = return_type {
+        declaration.push('\n');
+        declaration.push_str(&return_type);
+    }
+    declaration
+}
+
+#[derive(Default)]
+struct TypeScriptGenerator {
+    /// Interface names of the structs, keyed by path
+    struct_names: BTreeMap<String, String>,
+    /// Names of the Noir types declared as aliases of `string`
+    aliases: BTreeSet<String>,
+    /// Paths of the structs which already have an interface
+    struct_paths: BTreeSet<String>,
+    /// Interface declarations, in the order the structs were first found
+    interfaces: Vec<String>,
+}
+
+impl TypeScriptGenerator {
+    fn new(abi: &Abi) -> Self {
+        let mut paths = BTreeSet::new();
+        for parameter in &abi.parameters {
+            collect_struct_paths(&parameter.typ, &mut paths);
+        }
+        if let Some(return_type) = &abi.return_type {
+            collect_struct_paths(&return_type.abi_type, &mut paths);
+        }
+
+        let short_name = |path: &str| path.rsplit("::").next().unwrap_or(path).to_string();
+        let mut short_name_counts: BTreeMap<String, usize> = BTreeMap::new();
+        for path in &paths {
+            *short_name_counts.entry(short_name(path)).or_default() += 1;
+        }
+
+        let struct_names = paths
+            .into_iter()
+            .map(|path| {
+                let name = short_name(&path);
+                let name =
+                    if short_name_counts[&name] > 1 { path.replace("::", "_") } else { name };
+                (path, name)
+            })
+            .collect();
+        Self { struct_names, ..Self::default() }
+    }
+
+    /// Returns the TypeScript type for `typ`, declaring any aliases and interfaces it needs.
+    fn type_name(&mut self, typ: &AbiType) -> String {
+        match typ {
+            AbiType::Field => self.alias("Field"),
+            AbiType::Integer { sign: Sign::Unsigned, width } => self.alias(&format!("u{width}")),
+            AbiType::Integer { sign: Sign::Signed, width } => self.alias(&format!("i{width}")),
+            AbiType::Boolean => "boolean".to_string(),
+            AbiType::String { .. } => "string".to_string(),
+            AbiType::Array { length, typ } => {
+                let element = self.type_name(typ);
+                let elements = vec![element; *length as usize];
+                format!("[{}]", elements.join(", "))
+            }
+            AbiType::Tuple { fields } => {
+                let fields: Vec<_> = fields.iter().map(|field| self.type_name(field)).collect();
+                format!("[{}]", fields.join(", "))
+            }
+            AbiType::Struct { path, fields } => {
+                let name = self.struct_names[path].clone();
+                if self.struct_paths.insert(path.clone()) {
+                    let mut interface = format!("export interface {name} {{\n");
+                    for (field_name, field_type) in fields {
+                        let field_type = self.type_name(field_type);
+                        writeln!(interface, "  {field_name}: {field_type};").unwrap();
+                    }
+                    interface.push_str("}\n");
+                    self.interfaces.push(interface);
+                }
+                name
+            }
+        }
+    }
+
+    fn alias(&mut self, name: &str) -> String {
+        self.aliases.insert(name.to_string());
+        name.to_string()
+    }
+}
+
+/// Collects the paths of the structs used anywhere in `typ`.
+fn collect_struct_paths(typ: &AbiType, paths: &mut BTreeSet<String>) {
+    match typ {
+        AbiType::Array { typ, .. } => collect_struct_paths(typ, paths),
+        AbiType::Tuple { fields } => {
+            for field in fields {
+                collect_struct_paths(field, paths);
+            }
+        }
+        AbiType::Struct { path, fields } => {
+            paths.insert(path.clone());
+            for (_, field) in fields {
+                collect_struct_paths(field, paths);
+            }
+        }
+        AbiType::Field | AbiType::Integer { .. } | AbiType::Boolean | AbiType::String { .. } => {}
+    }
+}
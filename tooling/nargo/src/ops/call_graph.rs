@@ -0,0 +1,108 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt::Write;
+
+use fxhash::FxHashSet as HashSet;
+use noirc_frontend::{hir::Context, node_interner::FuncId};
+use serde::Serialize;
+
+/// The runtime a function is declared to run in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CallGraphRuntime {
+    Acir,
+    Brillig,
+}
+
+/// A function in a [CallGraph] together with the functions it calls.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CallGraphFunction {
+    pub runtime: CallGraphRuntime,
+    /// Fully qualified names of the functions called from this one
+    pub callees: BTreeSet<String>,
+}
+
+/// The static call graph of the functions reachable from an entry point,
+/// keyed by fully qualified function name.
+///
+/// Calls from an ACIR function to a Brillig function can be found by comparing
+/// the runtimes of the caller and the callee.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct CallGraph {
+    pub functions: BTreeMap<String, CallGraphFunction>,
+}
+
+impl CallGraph {
+    /// Renders the call graph in the DOT format. Brillig functions are drawn as boxes.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        for (name, function) in &self.functions {
+            let shape = match function.runtime {
+                CallGraphRuntime::Acir => "ellipse",
+                CallGraphRuntime::Brillig => "box",
+            };
+            writeln!(dot, "    \"{name}\" [shape={shape}];").unwrap();
+        }
+        for (name, function) in &self.functions {
+            for callee in &function.callees {
+                writeln!(dot, "    \"{name}\" -> \"{callee}\";").unwrap();
+            }
+        }
+        dot.push('}');
+        dot
+    }
+}
+
+/// Builds the static call graph of every function reachable from `entry_point`.
+///
+/// The crate containing `entry_point` must have been checked already, as the edges are
+/// the function dependencies recorded by the elaborator.
+pub fn call_graph(context: &Context, entry_point: FuncId) -> CallGraph {
+    let mut graph = CallGraph::default();
+    let mut visited = HashSet::default();
+    let mut queue = VecDeque::from([entry_point]);
+
+    while let Some(function_id) = queue.pop_front() {
+        if !visited.insert(function_id) {
+            continue;
+        }
+
+        let runtime = if context.def_interner.function_modifiers(&function_id).is_unconstrained {
+            CallGraphRuntime::Brillig
+        } else {
+            CallGraphRuntime::Acir
+        };
+
+        let callees = context.def_interner.function_dependencies(function_id);
+        let callee_names = callees.iter().map(|callee| function_name(context, *callee)).collect();
+        queue.extend(callees);
+
+        let function = CallGraphFunction { runtime, callees: callee_names };
+        graph.functions.insert(function_name(context, function_id), function);
+    }
+
+    graph
+}
+
+/// Returns the fully qualified name of `function`. Methods are further qualified by the
+/// type or trait they belong to so that methods with the same name don't collide.
+pub(super) fn function_name(context: &Context, function: FuncId) -> String {
+    let crate_id = context.def_interner.function_module(function).krate;
+    let name = context.fully_qualified_function_name(&crate_id, &function);
+
+    let meta = context.def_interner.function_meta(&function);
+    let owner = match (meta.trait_impl, meta.trait_id, &meta.self_type) {
+        (Some(impl_id), _, _) => {
+            let trait_impl = context.def_interner.get_trait_implementation(impl_id);
+            let trait_impl = trait_impl.borrow();
+            let trait_name = &context.def_interner.get_trait(trait_impl.trait_id).name;
+            format!("<{} as {trait_name}>", trait_impl.typ)
+        }
+        (None, Some(trait_id), _) => context.def_interner.get_trait(trait_id).name.to_string(),
+        (None, None, Some(self_type)) => self_type.to_string(),
+        (None, None, None) => return name,
+    };
+
+    let method_name = context.def_interner.function_name(&function);
+    let module_path = name.strip_suffix(method_name).unwrap_or_default();
+    format!("{module_path}{owner}::{method_name}")
+}
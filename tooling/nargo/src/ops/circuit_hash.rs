@@ -0,0 +1,32 @@
+use acvm::{FieldElement, acir::circuit::Program};
+use sha2::{Digest, Sha256};
+
+/// Compute a hash of the constraint systems of a compiled program, suitable as a cache key
+/// for data which is derived from the circuits alone, such as verification keys.
+///
+/// Only the parts of each circuit which determine its gate structure are hashed: the opcodes,
+/// the witness count, the expression width and the public and private inputs. Assertion
+/// payloads and unconstrained bytecode are left out, so changing an assertion message does
+/// not invalidate cached data. Witness indices are assigned deterministically during
+/// compilation, so compiling the same source twice produces the same hash.
+///
+/// The hash is a SHA-256 digest of the `bincode` serialization of those parts, which unlike
+/// `std::hash::Hash` does not depend on the Rust version or on how the types are laid out.
+pub fn circuit_hash(program: &Program<FieldElement>) -> [u8; 32] {
+    let circuits = program
+        .functions
+        .iter()
+        .map(|circuit| {
+            (
+                circuit.current_witness_index,
+                &circuit.opcodes,
+                &circuit.expression_width,
+                &circuit.private_parameters,
+                &circuit.public_parameters,
+                &circuit.return_values,
+            )
+        })
+        .collect::<Vec<_>>();
+    let bytes = bincode::serialize(&circuits).expect("circuits should serialize to bincode");
+    Sha256::digest(bytes).into()
+}
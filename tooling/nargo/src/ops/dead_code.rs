@@ -0,0 +1,98 @@
+use std::collections::VecDeque;
+
+use fxhash::FxHashSet as HashSet;
+use noirc_errors::CustomDiagnostic;
+use noirc_frontend::{ast::ItemVisibility, graph::CrateId, hir::Context, node_interner::FuncId};
+
+use super::call_graph::function_name;
+
+/// Reports the functions of a crate which can't be reached from any of its entry points
+/// as dead code warnings.
+///
+/// Entry points are `main`, the functions of contracts, tests, fuzzing harnesses and
+/// `#[export]` functions. `comptime` functions and functions referenced from items other
+/// than functions, such as globals, are considered used as well. In a library crate the
+/// `pub` functions make up its API, so they are entry points too.
+///
+/// Free functions and the methods of `impl` blocks are reported. Trait impl methods are
+/// always considered used, as they may be called through a generic trait bound, in which
+/// case the impl that ends up being called is only known after monomorphization.
+///
+/// The crate must have been checked already, as the edges followed are the function
+/// dependencies recorded by the elaborator.
+pub fn dead_code_warnings(
+    context: &Context,
+    crate_id: &CrateId,
+    is_library: bool,
+) -> Vec<CustomDiagnostic> {
+    let interner = &context.def_interner;
+    let def_map = context.def_map(crate_id).expect("The local crate should be analyzed already");
+
+    let mut functions = Vec::new();
+    let mut entry_points = Vec::new();
+    for (_, module) in def_map.modules().iter() {
+        let module_functions: Vec<FuncId> =
+            module.value_definitions().filter_map(|id| id.as_function()).collect();
+        if module.is_contract {
+            entry_points.extend(module_functions.iter().copied());
+        }
+        functions.extend(module_functions);
+    }
+
+    for function in interner.function_ids() {
+        if interner.function_module(function).krate != *crate_id {
+            continue;
+        }
+
+        let meta = interner.function_meta(&function);
+        if meta.trait_impl.is_some() {
+            entry_points.push(function);
+        } else if meta.self_type.is_some() && meta.trait_id.is_none() {
+            functions.push(function);
+        }
+    }
+
+    entry_points.extend(def_map.main_function());
+    entry_points.extend(def_map.get_all_test_functions(interner).map(|test| test.get_id()));
+    entry_points
+        .extend(def_map.get_all_fuzzing_harnesses(interner).map(|harness| harness.get_id()));
+    entry_points.extend(def_map.get_all_exported_functions(interner));
+    entry_points.extend(functions.iter().copied().filter(|function| {
+        interner.function_modifiers(function).is_comptime
+            || (is_library && interner.function_visibility(*function) == ItemVisibility::Public)
+            || interner.is_function_referenced_outside_functions(*function)
+    }));
+
+    let reachable = reachable_functions(context, entry_points);
+
+    let mut dead_functions: Vec<FuncId> =
+        functions.into_iter().filter(|function| !reachable.contains(function)).collect();
+    dead_functions.sort_by_key(|function| function_name(context, *function));
+
+    dead_functions
+        .into_iter()
+        .map(|function| {
+            let name = function_name(context, function);
+            let location = interner.function_meta(&function).name.location;
+            CustomDiagnostic::simple_warning(
+                format!("function `{name}` is never reachable from an entry point"),
+                String::new(),
+                location,
+            )
+        })
+        .collect()
+}
+
+/// Returns every function which can be called from one of the `entry_points`, including them.
+fn reachable_functions(context: &Context, entry_points: Vec<FuncId>) -> HashSet<FuncId> {
+    let mut reachable = HashSet::default();
+    let mut queue = VecDeque::from(entry_points);
+
+    while let Some(function) = queue.pop_front() {
+        if reachable.insert(function) {
+            queue.extend(context.def_interner.function_dependencies(function));
+        }
+    }
+
+    reachable
+}
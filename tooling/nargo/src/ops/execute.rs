@@ -1,6 +1,9 @@
-use acvm::acir::circuit::brillig::BrilligBytecode;
-use acvm::acir::circuit::{OpcodeLocation, Program};
-use acvm::acir::native_types::WitnessStack;
+use std::collections::{BTreeMap, BTreeSet};
+
+use acvm::acir::circuit::brillig::{BrilligBytecode, BrilligOutputs};
+use acvm::acir::circuit::opcodes::BlockId;
+use acvm::acir::circuit::{ErrorSelector, Opcode, OpcodeLocation, Program};
+use acvm::acir::native_types::{Witness, WitnessStack};
 use acvm::brillig_vm::BranchToFeatureMap;
 use acvm::pwg::{
     ACVM, ACVMStatus, ErrorLocation, OpcodeNotSolvable, OpcodeResolutionError, ProfilingSamples,
@@ -10,11 +13,14 @@ use acvm::{AcirField, BlackBoxFunctionSolver};
 type NargoErrorAndCoverage<F> = (NargoError<F>, Option<Vec<u32>>);
 type WitnessAndCoverage<F> = (WitnessStack<F>, Option<Vec<u32>>);
 use acvm::{acir::circuit::Circuit, acir::native_types::WitnessMap};
+use noirc_abi::AbiErrorType;
 
 use crate::NargoError;
 use crate::errors::{ExecutionError, ResolvedOpcodeLocation};
 use crate::foreign_calls::ForeignCallExecutor;
 
+use super::execution_trace::{ExecutionStep, ExecutionTrace};
+
 struct ProgramExecutor<'a, F: AcirField, B: BlackBoxFunctionSolver<F>, E: ForeignCallExecutor<F>> {
     functions: &'a [Circuit<F>],
 
@@ -48,6 +54,28 @@ struct ProgramExecutor<'a, F: AcirField, B: BlackBoxFunctionSolver<F>, E: Foreig
 
     // Last recorded fuzzing trace
     last_fuzzing_trace: Option<Vec<u32>>,
+
+    // Flag that states whether we want to count solving steps
+    step_counting_active: bool,
+
+    // Number of solving steps taken across all circuits executed so far
+    step_count: usize,
+
+    // Trace of the opcodes executed so far, if we want to record one
+    execution_trace: Option<ExecutionTrace<F>>,
+
+    // Flag that states whether opcodes of `main` waiting on unknown witnesses should be skipped
+    // rather than failing execution
+    partial_solving_active: bool,
+
+    // Opcodes of `main` skipped while solving partially, with the reason they couldn't be solved
+    unsolved_opcodes: Vec<(usize, OpcodeNotSolvable<F>)>,
+
+    // Memory blocks of `main` whose contents are unknown after skipping one of their opcodes
+    unknown_memory_blocks: BTreeSet<BlockId>,
+
+    // The maximum number of nested calls within each Brillig function
+    max_brillig_call_depth: Option<usize>,
 }
 
 impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>, E: ForeignCallExecutor<F>>
@@ -72,9 +100,23 @@ impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>, E: ForeignCallExecutor<F>>
             brillig_fuzzing_active: false,
             brillig_branch_to_feature_map: None,
             last_fuzzing_trace: None,
+            step_counting_active: false,
+            step_count: 0,
+            execution_trace: None,
+            partial_solving_active: false,
+            unsolved_opcodes: Vec::new(),
+            unknown_memory_blocks: BTreeSet::new(),
+            max_brillig_call_depth: None,
         }
     }
 
+    fn with_options(&mut self, options: ExecutionOptions) {
+        self.partial_solving_active = options.partial;
+        self.step_counting_active = options.count_steps;
+        self.execution_trace = options.record_trace.then(ExecutionTrace::default);
+        self.max_brillig_call_depth = options.max_brillig_call_depth;
+    }
+
     fn with_brillig_fuzzing(
         &mut self,
         brillig_branch_to_feature_map: Option<&'a BranchToFeatureMap>,
@@ -100,11 +142,19 @@ impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>, E: ForeignCallExecutor<F>>
             self.unconstrained_functions,
             &circuit.assert_messages,
         );
-        acvm.with_profiler(self.profiling_active);
+        // The Brillig opcodes executed are recorded in the trace from the profiling samples
+        acvm.with_profiler(self.profiling_active || self.execution_trace.is_some());
         acvm.with_brillig_fuzzing(self.brillig_branch_to_feature_map);
+        acvm.with_step_counter(self.step_counting_active);
+        acvm.with_max_brillig_call_depth(self.max_brillig_call_depth);
 
+        let skip_unsolvable = self.partial_solving_active && self.current_function_index == 0;
         loop {
-            let solver_status = acvm.solve();
+            let solver_status = if self.execution_trace.is_some() || skip_unsolvable {
+                self.solve_step_by_step(&mut acvm, skip_unsolvable)
+            } else {
+                acvm.solve()
+            };
 
             match solver_status {
                 ACVMStatus::Solved => break,
@@ -217,12 +267,157 @@ impl<'a, F: AcirField, B: BlackBoxFunctionSolver<F>, E: ForeignCallExecutor<F>>
         // included in a failure case.
         self.call_stack.clear();
 
+        self.step_count += acvm.step_count();
         let profiling_samples = acvm.take_profiling_samples();
         self.last_fuzzing_trace = acvm.get_brillig_fuzzing_trace();
         Ok((acvm.finalize(), profiling_samples))
     }
+
+    /// Solves the circuit one opcode at a time, recording each solved opcode in the execution
+    /// trace if one is being recorded.
+    ///
+    /// With `skip_unsolvable`, the opcodes which are waiting on unknown witnesses are skipped and
+    /// recorded as unsolved. Once a memory opcode is skipped the contents of its block are
+    /// unknown, so the later opcodes acting on the same block are skipped as well.
+    ///
+    /// Otherwise execution halts for the same reasons as [`ACVM::solve`]. An opcode waiting on a
+    /// foreign call or an ACIR call is recorded once it is solved after execution resumes.
+    fn solve_step_by_step(
+        &mut self,
+        acvm: &mut ACVM<'_, F, B>,
+        skip_unsolvable: bool,
+    ) -> ACVMStatus<F> {
+        while *acvm.get_status() == ACVMStatus::InProgress {
+            let opcode_index = acvm.instruction_pointer();
+            let block_id = match &acvm.opcodes()[opcode_index] {
+                Opcode::MemoryInit { block_id, .. } | Opcode::MemoryOp { block_id, .. } => {
+                    Some(*block_id)
+                }
+                _ => None,
+            };
+
+            if skip_unsolvable {
+                if let Some(block_id) =
+                    block_id.filter(|block_id| self.unknown_memory_blocks.contains(block_id))
+                {
+                    let reason = OpcodeNotSolvable::MissingMemoryBlock(block_id.0);
+                    self.unsolved_opcodes.push((opcode_index, reason));
+                    acvm.skip_opcode();
+                    continue;
+                }
+            }
+
+            let unknown_outputs: BTreeSet<Witness> = if self.execution_trace.is_some() {
+                opcode_output_witnesses(&acvm.opcodes()[opcode_index])
+                    .into_iter()
+                    .filter(|witness| !acvm.witness_map().contains_key(witness))
+                    .collect()
+            } else {
+                BTreeSet::new()
+            };
+
+            let status = acvm.solve_opcode();
+            if skip_unsolvable {
+                if let ACVMStatus::Failure(OpcodeResolutionError::OpcodeNotSolvable(reason)) =
+                    status
+                {
+                    self.unknown_memory_blocks.extend(block_id);
+                    self.unsolved_opcodes.push((opcode_index, reason));
+                    acvm.skip_opcode();
+                    continue;
+                }
+            }
+
+            // The instruction pointer only moves on once the opcode has been solved
+            if acvm.instruction_pointer() == opcode_index {
+                continue;
+            }
+            let acir_function_index = self.current_function_index;
+            let Some(trace) = self.execution_trace.as_mut() else {
+                continue;
+            };
+
+            for sample in acvm.take_profiling_samples() {
+                trace.steps.push(ExecutionStep::Brillig {
+                    acir_function_index,
+                    brillig_function_id: sample
+                        .brillig_function_id
+                        .expect("Brillig samples should have a function id"),
+                    call_stack: sample.call_stack,
+                });
+            }
+
+            let solved_witnesses = unknown_outputs
+                .into_iter()
+                .filter_map(|witness| {
+                    acvm.witness_map().get(&witness).map(|value| (witness, *value))
+                })
+                .collect();
+            trace.steps.push(ExecutionStep::Acir {
+                acir_function_index,
+                opcode_location: OpcodeLocation::Acir(opcode_index),
+                solved_witnesses,
+            });
+        }
+        acvm.get_status().clone()
+    }
+}
+
+/// Optional behaviors of [execute_program_with_options], all disabled by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExecutionOptions {
+    /// Count the number of solving steps the execution takes.
+    ///
+    /// A solving step is either an ACIR opcode being solved or a Brillig opcode being executed,
+    /// summed over every circuit and unconstrained function called during execution.
+    pub count_steps: bool,
+    /// Record a trace of every opcode executed, for replaying the execution in a debugger.
+    ///
+    /// Each solved ACIR opcode is recorded with the witnesses it solved, and each executed Brillig
+    /// opcode with its call stack. As this records every step of the execution the trace can get
+    /// very large, so it should only be requested when needed.
+    pub record_trace: bool,
+    /// Solve as much of the program as possible from a partial witness map.
+    ///
+    /// Rather than failing when an opcode of `main` is waiting on an unknown witness, the opcode
+    /// is skipped and reported in the result. As an opcode can solve a witness needed by an
+    /// earlier one, the program is executed again with the witnesses solved so far until a pass
+    /// doesn't solve any more opcodes. Foreign calls are made again on each pass, and the step
+    /// count and trace are those of the last pass.
+    ///
+    /// Only the opcodes of `main` are skipped: ACIR functions called with known inputs must be
+    /// solvable, and any other failure is returned as an error.
+    pub partial: bool,
+    /// Fail if a Brillig function nests more than this many calls.
+    ///
+    /// Deeply recursive unconstrained code would otherwise keep executing until it exhausts the
+    /// resources of the host. With a limit, the execution error reports the Brillig call stack
+    /// at the point the limit was reached, which shows the recursive calls.
+    pub max_brillig_call_depth: Option<usize>,
+}
+
+/// The result of executing a program with [execute_program_with_options].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Execution<F> {
+    /// The witnesses which could be solved.
+    pub witness_stack: WitnessStack<F>,
+    /// The number of solving steps taken, if [ExecutionOptions::count_steps] is set.
+    pub step_count: Option<usize>,
+    /// The opcodes executed, if [ExecutionOptions::record_trace] is set.
+    pub trace: Option<ExecutionTrace<F>>,
+    /// The opcodes of `main` which could not be solved when [ExecutionOptions::partial] is set,
+    /// with the reason each of them is blocked.
+    pub unsolved_opcodes: Vec<(OpcodeLocation, OpcodeNotSolvable<F>)>,
+    /// The witnesses of `main` which remain unknown when [ExecutionOptions::partial] is set.
+    pub unresolved_witnesses: BTreeSet<Witness>,
 }
 
+impl<F> Execution<F> {
+    /// Returns true if every opcode of the program could be solved.
+    pub fn is_solved(&self) -> bool {
+        self.unsolved_opcodes.is_empty()
+    }
+}
 pub fn execute_program<F: AcirField, B: BlackBoxFunctionSolver<F>, E: ForeignCallExecutor<F>>(
     program: &Program<F>,
     initial_witness: WitnessMap<F>,
@@ -242,6 +437,95 @@ pub fn execute_program<F: AcirField, B: BlackBoxFunctionSolver<F>, E: ForeignCal
     Ok(witness_stack)
 }
 
+/// Executes a program with the optional behaviors enabled by `options`.
+pub fn execute_program_with_options<
+    F: AcirField,
+    B: BlackBoxFunctionSolver<F>,
+    E: ForeignCallExecutor<F>,
+>(
+    program: &Program<F>,
+    initial_witness: WitnessMap<F>,
+    blackbox_solver: &B,
+    foreign_call_executor: &mut E,
+    options: ExecutionOptions,
+) -> Result<Execution<F>, NargoError<F>> {
+    let mut witness = initial_witness;
+    let mut previous_unsolved_count = usize::MAX;
+    loop {
+        let mut executor = ProgramExecutor::new(
+            &program.functions,
+            &program.unconstrained_functions,
+            blackbox_solver,
+            foreign_call_executor,
+            false,
+        );
+        executor.with_options(options);
+        let (main_witness, _) = executor.execute_circuit(witness)?;
+        let unsolved_opcodes = std::mem::take(&mut executor.unsolved_opcodes);
+
+        // Executing again with the witnesses solved so far may solve more of the skipped opcodes
+        if !unsolved_opcodes.is_empty() && unsolved_opcodes.len() != previous_unsolved_count {
+            previous_unsolved_count = unsolved_opcodes.len();
+            witness = main_witness;
+            continue;
+        }
+
+        let unresolved_witnesses = if options.partial {
+            (0..=program.functions[0].current_witness_index)
+                .map(Witness)
+                .filter(|witness| !main_witness.contains_key(witness))
+                .collect()
+        } else {
+            BTreeSet::new()
+        };
+        executor.witness_stack.push(0, main_witness);
+
+        let unsolved_opcodes = unsolved_opcodes
+            .into_iter()
+            .map(|(index, reason)| (OpcodeLocation::Acir(index), reason))
+            .collect();
+        return Ok(Execution {
+            step_count: options.count_steps.then_some(executor.step_count),
+            trace: executor.execution_trace.take(),
+            witness_stack: executor.finalize(),
+            unsolved_opcodes,
+            unresolved_witnesses,
+        });
+    }
+}
+
+/// Checks the result of executing a program which is expected to fail, mirroring
+/// `#[test(should_fail_with = "...")]`.
+///
+/// Returns `Ok` if execution failed and, when `expected_failure` is set, the failure message
+/// contains it. Otherwise returns an `Err` describing why the expectation was not met.
+pub fn check_expected_failure<T, F: AcirField>(
+    result: Result<T, NargoError<F>>,
+    error_types: &BTreeMap<ErrorSelector, AbiErrorType>,
+    expected_failure: Option<&str>,
+) -> Result<(), String> {
+    let error = match result {
+        Ok(_) => {
+            return Err("Program executed successfully but was expected to fail".to_string());
+        }
+        Err(error) => error,
+    };
+
+    let Some(expected_failure) = expected_failure else {
+        return Ok(());
+    };
+
+    let message =
+        error.user_defined_failure_message(error_types).unwrap_or_else(|| error.to_string());
+    if message.contains(expected_failure) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Program failed with the wrong message. \nExpected: {expected_failure} \nGot: {message}"
+        ))
+    }
+}
+
 pub fn execute_program_with_profiling<
     F: AcirField,
     B: BlackBoxFunctionSolver<F>,
@@ -310,3 +594,29 @@ fn execute_program_inner<F: AcirField, B: BlackBoxFunctionSolver<F>, E: ForeignC
 
     Ok((executor.finalize(), profiling_samples))
 }
+
+/// Returns the witnesses which `opcode` may assign a value to when it is solved.
+fn opcode_output_witnesses<F: AcirField>(opcode: &Opcode<F>) -> Vec<Witness> {
+    match opcode {
+        // Any witness of the expression may be the one it is solved for
+        Opcode::AssertZero(expression) => expression
+            .mul_terms
+            .iter()
+            .flat_map(|(_, lhs, rhs)| [*lhs, *rhs])
+            .chain(expression.linear_combinations.iter().map(|(_, witness)| *witness))
+            .collect(),
+        Opcode::BlackBoxFuncCall(call) => call.get_outputs_vec(),
+        Opcode::MemoryOp { op, .. } => {
+            op.value.linear_combinations.iter().map(|(_, witness)| *witness).collect()
+        }
+        Opcode::MemoryInit { .. } => Vec::new(),
+        Opcode::BrilligCall { outputs, .. } => outputs
+            .iter()
+            .flat_map(|output| match output {
+                BrilligOutputs::Simple(witness) => vec![*witness],
+                BrilligOutputs::Array(witnesses) => witnesses.clone(),
+            })
+            .collect(),
+        Opcode::Call { outputs, .. } => outputs.clone(),
+    }
+}
@@ -0,0 +1,64 @@
+use acvm::acir::circuit::OpcodeLocation;
+use acvm::acir::circuit::brillig::BrilligFunctionId;
+use acvm::acir::native_types::Witness;
+use noirc_errors::{Location, debug_info::DebugInfo};
+use serde::{Deserialize, Serialize};
+
+/// An ordered record of every opcode executed by [`execute_program_with_options`][super::execute_program_with_options]
+/// when [`ExecutionOptions::record_trace`][super::ExecutionOptions::record_trace] is set, meant to
+/// be replayed by a debugger.
+///
+/// Opcodes are recorded by location rather than by source location, which can be recovered
+/// from the program's debug info with [`ExecutionStep::source_locations`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionTrace<F> {
+    pub steps: Vec<ExecutionStep<F>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ExecutionStep<F> {
+    /// An ACIR opcode was solved, assigning values to the given witnesses.
+    ///
+    /// For a `BrilligCall` these are the outputs of the call, and for a `Call` the outputs
+    /// of the called circuit, whose own steps are recorded before this one.
+    Acir {
+        acir_function_index: usize,
+        opcode_location: OpcodeLocation,
+        solved_witnesses: Vec<(Witness, F)>,
+    },
+    /// A Brillig opcode was executed as part of a `BrilligCall`.
+    ///
+    /// The call stack starts with the location of the `BrilligCall` opcode, followed by the
+    /// Brillig locations of each call leading to the executed opcode, which comes last.
+    Brillig {
+        acir_function_index: usize,
+        brillig_function_id: BrilligFunctionId,
+        call_stack: Vec<OpcodeLocation>,
+    },
+}
+
+impl<F> ExecutionStep<F> {
+    /// Returns the source locations of the executed opcode, using the debug info of each
+    /// ACIR function in the program.
+    pub fn source_locations(&self, debug: &[DebugInfo]) -> Vec<Location> {
+        match self {
+            ExecutionStep::Acir { acir_function_index, opcode_location, .. } => debug
+                .get(*acir_function_index)
+                .and_then(|debug_info| debug_info.opcode_location(opcode_location))
+                .unwrap_or_default(),
+            ExecutionStep::Brillig { acir_function_index, brillig_function_id, call_stack } => {
+                let brillig_location =
+                    call_stack.last().and_then(|location| location.to_brillig_location());
+                debug
+                    .get(*acir_function_index)
+                    .and_then(|debug_info| debug_info.brillig_locations.get(brillig_function_id))
+                    .zip(brillig_location)
+                    .and_then(|(locations, brillig_location)| {
+                        locations.get(&brillig_location).cloned()
+                    })
+                    .unwrap_or_default()
+            }
+        }
+    }
+}
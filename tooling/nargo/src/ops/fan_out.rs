@@ -0,0 +1,62 @@
+use std::collections::BTreeMap;
+
+use noirc_frontend::{
+    hir::Context,
+    monomorphization::{errors::MonomorphizationError, generic_instances},
+    node_interner::FuncId,
+};
+use serde::Serialize;
+
+/// The generic arguments a monomorphic instance of a generic function was created with,
+/// keyed by the name of the generic they are bound to.
+pub type GenericArguments = BTreeMap<String, String>;
+
+/// The monomorphic instances created for each generic function reachable from an entry point,
+/// keyed by fully qualified function name.
+///
+/// Each instance is compiled separately, so a generic function with many instances can make up
+/// a large part of the program's size.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct MonomorphizationFanOut {
+    pub functions: BTreeMap<String, Vec<GenericArguments>>,
+}
+
+impl MonomorphizationFanOut {
+    /// Returns the number of instances of each generic function, from the most instantiated
+    /// function to the least.
+    pub fn instance_counts(&self) -> Vec<(&str, usize)> {
+        let mut counts: Vec<_> = self
+            .functions
+            .iter()
+            .map(|(name, instances)| (name.as_str(), instances.len()))
+            .collect();
+        counts.sort_by(|(_, a), (_, b)| b.cmp(a));
+        counts
+    }
+}
+
+/// Monomorphizes the program reachable from `entry_point`, reporting the instances created for
+/// each generic function along with their generic arguments.
+///
+/// The crate containing `entry_point` must have been checked already.
+pub fn monomorphization_fan_out(
+    context: &mut Context,
+    entry_point: FuncId,
+    force_unconstrained: bool,
+) -> Result<MonomorphizationFanOut, MonomorphizationError> {
+    let instances = generic_instances(entry_point, &mut context.def_interner, force_unconstrained)?;
+
+    let mut fan_out = MonomorphizationFanOut::default();
+    for (function, instances) in instances {
+        let crate_id = context.def_interner.function_module(function).krate;
+        let name = context.fully_qualified_function_name(&crate_id, &function);
+        let instances = instances
+            .into_iter()
+            .map(|arguments| {
+                arguments.into_iter().map(|(generic, typ)| (generic, typ.to_string())).collect()
+            })
+            .collect();
+        fan_out.functions.insert(name, instances);
+    }
+    Ok(fan_out)
+}
@@ -29,6 +29,9 @@ pub struct FuzzExecutionConfig {
     pub num_threads: usize,
     /// Maximum time in seconds to spend fuzzing (default: no timeout)
     pub timeout: u64,
+    /// Execute every input both as ACIR and as Brillig, and fail if their return values differ
+    /// or if only one of them fails
+    pub differential: bool,
 }
 
 /// Folder configuration for fuzzing
@@ -221,6 +224,7 @@ where
                 FuzzedExecutorExecutionConfiguration {
                     num_threads: fuzz_execution_config.num_threads,
                     timeout: fuzz_execution_config.timeout,
+                    differential: fuzz_execution_config.differential,
                 },
                 FuzzedExecutorFailureConfiguration {
                     fail_on_specific_asserts: fuzzing_harness.only_fail_enabled(),
@@ -284,6 +288,9 @@ where
                                     &unwrapped_brillig_program.abi,
                                     &unwrapped_brillig_program.debug,
                                 ),
+                                // In differential mode both executions can succeed
+                                // while returning different values
+                                Ok(..) if fuzz_execution_config.differential => None,
                                 Ok(..) => panic!(
                                     "The program being executed or the system is flakey. Found a failing testcase that didn't fail on reexecution"
                                 ),
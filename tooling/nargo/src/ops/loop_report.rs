@@ -0,0 +1,57 @@
+use fm::{FileId, FileManager, codespan_files::Files};
+use noirc_driver::{CompilationResult, CompileOptions, LoopReport, check_crate};
+use noirc_errors::{CustomDiagnostic, Location};
+use noirc_frontend::hir::ParsedFiles;
+
+use crate::prepare_package;
+use crate::{package::Package, workspace::Workspace};
+
+/// Reports how each loop of a binary package was optimized: whether it was unrolled, how many
+/// instructions were hoisted out of it and why loop invariant instructions were left in it.
+#[tracing::instrument(level = "trace", skip_all, fields(package = package.name.to_string()))]
+pub fn loop_report(
+    file_manager: &FileManager,
+    parsed_files: &ParsedFiles,
+    workspace: &Workspace,
+    package: &Package,
+    compile_options: &CompileOptions,
+) -> CompilationResult<LoopReport> {
+    let (mut context, crate_id) = prepare_package(file_manager, parsed_files, package);
+    context.package_build_path = workspace.package_build_path(package);
+
+    let (_, warnings) = check_crate(&mut context, crate_id, compile_options)?;
+
+    let main = context.get_main_function(&crate_id).ok_or_else(|| {
+        let error = CustomDiagnostic::from_message(
+            "cannot report on the loops of a crate which does not contain a `main` function",
+            FileId::default(),
+        );
+        vec![error]
+    })?;
+
+    let report = noirc_driver::loop_report(&mut context, compile_options, main)
+        .map_err(|error| vec![CustomDiagnostic::from(error)])?;
+    Ok((report, warnings))
+}
+
+/// Renders a [LoopReport] as text, formatting source locations as `path:line:column`.
+///
+/// The locations are looked up in `files`, such as the file map of a [FileManager] or of a
+/// compiled artifact.
+pub fn render_loop_report<'a>(
+    report: &LoopReport,
+    files: &'a impl Files<'a, FileId = FileId>,
+) -> String {
+    report.render(|location| format_location(files, location))
+}
+
+fn format_location<'a>(files: &'a impl Files<'a, FileId = FileId>, location: &Location) -> String {
+    let name = files.name(location.file);
+    let position = files.location(location.file, location.span.start() as usize);
+    match (name, position) {
+        (Ok(name), Ok(position)) => {
+            format!("{name}:{}:{}", position.line_number, position.column_number)
+        }
+        _ => "unknown location".to_string(),
+    }
+}
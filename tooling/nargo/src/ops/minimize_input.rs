@@ -0,0 +1,243 @@
+use acvm::{AcirField, BlackBoxFunctionSolver, FieldElement};
+use noirc_abi::{AbiType, InputMap, MAIN_RETURN_NAME, errors::AbiError, input_parser::InputValue};
+use noirc_driver::CompiledProgram;
+use thiserror::Error;
+
+use crate::foreign_calls::ForeignCallExecutor;
+
+use super::execute_program;
+
+/// Errors preventing [minimize_failing_input] from shrinking an input.
+#[derive(Debug, Error)]
+pub enum MinimizeInputError {
+    #[error(transparent)]
+    Abi(#[from] AbiError),
+    #[error("The program does not fail on the given input")]
+    InputDoesNotFail,
+}
+
+/// Shrinks an input on which a program fails to execute into a smaller input on which it
+/// still fails, to help debugging the failure.
+///
+/// Each field, integer and boolean in the input, including those nested in arrays, tuples and
+/// structs, is shrunk towards zero on its own: it is set to the smallest value, between zero
+/// and its current value, for which the program keeps failing. As shrinking one value can let
+/// others shrink further, this is repeated until no value changes. The values only ever get
+/// smaller, so the input stays valid for the program's ABI. Strings are left unchanged.
+///
+/// Any execution failure counts, so the program may fail on the returned input for a different
+/// reason than on the original one. A return value given in the input is dropped.
+pub fn minimize_failing_input<B, E>(
+    program: &CompiledProgram,
+    mut input_map: InputMap,
+    blackbox_solver: &B,
+    foreign_call_executor: &mut E,
+) -> Result<InputMap, MinimizeInputError>
+where
+    B: BlackBoxFunctionSolver<FieldElement>,
+    E: ForeignCallExecutor<FieldElement>,
+{
+    input_map.remove(MAIN_RETURN_NAME);
+
+    program.abi.encode(&input_map, None)?;
+    let fails = |input_map: &InputMap, foreign_call_executor: &mut E| {
+        // Shrinking keeps values within their type's range, so encoding should not fail
+        let Ok(initial_witness) = program.abi.encode(input_map, None) else {
+            return false;
+        };
+        execute_program(&program.program, initial_witness, blackbox_solver, foreign_call_executor)
+            .is_err()
+    };
+    if !fails(&input_map, foreign_call_executor) {
+        return Err(MinimizeInputError::InputDoesNotFail);
+    }
+
+    let mut leaves = Vec::new();
+    for parameter in &program.abi.parameters {
+        if let Some(value) = input_map.get(&parameter.name) {
+            collect_leaves(value, &parameter.typ, &parameter.name, &mut Vec::new(), &mut leaves);
+        }
+    }
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (name, path) in &leaves {
+            let InputValue::Field(current) = *leaf_mut(&mut input_map, name, path) else {
+                continue;
+            };
+
+            let mut fails_with = |value: u128| {
+                *leaf_mut(&mut input_map, name, path) = InputValue::Field(value.into());
+                fails(&input_map, foreign_call_executor)
+            };
+            let shrunk = shrink_value(current, &mut fails_with);
+
+            *leaf_mut(&mut input_map, name, path) = InputValue::Field(shrunk);
+            changed |= shrunk != current;
+        }
+    }
+
+    Ok(input_map)
+}
+
+/// An index into an array or tuple, or the name of a struct field.
+#[derive(Clone)]
+enum PathSegment {
+    Index(usize),
+    Field(String),
+}
+
+/// Pushes the path of each field, integer and boolean in `value` onto `leaves`, along with the
+/// name of the parameter it belongs to.
+fn collect_leaves(
+    value: &InputValue,
+    typ: &AbiType,
+    name: &str,
+    path: &mut Vec<PathSegment>,
+    leaves: &mut Vec<(String, Vec<PathSegment>)>,
+) {
+    match (value, typ) {
+        (InputValue::Field(_), AbiType::Field | AbiType::Integer { .. } | AbiType::Boolean) => {
+            leaves.push((name.to_string(), path.clone()));
+        }
+        (InputValue::Vec(elements), AbiType::Array { typ, .. }) => {
+            for (index, element) in elements.iter().enumerate() {
+                path.push(PathSegment::Index(index));
+                collect_leaves(element, typ, name, path, leaves);
+                path.pop();
+            }
+        }
+        (InputValue::Vec(elements), AbiType::Tuple { fields }) => {
+            for (index, (element, typ)) in elements.iter().zip(fields).enumerate() {
+                path.push(PathSegment::Index(index));
+                collect_leaves(element, typ, name, path, leaves);
+                path.pop();
+            }
+        }
+        (InputValue::Struct(map), AbiType::Struct { fields, .. }) => {
+            for (field_name, typ) in fields {
+                if let Some(field) = map.get(field_name) {
+                    path.push(PathSegment::Field(field_name.clone()));
+                    collect_leaves(field, typ, name, path, leaves);
+                    path.pop();
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns the value at `path` within the input named `name`.
+fn leaf_mut<'a>(
+    input_map: &'a mut InputMap,
+    name: &str,
+    path: &[PathSegment],
+) -> &'a mut InputValue {
+    let mut value = input_map.get_mut(name).expect("Expected the input to be in the input map");
+    for segment in path {
+        value = match (value, segment) {
+            (InputValue::Vec(elements), PathSegment::Index(index)) => &mut elements[*index],
+            (InputValue::Struct(fields), PathSegment::Field(name)) => {
+                fields.get_mut(name).expect("Expected the struct to have the field")
+            }
+            _ => unreachable!("Expected the path to match the structure of the input"),
+        };
+    }
+    value
+}
+
+/// Returns the smallest value, between zero and `current`, for which `fails` returns true,
+/// assuming that `fails` holds for `current` and for every value above the returned one.
+///
+/// Values which don't fit in a `u128` are only shrunk if the program also fails with zero.
+fn shrink_value(current: FieldElement, fails: &mut impl FnMut(u128) -> bool) -> FieldElement {
+    if current.is_zero() || fails(0) {
+        return FieldElement::zero();
+    }
+    let Some(mut failing) = current.try_into_u128() else {
+        return current;
+    };
+
+    let mut passing = 0;
+    while failing - passing > 1 {
+        let middle = passing + (failing - passing) / 2;
+        if fails(middle) {
+            failing = middle;
+        } else {
+            passing = middle;
+        }
+    }
+    failing.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use acvm::{FieldElement, blackbox_solver::StubbedBlackBoxSolver};
+    use noirc_abi::{InputMap, input_parser::InputValue};
+    use noirc_driver::{CompileOptions, CompiledProgram, file_manager_with_stdlib, prepare_crate};
+    use noirc_frontend::hir::{Context, def_map::parse_file};
+
+    use crate::{foreign_calls::layers::Unhandled, ops::validate_inputs};
+
+    use super::minimize_failing_input;
+
+    fn compile(source: &str) -> CompiledProgram {
+        let root = Path::new("");
+        let file_name = Path::new("main.nr");
+        let mut file_manager = file_manager_with_stdlib(root);
+        file_manager.add_file_with_source(file_name, source.to_owned()).expect(
+            "Adding source buffer to file manager should never fail when file manager is empty",
+        );
+        let parsed_files = file_manager
+            .as_file_map()
+            .all_file_ids()
+            .map(|&file_id| (file_id, parse_file(&file_manager, file_id)))
+            .collect();
+
+        let mut context = Context::new(file_manager, parsed_files);
+        let crate_id = prepare_crate(&mut context, file_name);
+        let (program, _) =
+            noirc_driver::compile_main(&mut context, crate_id, &CompileOptions::default(), None)
+                .expect("failed to compile program");
+        program
+    }
+
+    fn field(value: u128) -> InputValue {
+        InputValue::Field(FieldElement::from(value))
+    }
+
+    #[test]
+    fn reduces_large_failing_input() {
+        let program = compile(
+            "
+            fn main(x: [u32; 4], y: u32) {
+                assert(x[2] < y + 100);
+            }",
+        );
+        let input_map = InputMap::from([
+            (
+                "x".to_string(),
+                InputValue::Vec(vec![field(1000), field(2000), field(3000), field(4000)]),
+            ),
+            ("y".to_string(), field(12345)),
+        ]);
+
+        let minimized = minimize_failing_input(
+            &program,
+            input_map,
+            &StubbedBlackBoxSolver::default(),
+            &mut Unhandled,
+        )
+        .expect("expected the input to be minimized");
+
+        let expected = InputMap::from([
+            ("x".to_string(), InputValue::Vec(vec![field(0), field(0), field(100), field(0)])),
+            ("y".to_string(), field(0)),
+        ]);
+        assert_eq!(minimized, expected);
+        assert!(validate_inputs(&program.abi, &minimized).is_ok());
+    }
+}
@@ -1,21 +1,53 @@
+pub use self::abi_typescript::abi_typescript_declaration;
+pub use self::call_graph::{CallGraph, CallGraphFunction, CallGraphRuntime, call_graph};
 pub use self::check::check_program;
+pub use self::circuit_hash::circuit_hash;
 pub use self::compile::{
     collect_errors, compile_contract, compile_program, compile_program_with_debug_instrumenter,
     compile_workspace, report_errors,
 };
-pub use self::optimize::{optimize_contract, optimize_program};
+pub use self::dead_code::dead_code_warnings;
+pub use self::fan_out::{GenericArguments, MonomorphizationFanOut, monomorphization_fan_out};
+pub use self::loop_report::{loop_report, render_loop_report};
+pub use self::minimize_input::{MinimizeInputError, minimize_failing_input};
+pub use self::optimize::{
+    OptimizationConvergence, optimize_contract, optimize_program,
+    optimize_program_with_max_iterations,
+};
+pub use self::ssa_pass::run_ssa_pass;
 pub use self::transform::{transform_contract, transform_program};
+pub use self::validate_inputs::{InputValidationError, validate_inputs};
+pub use self::variable_values::{VariableValues, execute_program_with_variable_values};
 
-pub use self::execute::{execute_program, execute_program_with_profiling};
+pub use self::execute::{
+    Execution, ExecutionOptions, check_expected_failure, execute_program,
+    execute_program_with_options, execute_program_with_profiling,
+};
+pub use self::execution_trace::{ExecutionStep, ExecutionTrace};
 pub use self::fuzz::{
     FuzzExecutionConfig, FuzzFolderConfig, FuzzingRunStatus, run_fuzzing_harness,
 };
-pub use self::test::{TestStatus, run_test};
+pub use self::test::{
+    TestConfig, TestDefinition, TestRunOptions, TestStatus, list_tests, run_test, run_tests,
+};
+pub use self::witness_estimate::estimate_witness_counts;
 
+mod abi_typescript;
+mod call_graph;
 mod check;
+mod circuit_hash;
 mod compile;
+mod dead_code;
 mod execute;
+mod execution_trace;
+mod fan_out;
 mod fuzz;
+mod loop_report;
+mod minimize_input;
 mod optimize;
+mod ssa_pass;
 mod test;
 mod transform;
+mod validate_inputs;
+mod variable_values;
+mod witness_estimate;
@@ -1,14 +1,66 @@
-use acvm::{FieldElement, acir::circuit::Program};
+use acvm::{
+    FieldElement,
+    acir::circuit::{Circuit, Program},
+};
 use iter_extended::vecmap;
-use noirc_driver::{CompiledContract, CompiledProgram};
+use noirc_driver::{CompiledContract, CompiledProgram, SourceMap};
 use noirc_errors::debug_info::DebugInfo;
 
 pub fn optimize_program(mut compiled_program: CompiledProgram) -> CompiledProgram {
     compiled_program.program =
         optimize_program_internal(compiled_program.program, &mut compiled_program.debug);
+    if compiled_program.source_map.is_some() {
+        compiled_program.source_map =
+            Some(SourceMap::new(&compiled_program.program, &compiled_program.debug));
+    }
     compiled_program
 }
 
+/// How the repeated optimization of a program by [optimize_program_with_max_iterations] ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OptimizationConvergence {
+    /// The largest number of optimization passes run on any function of the program,
+    /// including the final pass which left the function unchanged.
+    pub iterations: usize,
+    /// Whether every function reached a pass which left it unchanged before hitting the cap.
+    pub converged: bool,
+}
+
+/// Optimizes a program like [optimize_program], but repeats the optimizations on each function
+/// until they leave it unchanged, running them at most `max_iterations` times per function.
+///
+/// When the result reports that the program didn't converge, some passes keep changing the
+/// circuit, which usually means that two of them undo each other's changes. To find them, run
+/// again with a larger cap: a program which still doesn't converge is oscillating rather than
+/// slowly shrinking, and comparing the opcodes of a function between two consecutive
+/// iterations, or enabling `trace` logging for `acvm::compiler`, shows which opcodes flip back
+/// and forth.
+pub fn optimize_program_with_max_iterations(
+    mut compiled_program: CompiledProgram,
+    max_iterations: usize,
+) -> (CompiledProgram, OptimizationConvergence) {
+    let functions = std::mem::take(&mut compiled_program.program.functions);
+    let mut convergence = OptimizationConvergence { iterations: 0, converged: true };
+
+    compiled_program.program.functions = functions
+        .into_iter()
+        .zip(compiled_program.debug.iter_mut())
+        .map(|(function, debug)| {
+            let (function, function_convergence) =
+                optimize_circuit_until_stable(function, debug, max_iterations);
+            convergence.iterations = convergence.iterations.max(function_convergence.iterations);
+            convergence.converged &= function_convergence.converged;
+            function
+        })
+        .collect();
+
+    if compiled_program.source_map.is_some() {
+        compiled_program.source_map =
+            Some(SourceMap::new(&compiled_program.program, &compiled_program.debug));
+    }
+    (compiled_program, convergence)
+}
+
 pub fn optimize_contract(contract: CompiledContract) -> CompiledContract {
     let functions = vecmap(contract.functions, |mut func| {
         func.bytecode = optimize_program_internal(func.bytecode, &mut func.debug);
@@ -37,3 +89,69 @@ fn optimize_program_internal(
     program.functions = optimized_functions;
     program
 }
+
+/// Optimizes a circuit until a pass leaves its opcodes unchanged, or `max_iterations` passes
+/// have been run.
+fn optimize_circuit_until_stable(
+    mut circuit: Circuit<FieldElement>,
+    debug: &mut DebugInfo,
+    max_iterations: usize,
+) -> (Circuit<FieldElement>, OptimizationConvergence) {
+    for iteration in 1..=max_iterations {
+        let prev_opcodes = circuit.opcodes.clone();
+        let (optimized_circuit, location_map) = acvm::compiler::optimize(circuit);
+        debug.update_acir(location_map);
+        circuit = optimized_circuit;
+
+        if circuit.opcodes == prev_opcodes {
+            return (circuit, OptimizationConvergence { iterations: iteration, converged: true });
+        }
+    }
+
+    (circuit, OptimizationConvergence { iterations: max_iterations, converged: false })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use acvm::{
+        FieldElement,
+        acir::{
+            circuit::{
+                Circuit, ExpressionWidth, Opcode, PublicInputs,
+                opcodes::{BlackBoxFuncCall, FunctionInput},
+            },
+            native_types::Witness,
+        },
+    };
+    use noirc_errors::debug_info::DebugInfo;
+
+    use super::{OptimizationConvergence, optimize_circuit_until_stable};
+
+    fn range_constraint(witness: Witness, num_bits: u32) -> Opcode<FieldElement> {
+        Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE {
+            input: FunctionInput::witness(witness, num_bits),
+        })
+    }
+
+    #[test]
+    fn reports_convergence_within_cap() {
+        // The second range constraint is implied by the first one, so the first pass removes
+        // it and the second pass leaves the circuit unchanged.
+        let circuit = Circuit {
+            current_witness_index: 1,
+            expression_width: ExpressionWidth::Bounded { width: 4 },
+            opcodes: vec![range_constraint(Witness(1), 8), range_constraint(Witness(1), 16)],
+            private_parameters: BTreeSet::from([Witness(1)]),
+            public_parameters: PublicInputs::default(),
+            return_values: PublicInputs::default(),
+            assert_messages: Default::default(),
+        };
+
+        let (circuit, convergence) =
+            optimize_circuit_until_stable(circuit, &mut DebugInfo::default(), 10);
+        assert_eq!(convergence, OptimizationConvergence { iterations: 2, converged: true });
+        assert_eq!(circuit.opcodes, vec![range_constraint(Witness(1), 8)]);
+    }
+}
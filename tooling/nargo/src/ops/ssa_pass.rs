@@ -0,0 +1,47 @@
+use fm::{FileId, FileManager};
+use noirc_driver::{CompilationResult, CompileOptions, SsaPassOutput, check_crate};
+use noirc_errors::CustomDiagnostic;
+use noirc_frontend::hir::ParsedFiles;
+
+use crate::prepare_package;
+use crate::{package::Package, workspace::Workspace};
+
+/// Compiles a binary package to SSA and runs the single SSA pass named `pass` on it, returning
+/// the SSA before and after the pass.
+///
+/// The pass is run on the initial SSA of the package, without running any other pass first.
+/// Passes are named after the steps of the SSA pipeline, e.g. `Loop Invariant Code Motion`.
+#[tracing::instrument(level = "trace", skip_all, fields(package = package.name.to_string()))]
+pub fn run_ssa_pass(
+    file_manager: &FileManager,
+    parsed_files: &ParsedFiles,
+    workspace: &Workspace,
+    package: &Package,
+    compile_options: &CompileOptions,
+    pass: &str,
+) -> CompilationResult<SsaPassOutput> {
+    let (mut context, crate_id) = prepare_package(file_manager, parsed_files, package);
+    context.package_build_path = workspace.package_build_path(package);
+
+    let (_, warnings) = check_crate(&mut context, crate_id, compile_options)?;
+
+    let main = context.get_main_function(&crate_id).ok_or_else(|| {
+        let error = CustomDiagnostic::from_message(
+            "cannot run an SSA pass on a crate which does not contain a `main` function",
+            FileId::default(),
+        );
+        vec![error]
+    })?;
+
+    let output = noirc_driver::run_ssa_pass(&mut context, compile_options, main, pass)
+        .map_err(|error| vec![CustomDiagnostic::from(error)])?;
+    let output = output.map_err(|unknown_pass| {
+        let passes = unknown_pass.pass_names.join(", ");
+        let error = CustomDiagnostic::from_message(
+            &format!("unknown SSA pass `{pass}`, expected one of: {passes}"),
+            FileId::default(),
+        );
+        vec![error]
+    })?;
+    Ok((output, warnings))
+}
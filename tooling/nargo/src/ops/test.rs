@@ -1,4 +1,9 @@
-use std::{fs::OpenOptions, path::PathBuf};
+use std::{
+    cell::{Cell, RefCell},
+    fs::OpenOptions,
+    path::PathBuf,
+    rc::Rc,
+};
 
 use acvm::{
     AcirField, BlackBoxFunctionSolver, FieldElement,
@@ -8,10 +13,16 @@ use acvm::{
     },
     pwg::ForeignCallWaitInfo,
 };
+use iter_extended::vecmap;
 use noirc_abi::Abi;
 use noirc_driver::{CompileError, CompileOptions, DEFAULT_EXPRESSION_WIDTH, compile_no_check};
-use noirc_errors::{CustomDiagnostic, debug_info::DebugInfo};
-use noirc_frontend::hir::{Context, def_map::TestFunction};
+use noirc_errors::{CustomDiagnostic, Location, debug_info::DebugInfo};
+use noirc_frontend::{
+    Kind, ResolvedGeneric, Type,
+    graph::CrateId,
+    hir::{Context, FunctionNameMatch, def_map::TestFunction},
+    node_interner::FuncId,
+};
 
 use crate::{
     NargoError,
@@ -21,19 +32,57 @@ use crate::{
     },
 };
 
-use super::execute_program;
+use super::{ExecutionOptions, execute_program, execute_program_with_options};
 
 #[derive(Debug)]
 pub enum TestStatus {
-    Pass,
-    Fail { message: String, error_diagnostic: Option<CustomDiagnostic> },
+    /// The test passed. `solving_steps` is the number of ACIR opcodes solved plus Brillig
+    /// opcodes executed, if step counting was requested and the test executed successfully.
+    Pass {
+        solving_steps: Option<usize>,
+    },
+    Fail {
+        message: String,
+        error_diagnostic: Option<CustomDiagnostic>,
+    },
     Skipped,
     CompileError(CustomDiagnostic),
 }
 
 impl TestStatus {
     pub fn failed(&self) -> bool {
-        !matches!(self, TestStatus::Pass | TestStatus::Skipped)
+        !matches!(self, TestStatus::Pass { .. } | TestStatus::Skipped)
+    }
+
+    /// Returns the source locations of the call stack of the failure behind this status,
+    /// from the test function down to the failing assertion.
+    ///
+    /// This is empty if the test didn't fail or if the location of the failure isn't known.
+    pub fn failure_call_stack(&self) -> &[Location] {
+        match self {
+            TestStatus::Fail { error_diagnostic: Some(diagnostic), .. }
+            | TestStatus::CompileError(diagnostic) => &diagnostic.call_stack,
+            _ => &[],
+        }
+    }
+}
+
+/// Options for running test functions.
+#[derive(Debug, Clone, Copy)]
+pub struct TestRunOptions<'a> {
+    /// The options each test function is compiled with
+    pub compile_options: &'a CompileOptions,
+    /// Whether to count the ACIR opcodes solved and Brillig opcodes executed by a passing test,
+    /// which are then reported in [TestStatus::Pass]. Counting adds some overhead to execution.
+    pub count_solving_steps: bool,
+    /// If set, incremented every time a test program is compiled
+    pub compile_count: Option<&'a Cell<usize>>,
+}
+
+impl<'a> TestRunOptions<'a> {
+    /// Options compiling tests with `compile_options`, without counting solving steps.
+    pub fn new(compile_options: &'a CompileOptions) -> Self {
+        Self { compile_options, count_solving_steps: false, compile_count: None }
     }
 }
 
@@ -42,7 +91,103 @@ pub fn run_test<'a, W, B, F, E>(
     context: &mut Context,
     test_function: &TestFunction,
     output: W,
-    config: &CompileOptions,
+    options: TestRunOptions,
+    build_foreign_call_executor: F,
+) -> TestStatus
+where
+    W: std::io::Write + 'a,
+    B: BlackBoxFunctionSolver<FieldElement>,
+    F: Fn(Box<dyn std::io::Write + 'a>, layers::Unhandled) -> E,
+    E: ForeignCallExecutor<FieldElement>,
+{
+    let instantiations = test_function.generic_instantiations();
+    if instantiations.is_empty() {
+        return run_test_instance(
+            blackbox_solver,
+            context,
+            test_function,
+            output,
+            options,
+            build_foreign_call_executor,
+        );
+    }
+
+    // A generic test is run once for each of its `#[test(N = ...)]` attributes, with its
+    // generics bound to the given values, and passes if every run passes.
+    let generics =
+        context.def_interner.function_meta(&test_function.get_id()).direct_generics.clone();
+    let output = SharedWriter(Rc::new(RefCell::new(output)));
+    let mut solving_steps = options.count_solving_steps.then_some(0);
+    for bindings in instantiations {
+        let bound_generics = match bind_test_generics(&generics, bindings) {
+            Ok(bound_generics) => bound_generics,
+            Err(message) => {
+                let diagnostic = CustomDiagnostic::from_message(&message, test_function.file_id());
+                return TestStatus::CompileError(diagnostic);
+            }
+        };
+
+        let status = run_test_instance(
+            blackbox_solver,
+            context,
+            test_function,
+            output.clone(),
+            options,
+            &build_foreign_call_executor,
+        );
+
+        for (generic, kind) in bound_generics {
+            generic.type_var.unbind(generic.type_var.id(), kind);
+        }
+
+        match status {
+            TestStatus::Pass { solving_steps: steps } => {
+                solving_steps = solving_steps.zip(steps).map(|(total, steps)| total + steps);
+            }
+            status => return status,
+        }
+    }
+    TestStatus::Pass { solving_steps }
+}
+
+/// Binds the numeric generics of a test function to the values of one of its instantiations.
+///
+/// Returns the bound generics along with their kinds, so they can be unbound once the test
+/// has run, or an error if a binding doesn't name a distinct numeric generic of the test.
+fn bind_test_generics(
+    generics: &[ResolvedGeneric],
+    bindings: &[(String, FieldElement)],
+) -> Result<Vec<(ResolvedGeneric, Kind)>, String> {
+    // Check every binding before binding anything, so that no generic is left bound on error
+    let mut to_bind: Vec<(&ResolvedGeneric, FieldElement)> = Vec::with_capacity(bindings.len());
+    for (name, value) in bindings {
+        let Some(generic) = generics.iter().find(|generic| generic.name.as_str() == name) else {
+            return Err(format!("Test function has no generic named `{name}`"));
+        };
+        if !matches!(generic.kind(), Kind::Numeric(_)) {
+            return Err(format!(
+                "Generic `{name}` must be numeric to be bound by a test attribute"
+            ));
+        }
+        if to_bind.iter().any(|(bound, _)| bound.name == generic.name) {
+            return Err(format!("Generic `{name}` is bound more than once"));
+        }
+        to_bind.push((generic, *value));
+    }
+
+    Ok(vecmap(to_bind, |(generic, value)| {
+        let kind = generic.kind();
+        generic.type_var.bind(Type::Constant(value, kind.clone()));
+        (generic.clone(), kind)
+    }))
+}
+
+fn run_test_instance<'a, W, B, F, E>(
+    blackbox_solver: &B,
+    context: &mut Context,
+    test_function: &TestFunction,
+    output: W,
+    options: TestRunOptions,
     build_foreign_call_executor: F,
 ) -> TestStatus
 where
@@ -58,6 +203,10 @@ where
         .0
         .is_empty();
 
+    let config = options.compile_options;
+    if let Some(compile_count) = options.compile_count {
+        compile_count.set(compile_count.get() + 1);
+    }
     match compile_no_check(context, config, test_function.get_id(), None, false) {
         Ok(compiled_program) => {
             // Do the same optimizations as `compile_cmd`.
@@ -92,19 +241,37 @@ where
                 let mut foreign_call_executor =
                     LoggingForeignCallExecutor::new(foreign_call_executor, writer);
 
-                let circuit_execution = execute_program(
-                    &compiled_program.program,
-                    WitnessMap::new(),
-                    blackbox_solver,
-                    &mut foreign_call_executor,
-                );
+                let (circuit_execution, solving_steps) = if options.count_solving_steps {
+                    let options = ExecutionOptions { count_steps: true, ..Default::default() };
+                    match execute_program_with_options(
+                        &compiled_program.program,
+                        WitnessMap::new(),
+                        blackbox_solver,
+                        &mut foreign_call_executor,
+                        options,
+                    ) {
+                        Ok(execution) => (Ok(execution.witness_stack), execution.step_count),
+                        Err(err) => (Err(err), None),
+                    }
+                } else {
+                    let circuit_execution = execute_program(
+                        &compiled_program.program,
+                        WitnessMap::new(),
+                        blackbox_solver,
+                        &mut foreign_call_executor,
+                    );
+                    (circuit_execution, None)
+                };
 
-                let status = test_status_program_compile_pass(
+                let status = match test_status_program_compile_pass(
                     test_function,
                     &compiled_program.abi,
                     &compiled_program.debug,
                     &circuit_execution,
-                );
+                ) {
+                    TestStatus::Pass { .. } => TestStatus::Pass { solving_steps },
+                    status => status,
+                };
 
                 let foreign_call_executor = foreign_call_executor.executor;
 
@@ -167,7 +334,7 @@ where
 
                 let result = fuzzer.fuzz();
                 if result.success {
-                    TestStatus::Pass
+                    TestStatus::Pass { solving_steps: None }
                 } else {
                     TestStatus::Fail {
                         message: result.reason.unwrap_or_default(),
@@ -180,6 +347,100 @@ where
     }
 }
 
+/// Runs each of the given test functions of `crate_id` against the same `context`.
+///
+/// The crate must have been checked already, so the crate and its dependencies are elaborated
+/// once and shared by all of the tests. Each test is then compiled once from its own entry
+/// point, or once per instantiation for generic tests. Every test gets a fresh writer from
+/// `output` and a fresh foreign call executor, so no execution state is shared between tests.
+///
+/// Panics if one of `tests` isn't a test function in `crate_id`.
+pub fn run_tests<'a, W, B, O, F, E>(
+    blackbox_solver: &B,
+    context: &mut Context,
+    crate_id: CrateId,
+    tests: &[FuncId],
+    mut output: O,
+    options: TestRunOptions,
+    build_foreign_call_executor: F,
+) -> Vec<(FuncId, TestStatus)>
+where
+    W: std::io::Write + 'a,
+    B: BlackBoxFunctionSolver<FieldElement>,
+    O: FnMut(FuncId) -> W,
+    F: Fn(Box<dyn std::io::Write + 'a>, layers::Unhandled) -> E,
+    E: ForeignCallExecutor<FieldElement>,
+{
+    let def_map = context.def_map(&crate_id).expect("The crate should be checked already");
+    let test_functions: Vec<TestFunction> =
+        def_map.get_all_test_functions(&context.def_interner).collect();
+
+    tests
+        .iter()
+        .map(|test_id| {
+            let test_function = test_functions
+                .iter()
+                .find(|test_function| test_function.get_id() == *test_id)
+                .expect("Expected a test function in the crate");
+            let status = run_test(
+                blackbox_solver,
+                context,
+                test_function,
+                output(*test_id),
+                options,
+                &build_foreign_call_executor,
+            );
+            (*test_id, status)
+        })
+        .collect()
+}
+
+/// A test function of a crate, as described by its `#[test]` attributes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestDefinition {
+    pub id: FuncId,
+    /// The fully qualified name of the test function
+    pub name: String,
+    /// The location of the test function's name
+    pub location: Location,
+    pub config: TestConfig,
+}
+
+/// How a test function is expected to run, from its `#[test]` attributes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TestConfig {
+    /// Whether the test is expected to fail, from `#[test(should_fail)]`
+    /// or `#[test(should_fail_with = "reason")]`
+    pub should_fail: bool,
+    /// The reason the test is expected to fail with, from `#[test(should_fail_with = "reason")]`
+    pub failure_reason: Option<String>,
+    /// The values to bind the test's numeric generics to for each run of the test,
+    /// from `#[test(N = 8)]` attributes. This is empty if the test isn't generic.
+    pub generic_instantiations: Vec<Vec<(String, FieldElement)>>,
+}
+
+/// Returns every test function of the given crate sorted by name, without compiling or
+/// running them.
+///
+/// Panics if `crate_id` hasn't been checked.
+pub fn list_tests(context: &Context, crate_id: &CrateId) -> Vec<TestDefinition> {
+    let mut tests = vecmap(
+        context.get_all_test_functions_in_crate_matching(crate_id, &FunctionNameMatch::Anything),
+        |(name, test_function)| TestDefinition {
+            id: test_function.get_id(),
+            name,
+            location: test_function.location(),
+            config: TestConfig {
+                should_fail: test_function.should_fail(),
+                failure_reason: test_function.failure_reason().map(ToString::to_string),
+                generic_instantiations: test_function.generic_instantiations().to_vec(),
+            },
+        },
+    );
+    tests.sort_by(|a, b| a.name.cmp(&b.name));
+    tests
+}
+
 /// Test function failed to compile
 ///
 /// Note: This could be because the compiler was able to deduce
@@ -215,7 +476,7 @@ fn test_status_program_compile_pass(
                     error_diagnostic: None,
                 };
             }
-            return TestStatus::Pass;
+            return TestStatus::Pass { solving_steps: None };
         }
         Err(err) => err,
     };
@@ -251,7 +512,7 @@ fn check_expected_failure_message(
     //
     let expected_failure_message = match test_function.failure_reason() {
         Some(reason) => reason,
-        None => return TestStatus::Pass,
+        None => return TestStatus::Pass { solving_steps: None },
     };
 
     // Match the failure message that the user will see, i.e. the failed_assertion
@@ -263,7 +524,7 @@ fn check_expected_failure_message(
         .map(|message| message.contains(expected_failure_message))
         .unwrap_or(false);
     if expected_failure_message_matches {
-        return TestStatus::Pass;
+        return TestStatus::Pass { solving_steps: None };
     }
 
     // The expected failure message does not match the actual failure message
@@ -277,6 +538,25 @@ fn check_expected_failure_message(
     }
 }
 
+/// A writer which can be cloned to let each run of a generic test write to the same output.
+struct SharedWriter<W>(Rc<RefCell<W>>);
+
+impl<W> Clone for SharedWriter<W> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<W: std::io::Write> std::io::Write for SharedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.borrow_mut().flush()
+    }
+}
+
 /// A specialized foreign call executor which tracks whether it has encountered any unknown foreign calls
 pub(crate) struct TestForeignCallExecutor<E> {
     executor: E,
@@ -3,7 +3,7 @@ use acvm::{
     acir::circuit::{ExpressionWidth, Program},
 };
 use iter_extended::vecmap;
-use noirc_driver::{CompiledContract, CompiledProgram};
+use noirc_driver::{CompiledContract, CompiledProgram, SourceMap};
 use noirc_errors::debug_info::DebugInfo;
 
 /// Apply ACVM optimizations on the circuit.
@@ -16,6 +16,11 @@ pub fn transform_program(
         &mut compiled_program.debug,
         expression_width,
     );
+    // Opcode indices change when transforming the program, so its source map must be rebuilt
+    if compiled_program.source_map.is_some() {
+        compiled_program.source_map =
+            Some(SourceMap::new(&compiled_program.program, &compiled_program.debug));
+    }
     compiled_program
 }
 
@@ -0,0 +1,207 @@
+use acvm::AcirField;
+use noirc_abi::{Abi, AbiType, InputMap, MAIN_RETURN_NAME, input_parser::InputValue};
+use thiserror::Error;
+
+/// A mismatch between a program's inputs and its ABI, found by [validate_inputs].
+///
+/// Each error carries the path of the offending value, made of the parameter name followed by
+/// `.field` for struct fields and tuple elements and `[index]` for array elements.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum InputValidationError {
+    #[error("Expected a value for `{path}`, but none was found")]
+    MissingInput { path: String },
+    #[error("Found a value for `{path}`, which is not expected by the ABI")]
+    UnexpectedInput { path: String },
+    #[error(
+        "Expected `{path}` to have length {expected_length}, but it has length {actual_length}"
+    )]
+    LengthMismatch { path: String, expected_length: usize, actual_length: usize },
+    #[error("The value of `{path}` does not fall within the range of a {typ:?}")]
+    OutOfRange { path: String, typ: AbiType },
+    #[error("The value of `{path}` is not compatible with type {typ:?}")]
+    IncompatibleType { path: String, typ: AbiType },
+}
+
+/// Checks the inputs of a program against its ABI, before they are handed to a backend.
+///
+/// Every parameter of the ABI must have a value of a compatible type, with arrays and strings
+/// of the exact expected length, and no value may be given which the ABI doesn't describe.
+/// A value for the return type is allowed but not required, as in a `Prover.toml` file.
+///
+/// Unlike encoding the inputs, which stops at the first problem, this reports every mismatch
+/// it finds, in the order of the ABI parameters followed by any unexpected inputs.
+pub fn validate_inputs(abi: &Abi, input_map: &InputMap) -> Result<(), Vec<InputValidationError>> {
+    let mut errors = Vec::new();
+
+    for parameter in &abi.parameters {
+        match input_map.get(&parameter.name) {
+            Some(value) => {
+                validate_value(value, &parameter.typ, parameter.name.clone(), &mut errors)
+            }
+            None => {
+                errors.push(InputValidationError::MissingInput { path: parameter.name.clone() })
+            }
+        }
+    }
+
+    if let (Some(return_type), Some(value)) = (&abi.return_type, input_map.get(MAIN_RETURN_NAME)) {
+        validate_value(value, &return_type.abi_type, MAIN_RETURN_NAME.to_string(), &mut errors);
+    }
+
+    for name in input_map.keys() {
+        let is_parameter = abi.parameters.iter().any(|parameter| &parameter.name == name);
+        let is_return_value = abi.return_type.is_some() && name == MAIN_RETURN_NAME;
+        if !is_parameter && !is_return_value {
+            errors.push(InputValidationError::UnexpectedInput { path: name.clone() });
+        }
+    }
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+fn validate_value(
+    value: &InputValue,
+    typ: &AbiType,
+    path: String,
+    errors: &mut Vec<InputValidationError>,
+) {
+    match (value, typ) {
+        (InputValue::Field(_), AbiType::Field) => {}
+        (InputValue::Field(field), AbiType::Integer { width, .. }) => {
+            if field.num_bits() > *width {
+                errors.push(InputValidationError::OutOfRange { path, typ: typ.clone() });
+            }
+        }
+        (InputValue::Field(field), AbiType::Boolean) => {
+            if !field.is_zero() && !field.is_one() {
+                errors.push(InputValidationError::OutOfRange { path, typ: typ.clone() });
+            }
+        }
+        (InputValue::String(string), AbiType::String { length }) => {
+            if string.len() != *length as usize {
+                errors.push(InputValidationError::LengthMismatch {
+                    path,
+                    expected_length: *length as usize,
+                    actual_length: string.len(),
+                });
+            }
+        }
+        (InputValue::Vec(elements), AbiType::Array { length, typ: element_type }) => {
+            if elements.len() != *length as usize {
+                errors.push(InputValidationError::LengthMismatch {
+                    path: path.clone(),
+                    expected_length: *length as usize,
+                    actual_length: elements.len(),
+                });
+            }
+            for (index, element) in elements.iter().enumerate() {
+                validate_value(element, element_type, format!("{path}[{index}]"), errors);
+            }
+        }
+        (InputValue::Vec(elements), AbiType::Tuple { fields }) => {
+            if elements.len() != fields.len() {
+                errors.push(InputValidationError::LengthMismatch {
+                    path: path.clone(),
+                    expected_length: fields.len(),
+                    actual_length: elements.len(),
+                });
+            }
+            for (index, (element, field_type)) in elements.iter().zip(fields).enumerate() {
+                validate_value(element, field_type, format!("{path}.{index}"), errors);
+            }
+        }
+        (InputValue::Struct(map), AbiType::Struct { fields, .. }) => {
+            for (field_name, field_type) in fields {
+                let field_path = format!("{path}.{field_name}");
+                match map.get(field_name) {
+                    Some(field) => validate_value(field, field_type, field_path, errors),
+                    None => errors.push(InputValidationError::MissingInput { path: field_path }),
+                }
+            }
+            for field_name in map.keys() {
+                if !fields.iter().any(|(name, _)| name == field_name) {
+                    let path = format!("{path}.{field_name}");
+                    errors.push(InputValidationError::UnexpectedInput { path });
+                }
+            }
+        }
+        _ => errors.push(InputValidationError::IncompatibleType { path, typ: typ.clone() }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use acvm::FieldElement;
+    use noirc_abi::{
+        Abi, AbiParameter, AbiType, AbiVisibility, InputMap, Sign, input_parser::InputValue,
+    };
+
+    use super::{InputValidationError, validate_inputs};
+
+    fn parameter(name: &str, typ: AbiType) -> AbiParameter {
+        AbiParameter { name: name.to_string(), typ, visibility: AbiVisibility::Private }
+    }
+
+    fn field(value: u128) -> InputValue {
+        InputValue::Field(FieldElement::from(value))
+    }
+
+    #[test]
+    fn reports_every_mismatch() {
+        let u8_type = AbiType::Integer { sign: Sign::Unsigned, width: 8 };
+        let point = AbiType::Struct {
+            path: "Point".to_string(),
+            fields: vec![("x".to_string(), AbiType::Field), ("y".to_string(), AbiType::Field)],
+        };
+        let abi = Abi {
+            parameters: vec![
+                parameter("bytes", AbiType::Array { length: 3, typ: Box::new(u8_type) }),
+                parameter("point", point),
+                parameter("flag", AbiType::Boolean),
+            ],
+            return_type: None,
+            error_types: Default::default(),
+        };
+
+        let input_map = InputMap::from([
+            ("bytes".to_string(), InputValue::Vec(vec![field(1), field(2)])),
+            ("point".to_string(), InputValue::Struct([("x".to_string(), field(1))].into())),
+            ("flag".to_string(), field(1)),
+        ]);
+
+        let errors = validate_inputs(&abi, &input_map).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                InputValidationError::LengthMismatch {
+                    path: "bytes".to_string(),
+                    expected_length: 3,
+                    actual_length: 2,
+                },
+                InputValidationError::MissingInput { path: "point.y".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_missing_and_unexpected_inputs() {
+        let abi = Abi {
+            parameters: vec![parameter("x", AbiType::Field)],
+            return_type: None,
+            error_types: Default::default(),
+        };
+        let input_map = InputMap::from([("y".to_string(), field(1))]);
+
+        let errors = validate_inputs(&abi, &input_map).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                InputValidationError::MissingInput { path: "x".to_string() },
+                InputValidationError::UnexpectedInput { path: "y".to_string() },
+            ]
+        );
+
+        let input_map = InputMap::from([("x".to_string(), field(1))]);
+        assert!(validate_inputs(&abi, &input_map).is_ok());
+    }
+}
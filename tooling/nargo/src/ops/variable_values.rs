@@ -0,0 +1,236 @@
+use std::collections::BTreeMap;
+
+use acvm::acir::brillig::{ForeignCallParam, ForeignCallResult};
+use acvm::acir::circuit::Program;
+use acvm::acir::native_types::{WitnessMap, WitnessStack};
+use acvm::pwg::ForeignCallWaitInfo;
+use acvm::{AcirField, BlackBoxFunctionSolver};
+use noirc_abi::decode_printable_value;
+use noirc_errors::debug_info::{DebugInfo, DebugVarId};
+use noirc_printable_type::{PrintableType, PrintableValue};
+
+use crate::NargoError;
+use crate::foreign_calls::{ForeignCallError, ForeignCallExecutor};
+
+use super::execute_program;
+
+/// The values assigned to each requested source variable by
+/// [execute_program_with_variable_values], in the order they were assigned.
+///
+/// Variables are told apart by their [DebugVarId] rather than by name, so variables sharing a
+/// name in different scopes or functions have separate entries. The name of each variable is
+/// found in the `variables` of the program's [DebugInfo].
+pub type VariableValues<F> = BTreeMap<DebugVarId, Vec<PrintableValue<F>>>;
+
+/// Executes a program, recording the values assigned to the source variables with the given
+/// names.
+///
+/// This is a lighter-weight alternative to a full execution trace for inspecting intermediate
+/// values. The program must have been compiled with debug instrumentation, which reports each
+/// assignment to a variable through a foreign call, and `debug` must be its debug info, which
+/// maps those calls back to the variables. Assigning to a field or an element of a variable, or
+/// through a reference held by it, records the whole updated value of the variable. A variable
+/// which is never assigned, or doesn't exist, has no entry in the result.
+///
+/// The remaining foreign calls are passed on to `foreign_call_executor`.
+pub fn execute_program_with_variable_values<
+    F: AcirField,
+    B: BlackBoxFunctionSolver<F>,
+    E: ForeignCallExecutor<F>,
+>(
+    program: &Program<F>,
+    initial_witness: WitnessMap<F>,
+    blackbox_solver: &B,
+    foreign_call_executor: &mut E,
+    debug: &[DebugInfo],
+    variable_names: &[String],
+) -> Result<(WitnessStack<F>, VariableValues<F>), NargoError<F>> {
+    let variable_types = debug
+        .iter()
+        .flat_map(|debug_info| {
+            debug_info
+                .variables
+                .iter()
+                .filter(|(_, variable)| variable_names.contains(&variable.name))
+                .filter_map(|(id, variable)| {
+                    let typ = debug_info.types.get(&variable.debug_type_id)?;
+                    Some((*id, typ.clone()))
+                })
+        })
+        .collect();
+
+    let mut recorder = VariableRecorder {
+        variable_types,
+        frames: vec![BTreeMap::new()],
+        values: BTreeMap::new(),
+        inner: foreign_call_executor,
+    };
+    let witness_stack = execute_program(program, initial_witness, blackbox_solver, &mut recorder)?;
+
+    Ok((witness_stack, recorder.values))
+}
+
+/// Records the values assigned to the tracked variables, forwarding the foreign calls which
+/// aren't debug instrumentation to `inner`.
+struct VariableRecorder<'a, F, E> {
+    variable_types: BTreeMap<DebugVarId, PrintableType>,
+    /// The current value of the tracked variables of each function call being executed, so
+    /// that assigning to a field updates the variable of the call it happens in.
+    frames: Vec<BTreeMap<DebugVarId, PrintableValue<F>>>,
+    values: VariableValues<F>,
+    inner: &'a mut E,
+}
+
+impl<F: AcirField, E> VariableRecorder<'_, F, E> {
+    fn assign_var(&mut self, var_id: DebugVarId, fields: &[F]) {
+        let Some(typ) = self.variable_types.get(&var_id) else {
+            return;
+        };
+        let value = decode_printable_value(&mut fields.iter().copied(), typ);
+        self.record(var_id, value);
+    }
+
+    fn assign_member(&mut self, var_id: DebugVarId, indexes: &[u32], fields: &[F]) {
+        let Some(typ) = self.variable_types.get(&var_id) else {
+            return;
+        };
+        let Some(frame) = self.frames.last() else {
+            return;
+        };
+        let Some(mut value) = frame.get(&var_id).cloned() else {
+            return;
+        };
+        if assign_member(&mut value, typ, indexes, fields) {
+            self.record(var_id, value);
+        }
+    }
+
+    fn record(&mut self, var_id: DebugVarId, value: PrintableValue<F>) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.insert(var_id, value.clone());
+        }
+        self.values.entry(var_id).or_default().push(value);
+    }
+}
+
+/// Replaces the member of `value` reached by following `indexes` with the value decoded from
+/// `fields`. Returns false, leaving `value` unchanged, if `indexes` don't lead to a member.
+fn assign_member<F: AcirField>(
+    value: &mut PrintableValue<F>,
+    typ: &PrintableType,
+    indexes: &[u32],
+    fields: &[F],
+) -> bool {
+    // A reference is recorded as the value it points to
+    if let PrintableType::Reference { typ, .. } = typ {
+        return assign_member(value, typ, indexes, fields);
+    }
+    let Some((index, indexes)) = indexes.split_first() else {
+        *value = decode_printable_value(&mut fields.iter().copied(), typ);
+        return true;
+    };
+    let index = *index as usize;
+    match (value, typ) {
+        (
+            PrintableValue::Vec { array_elements, .. },
+            PrintableType::Array { typ, .. } | PrintableType::Slice { typ },
+        ) => match array_elements.get_mut(index) {
+            Some(element) => assign_member(element, typ, indexes, fields),
+            None => false,
+        },
+        (PrintableValue::Vec { array_elements, .. }, PrintableType::Tuple { types }) => {
+            match (array_elements.get_mut(index), types.get(index)) {
+                (Some(element), Some(typ)) => assign_member(element, typ, indexes, fields),
+                _ => false,
+            }
+        }
+        (
+            PrintableValue::Struct(field_values),
+            PrintableType::Struct { fields: field_types, .. },
+        ) => {
+            let Some((name, typ)) = field_types.get(index) else {
+                return false;
+            };
+            match field_values.get_mut(name) {
+                Some(field_value) => assign_member(field_value, typ, indexes, fields),
+                None => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+fn debug_var_id<F: AcirField>(param: Option<&ForeignCallParam<F>>) -> Option<DebugVarId> {
+    match param {
+        Some(ForeignCallParam::Single(var_id)) => Some(DebugVarId(var_id.to_u128() as u32)),
+        _ => None,
+    }
+}
+
+impl<F: AcirField, E: ForeignCallExecutor<F>> ForeignCallExecutor<F>
+    for VariableRecorder<'_, F, E>
+{
+    fn execute(
+        &mut self,
+        foreign_call: &ForeignCallWaitInfo<F>,
+    ) -> Result<ForeignCallResult<F>, ForeignCallError> {
+        let name = foreign_call.function.as_str();
+        let inputs = &foreign_call.inputs;
+        if let Some(arity) = name.strip_prefix("__debug_member_assign_") {
+            // The value is followed by the indexes leading to the assigned member
+            let arity = arity.parse::<usize>().unwrap_or_default();
+            if let (Some(var_id), Some(index_start)) =
+                (debug_var_id(inputs.first()), inputs.len().checked_sub(arity))
+            {
+                let indexes: Option<Vec<u32>> = inputs[index_start..]
+                    .iter()
+                    .map(|index| match index {
+                        ForeignCallParam::Single(index) => Some(index.to_u128() as u32),
+                        ForeignCallParam::Array(_) => None,
+                    })
+                    .collect();
+                let fields: Vec<F> = inputs
+                    .get(1..index_start)
+                    .unwrap_or_default()
+                    .iter()
+                    .flat_map(|input| input.fields())
+                    .collect();
+                if let Some(indexes) = indexes {
+                    self.assign_member(var_id, &indexes, &fields);
+                }
+            }
+            return Ok(ForeignCallResult::default());
+        }
+
+        match name {
+            // Assigning through a reference held by a variable updates the value it points to,
+            // which is how the variable is recorded
+            "__debug_var_assign" | "__debug_dereference_assign" => {
+                if let Some(var_id) = debug_var_id(inputs.first()) {
+                    let fields: Vec<F> =
+                        inputs[1..].iter().flat_map(|input| input.fields()).collect();
+                    self.assign_var(var_id, &fields);
+                }
+                Ok(ForeignCallResult::default())
+            }
+            "__debug_var_drop" => {
+                if let (Some(var_id), Some(frame)) =
+                    (debug_var_id(inputs.first()), self.frames.last_mut())
+                {
+                    frame.remove(&var_id);
+                }
+                Ok(ForeignCallResult::default())
+            }
+            "__debug_fn_enter" => {
+                self.frames.push(BTreeMap::new());
+                Ok(ForeignCallResult::default())
+            }
+            "__debug_fn_exit" => {
+                self.frames.pop();
+                Ok(ForeignCallResult::default())
+            }
+            _ if name.starts_with("__debug_") => Ok(ForeignCallResult::default()),
+            _ => self.inner.execute(foreign_call),
+        }
+    }
+}
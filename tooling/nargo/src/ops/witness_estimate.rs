@@ -0,0 +1,42 @@
+use std::collections::BTreeMap;
+
+use fm::{FileId, FileManager};
+use noirc_driver::{CompilationResult, CompileOptions, check_crate};
+use noirc_errors::CustomDiagnostic;
+use noirc_frontend::hir::ParsedFiles;
+
+use crate::prepare_package;
+use crate::{package::Package, workspace::Workspace};
+
+/// Estimates the number of witnesses each ACIR function of a binary package needs, keyed by
+/// function name, from its optimized SSA and without generating ACIR.
+///
+/// This is a heuristic which is much cheaper than compiling the package, meant to compare
+/// functions or track the effect of changes on them. It doesn't account for the intermediate
+/// witnesses ACIR generation introduces, so the witness count of the compiled program can
+/// differ significantly from the estimate.
+#[tracing::instrument(level = "trace", skip_all, fields(package = package.name.to_string()))]
+pub fn estimate_witness_counts(
+    file_manager: &FileManager,
+    parsed_files: &ParsedFiles,
+    workspace: &Workspace,
+    package: &Package,
+    compile_options: &CompileOptions,
+) -> CompilationResult<BTreeMap<String, usize>> {
+    let (mut context, crate_id) = prepare_package(file_manager, parsed_files, package);
+    context.package_build_path = workspace.package_build_path(package);
+
+    let (_, warnings) = check_crate(&mut context, crate_id, compile_options)?;
+
+    let main = context.get_main_function(&crate_id).ok_or_else(|| {
+        let error = CustomDiagnostic::from_message(
+            "cannot estimate the witness counts of a crate which does not contain a `main` function",
+            FileId::default(),
+        );
+        vec![error]
+    })?;
+
+    let counts = noirc_driver::estimate_witness_counts(&mut context, compile_options, main)
+        .map_err(|error| vec![CustomDiagnostic::from(error)])?;
+    Ok((counts, warnings))
+}
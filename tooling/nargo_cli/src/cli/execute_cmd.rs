@@ -37,6 +37,11 @@ pub(crate) struct ExecuteCommand {
     /// Path to the oracle transcript.
     #[clap(long, conflicts_with = "oracle_resolver")]
     oracle_file: Option<PathBuf>,
+
+    /// Path to a JSON file to write a trace of every opcode executed, for replaying
+    /// the execution in a debugger. The trace can be very large.
+    #[clap(long)]
+    trace_file: Option<PathBuf>,
 }
 
 impl WorkspaceCommand for ExecuteCommand {
@@ -72,6 +77,7 @@ pub(crate) fn run(args: ExecuteCommand, workspace: Workspace) -> Result<(), CliE
             oracle_root_dir: Some(workspace.root_dir.clone()),
             oracle_package_name: Some(package.name.to_string()),
             pedantic_solving: args.compile_options.pedantic_solving,
+            trace_file: args.trace_file.clone(),
         };
 
         noir_artifact_cli::commands::execute_cmd::run(cmd)?;
@@ -70,6 +70,10 @@ pub(crate) struct FuzzCommand {
     /// Maximum time in seconds to spend fuzzing (default: no timeout)
     #[arg(long)]
     timeout: Option<u64>,
+
+    /// Execute every input both as ACIR and as Brillig and fail if their results differ
+    #[arg(long)]
+    differential: bool,
 }
 impl WorkspaceCommand for FuzzCommand {
     fn package_selection(&self) -> PackageSelection {
@@ -150,8 +154,11 @@ pub(crate) fn run(args: FuzzCommand, workspace: Workspace) -> Result<(), CliErro
         minimized_corpus_dir: args.minimized_corpus_dir,
         fuzzing_failure_dir: args.fuzzing_failure_dir,
     };
-    let fuzz_execution_config =
-        FuzzExecutionConfig { timeout: args.timeout.unwrap_or(0), num_threads: args.num_threads };
+    let fuzz_execution_config = FuzzExecutionConfig {
+        timeout: args.timeout.unwrap_or(0),
+        num_threads: args.num_threads,
+        differential: args.differential,
+    };
 
     let fuzzing_reports: Vec<Vec<(String, FuzzingRunStatus)>> = workspace
         .into_iter()
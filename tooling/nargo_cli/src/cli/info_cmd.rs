@@ -3,12 +3,12 @@ use bn254_blackbox_solver::Bn254BlackBoxSolver;
 use clap::Args;
 use iter_extended::vecmap;
 use nargo::{
-    constants::PROVER_INPUT_FILE, foreign_calls::DefaultForeignCallBuilder, package::Package,
-    workspace::Workspace,
+    constants::PROVER_INPUT_FILE, foreign_calls::DefaultForeignCallBuilder,
+    ops::render_loop_report, package::Package, workspace::Workspace,
 };
 use nargo_toml::PackageSelection;
 use noir_artifact_cli::fs::{artifact::read_program_from_file, inputs::read_inputs_from_file};
-use noirc_artifacts::program::ProgramArtifact;
+use noirc_artifacts::{debug::DebugArtifact, program::ProgramArtifact};
 use noirc_artifacts_info::{
     FunctionInfo, InfoReport, ProgramInfo, count_opcodes_and_gates_in_program, show_info_report,
 };
@@ -74,11 +74,31 @@ pub(crate) fn run(mut args: InfoCommand, workspace: Workspace) -> Result<(), Cli
         .filter(|package| package.is_binary())
         .map(|package| -> Result<(Package, ProgramArtifact), CliError> {
             let program_artifact_path = workspace.package_build_path(package);
-            let program = read_program_from_file(&program_artifact_path)?;
+            let mut program = read_program_from_file(&program_artifact_path)?;
+            // An artifact reused from an earlier compilation may hold a report nobody asked for
+            if !args.compile_options.loop_report {
+                program.loop_report = None;
+            }
             Ok((package.clone(), program))
         })
         .collect::<Result<_, _>>()?;
 
+    // `--loop-report` records the loop reports in the artifacts, so they are rendered from
+    // there. The JSON output includes them in the info of each program instead.
+    let loop_reports: Vec<(String, String)> = if args.json {
+        Vec::new()
+    } else {
+        binary_packages
+            .iter()
+            .filter_map(|(package, program)| {
+                let report = program.loop_report.as_ref()?;
+                let files =
+                    DebugArtifact { debug_symbols: Vec::new(), file_map: program.file_map.clone() };
+                Some((package.name.to_string(), render_loop_report(report, &files)))
+            })
+            .collect()
+    };
+
     let program_info = if args.profile_execution {
         assert!(
             args.compile_options.force_brillig,
@@ -108,6 +128,11 @@ pub(crate) fn run(mut args: InfoCommand, workspace: Workspace) -> Result<(), Cli
     let info_report = InfoReport { programs: program_info };
     show_info_report(info_report, args.json);
 
+    for (package_name, loop_report) in loop_reports {
+        println!("[{package_name}] Loop report:");
+        print!("{loop_report}");
+    }
+
     Ok(())
 }
 
@@ -173,6 +198,7 @@ fn profile_brillig_execution(
                 name: "main".to_string(),
                 opcodes: profiling_samples.len(),
             }],
+            loop_report: program_artifact.loop_report.clone(),
         });
     }
     Ok(program_info)
@@ -27,6 +27,7 @@ mod info_cmd;
 mod init_cmd;
 mod lsp_cmd;
 mod new_cmd;
+mod ssa_cmd;
 mod test_cmd;
 
 const GIT_HASH: &str = env!("GIT_COMMIT");
@@ -107,6 +108,8 @@ enum NargoCommand {
     Lsp(lsp_cmd::LspCommand),
     #[command(hide = true)]
     Dap(dap_cmd::DapCommand),
+    #[command(hide = true)]
+    Ssa(ssa_cmd::SsaCommand),
     GenerateCompletionScript(generate_completion_script_cmd::GenerateCompletionScriptCommand),
 }
 
@@ -148,6 +151,7 @@ pub(crate) fn start_cli() -> eyre::Result<()> {
         NargoCommand::Info(args) => with_workspace(args, config, info_cmd::run),
         NargoCommand::Lsp(_) => lsp_cmd::run(),
         NargoCommand::Dap(args) => dap_cmd::run(args),
+        NargoCommand::Ssa(args) => with_workspace(args, config, ssa_cmd::run),
         NargoCommand::Fmt(args) => with_workspace(args, config, fmt_cmd::run),
         NargoCommand::GenerateCompletionScript(args) => generate_completion_script_cmd::run(args),
     }?;
@@ -0,0 +1,68 @@
+use clap::Args;
+use nargo::{
+    insert_all_files_for_workspace_into_file_manager,
+    ops::{report_errors, run_ssa_pass},
+    parse_all,
+    workspace::Workspace,
+};
+use nargo_toml::PackageSelection;
+use noirc_driver::CompileOptions;
+
+use crate::errors::CliError;
+
+use super::{LockType, PackageOptions, WorkspaceCommand};
+
+/// Compiles a binary package to SSA, runs a single SSA pass on it and prints the SSA
+/// before and after the pass
+#[derive(Debug, Clone, Args)]
+pub(crate) struct SsaCommand {
+    /// The name of the SSA pass to run, as printed by `--show-ssa`, e.g. `Loop Invariant Code Motion`.
+    /// Ordinals such as `(2nd)` are left out.
+    #[clap(long)]
+    ssa_pass: String,
+
+    #[clap(flatten)]
+    pub(super) package_options: PackageOptions,
+
+    #[clap(flatten)]
+    compile_options: CompileOptions,
+}
+
+impl WorkspaceCommand for SsaCommand {
+    fn package_selection(&self) -> PackageSelection {
+        self.package_options.package_selection()
+    }
+
+    fn lock_type(&self) -> LockType {
+        // Only prints to stdout.
+        LockType::None
+    }
+}
+
+pub(crate) fn run(args: SsaCommand, workspace: Workspace) -> Result<(), CliError> {
+    let mut workspace_file_manager = workspace.new_file_manager();
+    insert_all_files_for_workspace_into_file_manager(&workspace, &mut workspace_file_manager);
+    let parsed_files = parse_all(&workspace_file_manager);
+
+    let binary_packages = workspace.into_iter().filter(|package| package.is_binary());
+    for package in binary_packages {
+        let result = run_ssa_pass(
+            &workspace_file_manager,
+            &parsed_files,
+            &workspace,
+            package,
+            &args.compile_options,
+            &args.ssa_pass,
+        );
+        let output = report_errors(
+            result,
+            &workspace_file_manager,
+            args.compile_options.deny_warnings,
+            args.compile_options.silence_warnings,
+        )?;
+
+        println!("[{}] Before {}:\n{}", package.name, args.ssa_pass, output.before);
+        println!("[{}] After {}:\n{}", package.name, args.ssa_pass, output.after);
+    }
+    Ok(())
+}
@@ -1,8 +1,8 @@
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     fmt::Display,
     panic::{UnwindSafe, catch_unwind},
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::{Mutex, mpsc},
     thread,
     time::Duration,
@@ -12,10 +12,14 @@ use acvm::{BlackBoxFunctionSolver, FieldElement};
 use bn254_blackbox_solver::Bn254BlackBoxSolver;
 use clap::Args;
 use fm::FileManager;
-use formatters::{Formatter, JsonFormatter, PrettyFormatter, TerseFormatter};
+use formatters::{Formatter, JsonFormatter, JunitFormatter, PrettyFormatter, TerseFormatter};
 use nargo::{
-    foreign_calls::DefaultForeignCallBuilder, insert_all_files_for_workspace_into_file_manager,
-    ops::TestStatus, package::Package, parse_all, prepare_package, workspace::Workspace,
+    foreign_calls::DefaultForeignCallBuilder,
+    insert_all_files_for_workspace_into_file_manager,
+    ops::{TestRunOptions, TestStatus},
+    package::Package,
+    parse_all, prepare_package,
+    workspace::Workspace,
 };
 use nargo_toml::PackageSelection;
 use noirc_driver::{CompileOptions, check_crate};
@@ -67,6 +71,14 @@ pub(crate) struct TestCommand {
     /// Display one character per test instead of one line
     #[clap(short = 'q', long = "quiet")]
     quiet: bool,
+
+    /// Report the number of ACIR opcodes solved and Brillig opcodes executed by each passing test
+    #[clap(long)]
+    show_solving_steps: bool,
+
+    /// Only run the tests which failed the last time they ran, or all tests if none did
+    #[clap(long)]
+    last_failed: bool,
 }
 
 impl WorkspaceCommand for TestCommand {
@@ -87,6 +99,8 @@ enum Format {
     Terse,
     /// Output a JSON Lines document
     Json,
+    /// Output a JUnit XML report once all tests have run
+    Junit,
 }
 
 impl Format {
@@ -95,6 +109,7 @@ impl Format {
             Format::Pretty => Box::new(PrettyFormatter),
             Format::Terse => Box::new(TerseFormatter),
             Format::Json => Box::new(JsonFormatter),
+            Format::Junit => Box::new(JunitFormatter::default()),
         }
     }
 }
@@ -105,6 +120,7 @@ impl Display for Format {
             Format::Pretty => write!(f, "pretty"),
             Format::Terse => write!(f, "terse"),
             Format::Json => write!(f, "json"),
+            Format::Junit => write!(f, "junit"),
         }
     }
 }
@@ -125,6 +141,9 @@ struct TestResult {
 
 const STACK_SIZE: usize = 4 * 1024 * 1024;
 
+/// The file in the target directory recording the tests which failed the last time they ran.
+const FAILED_TESTS_FILE: &str = "failed_tests.txt";
+
 pub(crate) fn run(args: TestCommand, workspace: Workspace) -> Result<(), CliError> {
     let mut file_manager = workspace.new_file_manager();
     insert_all_files_for_workspace_into_file_manager(&workspace, &mut file_manager);
@@ -182,18 +201,50 @@ impl<'a> TestRunner<'a> {
             return Ok(());
         }
 
+        let failed_tests_path = self.workspace.target_directory_path().join(FAILED_TESTS_FILE);
+        let previous_failed_tests = match read_failed_tests(&failed_tests_path) {
+            Ok(previous_failed_tests) => previous_failed_tests,
+            Err(error) if self.args.last_failed => {
+                return Err(CliError::Generic(format!(
+                    "Could not read {}: {error}",
+                    failed_tests_path.display()
+                )));
+            }
+            Err(error) => {
+                eprintln!("Warning: could not read {}: {error}", failed_tests_path.display());
+                BTreeSet::new()
+            }
+        };
+
         // Now gather all tests and how many are per packages
         let mut tests = Vec::new();
         let mut test_count_per_package = BTreeMap::new();
+        let mut run_tests = BTreeSet::new();
 
-        for (package_name, package_tests) in packages_tests {
+        for (package_name, mut package_tests) in packages_tests {
+            if self.args.last_failed && !previous_failed_tests.is_empty() {
+                package_tests.retain(|test| {
+                    previous_failed_tests.contains(&(package_name.clone(), test.name.clone()))
+                });
+            }
+            run_tests
+                .extend(package_tests.iter().map(|test| (package_name.clone(), test.name.clone())));
             test_count_per_package.insert(package_name, package_tests.len());
             tests.extend(package_tests);
         }
 
         // Now run all tests in parallel, but show output for each package sequentially
         let tests_count = tests.len();
-        let all_passed = self.run_all_tests(tests, &test_count_per_package);
+        let failed_tests = self.run_all_tests(tests, &test_count_per_package);
+
+        // Tests which weren't run, e.g. because they didn't match the pattern, keep their
+        // previous record so that a filtered run doesn't forget about earlier failures
+        let mut recorded_failed_tests = previous_failed_tests;
+        recorded_failed_tests.retain(|test| !run_tests.contains(test));
+        recorded_failed_tests.extend(failed_tests.iter().cloned());
+        if let Err(error) = write_failed_tests(&failed_tests_path, &recorded_failed_tests) {
+            eprintln!("Warning: could not write {}: {error}", failed_tests_path.display());
+        }
 
         if tests_count == 0 {
             match &self.pattern {
@@ -228,16 +279,16 @@ impl<'a> TestRunner<'a> {
             };
         }
 
-        if all_passed { Ok(()) } else { Err(CliError::Generic(String::new())) }
+        if failed_tests.is_empty() { Ok(()) } else { Err(CliError::Generic(String::new())) }
     }
 
-    /// Runs all tests. Returns `true` if all tests passed, `false` otherwise.
+    /// Runs all tests. Returns the package and the name of each test which failed.
     fn run_all_tests(
         &self,
         tests: Vec<Test<'a>>,
         test_count_per_package: &BTreeMap<String, usize>,
-    ) -> bool {
-        let mut all_passed = true;
+    ) -> BTreeSet<(String, String)> {
+        let mut failed_tests = BTreeSet::new();
 
         for (package_name, total_test_count) in test_count_per_package {
             self.formatter
@@ -331,6 +382,9 @@ impl<'a> TestRunner<'a> {
                 // Check if we have buffered test results for this package
                 if let Some(buffered_tests) = buffer.remove(package_name) {
                     for test_result in buffered_tests {
+                        if test_result.status.failed() {
+                            failed_tests.insert((package_name.clone(), test_result.name.clone()));
+                        }
                         self.display_test_result(
                             &test_result,
                             current_test_count + 1,
@@ -344,10 +398,6 @@ impl<'a> TestRunner<'a> {
 
                 if current_test_count < total_test_count {
                     while let Ok(test_result) = receiver.recv() {
-                        if test_result.status.failed() {
-                            all_passed = false;
-                        }
-
                         // This is a test result from a different package: buffer it.
                         if &test_result.package_name != package_name {
                             buffer
@@ -357,6 +407,10 @@ impl<'a> TestRunner<'a> {
                             continue;
                         }
 
+                        if test_result.status.failed() {
+                            failed_tests.insert((package_name.clone(), test_result.name.clone()));
+                        }
+
                         self.display_test_result(
                             &test_result,
                             current_test_count + 1,
@@ -384,7 +438,9 @@ impl<'a> TestRunner<'a> {
             }
         });
 
-        all_passed
+        self.formatter.run_end().expect("Could not display test report");
+
+        failed_tests
     }
 
     /// Compiles all packages in parallel and returns their tests
@@ -518,7 +574,11 @@ impl<'a> TestRunner<'a> {
             &mut context,
             test_function,
             &mut output_buffer,
-            &self.args.compile_options,
+            TestRunOptions {
+                compile_options: &self.args.compile_options,
+                count_solving_steps: self.args.show_solving_steps,
+                compile_count: None,
+            },
             |output, base| {
                 DefaultForeignCallBuilder {
                     output,
@@ -555,3 +615,33 @@ impl<'a> TestRunner<'a> {
         )
     }
 }
+
+/// Reads the package and the name of each test recorded as failing the last time it ran.
+/// Returns no tests if there was no previous run.
+fn read_failed_tests(path: &Path) -> std::io::Result<BTreeSet<(String, String)>> {
+    if !path.exists() {
+        return Ok(BTreeSet::new());
+    }
+    let contents = std::fs::read_to_string(path)?;
+    Ok(contents
+        .lines()
+        .filter_map(|line| line.split_once(' '))
+        .map(|(package_name, test_name)| (package_name.to_string(), test_name.to_string()))
+        .collect())
+}
+
+/// Records the package and the name of each failing test, one per line, so that they can be
+/// run again with `--last-failed`.
+fn write_failed_tests(
+    path: &Path,
+    failed_tests: &BTreeSet<(String, String)>,
+) -> std::io::Result<()> {
+    let contents: String = failed_tests
+        .iter()
+        .map(|(package_name, test_name)| format!("{package_name} {test_name}\n"))
+        .collect();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, contents)
+}
@@ -1,4 +1,4 @@
-use std::{io::Write, panic::RefUnwindSafe, time::Duration};
+use std::{fmt::Write as _, io::Write, panic::RefUnwindSafe, sync::Mutex, time::Duration};
 
 use fm::FileManager;
 use nargo::ops::TestStatus;
@@ -19,6 +19,7 @@ use super::TestResult;
 ///     a. A `package_start_sync` event
 ///     b. One `test_end` event for each test
 ///     a. A `package_end` event
+/// 5. A `run_end` event once all packages are done
 ///
 /// The reason we have some `sync` and `async` events is that formatters that show output
 /// to humans rely on the `sync` events to show a more predictable output (package by package),
@@ -62,6 +63,8 @@ pub(super) trait Formatter: Send + Sync + RefUnwindSafe {
         deny_warnings: bool,
         silence_warnings: bool,
     ) -> std::io::Result<()>;
+
+    fn run_end(&self) -> std::io::Result<()>;
 }
 
 pub(super) struct PrettyFormatter;
@@ -116,10 +119,13 @@ impl Formatter for PrettyFormatter {
         writer.flush()?;
 
         match &test_result.status {
-            TestStatus::Pass { .. } => {
+            TestStatus::Pass { solving_steps } => {
                 writer.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
                 write!(writer, "ok")?;
                 writer.reset()?;
+                if let Some(solving_steps) = solving_steps {
+                    write!(writer, " ({solving_steps} solving steps)")?;
+                }
                 show_time(&mut writer)?;
                 writeln!(writer)?;
             }
@@ -219,6 +225,10 @@ impl Formatter for PrettyFormatter {
 
         Ok(())
     }
+
+    fn run_end(&self) -> std::io::Result<()> {
+        Ok(())
+    }
 }
 
 pub(super) struct TerseFormatter;
@@ -261,7 +271,7 @@ impl Formatter for TerseFormatter {
         let mut writer = writer.lock();
 
         match &test_result.status {
-            TestStatus::Pass => {
+            TestStatus::Pass { .. } => {
                 writer.set_color(ColorSpec::new().set_fg(Some(Color::Green)))?;
                 write!(writer, ".")?;
                 writer.reset()?;
@@ -314,7 +324,7 @@ impl Formatter for TerseFormatter {
                 }
 
                 match &test_result.status {
-                    TestStatus::Pass | TestStatus::Skipped => (),
+                    TestStatus::Pass { .. } | TestStatus::Skipped => (),
                     TestStatus::Fail { message, error_diagnostic } => {
                         writer.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
                         writeln!(writer, "{message}")?;
@@ -385,6 +395,10 @@ impl Formatter for TerseFormatter {
 
         Ok(())
     }
+
+    fn run_end(&self) -> std::io::Result<()> {
+        Ok(())
+    }
 }
 
 pub(super) struct JsonFormatter;
@@ -426,8 +440,11 @@ impl Formatter for JsonFormatter {
         }
 
         match &test_result.status {
-            TestStatus::Pass => {
+            TestStatus::Pass { solving_steps } => {
                 json.insert("event".to_string(), json!("ok"));
+                if let Some(solving_steps) = solving_steps {
+                    json.insert("solving_steps".to_string(), json!(solving_steps));
+                }
             }
             TestStatus::Fail { message, error_diagnostic } => {
                 json.insert("event".to_string(), json!("failed"));
@@ -496,7 +513,7 @@ impl Formatter for JsonFormatter {
         let mut ignored = 0;
         for test_result in test_results {
             match &test_result.status {
-                TestStatus::Pass => passed += 1,
+                TestStatus::Pass { .. } => passed += 1,
                 TestStatus::Fail { .. } | TestStatus::CompileError(..) => failed += 1,
                 TestStatus::Skipped => ignored += 1,
             }
@@ -506,6 +523,198 @@ impl Formatter for JsonFormatter {
         println!("{json}");
         Ok(())
     }
+
+    fn run_end(&self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Outputs a JUnit XML report once all tests have run, with one `<testsuite>` per package.
+#[derive(Default)]
+pub(super) struct JunitFormatter {
+    test_suites: Mutex<Vec<String>>,
+}
+
+impl Formatter for JunitFormatter {
+    fn package_start_async(&self, _package_name: &str, _test_count: usize) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn package_start_sync(&self, _package_name: &str, _test_count: usize) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn test_start_async(&self, _name: &str, _package_name: &str) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn test_end_async(
+        &self,
+        _test_result: &TestResult,
+        _file_manager: &FileManager,
+        _show_output: bool,
+        _deny_warnings: bool,
+        _silence_warnings: bool,
+    ) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn test_end_sync(
+        &self,
+        _test_result: &TestResult,
+        _current_test_count: usize,
+        _total_test_count: usize,
+        _file_manager: &FileManager,
+        _show_output: bool,
+        _deny_warnings: bool,
+        _silence_warnings: bool,
+    ) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn package_end(
+        &self,
+        package_name: &str,
+        test_results: &[TestResult],
+        file_manager: &FileManager,
+        show_output: bool,
+        _deny_warnings: bool,
+        silence_warnings: bool,
+    ) -> std::io::Result<()> {
+        let test_suite = junit_test_suite(
+            package_name,
+            test_results,
+            file_manager,
+            show_output,
+            silence_warnings,
+        );
+        self.test_suites.lock().unwrap().push(test_suite);
+        Ok(())
+    }
+
+    fn run_end(&self) -> std::io::Result<()> {
+        let test_suites = self.test_suites.lock().unwrap();
+        println!(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        println!("<testsuites>");
+        for test_suite in test_suites.iter() {
+            print!("{test_suite}");
+        }
+        println!("</testsuites>");
+        Ok(())
+    }
+}
+
+/// Renders the results of a package's tests as a JUnit `<testsuite>` element.
+///
+/// Failed tests get a `<failure>` element and tests which didn't compile an `<error>` one,
+/// both holding the failure message. Skipped tests get a `<skipped/>` element.
+fn junit_test_suite(
+    package_name: &str,
+    test_results: &[TestResult],
+    file_manager: &FileManager,
+    show_output: bool,
+    silence_warnings: bool,
+) -> String {
+    let count = |predicate: fn(&TestStatus) -> bool| {
+        test_results.iter().filter(|test_result| predicate(&test_result.status)).count()
+    };
+    let failures = count(|status| matches!(status, TestStatus::Fail { .. }));
+    let errors = count(|status| matches!(status, TestStatus::CompileError(_)));
+    let skipped = count(|status| matches!(status, TestStatus::Skipped));
+    let time: Duration = test_results.iter().map(|test_result| test_result.time_to_run).sum();
+
+    let mut xml = String::new();
+    writeln!(
+        xml,
+        r#"  <testsuite name="{}" tests="{}" failures="{failures}" errors="{errors}" skipped="{skipped}" time="{:.3}">"#,
+        escape_xml(package_name),
+        test_results.len(),
+        time.as_secs_f64(),
+    )
+    .unwrap();
+
+    for test_result in test_results {
+        write!(
+            xml,
+            r#"    <testcase name="{}" classname="{}" time="{:.3}""#,
+            escape_xml(&test_result.name),
+            escape_xml(&test_result.package_name),
+            test_result.time_to_run.as_secs_f64(),
+        )
+        .unwrap();
+
+        let output = (show_output && !test_result.output.is_empty()).then(|| {
+            format!("      <system-out>{}</system-out>\n", escape_xml(test_result.output.trim()))
+        });
+
+        let (element, message, diagnostic) = match &test_result.status {
+            TestStatus::Pass { .. } => {
+                match output {
+                    Some(output) => write!(xml, ">\n{output}    </testcase>\n").unwrap(),
+                    None => xml.push_str("/>\n"),
+                }
+                continue;
+            }
+            TestStatus::Skipped => {
+                xml.push_str(">\n      <skipped/>\n");
+                xml.push_str(output.as_deref().unwrap_or_default());
+                xml.push_str("    </testcase>\n");
+                continue;
+            }
+            TestStatus::Fail { message, error_diagnostic } => {
+                ("failure", message.trim(), error_diagnostic.as_ref())
+            }
+            TestStatus::CompileError(diagnostic) => {
+                ("error", diagnostic.message.trim(), Some(diagnostic))
+            }
+        };
+
+        let mut details = message.to_string();
+        if let Some(diagnostic) = diagnostic {
+            if !(diagnostic.is_warning() && silence_warnings) {
+                details.push('\n');
+                details.push_str(&diagnostic_to_string(diagnostic, file_manager));
+            }
+        }
+
+        writeln!(xml, ">").unwrap();
+        writeln!(
+            xml,
+            r#"      <{element} message="{}">{}</{element}>"#,
+            escape_xml(message),
+            escape_xml(&details),
+        )
+        .unwrap();
+        xml.push_str(output.as_deref().unwrap_or_default());
+        xml.push_str("    </testcase>\n");
+    }
+
+    xml.push_str("  </testsuite>\n");
+    xml
+}
+
+/// Escapes the characters which can't appear as is in XML text or attribute values.
+///
+/// Characters which XML 1.0 doesn't allow at all, even escaped, such as the `\x1b` starting
+/// terminal color codes, are replaced with `U+FFFD`.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for char in text.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            '\t' | '\r' => escaped.push(char),
+            '\u{0}'..='\u{1f}' | '\u{fffe}' | '\u{ffff}' => {
+                escaped.push(char::REPLACEMENT_CHARACTER)
+            }
+            _ => escaped.push(char),
+        }
+    }
+    escaped
 }
 
 fn package_start(package_name: &str, test_count: usize) -> std::io::Result<()> {
@@ -544,3 +753,73 @@ pub(crate) fn diagnostic_to_string(
 fn stdout() -> StandardStream {
     StandardStream::stdout(ColorChoice::Always)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{path::Path, time::Duration};
+
+    use fm::FileManager;
+    use nargo::ops::TestStatus;
+
+    use super::{super::TestResult, junit_test_suite};
+
+    fn test_result(name: &str, status: TestStatus, output: &str, millis: u64) -> TestResult {
+        TestResult {
+            name: name.to_string(),
+            package_name: "my_package".to_string(),
+            status,
+            output: output.to_string(),
+            time_to_run: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn junit_test_suite_reports_each_status() {
+        let test_results = vec![
+            test_result("passes", TestStatus::Pass { solving_steps: None }, "", 250),
+            test_result(
+                "fails",
+                TestStatus::Fail {
+                    message: "Failed constraint: 'x < y'".to_string(),
+                    error_diagnostic: None,
+                },
+                "",
+                500,
+            ),
+            test_result("is_skipped", TestStatus::Skipped, "", 0),
+        ];
+        let file_manager = FileManager::new(Path::new(""));
+
+        let xml = junit_test_suite("my_package", &test_results, &file_manager, false, false);
+
+        let expected = r#"  <testsuite name="my_package" tests="3" failures="1" errors="0" skipped="1" time="0.750">
+    <testcase name="passes" classname="my_package" time="0.250"/>
+    <testcase name="fails" classname="my_package" time="0.500">
+      <failure message="Failed constraint: &apos;x &lt; y&apos;">Failed constraint: &apos;x &lt; y&apos;</failure>
+    </testcase>
+    <testcase name="is_skipped" classname="my_package" time="0.000">
+      <skipped/>
+    </testcase>
+  </testsuite>
+"#;
+        assert_eq!(xml, expected);
+    }
+
+    #[test]
+    fn junit_test_suite_replaces_characters_not_allowed_in_xml() {
+        let output = "\x1b[31mred\x1b[0m\tnull: \0";
+        let test_results =
+            vec![test_result("prints", TestStatus::Pass { solving_steps: None }, output, 100)];
+        let file_manager = FileManager::new(Path::new(""));
+
+        let xml = junit_test_suite("my_package", &test_results, &file_manager, true, false);
+
+        let expected = "  <testsuite name=\"my_package\" tests=\"1\" failures=\"0\" errors=\"0\" skipped=\"0\" time=\"0.100\">
+    <testcase name=\"prints\" classname=\"my_package\" time=\"0.100\">
+      <system-out>\u{fffd}[31mred\u{fffd}[0m\tnull: \u{fffd}</system-out>
+    </testcase>
+  </testsuite>
+";
+        assert_eq!(xml, expected);
+    }
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use nargo::ops::abi_typescript_declaration;
+
+#[test]
+fn typescript_declaration_for_struct_input_and_array_return() {
+    let source = "
+    struct Point {
+        x: Field,
+        y: u32,
+    }
+
+    fn main(point: Point, flag: bool) -> pub [Field; 2] {
+        assert(flag);
+        [point.x, point.y as Field]
+    }"
+    .to_string();
+    let (program, _) =
+        common::prepare_and_compile_snippet(source, false).expect("failed to compile program");
+
+    let expected = "export type Field = string;
+export type u32 = string;
+
+export interface Point {
+  x: Field;
+  y: u32;
+}
+
+export interface Inputs {
+  point: Point;
+  flag: boolean;
+}
+
+export type ReturnType = [Field, Field];
+";
+    assert_eq!(abi_typescript_declaration(&program.abi), expected);
+}
+
+#[test]
+fn typescript_declaration_qualifies_structs_sharing_a_name() {
+    let source = "
+    mod foo {
+        pub struct Point {
+            pub x: Field,
+        }
+    }
+
+    mod bar {
+        pub struct Point {
+            pub y: u8,
+        }
+    }
+
+    fn main(a: foo::Point, b: bar::Point) {
+        assert(a.x == b.y as Field);
+    }"
+    .to_string();
+    let (program, _) =
+        common::prepare_and_compile_snippet(source, false).expect("failed to compile program");
+
+    let expected = "export type Field = string;
+export type u8 = string;
+
+export interface foo_Point {
+  x: Field;
+}
+
+export interface bar_Point {
+  y: u8;
+}
+
+export interface Inputs {
+  a: foo_Point;
+  b: bar_Point;
+}
+";
+    assert_eq!(abi_typescript_declaration(&program.abi), expected);
+}
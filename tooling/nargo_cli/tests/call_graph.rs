@@ -0,0 +1,104 @@
+mod common;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use nargo::ops::{CallGraphRuntime, call_graph};
+use noirc_driver::CompileOptions;
+
+#[test]
+fn call_graph_of_main_with_nested_helpers() {
+    let source = "
+    fn main(x: Field) -> pub Field {
+        // Safety: test
+        let y = unsafe { double_unconstrained(x) };
+        increment(y)
+    }
+
+    fn increment(x: Field) -> Field {
+        double(x) + 1
+    }
+
+    fn double(x: Field) -> Field {
+        x * 2
+    }
+
+    unconstrained fn double_unconstrained(x: Field) -> Field {
+        x * 2
+    }
+    "
+    .to_string();
+
+    let (context, crate_id) = common::prepare_and_check_snippet(source, &CompileOptions::default());
+    let main = context.get_main_function(&crate_id).expect("main should exist");
+
+    let graph = call_graph(&context, main);
+
+    let edges: BTreeMap<&str, BTreeSet<&str>> = graph
+        .functions
+        .iter()
+        .map(|(name, function)| {
+            (name.as_str(), function.callees.iter().map(String::as_str).collect())
+        })
+        .collect();
+    let expected = BTreeMap::from([
+        ("main", BTreeSet::from(["increment", "double_unconstrained"])),
+        ("increment", BTreeSet::from(["double"])),
+        ("double", BTreeSet::new()),
+        ("double_unconstrained", BTreeSet::new()),
+    ]);
+    assert_eq!(edges, expected);
+
+    assert_eq!(graph.functions["main"].runtime, CallGraphRuntime::Acir);
+    assert_eq!(graph.functions["double_unconstrained"].runtime, CallGraphRuntime::Brillig);
+
+    let dot = graph.to_dot();
+    assert!(dot.contains("\"main\" -> \"double_unconstrained\";"), "{dot}");
+    assert!(dot.contains("\"double_unconstrained\" [shape=box];"), "{dot}");
+}
+
+#[test]
+fn call_graph_includes_method_calls_trait_methods_and_operators() {
+    let source = "
+    struct Foo { x: Field }
+
+    impl Foo {
+        fn double(self) -> Foo {
+            Foo { x: self.x * 2 }
+        }
+    }
+
+    impl std::ops::Add for Foo {
+        fn add(self, other: Foo) -> Foo {
+            Foo { x: self.x + other.x }
+        }
+    }
+
+    trait Halve {
+        fn halve(self) -> Self;
+    }
+
+    impl Halve for Foo {
+        fn halve(self) -> Foo {
+            Foo { x: self.x / 2 }
+        }
+    }
+
+    fn main(x: Field) -> pub Field {
+        let foo = Foo { x };
+        let doubled = foo.double();
+        let halved = doubled.halve();
+        (halved + foo).x
+    }
+    "
+    .to_string();
+
+    let (context, crate_id) = common::prepare_and_check_snippet(source, &CompileOptions::default());
+    let main = context.get_main_function(&crate_id).expect("main should exist");
+
+    let graph = call_graph(&context, main);
+
+    let callees: BTreeSet<&str> =
+        graph.functions["main"].callees.iter().map(String::as_str).collect();
+    let expected = BTreeSet::from(["Foo::double", "<Foo as Halve>::halve", "<Foo as Add>::add"]);
+    assert_eq!(callees, expected);
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use std::collections::BTreeSet;
+
+use acvm::{
+    AcirField, FieldElement,
+    acir::{
+        circuit::{Circuit, Opcode, Program},
+        native_types::{Expression, Witness},
+    },
+};
+use nargo::ops::circuit_hash;
+
+fn compile_and_hash(source: &str) -> [u8; 32] {
+    let (program, _) = common::prepare_and_compile_snippet(source.to_string(), false)
+        .expect("failed to compile program");
+    circuit_hash(&program.program)
+}
+
+#[test]
+fn circuit_hash_is_stable_across_compilations() {
+    let source = "
+    fn main(x: Field, y: pub Field) {
+        assert(x * y == 10, \"product must be ten\");
+    }";
+    assert_eq!(compile_and_hash(source), compile_and_hash(source));
+}
+
+#[test]
+fn circuit_hash_ignores_assertion_messages() {
+    let source = "
+    fn main(x: Field, y: pub Field) {
+        assert(x * y == 10, \"product must be ten\");
+    }";
+    let other_source = "
+    fn main(x: Field, y: pub Field) {
+        assert(x * y == 10, \"x times y is not ten\");
+    }";
+    assert_eq!(compile_and_hash(source), compile_and_hash(other_source));
+}
+
+#[test]
+fn circuit_hash_changes_with_constraints() {
+    let source = "
+    fn main(x: Field, y: pub Field) {
+        assert(x * y == 10);
+    }";
+    let other_source = "
+    fn main(x: Field, y: pub Field) {
+        assert(x + y == 10);
+    }";
+    assert_ne!(compile_and_hash(source), compile_and_hash(other_source));
+}
+
+#[test]
+fn circuit_hash_is_pinned() {
+    // `w1 - 10 == 0`
+    let opcode = Opcode::AssertZero(Expression {
+        mul_terms: Vec::new(),
+        linear_combinations: vec![(FieldElement::one(), Witness(1))],
+        q_c: -FieldElement::from(10_u128),
+    });
+    let circuit = Circuit {
+        current_witness_index: 2,
+        opcodes: vec![opcode],
+        private_parameters: BTreeSet::from([Witness(1)]),
+        ..Circuit::default()
+    };
+    let program = Program { functions: vec![circuit], unconstrained_functions: Vec::new() };
+
+    // The hash is used as a cache key across compiler builds, so it must never change
+    // for the same circuit.
+    let hash: String = circuit_hash(&program).iter().map(|byte| format!("{byte:02x}")).collect();
+    assert_eq!(hash, "27cd9ed5841a77859690ae33a6684a75cb6cbc4cd1e6e936c1a34271bac3fa40");
+}
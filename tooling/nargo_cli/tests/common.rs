@@ -1,8 +1,11 @@
+// Each test binary only uses some of these helpers
+#![allow(dead_code)]
+
 use std::path::Path;
 
 use nargo::parse_all;
 use noirc_driver::{
-    CompilationResult, CompileOptions, CompiledProgram, CrateId, compile_main,
+    CompilationResult, CompileOptions, CompiledProgram, CrateId, check_crate, compile_main,
     file_manager_with_stdlib, prepare_crate,
 };
 use noirc_frontend::hir::Context;
@@ -36,3 +39,13 @@ pub fn prepare_and_compile_snippet(
     // TODO: Run nargo::ops::transform_program?
     compile_main(&mut context, root_crate_id, &options, None)
 }
+
+/// Prepare a code snippet and check it, without compiling it.
+pub fn prepare_and_check_snippet(
+    source: String,
+    options: &CompileOptions,
+) -> (Context<'static, 'static>, CrateId) {
+    let (mut context, root_crate_id) = prepare_snippet(source);
+    check_crate(&mut context, root_crate_id, options).expect("failed to check snippet");
+    (context, root_crate_id)
+}
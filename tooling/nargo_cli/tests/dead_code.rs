@@ -0,0 +1,102 @@
+mod common;
+
+use nargo::ops::dead_code_warnings;
+use noirc_driver::CompileOptions;
+
+#[test]
+fn warns_on_functions_unreachable_from_entry_points() {
+    let source = r#"
+    global VALUE: Field = from_global();
+
+    fn main() {
+        called_from_main();
+    }
+
+    fn called_from_main() {}
+
+    fn from_global() -> Field {
+        1
+    }
+
+    fn unused() {
+        only_called_from_unused();
+    }
+
+    fn only_called_from_unused() {}
+
+    pub fn unused_public() {}
+
+    #[test]
+    fn test_something() {
+        called_from_test();
+    }
+
+    fn called_from_test() {}
+    "#
+    .to_string();
+    let (context, crate_id) = common::prepare_and_check_snippet(source, &CompileOptions::default());
+
+    let messages = |is_library| {
+        dead_code_warnings(&context, &crate_id, is_library)
+            .into_iter()
+            .map(|warning| warning.message)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        messages(false),
+        [
+            "function `only_called_from_unused` is never reachable from an entry point",
+            "function `unused` is never reachable from an entry point",
+            "function `unused_public` is never reachable from an entry point",
+        ]
+    );
+    assert_eq!(
+        messages(true),
+        [
+            "function `only_called_from_unused` is never reachable from an entry point",
+            "function `unused` is never reachable from an entry point",
+        ]
+    );
+}
+
+#[test]
+fn follows_method_calls_and_reports_unused_methods() {
+    let source = r#"
+    struct Foo { x: Field }
+
+    impl Foo {
+        fn called_as_method(self) -> Field {
+            self.x
+        }
+
+        fn called_from_trait_impl(self) -> Field {
+            self.x
+        }
+
+        fn unused_method(self) -> Field {
+            self.x
+        }
+    }
+
+    impl Eq for Foo {
+        fn eq(self, other: Foo) -> bool {
+            self.called_from_trait_impl() == other.x
+        }
+    }
+
+    fn main(x: Field) -> pub Field {
+        let foo = Foo { x };
+        foo.called_as_method()
+    }
+    "#
+    .to_string();
+    let (context, crate_id) = common::prepare_and_check_snippet(source, &CompileOptions::default());
+
+    let messages = dead_code_warnings(&context, &crate_id, false)
+        .into_iter()
+        .map(|warning| warning.message)
+        .collect::<Vec<_>>();
+
+    assert_eq!(messages, ["function `Foo::unused_method` is never reachable from an entry point"]);
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use std::collections::BTreeMap;
+
+use nargo::{
+    foreign_calls::DefaultForeignCallBuilder,
+    ops::{check_expected_failure, execute_program},
+};
+use noirc_abi::input_parser::InputValue;
+
+fn execute_failing_snippet(expected_failure: Option<&str>) -> Result<(), String> {
+    let source = "
+    fn main(x: Field) {
+        assert(x == 1, \"x must be one\");
+    }"
+    .to_string();
+    let (program, _) =
+        common::prepare_and_compile_snippet(source, false).expect("failed to compile program");
+
+    let inputs = BTreeMap::from([("x".to_string(), InputValue::Field(0u32.into()))]);
+    let initial_witness = program.abi.encode(&inputs, None).expect("failed to encode");
+
+    let pedantic_solving = true;
+    let blackbox_solver = bn254_blackbox_solver::Bn254BlackBoxSolver(pedantic_solving);
+    let mut foreign_call_executor = DefaultForeignCallBuilder::default().build();
+
+    let result = execute_program(
+        &program.program,
+        initial_witness,
+        &blackbox_solver,
+        &mut foreign_call_executor,
+    );
+    check_expected_failure(result, &program.abi.error_types, expected_failure)
+}
+
+#[test]
+fn expected_failure_with_matching_message_is_ok() {
+    assert_eq!(execute_failing_snippet(Some("must be one")), Ok(()));
+}
+
+#[test]
+fn expected_failure_without_message_is_ok() {
+    assert_eq!(execute_failing_snippet(None), Ok(()));
+}
+
+#[test]
+fn expected_failure_with_mismatched_message_is_err() {
+    let error = execute_failing_snippet(Some("must be two")).unwrap_err();
+    assert!(error.contains("wrong message"), "{error}");
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use std::collections::BTreeMap;
+
+use nargo::{
+    foreign_calls::DefaultForeignCallBuilder,
+    ops::{ExecutionOptions, ExecutionStep, execute_program_with_options},
+};
+use noirc_abi::input_parser::InputValue;
+
+const SOURCE: &str = "
+    fn main(x: Field, y: pub Field) {
+        assert(x != y);
+    }";
+
+#[test]
+fn trace_records_every_step_with_source_locations() {
+    let (program, _) = common::prepare_and_compile_snippet(SOURCE.to_string(), false)
+        .expect("failed to compile program");
+
+    let inputs = BTreeMap::from([
+        ("x".to_string(), InputValue::Field(1u32.into())),
+        ("y".to_string(), InputValue::Field(2u32.into())),
+    ]);
+    let initial_witness = program.abi.encode(&inputs, None).expect("failed to encode");
+
+    let pedantic_solving = true;
+    let blackbox_solver = bn254_blackbox_solver::Bn254BlackBoxSolver(pedantic_solving);
+
+    let options = ExecutionOptions { count_steps: true, record_trace: true, ..Default::default() };
+    let execution = execute_program_with_options(
+        &program.program,
+        initial_witness.clone(),
+        &blackbox_solver,
+        &mut DefaultForeignCallBuilder::default().build(),
+        options,
+    )
+    .expect("failed to execute program");
+    let trace = execution.trace.expect("expected a trace");
+    let step_count = execution.step_count.expect("expected a step count");
+    let witness_stack = execution.witness_stack;
+
+    // Every ACIR opcode is solved once, and the `!=` needs a Brillig call to compute an inverse
+    let acir_steps =
+        trace.steps.iter().filter(|step| matches!(step, ExecutionStep::Acir { .. })).count();
+    assert_eq!(acir_steps, program.program.functions[0].opcodes.len());
+    assert!(trace.steps.iter().any(|step| matches!(step, ExecutionStep::Brillig { .. })));
+    assert_eq!(trace.steps.len(), step_count);
+
+    // Each witness which isn't an input is recorded by the step which solved it
+    let mut witnesses: Vec<_> = initial_witness.into_iter().collect();
+    for step in &trace.steps {
+        if let ExecutionStep::Acir { solved_witnesses, .. } = step {
+            witnesses.extend(solved_witnesses.iter().copied());
+        }
+    }
+    witnesses.sort_by_key(|(witness, _)| *witness);
+    let final_witnesses: Vec<_> =
+        witness_stack.peek().expect("expected a witness").witness.clone().into_iter().collect();
+    assert_eq!(witnesses, final_witnesses);
+
+    let assertion_start = SOURCE.find("assert(x != y)").unwrap() as u32;
+    let assertion = assertion_start..assertion_start + "assert(x != y)".len() as u32;
+    let locations: Vec<_> =
+        trace.steps.iter().flat_map(|step| step.source_locations(&program.debug)).collect();
+    assert!(!locations.is_empty());
+    for location in &locations {
+        assert!(location.span.end() as usize <= SOURCE.len(), "{location:?}");
+    }
+    assert!(locations.iter().any(|location| assertion.contains(&location.span.start())));
+}
+
+#[test]
+fn trace_of_program_without_opcodes_is_empty() {
+    let source = "fn main() {}".to_string();
+    let (program, _) =
+        common::prepare_and_compile_snippet(source, false).expect("failed to compile program");
+    assert!(program.program.functions[0].opcodes.is_empty());
+
+    let pedantic_solving = true;
+    let options = ExecutionOptions { record_trace: true, ..Default::default() };
+    let execution = execute_program_with_options(
+        &program.program,
+        Default::default(),
+        &bn254_blackbox_solver::Bn254BlackBoxSolver(pedantic_solving),
+        &mut DefaultForeignCallBuilder::default().build(),
+        options,
+    )
+    .expect("failed to execute program");
+
+    assert_eq!(execution.trace, Some(Default::default()));
+}
@@ -0,0 +1,37 @@
+mod common;
+
+use std::collections::BTreeSet;
+
+use nargo::ops::monomorphization_fan_out;
+use noirc_driver::CompileOptions;
+
+#[test]
+fn generic_called_with_three_types_has_three_instances() {
+    let source = "
+    fn main(x: Field, y: u8, z: bool) {
+        let _ = identity(x);
+        let _ = identity(y);
+        let _ = identity(z);
+        // Calling `identity` with `Field` again reuses the existing instance
+        let _ = identity(x);
+    }
+
+    fn identity<T>(value: T) -> T {
+        value
+    }
+    "
+    .to_string();
+
+    let (mut context, crate_id) =
+        common::prepare_and_check_snippet(source, &CompileOptions::default());
+    let main = context.get_main_function(&crate_id).expect("main should exist");
+
+    let fan_out =
+        monomorphization_fan_out(&mut context, main, false).expect("failed to monomorphize");
+
+    assert_eq!(fan_out.instance_counts(), vec![("identity", 3)]);
+
+    let types: BTreeSet<&str> =
+        fan_out.functions["identity"].iter().map(|arguments| arguments["T"].as_str()).collect();
+    assert_eq!(types, BTreeSet::from(["Field", "u8", "bool"]));
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use nargo::{
+    foreign_calls::DefaultForeignCallBuilder,
+    ops::{TestRunOptions, TestStatus, run_test},
+};
+use noirc_driver::CompileOptions;
+use noirc_frontend::hir::FunctionNameMatch;
+
+#[test]
+fn generic_test_runs_once_per_instantiation() {
+    let source = "
+    #[test(N = 8)]
+    #[test(N = 16)]
+    fn test_roundtrip<let N: u32>() {
+        let array = [1; N];
+        assert_eq(array.len(), N);
+        println(N);
+    }
+    "
+    .to_string();
+    let options = CompileOptions::default();
+    let (mut context, crate_id) = common::prepare_and_check_snippet(source, &options);
+
+    let pattern = FunctionNameMatch::Exact(vec!["test_roundtrip".to_string()]);
+    let test_functions = context.get_all_test_functions_in_crate_matching(&crate_id, &pattern);
+    let (_, test_function) = test_functions.first().expect("test function should exist");
+
+    let mut output = Vec::new();
+    let pedantic_solving = true;
+    let status = run_test(
+        &bn254_blackbox_solver::Bn254BlackBoxSolver(pedantic_solving),
+        &mut context,
+        test_function,
+        &mut output,
+        TestRunOptions::new(&options),
+        |output, base| {
+            DefaultForeignCallBuilder::default().with_output(output).build_with_base(base)
+        },
+    );
+    assert!(matches!(status, TestStatus::Pass { .. }), "{status:?}");
+    assert_eq!(String::from_utf8(output).unwrap(), "8\n16\n");
+}
@@ -0,0 +1,84 @@
+use assert_cmd::prelude::*;
+use predicates::prelude::*;
+use std::process::Command;
+
+use assert_fs::prelude::{FileWriteStr, PathChild};
+
+/// Writes a package with two passing tests and a failing one into a new temporary directory.
+fn write_package() -> assert_fs::TempDir {
+    let project_dir = assert_fs::TempDir::new().unwrap();
+    project_dir
+        .child("Nargo.toml")
+        .write_str(
+            r#"[package]
+name = "last_failed"
+type = "bin"
+authors = [""]
+
+[dependencies]"#,
+        )
+        .unwrap();
+    project_dir
+        .child("src")
+        .child("main.nr")
+        .write_str(
+            "
+            fn main() {}
+
+            #[test]
+            fn test_passes() {}
+
+            #[test]
+            fn test_also_passes() {}
+
+            #[test]
+            fn test_fails() {
+                assert(false);
+            }
+            ",
+        )
+        .unwrap();
+    project_dir
+}
+
+#[test]
+fn last_failed_only_runs_previously_failing_tests() {
+    let project_dir = write_package();
+
+    // `nargo test` runs all tests, one of which fails
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.arg("--program-dir").arg(project_dir.path()).arg("test");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("[last_failed] Running 3 test functions"));
+
+    // `nargo test --last-failed` only runs the failing test
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.arg("--program-dir").arg(project_dir.path()).arg("test").arg("--last-failed");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("[last_failed] Running 1 test function\n"))
+        .stdout(predicate::str::contains("Testing test_fails"))
+        .stdout(predicate::str::contains("test_passes").not());
+}
+
+#[test]
+fn filtered_run_keeps_the_failures_of_the_tests_it_does_not_run() {
+    let project_dir = write_package();
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.arg("--program-dir").arg(project_dir.path()).arg("test");
+    cmd.assert().failure();
+
+    // Only running a passing test doesn't forget that `test_fails` failed
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.arg("--program-dir").arg(project_dir.path()).arg("test").arg("test_passes");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.arg("--program-dir").arg(project_dir.path()).arg("test").arg("--last-failed");
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("[last_failed] Running 1 test function\n"))
+        .stdout(predicate::str::contains("Testing test_fails"));
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use acvm::FieldElement;
+use nargo::ops::{TestConfig, list_tests};
+use noirc_driver::CompileOptions;
+
+#[test]
+fn lists_tests_with_their_attributes() {
+    let source = r#"
+    fn main() {}
+
+    #[test]
+    fn test_passes() {}
+
+    #[test(should_fail)]
+    fn test_fails() {
+        assert(false);
+    }
+
+    mod nested {
+        #[test(should_fail_with = "boom")]
+        fn test_fails_with_reason() {
+            assert(false, "boom");
+        }
+
+        #[test(N = 1)]
+        #[test(N = 2)]
+        fn test_generic<let N: u32>() {}
+    }
+
+    fn not_a_test() {}
+    "#
+    .to_string();
+    let (context, crate_id) = common::prepare_and_check_snippet(source, &CompileOptions::default());
+
+    let tests = list_tests(&context, &crate_id);
+    let names: Vec<_> = tests.iter().map(|test| test.name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["nested::test_fails_with_reason", "nested::test_generic", "test_fails", "test_passes"]
+    );
+
+    let configs: Vec<_> = tests.iter().map(|test| test.config.clone()).collect();
+    let instantiation = |n: u128| vec![("N".to_string(), FieldElement::from(n))];
+    assert_eq!(
+        configs,
+        [
+            TestConfig {
+                should_fail: true,
+                failure_reason: Some("boom".to_string()),
+                ..TestConfig::default()
+            },
+            TestConfig {
+                generic_instantiations: vec![instantiation(1), instantiation(2)],
+                ..TestConfig::default()
+            },
+            TestConfig { should_fail: true, ..TestConfig::default() },
+            TestConfig::default(),
+        ]
+    );
+
+    for test in &tests {
+        let name = context.def_interner.function_name(&test.id);
+        assert!(test.name.ends_with(name), "{} has id of {name}", test.name);
+
+        let file = context.file_manager.fetch_file(test.location.file).unwrap();
+        let span = test.location.span;
+        assert_eq!(&file[span.start() as usize..span.end() as usize], name);
+    }
+}
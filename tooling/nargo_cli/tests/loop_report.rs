@@ -0,0 +1,91 @@
+mod common;
+
+use assert_cmd::prelude::*;
+use assert_fs::prelude::{FileWriteStr, PathChild};
+use noirc_driver::{CompileOptions, NotHoistedReason, loop_report};
+use predicates::prelude::*;
+use std::process::Command;
+
+const SOURCE: &str = "
+    fn main(x: u32, flag: bool) {
+        for _ in 0..4 {
+            if flag {
+                assert(x == 5);
+            }
+        }
+    }
+";
+
+/// Writes a binary package with [SOURCE] as its `main.nr` into a new temporary directory.
+fn write_package() -> assert_fs::TempDir {
+    let project_dir = assert_fs::TempDir::new().unwrap();
+    project_dir
+        .child("Nargo.toml")
+        .write_str(
+            r#"[package]
+name = "loop_report"
+type = "bin"
+authors = [""]
+
+[dependencies]"#,
+        )
+        .unwrap();
+    project_dir.child("src").child("main.nr").write_str(SOURCE).unwrap();
+    project_dir
+}
+
+#[test]
+fn loop_report_explains_why_control_dependent_assertion_is_not_hoisted() {
+    let options = CompileOptions::default();
+    let (mut context, crate_id) = common::prepare_and_check_snippet(SOURCE.to_string(), &options);
+    let main = context.get_main_function(&crate_id).expect("snippet should have a main function");
+
+    let report = loop_report(&mut context, &options, main).expect("failed to create loop report");
+
+    assert_eq!(report.loops.len(), 1);
+    let loop_ = &report.loops[0];
+    assert_eq!(loop_.function, "main");
+    assert!(loop_.location.is_some());
+    assert!(loop_.unrolled);
+
+    // `x == 5` is loop invariant, but the assertion is only executed when `flag` is set
+    assert_eq!(loop_.not_hoisted.len(), 1);
+    let not_hoisted = &loop_.not_hoisted[0];
+    assert!(not_hoisted.instruction.starts_with("constrain"));
+    assert!(not_hoisted.location.is_some());
+    assert_eq!(not_hoisted.reason, NotHoistedReason::ControlDependent);
+}
+
+#[test]
+fn nargo_info_prints_the_loop_report_recorded_in_the_artifact() {
+    let project_dir = write_package();
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.arg("--program-dir").arg(project_dir.path()).arg("info").arg("--loop-report");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("[loop_report] Loop report:"))
+        .stdout(predicate::str::contains("it is only executed on some paths of the loop"));
+
+    // The artifact is reused, but its report is only shown when asked for
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.arg("--program-dir").arg(project_dir.path()).arg("info");
+    cmd.assert().success().stdout(predicate::str::contains("Loop report:").not());
+}
+
+#[test]
+fn nargo_info_includes_the_loop_report_in_its_json_output() {
+    let project_dir = write_package();
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.arg("--program-dir").arg(project_dir.path()).arg("info").arg("--loop-report").arg("--json");
+    let output = cmd.assert().success().get_output().stdout.clone();
+
+    // The output is a single JSON document, with no text report following it
+    let info: serde_json::Value =
+        serde_json::from_slice(&output).expect("expected the output to be JSON");
+    let loops = &info["programs"][0]["loop_report"]["loops"];
+    assert_eq!(loops.as_array().map(Vec::len), Some(1));
+    assert_eq!(loops[0]["unrolled"], true);
+    assert_eq!(loops[0]["not_hoisted"][0]["reason"], "ControlDependent");
+}
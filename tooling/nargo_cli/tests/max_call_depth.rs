@@ -0,0 +1,74 @@
+mod common;
+
+use std::collections::BTreeMap;
+
+use acvm::FieldElement;
+use nargo::{
+    NargoError,
+    errors::ExecutionError,
+    foreign_calls::DefaultForeignCallBuilder,
+    ops::{Execution, ExecutionOptions, execute_program_with_options},
+};
+use noirc_abi::input_parser::InputValue;
+use noirc_driver::CompiledProgram;
+
+const SOURCE: &str = "
+    fn main(n: u32) -> pub u32 {
+        depth(n)
+    }
+
+    fn depth(n: u32) -> u32 {
+        if n == 0 { 0 } else { 1 + depth(n - 1) }
+    }";
+
+const MAX_CALL_DEPTH: usize = 100;
+
+/// Executes [SOURCE] compiled to Brillig, recursing `n` times, with a limit on the call depth.
+fn execute_recursion(
+    n: u32,
+) -> (CompiledProgram, Result<Execution<FieldElement>, NargoError<FieldElement>>) {
+    let force_brillig = true;
+    let (program, _) = common::prepare_and_compile_snippet(SOURCE.to_string(), force_brillig)
+        .expect("failed to compile program");
+
+    let inputs = BTreeMap::from([("n".to_string(), InputValue::Field(n.into()))]);
+    let initial_witness = program.abi.encode(&inputs, None).expect("failed to encode");
+
+    let pedantic_solving = true;
+    let options =
+        ExecutionOptions { max_brillig_call_depth: Some(MAX_CALL_DEPTH), ..Default::default() };
+    let result = execute_program_with_options(
+        &program.program,
+        initial_witness,
+        &bn254_blackbox_solver::Bn254BlackBoxSolver(pedantic_solving),
+        &mut DefaultForeignCallBuilder::default().build(),
+        options,
+    );
+    (program, result)
+}
+
+#[test]
+fn recursion_within_the_max_call_depth_succeeds() {
+    let (program, result) = execute_recursion(10);
+    let execution = result.expect("failed to execute program");
+
+    let main_witness = &execution.witness_stack.peek().expect("expected a witness").witness;
+    let (_, return_value) = program.abi.decode(main_witness).expect("failed to decode");
+    assert_eq!(return_value, Some(InputValue::Field(10u32.into())));
+}
+
+#[test]
+fn deep_recursion_fails_at_the_max_call_depth() {
+    let (program, result) = execute_recursion(100_000);
+    let error = result.expect_err("expected execution to fail");
+
+    let message = error.user_defined_failure_message(&program.abi.error_types);
+    assert_eq!(message, Some(format!("Exceeded the maximum call depth of {MAX_CALL_DEPTH}")));
+
+    // The call stack of the error shows the recursive calls up to the limit
+    let NargoError::ExecutionError(ExecutionError::AssertionFailed(_, call_stack, _)) = &error
+    else {
+        panic!("expected an assertion failure, got {error:?}");
+    };
+    assert!(call_stack.len() >= MAX_CALL_DEPTH, "{call_stack:?}");
+}
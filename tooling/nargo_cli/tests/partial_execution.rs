@@ -0,0 +1,83 @@
+mod common;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use acvm::{
+    FieldElement,
+    acir::native_types::{Witness, WitnessMap},
+};
+use nargo::{
+    foreign_calls::DefaultForeignCallBuilder,
+    ops::{ExecutionOptions, execute_program_with_options},
+};
+
+const SOURCE: &str = "
+    fn main(x: Field, y: Field) -> pub Field {
+        x * 2 + y
+    }";
+
+#[test]
+fn partial_execution_reports_unresolved_witnesses() {
+    let (program, _) = common::prepare_and_compile_snippet(SOURCE.to_string(), false)
+        .expect("failed to compile program");
+    let main = &program.program.functions[0];
+
+    let pedantic_solving = true;
+    let blackbox_solver = bn254_blackbox_solver::Bn254BlackBoxSolver(pedantic_solving);
+
+    // Only `x` is known
+    let initial_witness =
+        WitnessMap::from(BTreeMap::from([(Witness(0), FieldElement::from(3u128))]));
+    let execution = execute_program_with_options(
+        &program.program,
+        initial_witness,
+        &blackbox_solver,
+        &mut DefaultForeignCallBuilder::default().build(),
+        ExecutionOptions { partial: true, ..Default::default() },
+    )
+    .expect("failed to execute program");
+
+    assert!(!execution.is_solved());
+    let mut expected = BTreeSet::from([Witness(1)]);
+    expected.extend(main.return_values.indices().into_iter().map(Witness));
+    assert_eq!(execution.unresolved_witnesses, expected);
+
+    // Providing `y` on top of the partial witness solves the rest of the program
+    let mut witness = execution.witness_stack.peek().expect("expected a witness").witness.clone();
+    witness.insert(Witness(1), FieldElement::from(4u128));
+    let execution = execute_program_with_options(
+        &program.program,
+        witness,
+        &blackbox_solver,
+        &mut DefaultForeignCallBuilder::default().build(),
+        ExecutionOptions { partial: true, ..Default::default() },
+    )
+    .expect("failed to execute program");
+
+    assert!(execution.is_solved());
+    assert!(execution.unresolved_witnesses.is_empty());
+    let return_witness = Witness(main.return_values.indices()[0]);
+    let main_witness = &execution.witness_stack.peek().unwrap().witness;
+    assert_eq!(main_witness.get(&return_witness), Some(&FieldElement::from(10u128)));
+}
+
+#[test]
+fn partial_execution_of_program_without_opcodes_is_solved() {
+    let source = "fn main() {}".to_string();
+    let (program, _) =
+        common::prepare_and_compile_snippet(source, false).expect("failed to compile program");
+    assert!(program.program.functions[0].opcodes.is_empty());
+
+    let pedantic_solving = true;
+    let execution = execute_program_with_options(
+        &program.program,
+        WitnessMap::new(),
+        &bn254_blackbox_solver::Bn254BlackBoxSolver(pedantic_solving),
+        &mut DefaultForeignCallBuilder::default().build(),
+        ExecutionOptions { partial: true, ..Default::default() },
+    )
+    .expect("failed to execute program");
+
+    assert!(execution.is_solved());
+    assert!(execution.unresolved_witnesses.is_empty());
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use std::cell::Cell;
+
+use nargo::{
+    foreign_calls::DefaultForeignCallBuilder,
+    ops::{TestRunOptions, TestStatus, run_tests},
+};
+use noirc_driver::CompileOptions;
+use noirc_frontend::hir::FunctionNameMatch;
+
+#[test]
+fn runs_all_tests_against_a_single_checked_crate() {
+    let source = "
+    fn double(x: Field) -> Field {
+        x * 2
+    }
+
+    #[test]
+    fn double_one() {
+        assert_eq(double(1), 2);
+    }
+
+    #[test(should_fail_with = \"not four\")]
+    fn double_two() {
+        assert(double(2) != 4, \"not four\");
+    }
+    "
+    .to_string();
+
+    let options = CompileOptions::default();
+    let (mut context, crate_id) = common::prepare_and_check_snippet(source, &options);
+
+    let tests: Vec<_> = context
+        .get_all_test_functions_in_crate_matching(&crate_id, &FunctionNameMatch::Anything)
+        .into_iter()
+        .map(|(_, test_function)| test_function.get_id())
+        .collect();
+    assert_eq!(tests.len(), 2);
+
+    let functions_before = context.def_interner.function_ids().count();
+
+    let writers = Cell::new(0);
+    let compile_count = Cell::new(0);
+    let pedantic_solving = true;
+    let results = run_tests(
+        &bn254_blackbox_solver::Bn254BlackBoxSolver(pedantic_solving),
+        &mut context,
+        crate_id,
+        &tests,
+        |_| {
+            writers.set(writers.get() + 1);
+            std::io::empty()
+        },
+        TestRunOptions { compile_count: Some(&compile_count), ..TestRunOptions::new(&options) },
+        |output, base| {
+            DefaultForeignCallBuilder::default().with_output(output).build_with_base(base)
+        },
+    );
+
+    // Each test is compiled exactly once, without elaborating the crate again
+    assert_eq!(compile_count.get(), 2);
+    assert_eq!(context.def_interner.function_ids().count(), functions_before);
+
+    assert_eq!(writers.get(), 2);
+    assert_eq!(results.len(), 2);
+    for ((test_id, status), expected_id) in results.iter().zip(&tests) {
+        assert_eq!(test_id, expected_id);
+        assert!(matches!(status, TestStatus::Pass { .. }), "{status:?}");
+    }
+}
@@ -0,0 +1,148 @@
+mod common;
+
+use assert_cmd::prelude::*;
+use assert_fs::prelude::{FileWriteStr, PathChild};
+use nargo::insert_all_files_for_workspace_into_file_manager;
+use nargo_toml::{PackageSelection, resolve_workspace_from_toml};
+use noirc_driver::{CompileOptions, run_ssa_pass};
+use predicates::prelude::*;
+use std::process::Command;
+
+const SOURCE: &str = "
+    fn main(x: Field, y: Field) {
+        for i in 0..4 {
+            assert(x * y != i as Field);
+        }
+    }
+";
+
+/// Writes a binary package with [SOURCE] as its `main.nr` into a new temporary directory.
+fn write_package() -> assert_fs::TempDir {
+    let project_dir = assert_fs::TempDir::new().unwrap();
+    project_dir
+        .child("Nargo.toml")
+        .write_str(
+            r#"[package]
+name = "ssa_pass"
+type = "bin"
+authors = [""]
+
+[dependencies]"#,
+        )
+        .unwrap();
+    project_dir.child("src").child("main.nr").write_str(SOURCE).unwrap();
+    project_dir
+}
+
+/// Returns the position of `pattern` in `ssa`, panicking if it is not found.
+fn position(ssa: &str, pattern: &str) -> usize {
+    ssa.find(pattern).unwrap_or_else(|| panic!("expected `{pattern}` in:\n{ssa}"))
+}
+
+#[test]
+fn runs_loop_invariant_code_motion_on_its_own() {
+    let options = CompileOptions::default();
+    let (mut context, crate_id) = common::prepare_and_check_snippet(SOURCE.to_string(), &options);
+
+    let main = context.get_main_function(&crate_id).expect("snippet should have a main function");
+    let output = run_ssa_pass(&mut context, &options, main, "Loop Invariant Code Motion")
+        .expect("failed to run SSA pass")
+        .expect("expected the pass to exist");
+
+    // `x * y` is computed in the loop body before the pass, and before entering the loop after it
+    assert!(position(&output.before, "mul v0, v1") > position(&output.before, "jmp b1"));
+    assert!(position(&output.after, "mul v0, v1") < position(&output.after, "jmp b1"));
+}
+
+#[test]
+fn runs_unrolling_on_its_own() {
+    let options = CompileOptions::default();
+    let (mut context, crate_id) = common::prepare_and_check_snippet(SOURCE.to_string(), &options);
+
+    let main = context.get_main_function(&crate_id).expect("snippet should have a main function");
+    let output = run_ssa_pass(&mut context, &options, main, "unrolling")
+        .expect("failed to run SSA pass")
+        .expect("expected the pass to exist");
+
+    // The loop condition is gone once the loop is unrolled
+    assert!(output.before.contains("jmpif"));
+    assert!(!output.after.contains("jmpif"));
+}
+
+#[test]
+fn lists_the_passes_of_the_pipeline_for_an_unknown_ssa_pass() {
+    let options = CompileOptions::default();
+    let (mut context, crate_id) = common::prepare_and_check_snippet(SOURCE.to_string(), &options);
+
+    let main = context.get_main_function(&crate_id).expect("snippet should have a main function");
+    let unknown_pass = run_ssa_pass(&mut context, &options, main, "no_such_pass")
+        .expect("failed to run SSA pass")
+        .expect_err("expected the pass not to exist");
+
+    let pass_names = &unknown_pass.pass_names;
+    for pass in ["Inlining", "Mem2Reg", "Loop Invariant Code Motion", "Unrolling"] {
+        let count = pass_names.iter().filter(|name| *name == pass).count();
+        assert_eq!(count, 1, "expected `{pass}` once in {pass_names:?}");
+    }
+}
+
+#[test]
+fn runs_ssa_pass_of_a_workspace_package() {
+    let project_dir = write_package();
+    let toml_path = project_dir.child("Nargo.toml");
+    let workspace =
+        resolve_workspace_from_toml(toml_path.path(), PackageSelection::DefaultOrAll, None)
+            .expect("failed to resolve workspace");
+    let mut file_manager = workspace.new_file_manager();
+    insert_all_files_for_workspace_into_file_manager(&workspace, &mut file_manager);
+    let parsed_files = nargo::parse_all(&file_manager);
+    let package = workspace.into_iter().next().expect("expected a package");
+
+    let options = CompileOptions::default();
+    let (output, _) = nargo::ops::run_ssa_pass(
+        &file_manager,
+        &parsed_files,
+        &workspace,
+        package,
+        &options,
+        "Loop Invariant Code Motion",
+    )
+    .expect("failed to run SSA pass");
+    assert!(position(&output.after, "mul v0, v1") < position(&output.after, "jmp b1"));
+
+    let errors = nargo::ops::run_ssa_pass(
+        &file_manager,
+        &parsed_files,
+        &workspace,
+        package,
+        &options,
+        "no_such_pass",
+    )
+    .expect_err("expected the pass not to exist");
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].message.starts_with("unknown SSA pass `no_such_pass`, expected one of:"));
+}
+
+#[test]
+fn nargo_ssa_prints_the_ssa_before_and_after_the_pass() {
+    let project_dir = write_package();
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.arg("--program-dir")
+        .arg(project_dir.path())
+        .arg("ssa")
+        .arg("--ssa-pass")
+        .arg("Loop Invariant Code Motion");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("[ssa_pass] Before Loop Invariant Code Motion:"))
+        .stdout(predicate::str::contains("[ssa_pass] After Loop Invariant Code Motion:"));
+
+    let mut cmd = Command::cargo_bin("nargo").unwrap();
+    cmd.arg("--program-dir")
+        .arg(project_dir.path())
+        .arg("ssa")
+        .arg("--ssa-pass")
+        .arg("no_such_pass");
+    cmd.assert().failure().stderr(predicate::str::contains("unknown SSA pass `no_such_pass`"));
+}
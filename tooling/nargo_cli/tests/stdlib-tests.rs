@@ -9,7 +9,7 @@ use std::io::Write;
 use std::{collections::BTreeMap, path::PathBuf};
 
 use nargo::{
-    ops::{TestStatus, report_errors, run_test},
+    ops::{TestRunOptions, TestStatus, report_errors, run_test},
     package::{Package, PackageType},
     parse_all, prepare_package,
 };
@@ -96,7 +96,11 @@ fn run_stdlib_tests(force_brillig: bool, inliner_aggressiveness: i64) {
                     &mut context,
                     &test_function,
                     std::io::stdout(),
-                    &CompileOptions { force_brillig, inliner_aggressiveness, ..Default::default() },
+                    TestRunOptions::new(&CompileOptions {
+                        force_brillig,
+                        inliner_aggressiveness,
+                        ..Default::default()
+                    }),
                     |output, base| {
                         DefaultForeignCallBuilder::default()
                             .with_output(output)
@@ -0,0 +1,55 @@
+mod common;
+
+use nargo::{
+    foreign_calls::DefaultForeignCallBuilder,
+    ops::{TestRunOptions, TestStatus, run_test},
+};
+use noirc_driver::CompileOptions;
+use noirc_frontend::hir::FunctionNameMatch;
+
+const SOURCE: &str = "
+    fn check_is_two(x: Field) {
+        assert(x == 2, \"x must be two\");
+    }
+
+    #[test]
+    fn fails_in_callee() {
+        check_is_two(std::hint::black_box(1));
+    }
+";
+
+#[test]
+fn failure_call_stack_reports_the_callee_location() {
+    let options = CompileOptions::default();
+    let (mut context, crate_id) = common::prepare_and_check_snippet(SOURCE.to_string(), &options);
+
+    let pattern = FunctionNameMatch::Exact(vec!["fails_in_callee".to_string()]);
+    let test_functions = context.get_all_test_functions_in_crate_matching(&crate_id, &pattern);
+    let (_, test_function) = test_functions.first().expect("test function should exist");
+
+    let pedantic_solving = true;
+    let status = run_test(
+        &bn254_blackbox_solver::Bn254BlackBoxSolver(pedantic_solving),
+        &mut context,
+        test_function,
+        std::io::empty(),
+        TestRunOptions::new(&options),
+        |output, base| {
+            DefaultForeignCallBuilder::default().with_output(output).build_with_base(base)
+        },
+    );
+    assert!(matches!(status, TestStatus::Fail { .. }), "{status:?}");
+
+    // The call stack goes from the call in the test function down to the assertion in the callee
+    let call_stack = status.failure_call_stack();
+    let (Some(call_site), Some(assertion)) = (call_stack.first(), call_stack.last()) else {
+        panic!("Expected the failure to have a call stack");
+    };
+    assert_eq!(call_stack.len(), 2, "{call_stack:?}");
+
+    let offset = |needle: &str| SOURCE.find(needle).unwrap() as u32;
+    let callee = offset("assert(x == 2")..offset("#[test]");
+    let test_body = offset("check_is_two(std::hint")..SOURCE.len() as u32;
+    assert!(callee.contains(&assertion.span.start()), "{assertion:?}");
+    assert!(test_body.contains(&call_site.span.start()), "{call_site:?}");
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use nargo::{
+    foreign_calls::DefaultForeignCallBuilder,
+    ops::{TestRunOptions, TestStatus, run_test},
+};
+use noirc_driver::CompileOptions;
+use noirc_frontend::hir::FunctionNameMatch;
+
+const SOURCE: &str = "
+    unconstrained fn sum_to(n: u32) -> u32 {
+        let mut sum = 0;
+        for i in 0..n {
+            sum += i;
+        }
+        sum
+    }
+
+    #[test]
+    unconstrained fn sum_to_10() {
+        assert_eq(sum_to(std::hint::black_box(10)), 45);
+    }
+
+    #[test]
+    unconstrained fn sum_to_20() {
+        assert_eq(sum_to(std::hint::black_box(20)), 190);
+    }
+
+    #[test]
+    unconstrained fn sum_to_40() {
+        assert_eq(sum_to(std::hint::black_box(40)), 780);
+    }
+";
+
+fn run_snippet_test(test_name: &str, count_solving_steps: bool) -> TestStatus {
+    let options = CompileOptions::default();
+    let (mut context, crate_id) = common::prepare_and_check_snippet(SOURCE.to_string(), &options);
+
+    let pattern = FunctionNameMatch::Exact(vec![test_name.to_string()]);
+    let test_functions = context.get_all_test_functions_in_crate_matching(&crate_id, &pattern);
+    let (_, test_function) = test_functions.first().expect("test function should exist");
+
+    let pedantic_solving = true;
+    run_test(
+        &bn254_blackbox_solver::Bn254BlackBoxSolver(pedantic_solving),
+        &mut context,
+        test_function,
+        std::io::empty(),
+        TestRunOptions { compile_options: &options, count_solving_steps, compile_count: None },
+        |output, base| {
+            DefaultForeignCallBuilder::default().with_output(output).build_with_base(base)
+        },
+    )
+}
+
+fn solving_steps(test_name: &str) -> usize {
+    match run_snippet_test(test_name, true) {
+        TestStatus::Pass { solving_steps: Some(steps) } => steps,
+        status => panic!("expected {test_name} to pass with solving steps, got {status:?}"),
+    }
+}
+
+#[test]
+fn solving_steps_are_not_reported_by_default() {
+    let status = run_snippet_test("sum_to_10", false);
+    assert!(matches!(status, TestStatus::Pass { solving_steps: None }), "{status:?}");
+}
+
+#[test]
+fn solving_steps_grow_with_loop_size() {
+    let steps = ["sum_to_10", "sum_to_20", "sum_to_40"].map(solving_steps);
+    assert!(steps[0] > 0);
+    assert!(steps[0] < steps[1] && steps[1] < steps[2], "{steps:?}");
+}
@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use acvm::FieldElement;
+use nargo::{
+    foreign_calls::DefaultForeignCallBuilder,
+    ops::{VariableValues, execute_program_with_variable_values},
+    parse_all,
+};
+use noirc_abi::input_parser::InputValue;
+use noirc_driver::{
+    CompileOptions, compile_main, file_manager_with_stdlib, link_to_debug_crate, prepare_crate,
+};
+use noirc_errors::debug_info::{DebugInfo, DebugVarId};
+use noirc_frontend::{debug::DebugInstrumenter, hir::Context};
+use noirc_printable_type::PrintableValue;
+
+/// Compiles `source` with debug instrumentation and executes it with `x` as its input,
+/// returning the debug info of the program and the values of the requested variables.
+fn execute_with_variable_values(
+    source: &str,
+    x: u32,
+    variable_names: &[&str],
+) -> (Vec<DebugInfo>, VariableValues<FieldElement>) {
+    let root = Path::new("");
+    let file_name = Path::new("main.nr");
+    let mut file_manager = file_manager_with_stdlib(root);
+    let file_id = file_manager.add_file_with_source(file_name, source.to_string()).expect(
+        "Adding source buffer to file manager should never fail when file manager is empty",
+    );
+    let mut parsed_files = parse_all(&file_manager);
+
+    // Instrument the snippet so that it reports each assignment to a variable
+    let mut debug_instrumenter = DebugInstrumenter::default();
+    let (parsed_module, _) = parsed_files.get_mut(&file_id).expect("snippet should be parsed");
+    debug_instrumenter.instrument_module(parsed_module, file_id);
+
+    let mut context = Context::new(file_manager, parsed_files);
+    let crate_id = prepare_crate(&mut context, file_name);
+    link_to_debug_crate(&mut context, crate_id);
+    context.debug_instrumenter = debug_instrumenter;
+
+    let options =
+        CompileOptions { instrument_debug: true, force_brillig: true, ..Default::default() };
+    let (program, _) =
+        compile_main(&mut context, crate_id, &options, None).expect("failed to compile program");
+
+    let inputs = BTreeMap::from([("x".to_string(), InputValue::Field(x.into()))]);
+    let initial_witness = program.abi.encode(&inputs, None).expect("failed to encode");
+
+    let pedantic_solving = true;
+    let variable_names: Vec<String> = variable_names.iter().map(|name| name.to_string()).collect();
+    let (_, values) = execute_program_with_variable_values(
+        &program.program,
+        initial_witness,
+        &bn254_blackbox_solver::Bn254BlackBoxSolver(pedantic_solving),
+        &mut DefaultForeignCallBuilder::default().build(),
+        &program.debug,
+        &variable_names,
+    )
+    .expect("failed to execute program");
+    (program.debug, values)
+}
+
+/// Returns the IDs of the variables with the given name.
+fn variable_ids(debug: &[DebugInfo], name: &str) -> Vec<DebugVarId> {
+    debug
+        .iter()
+        .flat_map(|debug_info| &debug_info.variables)
+        .filter(|(_, variable)| variable.name == name)
+        .map(|(id, _)| *id)
+        .collect()
+}
+
+fn field(value: u128) -> PrintableValue<FieldElement> {
+    PrintableValue::Field(FieldElement::from(value))
+}
+
+#[test]
+fn returns_values_of_requested_variables() {
+    let source = "
+    fn main(x: Field) -> pub Field {
+        let doubled = x * 2;
+        let result = doubled + 1;
+        result
+    }";
+    let (debug, values) = execute_with_variable_values(source, 3, &["doubled"]);
+
+    let [doubled] = variable_ids(&debug, "doubled")[..] else {
+        panic!("expected a single `doubled` variable");
+    };
+    let expected = BTreeMap::from([(doubled, vec![field(6)])]);
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn tells_apart_variables_with_the_same_name() {
+    let source = "
+    fn main(x: Field) -> pub Field {
+        let total = {
+            let scaled = x * 3;
+            scaled
+        };
+        let scaled = total + 1;
+        scaled
+    }";
+    let (debug, values) = execute_with_variable_values(source, 3, &["scaled"]);
+
+    let ids = variable_ids(&debug, "scaled");
+    assert_eq!(ids.len(), 2);
+    let scaled_values: Vec<_> = ids.iter().map(|id| values[id].clone()).collect();
+    assert!(scaled_values.contains(&vec![field(9)]));
+    assert!(scaled_values.contains(&vec![field(10)]));
+}
+
+#[test]
+fn records_assignments_to_members_of_variables() {
+    let source = "
+    struct Point {
+        x: Field,
+        y: Field,
+    }
+
+    fn main(x: Field) -> pub Field {
+        let mut point = Point { x, y: 0 };
+        point.y = x + 1;
+        let mut pair = [0, 0];
+        pair[1] = x;
+        point.y + pair[1]
+    }";
+    let (debug, values) = execute_with_variable_values(source, 3, &["point", "pair"]);
+
+    let [point] = variable_ids(&debug, "point")[..] else {
+        panic!("expected a single `point` variable");
+    };
+    let point_value = |y| {
+        PrintableValue::Struct(BTreeMap::from([
+            ("x".to_string(), field(3)),
+            ("y".to_string(), field(y)),
+        ]))
+    };
+    assert_eq!(values[&point], vec![point_value(0), point_value(4)]);
+
+    let [pair] = variable_ids(&debug, "pair")[..] else {
+        panic!("expected a single `pair` variable");
+    };
+    let pair_value = |second| PrintableValue::Vec {
+        array_elements: vec![field(0), field(second)],
+        is_slice: false,
+    };
+    assert_eq!(values[&pair], vec![pair_value(0), pair_value(3)]);
+}
@@ -0,0 +1,34 @@
+mod common;
+
+use noirc_driver::{CompileOptions, estimate_witness_counts};
+
+const SOURCE: &str = "
+    fn main(x: Field, y: Field, z: pub Field) {
+        let mut acc = x;
+        for _ in 0..10 {
+            acc = acc * y;
+        }
+        assert(acc == z);
+    }
+";
+
+#[test]
+fn witness_estimate_is_close_to_compiled_witness_count() {
+    let options = CompileOptions::default();
+    let (mut context, crate_id) = common::prepare_and_check_snippet(SOURCE.to_string(), &options);
+
+    let main = context.get_main_function(&crate_id).expect("snippet should have a main function");
+    let estimates = estimate_witness_counts(&mut context, &options, main)
+        .expect("failed to estimate witness counts");
+    let estimate = estimates["main"];
+
+    let (program, _) = common::prepare_and_compile_snippet(SOURCE.to_string(), false)
+        .expect("failed to compile snippet");
+    let actual = program.program.functions[0].current_witness_index as usize + 1;
+
+    // The estimate is a heuristic, so it is only expected to be within 50% of the actual count
+    assert!(
+        estimate.abs_diff(actual) * 2 <= actual,
+        "estimated {estimate} witnesses but the compiled program has {actual}"
+    );
+}
@@ -7,6 +7,7 @@ use super::Formatter;
 
 impl Formatter<'_> {
     pub(super) fn format_type_alias(&mut self, type_alias: NoirTypeAlias) {
+        self.format_secondary_attributes(type_alias.attributes);
         self.write_indentation();
         self.format_item_visibility(type_alias.visibility);
         self.write_keyword(Keyword::Type);
@@ -38,4 +39,11 @@ mod tests {
         let expected = "pub type Foo<A, B> = i32;\n";
         assert_format(src, expected);
     }
+
+    #[test]
+    fn format_type_alias_with_attribute() {
+        let src = " #[cfg( feature  =  \"foo\" )]  type  Foo  =   i32  ; ";
+        let expected = "#[cfg(feature = \"foo\")]\ntype Foo = i32;\n";
+        assert_format(src, expected);
+    }
 }
@@ -82,7 +82,8 @@ impl Formatter<'_> {
             SecondaryAttribute::ContractLibraryMethod
             | SecondaryAttribute::Export
             | SecondaryAttribute::Varargs
-            | SecondaryAttribute::UseCallersScope => {
+            | SecondaryAttribute::UseCallersScope
+            | SecondaryAttribute::NonExhaustive => {
                 self.format_no_args_attribute();
             }
             SecondaryAttribute::Field(_)
@@ -96,6 +97,9 @@ impl Formatter<'_> {
             SecondaryAttribute::Meta(meta_attribute) => {
                 self.format_meta_attribute(meta_attribute);
             }
+            SecondaryAttribute::Cfg(_) => {
+                self.format_cfg_attribute();
+            }
         }
 
         self.write_line();
@@ -116,7 +120,36 @@ impl Formatter<'_> {
         self.write_right_bracket(); // ]
     }
 
+    fn format_cfg_attribute(&mut self) {
+        self.write_current_token_and_bump(); // #[
+        self.skip_comments_and_whitespace();
+        self.write_current_token_and_bump(); // cfg
+        self.write_left_paren(); // (
+        self.skip_comments_and_whitespace();
+        self.write_current_token_and_bump(); // feature
+        self.write_space();
+        self.write_token(Token::Assign);
+        self.write_space();
+        self.skip_comments_and_whitespace();
+        self.write_current_token_and_bump(); // "name"
+        self.write_right_paren(); // )
+        self.write_right_bracket(); // ]
+    }
+
     fn format_test_attribute(&mut self, test_scope: TestScope) {
+        if let TestScope::Generic { instantiations } = test_scope {
+            // Each instantiation comes from its own `#[test(...)]` attribute
+            for (index, bindings) in instantiations.iter().enumerate() {
+                if index > 0 {
+                    self.write_line();
+                    self.format_outer_doc_comments();
+                    self.write_indentation();
+                }
+                self.format_generic_test_attribute(bindings.len());
+            }
+            return;
+        }
+
         self.write_current_token_and_bump(); // #[
         self.skip_comments_and_whitespace();
         self.write_current_token_and_bump(); // test
@@ -145,6 +178,32 @@ impl Formatter<'_> {
         self.write_right_bracket(); // ]
     }
 
+    fn format_generic_test_attribute(&mut self, bindings: usize) {
+        self.write_current_token_and_bump(); // #[
+        self.skip_comments_and_whitespace();
+        self.write_current_token_and_bump(); // test
+        self.write_left_paren(); // (
+        for index in 0..bindings {
+            if index > 0 {
+                self.write_comma();
+                self.write_space();
+            }
+            self.skip_comments_and_whitespace();
+            self.write_current_token_and_bump(); // N
+            self.write_space();
+            self.write_token(Token::Assign);
+            self.write_space();
+            self.skip_comments_and_whitespace();
+            self.write_current_token_and_bump(); // 8
+        }
+        self.skip_comments_and_whitespace();
+        if self.is_at(Token::Comma) {
+            self.bump();
+        }
+        self.write_right_paren(); // )
+        self.write_right_bracket(); // ]
+    }
+
     fn format_fuzz_attribute(&mut self, fuzz_scope: FuzzingScope) {
         self.write_current_token_and_bump(); // #[
         self.skip_comments_and_whitespace();
@@ -295,6 +354,13 @@ mod tests {
         assert_format_attribute(src, expected);
     }
 
+    #[test]
+    fn format_cfg_attribute() {
+        let src = "  #[ cfg ( feature  =  \"foo\" ) ] ";
+        let expected = "#[cfg(feature = \"foo\")]";
+        assert_format_attribute(src, expected);
+    }
+
     #[test]
     fn format_allow_attribute() {
         let src = "  #[ allow ( unused_vars ) ] ";
@@ -365,6 +431,13 @@ mod tests {
         assert_format_attribute(src, expected);
     }
 
+    #[test]
+    fn format_generic_test_attributes() {
+        let src = "  #[ test ( N=8,M = 2, )] #[test(N=16)] ";
+        let expected = "#[test(N = 8, M = 2)]\n#[test(N = 16)]";
+        assert_format_attribute(src, expected);
+    }
+
     #[test]
     fn format_fuzz_attribute() {
         let src = "  #[ fuzz ] ";
@@ -76,6 +76,15 @@ impl ChunkFormatter<'_, '_> {
             ExpressionKind::Match(match_expression) => {
                 group.group(self.format_match_expression(*match_expression));
             }
+            ExpressionKind::Range(range) => {
+                self.format_expression(range.start, group);
+                group.text(self.chunk(|formatter| {
+                    formatter.skip_comments_and_whitespace();
+                    formatter.write_current_token();
+                    formatter.bump();
+                }));
+                self.format_expression(range.end, group);
+            }
             ExpressionKind::Variable(path) => {
                 group.text(self.chunk(|formatter| {
                     formatter.format_path(path);
@@ -2489,6 +2498,19 @@ global y = 1;
         assert_format(src, expected);
     }
 
+    #[test]
+    fn format_match_with_range_patterns() {
+        let src = "fn main() {  match  x  {  0 .. 10=>A,10..= 19  =>  B,_=>C  } }";
+        let expected = "fn main() {
+    match x {
+        0..10 => A,
+        10..=19 => B,
+        _ => C,
+    }
+}\n";
+        assert_format(src, expected);
+    }
+
     #[test]
     fn format_last_assignment_in_block_without_semicolon() {
         let src = "fn main() {
@@ -53,8 +53,12 @@ impl ChunkFormatter<'_, '_> {
 
                 *pattern
             }
-            Pattern::Tuple(..) | Pattern::Struct(..) | Pattern::Interned(..) => {
-                unreachable!("Global pattern cannot be a tuple, struct or interned")
+            Pattern::Tuple(..)
+            | Pattern::Struct(..)
+            | Pattern::TupleStruct(..)
+            | Pattern::Typed(..)
+            | Pattern::Interned(..) => {
+                unreachable!("Global pattern cannot be a tuple, struct, typed or interned")
             }
         };
 
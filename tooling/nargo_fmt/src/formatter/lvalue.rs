@@ -29,6 +29,27 @@ impl Formatter<'_> {
                 self.write_token(Token::Star);
                 self.format_lvalue(*lvalue);
             }
+            LValue::Tuple(lvalues, _location) => {
+                // The tuple's parentheses are written together with the parentheses around it
+                let lvalues_len = lvalues.len();
+                for (index, lvalue) in lvalues.into_iter().enumerate() {
+                    if index > 0 {
+                        self.write_comma();
+                        self.write_space();
+                    }
+                    self.format_lvalue(lvalue);
+                }
+
+                // Check for trailing comma
+                self.skip_comments_and_whitespace();
+                if self.is_at(Token::Comma) {
+                    if lvalues_len == 1 {
+                        self.write_comma();
+                    } else {
+                        self.bump();
+                    }
+                }
+            }
             LValue::Interned(..) => {
                 unreachable!("Should not be present in the AST")
             }
@@ -48,7 +48,26 @@ impl Formatter<'_> {
 
                 self.write_right_paren();
             }
-            Pattern::Struct(path, fields, _span) => {
+            Pattern::TupleStruct(path, patterns, _span) => {
+                self.format_path(path);
+                self.write_left_paren();
+                for (index, pattern) in patterns.into_iter().enumerate() {
+                    if index > 0 {
+                        self.write_comma();
+                        self.write_space();
+                    }
+                    self.format_pattern(pattern);
+                }
+
+                // Remove trailing comma
+                self.skip_comments_and_whitespace();
+                if self.is_at(Token::Comma) {
+                    self.bump();
+                }
+
+                self.write_right_paren();
+            }
+            Pattern::Struct(path, fields, _span, has_rest) => {
                 let mut group = ChunkGroup::new();
 
                 group.text(self.chunk_formatter().chunk(|formatter| {
@@ -57,20 +76,33 @@ impl Formatter<'_> {
                     formatter.write_left_brace();
                 }));
 
-                if fields.is_empty() {
+                if fields.is_empty() && !has_rest {
                     if let Some(inner_group) = self.chunk_formatter().empty_block_contents_chunk() {
                         group.group(inner_group);
                     }
                 } else {
+                    // A `..` rest pattern is formatted as a last item without a field
+                    let items = fields.into_iter().map(Some).chain(has_rest.then_some(None));
                     self.chunk_formatter().format_items_separated_by_comma(
-                        fields,
+                        items.collect(),
                         false, // force trailing comma,
                         true,  // surround with spaces
                         &mut group,
-                        |formatter, (name, pattern), chunks| {
+                        |formatter, field, chunks| {
+                            let Some((name, pattern)) = field else {
+                                chunks.text(formatter.chunk(|formatter| {
+                                    formatter.write_token(Token::DoubleDot);
+                                }));
+                                return;
+                            };
                             let is_identifier_pattern = is_identifier_pattern(&pattern, &name);
 
                             chunks.text(formatter.chunk(|formatter| {
+                                // A `mut field` shorthand
+                                if formatter.is_at_keyword(Keyword::Mut) {
+                                    formatter.write_keyword(Keyword::Mut);
+                                    formatter.write_space();
+                                }
                                 formatter.write_identifier(name);
                                 formatter.skip_comments_and_whitespace();
                             }));
@@ -94,6 +126,12 @@ impl Formatter<'_> {
 
                 self.format_chunk_group(group);
             }
+            Pattern::Typed(pattern, typ) => {
+                self.format_pattern(*pattern);
+                self.write_token(Token::Colon);
+                self.write_space();
+                self.format_type(typ);
+            }
             Pattern::Interned(..) => {
                 unreachable!("Should not be present in the AST")
             }
@@ -165,6 +203,27 @@ mod tests {
         assert_format(src, expected);
     }
 
+    #[test]
+    fn format_struct_pattern_with_rest() {
+        let src = "fn foo( Foo { x  ,  .. } : i32) {}";
+        let expected = "fn foo(Foo { x, .. }: i32) {}\n";
+        assert_format(src, expected);
+    }
+
+    #[test]
+    fn format_struct_pattern_with_mut_field() {
+        let src = "fn foo( Foo { mut  x  , y : y } : i32) {}";
+        let expected = "fn foo(Foo { mut x, y }: i32) {}\n";
+        assert_format(src, expected);
+    }
+
+    #[test]
+    fn format_tuple_struct_pattern() {
+        let src = "fn foo( Foo ( x , mut y , ) : Foo) {}";
+        let expected = "fn foo(Foo(x, mut y): Foo) {}\n";
+        assert_format(src, expected);
+    }
+
     #[test]
     fn format_struct_pattern_that_exceeds_max_width() {
         let src = "
@@ -23,6 +23,31 @@ impl Formatter<'_> {
             return;
         }
 
+        // A case like `struct Foo(Field, u8);`
+        if self.is_at(Token::LeftParen) {
+            self.write_left_paren();
+            for (index, documented_field) in noir_struct.fields.into_iter().enumerate() {
+                if index > 0 {
+                    self.write_comma();
+                    self.write_space();
+                }
+
+                let field = documented_field.item;
+                self.format_item_visibility(field.visibility);
+                self.format_type(field.typ);
+            }
+
+            // Remove trailing comma
+            self.skip_comments_and_whitespace();
+            if self.is_at(Token::Comma) {
+                self.bump();
+            }
+
+            self.write_right_paren();
+            self.write_semicolon();
+            return;
+        }
+
         // A case like `struct Foo { ... }`
         self.write_space();
         self.write_left_brace();
@@ -106,6 +131,16 @@ mod tests {
         assert_format(src, expected);
     }
 
+    #[test]
+    fn format_tuple_struct() {
+        let src = " mod moo { struct Foo ( pub  Field , u8 , ) ; }";
+        let expected = "mod moo {
+    struct Foo(pub Field, u8);
+}
+";
+        assert_format(src, expected);
+    }
+
     #[test]
     fn format_struct_with_fields() {
         let src = " mod moo { struct Foo { 
@@ -108,6 +108,9 @@ impl ContractFunctionArtifact {
             warnings: Vec::new(),
             names: self.names,
             brillig_names: self.brillig_names,
+            pass_statistics: None,
+            loop_report: None,
+            source_map: None,
         }
     }
 }
@@ -6,6 +6,9 @@ use fm::FileId;
 use noirc_abi::Abi;
 use noirc_driver::CompiledProgram;
 use noirc_driver::DebugFile;
+use noirc_driver::LoopReport;
+use noirc_driver::PassStatistics;
+use noirc_driver::SourceMap;
 use noirc_errors::debug_info::ProgramDebugInfo;
 use serde::{Deserialize, Serialize};
 
@@ -41,6 +44,18 @@ pub struct ProgramArtifact {
     pub names: Vec<String>,
     /// Names of the unconstrained functions in the program.
     pub brillig_names: Vec<String>,
+
+    /// Statistics about the SSA optimization passes, only present when compiling with `--pass-statistics`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pass_statistics: Option<PassStatistics>,
+
+    /// How each loop was optimized, only present when compiling with `--loop-report`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loop_report: Option<LoopReport>,
+
+    /// Source locations of each ACIR opcode, only present when compiling with `--source-map`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_map: Option<SourceMap>,
 }
 
 impl From<CompiledProgram> for ProgramArtifact {
@@ -54,6 +69,9 @@ impl From<CompiledProgram> for ProgramArtifact {
             file_map: compiled_program.file_map,
             names: compiled_program.names,
             brillig_names: compiled_program.brillig_names,
+            pass_statistics: compiled_program.pass_statistics,
+            loop_report: compiled_program.loop_report,
+            source_map: compiled_program.source_map,
         }
     }
 }
@@ -70,6 +88,9 @@ impl From<ProgramArtifact> for CompiledProgram {
             warnings: vec![],
             names: program.names,
             brillig_names: program.brillig_names,
+            pass_statistics: program.pass_statistics,
+            loop_report: program.loop_report,
+            source_map: program.source_map,
         }
     }
 }
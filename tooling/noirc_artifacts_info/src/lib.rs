@@ -1,6 +1,7 @@
 use acvm::acir::circuit::ExpressionWidth;
 use iter_extended::vecmap;
 use noirc_artifacts::program::ProgramArtifact;
+use noirc_driver::LoopReport;
 use prettytable::{Row, row, table};
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
 use serde::Serialize;
@@ -19,6 +20,8 @@ pub struct ProgramInfo {
     #[serde(skip)]
     pub unconstrained_functions_opcodes: usize,
     pub unconstrained_functions: Vec<FunctionInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loop_report: Option<LoopReport>,
 }
 
 impl From<ProgramInfo> for Vec<Row> {
@@ -98,6 +101,7 @@ pub fn count_opcodes_and_gates_in_program(
         functions,
         unconstrained_functions_opcodes,
         unconstrained_functions: unconstrained_info,
+        loop_report: compiled_program.loop_report,
     }
 }
 
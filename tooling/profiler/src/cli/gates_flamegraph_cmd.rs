@@ -190,6 +190,9 @@ mod tests {
             file_map: BTreeMap::default(),
             names: vec!["main".to_string()],
             brillig_names: Vec::new(),
+            pass_statistics: None,
+            loop_report: None,
+            source_map: None,
         };
 
         // Write the artifact to a file
@@ -205,6 +205,9 @@ mod tests {
             file_map: BTreeMap::default(),
             names: vec!["main".to_string()],
             brillig_names: Vec::new(),
+            pass_statistics: None,
+            loop_report: None,
+            source_map: None,
         };
 
         // Write the artifact to a file
@@ -264,6 +267,9 @@ mod tests {
             file_map: BTreeMap::default(),
             names: vec!["main".to_string()],
             brillig_names: vec!["main".to_string(), "main".to_string(), "main_1".to_string()],
+            pass_statistics: None,
+            loop_report: None,
+            source_map: None,
         };
 
         // Write the artifact to a file